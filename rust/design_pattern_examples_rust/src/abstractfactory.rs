@@ -0,0 +1,68 @@
+//! The Abstract Factory design pattern example module
+//!
+//! The Abstract Factory pattern provides a way to create families of related
+//! objects without specifying the concrete types of those objects.  The
+//! program picks one concrete factory and from then on works only with the
+//! traits that represent the factory and the objects it creates.
+//!
+//! In this exercise, a widget factory creates the border, fill, and title
+//! widgets used to draw a window in the console.  Each concrete factory
+//! produces a family of widgets that share the same visual theme.  The
+//! concrete factory is selected at runtime by name and the same window
+//! drawing code is used for every theme.
+//!
+//! Accessed through the abstractfactory_exercise() function.
+
+//-----------------------------------------------------------------------------
+
+pub mod abstractfactory_iwidgets_trait;
+pub mod abstractfactory_iwidgetfactory_trait;
+pub mod abstractfactory_themes;
+pub mod abstractfactory_window;
+
+//-----------------------------------------------------------------------------
+
+use abstractfactory_themes::{create_widget_factory, THEME_NAMES};
+use abstractfactory_window::ThemedWindow;
+
+//-----------------------------------------------------------------------------
+
+/// Example of using the "Abstract Factory" design pattern.
+///
+/// The Abstract Factory pattern provides a way to create families of related
+/// objects without specifying the concrete types of those objects.  The
+/// program picks one concrete factory and from then on works only with the
+/// traits that represent the factory and the objects it creates.
+///
+/// In this exercise, a widget factory creates the border, fill, and title
+/// widgets used to draw a window in the console.  Each concrete factory
+/// produces a family of widgets that share the same visual theme.  The
+/// concrete factory is selected at runtime by name and the same window
+/// drawing code is used for every theme.
+// ! [Using AbstractFactory in Rust]
+pub fn abstractfactory_exercise() -> Result<(), String> {
+    println!();
+    println!("AbstractFactory Exercise");
+
+    // The theme names could just as easily come from a configuration file or
+    // the command line; the window drawing code never changes.
+    for theme_name in THEME_NAMES {
+        let widget_factory = create_widget_factory(theme_name)?;
+        println!("  Drawing a window using the {} theme:", widget_factory.get_theme_name());
+        let window = ThemedWindow::new(widget_factory.as_ref(), "Status", 24, 3);
+        for line in window.render() {
+            println!("    {line}");
+        }
+    }
+
+    println!("  Asking for a theme that does not exist:");
+    match create_widget_factory("plaid") {
+        Ok(_) => println!("    Unexpectedly created a factory for the plaid theme!"),
+        Err(message) => println!("    {message}"),
+    }
+
+    println!("  Done.");
+
+    Ok(())
+}
+// ! [Using AbstractFactory in Rust]
@@ -0,0 +1,25 @@
+//! Contains the IWidgetFactory trait that represents the abstract factory in
+//! the "Abstract Factory" design pattern example.
+
+//-----------------------------------------------------------------------------
+
+use super::abstractfactory_iwidgets_trait::{IBorder, IFill, ITitle};
+
+//-----------------------------------------------------------------------------
+
+/// Represents a factory that creates a family of widgets that all share the
+/// same visual theme.  The program asks the factory for each widget and never
+/// knows which concrete widgets it is given.
+pub trait IWidgetFactory {
+    /// Retrieve the name of the theme produced by this factory.
+    fn get_theme_name(&self) -> &str;
+
+    /// Create the border widget for this theme.
+    fn create_border(&self) -> Box<dyn IBorder>;
+
+    /// Create the fill widget for this theme.
+    fn create_fill(&self) -> Box<dyn IFill>;
+
+    /// Create the title widget for this theme.
+    fn create_title(&self) -> Box<dyn ITitle>;
+}
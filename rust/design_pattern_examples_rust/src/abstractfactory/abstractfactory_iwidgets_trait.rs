@@ -0,0 +1,66 @@
+//! Contains the IBorder, IFill, and ITitle traits that represent the family
+//! of widgets created by a widget factory in the "Abstract Factory" design
+//! pattern example.
+
+/// Represents the border drawn around a window.
+pub trait IBorder {
+    /// Render the top edge of the border, including the corners.
+    ///
+    /// # Parameters
+    /// - inner_width
+    ///
+    ///   Number of characters between the left and right corners.
+    ///
+    /// # Returns
+    /// Returns a string containing the top edge.
+    fn render_top(&self, inner_width: usize) -> String;
+
+    /// Render the bottom edge of the border, including the corners.
+    ///
+    /// # Parameters
+    /// - inner_width
+    ///
+    ///   Number of characters between the left and right corners.
+    ///
+    /// # Returns
+    /// Returns a string containing the bottom edge.
+    fn render_bottom(&self, inner_width: usize) -> String;
+
+    /// Retrieve the character used for the left and right edges.
+    fn side(&self) -> char;
+}
+
+
+/// Represents the fill used for the interior of a window.
+pub trait IFill {
+    /// Render one row of the interior of a window.
+    ///
+    /// # Parameters
+    /// - inner_width
+    ///
+    ///   Number of characters in the row.
+    ///
+    /// # Returns
+    /// Returns a string containing the row.
+    fn render_row(&self, inner_width: usize) -> String;
+}
+
+
+/// Represents the title shown at the top of a window.
+pub trait ITitle {
+    /// Render the title so it fits in the given width.  The title is
+    /// truncated if it is too long.
+    ///
+    /// # Parameters
+    /// - title
+    ///
+    ///   The text of the title.
+    /// - inner_width
+    ///
+    ///   Number of characters available for the title.
+    ///
+    /// # Returns
+    /// Returns a string containing the title, exactly `inner_width`
+    /// characters long.
+    fn render(&self, title: &str, inner_width: usize) -> String;
+}
@@ -0,0 +1,280 @@
+//! Contains the concrete widgets and the concrete widget factories for each
+//! of the themes, along with the create_widget_factory() function that
+//! selects a concrete factory by name at runtime.
+
+//-----------------------------------------------------------------------------
+
+use super::abstractfactory_iwidgets_trait::{IBorder, IFill, ITitle};
+use super::abstractfactory_iwidgetfactory_trait::IWidgetFactory;
+
+//-----------------------------------------------------------------------------
+
+/// Names of the themes that can be given to create_widget_factory().
+pub const THEME_NAMES: [&str; 3] = ["classic", "double", "rounded"];
+
+
+/// Helper function to truncate the given text to a maximum number of
+/// characters.
+///
+/// # Parameters
+/// - text
+///
+///   The text to truncate.
+/// - max_length
+///
+///   The maximum number of characters to keep.
+///
+/// # Returns
+/// Returns a new string containing at most `max_length` characters.
+fn truncate(text: &str, max_length: usize) -> String {
+    text.chars().take(max_length).collect()
+}
+
+//=============================================================================
+// Classic theme widgets: plain ASCII.
+//=============================================================================
+
+/// Represents a border drawn with plain ASCII characters.
+struct AsciiBorder {}
+
+impl IBorder for AsciiBorder {
+    fn render_top(&self, inner_width: usize) -> String {
+        format!("+{}+", "-".repeat(inner_width))
+    }
+
+    fn render_bottom(&self, inner_width: usize) -> String {
+        self.render_top(inner_width)
+    }
+
+    fn side(&self) -> char {
+        '|'
+    }
+}
+
+
+/// Represents a window interior filled with spaces.
+struct BlankFill {}
+
+impl IFill for BlankFill {
+    fn render_row(&self, inner_width: usize) -> String {
+        " ".repeat(inner_width)
+    }
+}
+
+
+/// Represents a title aligned to the left side of the window.
+struct LeftAlignedTitle {}
+
+impl ITitle for LeftAlignedTitle {
+    fn render(&self, title: &str, inner_width: usize) -> String {
+        format!("{:<inner_width$}", truncate(title, inner_width))
+    }
+}
+
+
+/// Represents the factory for the classic theme.
+pub struct ClassicWidgetFactory {}
+
+impl ClassicWidgetFactory {
+    /// Constructor.
+    ///
+    /// # Returns
+    /// Returns a new instance of the ClassicWidgetFactory struct as
+    /// represented by the IWidgetFactory trait.
+    pub fn new() -> Box<dyn IWidgetFactory> {
+        Box::new(ClassicWidgetFactory {})
+    }
+}
+
+impl IWidgetFactory for ClassicWidgetFactory {
+    fn get_theme_name(&self) -> &str {
+        "classic"
+    }
+
+    fn create_border(&self) -> Box<dyn IBorder> {
+        Box::new(AsciiBorder {})
+    }
+
+    fn create_fill(&self) -> Box<dyn IFill> {
+        Box::new(BlankFill {})
+    }
+
+    fn create_title(&self) -> Box<dyn ITitle> {
+        Box::new(LeftAlignedTitle {})
+    }
+}
+
+//=============================================================================
+// Double theme widgets: double line box drawing characters.
+//=============================================================================
+
+/// Represents a border drawn with double line box drawing characters.
+struct DoubleLineBorder {}
+
+impl IBorder for DoubleLineBorder {
+    fn render_top(&self, inner_width: usize) -> String {
+        format!("\u{2554}{}\u{2557}", "\u{2550}".repeat(inner_width))
+    }
+
+    fn render_bottom(&self, inner_width: usize) -> String {
+        format!("\u{255a}{}\u{255d}", "\u{2550}".repeat(inner_width))
+    }
+
+    fn side(&self) -> char {
+        '\u{2551}'
+    }
+}
+
+
+/// Represents a window interior filled with a light shade.
+struct ShadedFill {}
+
+impl IFill for ShadedFill {
+    fn render_row(&self, inner_width: usize) -> String {
+        "\u{2591}".repeat(inner_width)
+    }
+}
+
+
+/// Represents a title centered in the window and shown in uppercase.
+struct CenteredTitle {}
+
+impl ITitle for CenteredTitle {
+    fn render(&self, title: &str, inner_width: usize) -> String {
+        format!("{:^inner_width$}", truncate(&title.to_uppercase(), inner_width))
+    }
+}
+
+
+/// Represents the factory for the double line theme.
+pub struct DoubleWidgetFactory {}
+
+impl DoubleWidgetFactory {
+    /// Constructor.
+    ///
+    /// # Returns
+    /// Returns a new instance of the DoubleWidgetFactory struct as
+    /// represented by the IWidgetFactory trait.
+    pub fn new() -> Box<dyn IWidgetFactory> {
+        Box::new(DoubleWidgetFactory {})
+    }
+}
+
+impl IWidgetFactory for DoubleWidgetFactory {
+    fn get_theme_name(&self) -> &str {
+        "double"
+    }
+
+    fn create_border(&self) -> Box<dyn IBorder> {
+        Box::new(DoubleLineBorder {})
+    }
+
+    fn create_fill(&self) -> Box<dyn IFill> {
+        Box::new(ShadedFill {})
+    }
+
+    fn create_title(&self) -> Box<dyn ITitle> {
+        Box::new(CenteredTitle {})
+    }
+}
+
+//=============================================================================
+// Rounded theme widgets: light line box drawing characters with rounded
+// corners.
+//=============================================================================
+
+/// Represents a border drawn with light line box drawing characters and
+/// rounded corners.
+struct RoundedBorder {}
+
+impl IBorder for RoundedBorder {
+    fn render_top(&self, inner_width: usize) -> String {
+        format!("\u{256d}{}\u{256e}", "\u{2500}".repeat(inner_width))
+    }
+
+    fn render_bottom(&self, inner_width: usize) -> String {
+        format!("\u{2570}{}\u{256f}", "\u{2500}".repeat(inner_width))
+    }
+
+    fn side(&self) -> char {
+        '\u{2502}'
+    }
+}
+
+
+/// Represents a window interior filled with dots.
+struct DottedFill {}
+
+impl IFill for DottedFill {
+    fn render_row(&self, inner_width: usize) -> String {
+        ".".repeat(inner_width)
+    }
+}
+
+
+/// Represents a title aligned to the right side of the window and
+/// surrounded by angle brackets.
+struct RightAlignedTitle {}
+
+impl ITitle for RightAlignedTitle {
+    fn render(&self, title: &str, inner_width: usize) -> String {
+        let decorated_title = format!("< {title} >");
+        format!("{:>inner_width$}", truncate(&decorated_title, inner_width))
+    }
+}
+
+
+/// Represents the factory for the rounded theme.
+pub struct RoundedWidgetFactory {}
+
+impl RoundedWidgetFactory {
+    /// Constructor.
+    ///
+    /// # Returns
+    /// Returns a new instance of the RoundedWidgetFactory struct as
+    /// represented by the IWidgetFactory trait.
+    pub fn new() -> Box<dyn IWidgetFactory> {
+        Box::new(RoundedWidgetFactory {})
+    }
+}
+
+impl IWidgetFactory for RoundedWidgetFactory {
+    fn get_theme_name(&self) -> &str {
+        "rounded"
+    }
+
+    fn create_border(&self) -> Box<dyn IBorder> {
+        Box::new(RoundedBorder {})
+    }
+
+    fn create_fill(&self) -> Box<dyn IFill> {
+        Box::new(DottedFill {})
+    }
+
+    fn create_title(&self) -> Box<dyn ITitle> {
+        Box::new(RightAlignedTitle {})
+    }
+}
+
+//#############################################################################
+//#############################################################################
+
+/// Factory function that selects a concrete widget factory by the name of
+/// its theme.
+///
+/// # Parameters
+/// - theme_name
+///
+///   Name of the theme (one of the names in THEME_NAMES).
+///
+/// # Returns
+/// Returns `Ok(Box<dyn IWidgetFactory>)` if the theme is known; otherwise,
+/// returns `Err(String)` describing the problem.
+pub fn create_widget_factory(theme_name: &str) -> Result<Box<dyn IWidgetFactory>, String> {
+    match theme_name {
+        "classic" => Ok(ClassicWidgetFactory::new()),
+        "double" => Ok(DoubleWidgetFactory::new()),
+        "rounded" => Ok(RoundedWidgetFactory::new()),
+        _ => Err(format!("Unknown theme '{theme_name}'; expected one of {}", THEME_NAMES.join(", "))),
+    }
+}
@@ -0,0 +1,75 @@
+//! Contains the ThemedWindow struct that draws a window using the widgets
+//! created by a widget factory.
+
+//-----------------------------------------------------------------------------
+
+use super::abstractfactory_iwidgets_trait::{IBorder, IFill, ITitle};
+use super::abstractfactory_iwidgetfactory_trait::IWidgetFactory;
+
+//-----------------------------------------------------------------------------
+
+/// Represents a window drawn in the console.  The window does not know which
+/// theme it is drawn in; it only knows the widgets it was given by the
+/// widget factory.
+pub struct ThemedWindow {
+    /// The title shown at the top of the window.
+    title: String,
+    /// Width of the interior of the window, in characters.
+    inner_width: usize,
+    /// Height of the interior of the window, in rows (not counting the
+    /// title row).
+    inner_height: usize,
+    /// The border widget.
+    border: Box<dyn IBorder>,
+    /// The fill widget.
+    fill: Box<dyn IFill>,
+    /// The title widget.
+    title_widget: Box<dyn ITitle>,
+}
+
+impl ThemedWindow {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - widget_factory
+    ///
+    ///   The factory used to create the widgets for the window.
+    /// - title
+    ///
+    ///   The title of the window.
+    /// - inner_width
+    ///
+    ///   Width of the interior of the window, in characters.
+    /// - inner_height
+    ///
+    ///   Height of the interior of the window, in rows (not counting the
+    ///   title row).
+    ///
+    /// # Returns
+    /// Returns a new instance of the ThemedWindow struct.
+    pub fn new(widget_factory: &dyn IWidgetFactory, title: &str, inner_width: usize, inner_height: usize) -> ThemedWindow {
+        ThemedWindow {
+            title: title.to_string(),
+            inner_width,
+            inner_height,
+            border: widget_factory.create_border(),
+            fill: widget_factory.create_fill(),
+            title_widget: widget_factory.create_title(),
+        }
+    }
+
+    /// Render the window as a list of lines of text.
+    ///
+    /// # Returns
+    /// Returns a list of strings, one for each row of the window.
+    pub fn render(&self) -> Vec<String> {
+        let side = self.border.side();
+        let mut lines = vec![self.border.render_top(self.inner_width)];
+        lines.push(format!("{side}{}{side}", self.title_widget.render(&self.title, self.inner_width)));
+        for _ in 0..self.inner_height {
+            lines.push(format!("{side}{}{side}", self.fill.render_row(self.inner_width)));
+        }
+        lines.push(self.border.render_bottom(self.inner_width));
+        lines
+    }
+}
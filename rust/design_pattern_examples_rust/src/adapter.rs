@@ -6,55 +6,162 @@
 //! 
 //! The DataReaderWriter struct (in the adapter_functions.rs module) translates
 //! the 32-bit chunk access into arrays of bytes.  The DataReaderWriter struct
-//! also provides human-readable messages for error codes.
+//! also turns error codes into DdrError values (in the adapter_ddrerror.rs
+//! module) that can be matched on and that provide human-readable messages.
+//!
+//! The exercise opens each of the memory blocks available in the back-end
+//! and writes different data to each, as described by BLOCK_CONFIGURATIONS.
+//! The `--blocks` exercise option picks which blocks to use; for example,
+//! `--blocks 0,2` uses only the first and third blocks.
+//!
+//! DataReaderWriter also reads and writes 16-, 32- and 64-bit integers in
+//! either byte order, building on the byte access, so the word-oriented
+//! back-end is adapted all the way to typed values.
+//!
+//! Finally, the DdrStream struct (in adapter_ddrstream.rs) adapts an open
+//! memory block to the standard Read, Write and Seek traits, so the exercise
+//! can use std::io::copy() to move data into and between memory blocks.
+//! DataReaderWriter can also read and write several spans at once, touching
+//! each chunk in the back-end only once.
+//!
+//! The DataReaderWriter reaches the back-end through the IDdrBackend trait
+//! (in adapter_iddrbackend_trait.rs), so it can use either the real DLL or
+//! the MockBackend (in adapter_mockbackend.rs), which records every call made
+//! to it and can be scripted to fail chosen calls.  The exercise ends by
+//! using a scripted MockBackend to show a read failing partway through.
+//!
+//! The `--shared` exercise option runs a different demonstration instead, in
+//! which a writer and a reader pass structured records (see
+//! adapter_sharedrecord.rs) through one memory block, showing how the
+//! back-end locks memory blocks.
+//!
+//! When built with the `mock_backend` feature, the Adapter_BackEnd DLL is
+//! replaced by the mock, and the exercise also shows the exact sequence of
+//! back-end calls made to write data to the memory block.
 //!
 //! Accessed through the adapter_exercise() function.
 
+pub mod adapter_ddrerror;
+pub mod adapter_ddrstream;
 pub mod adapter_functions;
+pub mod adapter_iddrbackend_trait;
+pub mod adapter_mockbackend;
+pub mod adapter_nativebackend;
+pub mod adapter_sharedrecord;
+#[cfg(not(feature = "mock_backend"))]
 pub mod adapter_backend;
+#[cfg(feature = "mock_backend")]
+pub use adapter_mockbackend as adapter_backend;
+
+use std::io::{self, Read, Seek, SeekFrom};
+
+use crate::helpers::exercise_options::{get_exercise_option_value, has_exercise_option};
 
+use adapter_backend::DDR_ErrorCode_Block_Not_Opened;
+use adapter_ddrerror::DdrError;
+use adapter_ddrstream::DdrStream;
 use adapter_functions::{
-    MemoryBlockNumber, DataReaderWriter, adapter_buffer_to_string
+    MemoryBlockNumber, DataReaderWriter, Endianness, HexDumpOptions,
+    adapter_buffer_to_string, adapter_buffer_to_string_with
 };
+use adapter_mockbackend::{error_code_name, MockBackEndCall, MockBackEndFunction, MockBackend};
+use adapter_sharedrecord::SharedRecord;
 
-/// Example of using the "Adapter" design pattern in rust.
-/// 
-/// This example adapts functions that:
-/// 1. Accesses memory in 32-bit chunks instead of bytes
-/// 2. Returns error codes but no human-readable error messages
-/// 
-/// The Adapter functions translate the 32-bit chunk access into arrays of bytes.
-/// The Adapter functions also provide human-readable messages for error codes.
+/// Describes the data the exercise writes to a memory block.
+struct BlockConfiguration {
+    /// The memory block this configuration applies to.
+    block_number: MemoryBlockNumber,
+    /// Byte offset into the memory block at which to write.
+    write_offset: i32,
+    /// Number of bytes to write.
+    data_size: usize,
+    /// Value of the first byte written.  Each byte after that is one more.
+    first_value: u8,
+}
 
-// ! [Using Adapter in Rust]
-pub fn adapter_exercise() -> Result<(), String> {
-    println!("");
-    println!("Adapter Exercise");
+/// How the exercise writes to each memory block.
+const BLOCK_CONFIGURATIONS: [BlockConfiguration; 3] = [
+    BlockConfiguration { block_number: MemoryBlockNumber::MemoryBlock0, write_offset: 41, data_size: 16, first_value: 0x01 },
+    BlockConfiguration { block_number: MemoryBlockNumber::MemoryBlock1, write_offset: 0, data_size: 8, first_value: 0xa0 },
+    BlockConfiguration { block_number: MemoryBlockNumber::MemoryBlock2, write_offset: 100, data_size: 28, first_value: 0x40 },
+];
 
-    let mut reader_writer = DataReaderWriter::new(MemoryBlockNumber::MemoryBlock0);
+/// Helper function to get the memory blocks to use from the `--blocks`
+/// exercise option, which is a comma-separated list of block indices.
+///
+/// # Returns
+/// Returns `Ok(Vec<MemoryBlockNumber>)` containing the blocks to use (all
+/// blocks if the option was not given); otherwise, returns `Err(String)` if
+/// the option names a block that does not exist.
+fn _adapter_selected_blocks() -> Result<Vec<MemoryBlockNumber>, String> {
+    let Some(value) = get_exercise_option_value("--blocks") else {
+        return Ok(MemoryBlockNumber::iter().collect());
+    };
+    let mut blocks = Vec::new();
+    for field in value.split(',') {
+        let block_number = field.trim().parse::<usize>().ok()
+            .and_then(MemoryBlockNumber::from_index)
+            .ok_or_else(|| format!("--blocks: '{field}' is not a memory block (expected 0 to {})",
+                MemoryBlockNumber::iter().count() - 1))?;
+        if !blocks.contains(&block_number) {
+            blocks.push(block_number);
+        }
+    }
+    Ok(blocks)
+}
 
-    reader_writer.open()?;
+/// Helper function to show the calls made to a mock back-end.
+///
+/// # Parameters
+/// - calls
+///
+///   The calls to show, as recorded by the mock back-end.
+fn _adapter_show_calls(calls: &[MockBackEndCall]) {
+    for call in calls.iter() {
+        println!("    {}({}) = {}", call.function, call.arguments, error_code_name(call.result));
+    }
+}
 
+/// Helper function to read, write and read back one memory block according
+/// to its configuration.
+///
+/// # Parameters
+/// - reader_writer
+///
+///   The DataReaderWriter for the memory block, which must be open.
+fn _adapter_exercise_block(reader_writer: &DataReaderWriter) -> Result<(), String> {
+    let block_number = reader_writer.block_number();
+    let configuration = BLOCK_CONFIGURATIONS.iter()
+        .find(|configuration| configuration.block_number == block_number)
+        .ok_or_else(|| format!("No configuration for memory block {}", block_number.index()))?;
     let memory_block_size = reader_writer.memory_block_byte_size;
 
+    println!("  Memory block {} ('{}'), {memory_block_size} bytes",
+        block_number.index(), block_number.name());
+
     let mut read_data = reader_writer.read(0, memory_block_size)?;
     let mut hex_dump = adapter_buffer_to_string(&read_data, 2)?;
     println!("  Initial memory block contents:");
     println!("{hex_dump}");
 
-    let data_size = 16;
-    let buffer_offset = 41;
-    let mut write_data: Vec<u8> = vec![0;data_size];
-    for index in 0..write_data.len() {
-        write_data[index] = (index + 1) as u8;
-    }
+    let buffer_offset = configuration.write_offset;
+    let write_data: Vec<u8> = (0..configuration.data_size)
+        .map(|index| configuration.first_value.wrapping_add(index as u8))
+        .collect();
 
     hex_dump = adapter_buffer_to_string(&write_data, 2)?;
     println!("  Data to be written to memory block:");
     println!("{hex_dump}");
 
     println!("  Writing data to byte offset {buffer_offset}");
+    #[cfg(feature = "mock_backend")]
+    adapter_mockbackend::clear_call_log();
     let _ = reader_writer.write(buffer_offset, &write_data)?;
+    #[cfg(feature = "mock_backend")]
+    {
+        println!("  Back-end calls made by the write (mock back-end):");
+        _adapter_show_calls(&adapter_mockbackend::get_call_log());
+    }
 
     println!("  Reading back the memory block...");
     read_data = reader_writer.read(0, memory_block_size)?;
@@ -64,11 +171,345 @@ pub fn adapter_exercise() -> Result<(), String> {
     println!("  Current memory block contents:");
     println!("{hex_dump}");
 
+    println!("  Reading past the end of the memory block...");
+    match reader_writer.read(memory_block_size as i32, 4) {
+        Ok(_) => println!("    Unexpectedly succeeded!"),
+        Err(error @ DdrError::InvalidOffset { .. }) => {
+            println!("    Rejected as expected: {error}");
+        }
+        Err(error) => return Err(error.into()),
+    }
+    println!();
+
+    Ok(())
+}
+
+/// Helper function to write integers to a memory block in both byte orders
+/// and read them back, in the same byte order and in the other byte order.
+///
+/// # Parameters
+/// - reader_writer
+///
+///   The DataReaderWriter for the memory block, which must be open.
+fn _adapter_exercise_typed_access(reader_writer: &DataReaderWriter) -> Result<(), String> {
+    println!("  Writing integers to memory block {} in both byte orders...",
+        reader_writer.block_number().index());
+    reader_writer.write_u16(0, 0x1234, Endianness::Little)?;
+    reader_writer.write_u16(2, 0x1234, Endianness::Big)?;
+    reader_writer.write_u32(4, 0xdeadbeef, Endianness::Little)?;
+    reader_writer.write_u32(8, 0xdeadbeef, Endianness::Big)?;
+    reader_writer.write_u64(16, 0x0123456789abcdef, Endianness::Little)?;
+    reader_writer.write_u64(24, 0x0123456789abcdef, Endianness::Big)?;
+
+    // Group the bytes by 4 so the integers are easier to pick out.
+    let read_data = reader_writer.read(0, 32)?;
+    let grouped = HexDumpOptions { indent: 2, bytes_per_row: 16, group_size: 4, ..Default::default() };
+    println!("{}", adapter_buffer_to_string_with(&read_data, &grouped)?);
+
+    println!("  Reading the integers back (as written / with the other byte order):");
+    println!("    u16 little-endian at  0: 0x{:04x} / 0x{:04x}",
+        reader_writer.read_u16(0, Endianness::Little)?, reader_writer.read_u16(0, Endianness::Big)?);
+    println!("    u16 big-endian    at  2: 0x{:04x} / 0x{:04x}",
+        reader_writer.read_u16(2, Endianness::Big)?, reader_writer.read_u16(2, Endianness::Little)?);
+    println!("    u32 little-endian at  4: 0x{:08x} / 0x{:08x}",
+        reader_writer.read_u32(4, Endianness::Little)?, reader_writer.read_u32(4, Endianness::Big)?);
+    println!("    u32 big-endian    at  8: 0x{:08x} / 0x{:08x}",
+        reader_writer.read_u32(8, Endianness::Big)?, reader_writer.read_u32(8, Endianness::Little)?);
+    println!("    u64 little-endian at 16: 0x{:016x} / 0x{:016x}",
+        reader_writer.read_u64(16, Endianness::Little)?, reader_writer.read_u64(16, Endianness::Big)?);
+    println!("    u64 big-endian    at 24: 0x{:016x} / 0x{:016x}",
+        reader_writer.read_u64(24, Endianness::Big)?, reader_writer.read_u64(24, Endianness::Little)?);
+
+    let last_offset = reader_writer.memory_block_byte_size as i32 - 4;
+    match reader_writer.read_u64(last_offset, Endianness::Little) {
+        Ok(_) => println!("    Unexpectedly read a u64 at {last_offset}!"),
+        Err(error) => println!("    u64 at {last_offset} rejected: {error}"),
+    }
+    println!();
+
+    Ok(())
+}
+
+/// Helper function to use std::io::copy() to copy a message into the first
+/// memory block through a DdrStream, and then to copy the whole first memory
+/// block into the second, if there is a second.
+///
+/// # Parameters
+/// - reader_writers
+///
+///   The DataReaderWriters for the memory blocks, which must be open.
+fn _adapter_exercise_streams(reader_writers: &[DataReaderWriter]) -> Result<(), String> {
+    let Some(first) = reader_writers.first() else {
+        return Ok(());
+    };
+    let to_message = |error: io::Error| error.to_string();
+
+    let message = b"Copied with std::io::copy()";
+    let message_offset = 64;
+    let mut first_stream = DdrStream::new(first);
+    first_stream.seek(SeekFrom::Start(message_offset)).map_err(to_message)?;
+    let copied = io::copy(&mut &message[..], &mut first_stream).map_err(to_message)?;
+    println!("  Copied {copied} bytes into memory block {} at byte offset {message_offset}",
+        first.block_number().index());
+
+    let mut read_back = vec![0u8; message.len()];
+    first_stream.seek(SeekFrom::Start(message_offset)).map_err(to_message)?;
+    first_stream.read_exact(&mut read_back).map_err(to_message)?;
+    println!("    Read back: \"{}\"", String::from_utf8_lossy(&read_back));
+    let with_ascii = HexDumpOptions {
+        indent: 4,
+        bytes_per_row: 16,
+        group_size: 8,
+        show_ascii: true,
+        base_offset: message_offset as usize,
+        uppercase: true,
+    };
+    println!("{}", adapter_buffer_to_string_with(&read_back, &with_ascii)?);
+
+    if let Some(second) = reader_writers.get(1) {
+        first_stream.rewind().map_err(to_message)?;
+        let mut second_stream = DdrStream::new(second);
+        let copied = io::copy(&mut first_stream, &mut second_stream).map_err(to_message)?;
+        println!("  Copied {copied} bytes from memory block {} to memory block {}:",
+            first.block_number().index(), second.block_number().index());
+        let read_data = second.read(0, second.memory_block_byte_size)?;
+        println!("{}", adapter_buffer_to_string(&read_data, 2)?);
+    } else {
+        println!();
+    }
+
+    Ok(())
+}
+
+/// Helper function to write several spans to a memory block with a single
+/// scatter/gather write and read them back with a single scatter/gather
+/// read.
+///
+/// # Parameters
+/// - reader_writer
+///
+///   The DataReaderWriter for the memory block, which must be open.
+fn _adapter_exercise_vectored(reader_writer: &DataReaderWriter) -> Result<(), String> {
+    // The first two spans share a chunk, the third covers a whole chunk,
+    // and the last ends at the end of the memory block.
+    let block_end = reader_writer.memory_block_byte_size as i32;
+    let spans: [(i32, &[u8]); 4] = [
+        (block_end - 31, b"ab"),
+        (block_end - 29, b"cde"),
+        (block_end - 20, b"WXYZ"),
+        (block_end - 2, b"!!"),
+    ];
+    println!("  Scatter-writing {} spans to memory block {}...",
+        spans.len(), reader_writer.block_number().index());
+    #[cfg(feature = "mock_backend")]
+    adapter_mockbackend::clear_call_log();
+    let written = reader_writer.write_v(&spans)?;
+    #[cfg(feature = "mock_backend")]
+    {
+        println!("  Back-end calls made by the write (mock back-end):");
+        _adapter_show_calls(&adapter_mockbackend::get_call_log());
+    }
+    println!("    Wrote {written} bytes");
+
+    let regions: Vec<(i32, usize)> = spans.iter()
+        .map(|(byte_offset, bytes)| (*byte_offset, bytes.len()))
+        .collect();
+    println!("  Gather-reading the same spans back:");
+    for ((byte_offset, _), data) in regions.iter().zip(reader_writer.read_v(&regions)?) {
+        println!("    byte offset {byte_offset:3}: \"{}\"", String::from_utf8_lossy(&data));
+    }
+
+    let last_row = block_end - 32;
+    let read_data = reader_writer.read(last_row, 32)?;
+    let options = HexDumpOptions { indent: 4, bytes_per_row: 16, show_ascii: true,
+        base_offset: last_row as usize, ..Default::default() };
+    println!("{}", adapter_buffer_to_string_with(&read_data, &options)?);
+
+    match reader_writer.read_v(&[(0, 4), (block_end - 2, 4)]) {
+        Ok(_) => println!("    Unexpectedly read past the end of the memory block!"),
+        Err(error) => println!("    Span past the end rejected: {error}"),
+    }
+    println!();
+
+    Ok(())
+}
+
+/// Helper function to try to open a memory block that should already be
+/// opened by someone else, reporting the outcome.
+///
+/// # Parameters
+/// - name
+///
+///   Name of the endpoint trying to open the memory block.
+/// - reader_writer
+///
+///   The DataReaderWriter the endpoint is trying to open.
+fn _adapter_try_open(name: &str, reader_writer: &mut DataReaderWriter) -> Result<(), String> {
+    match reader_writer.open() {
+        Ok(()) => {
+            println!("    {name} unexpectedly opened the memory block!");
+            reader_writer.close()?;
+        }
+        Err(error @ DdrError::BlockAlreadyOpened { .. }) => println!("    {name} is locked out: {error}"),
+        Err(error) => return Err(error.into()),
+    }
+    Ok(())
+}
+
+/// Helper function to have the reader endpoint read and validate the record,
+/// reporting the outcome.
+///
+/// # Parameters
+/// - reader_writer
+///
+///   The DataReaderWriter the reader endpoint reads through, which must be
+///   open.
+/// - byte_offset
+///
+///   Byte offset into the memory block where the record starts.
+fn _adapter_reader_validate(reader_writer: &DataReaderWriter, byte_offset: i32) {
+    match SharedRecord::read_from(reader_writer, byte_offset) {
+        Ok(record) => println!("    Reader validated {record}"),
+        Err(message) => println!("    Reader rejected the record: {message}"),
+    }
+}
+
+/// Helper function for the `--shared` mode, in which a writer endpoint and a
+/// reader endpoint pass structured records through one memory block.
+///
+/// The back-end gives one handle exclusive use of a memory block and clears
+/// the memory block each time it is opened.  So the reader cannot open the
+/// memory block for itself while the writer has it, and must share the
+/// writer's open handle instead; and nothing the writer wrote survives the
+/// memory block being closed and opened again.
+///
+/// # Parameters
+/// - block_number
+///
+///   The memory block to share.
+fn _adapter_exercise_shared(block_number: MemoryBlockNumber) -> Result<(), String> {
+    println!("  Sharing memory block {} ('{}') between a writer and a reader",
+        block_number.index(), block_number.name());
+    let record_offset = 16;
+    let mut writer = DataReaderWriter::new(block_number)?;
+    let mut reader = DataReaderWriter::new(block_number)?;
+
+    writer.open()?;
+    println!("    Writer opened the memory block");
+    _adapter_try_open("Reader", &mut reader)?;
+    println!("    Reader shares the writer's handle instead");
+
+    let mut record = SharedRecord { sequence: 1, temperature: 2137, timestamp: 1_700_000_000 };
+    for temperature in [2137, -415] {
+        record.temperature = temperature;
+        record.write_to(&writer, record_offset)?;
+        println!("    Writer wrote {record} ({} bytes at byte offset {record_offset})",
+            SharedRecord::BYTE_SIZE);
+        _adapter_reader_validate(&writer, record_offset);
+        record.sequence += 1;
+        record.timestamp += 60;
+    }
+
+    // Change the temperature without updating the checksum.
+    writer.write(record_offset + 11, &vec![0x00])?;
+    println!("    Writer changed one byte of the record without updating the checksum");
+    _adapter_reader_validate(&writer, record_offset);
+
+    writer.close()?;
+    println!("    Writer closed the memory block");
+    reader.open()?;
+    println!("    Reader opened the memory block, which the back-end cleared");
+    _adapter_reader_validate(&reader, record_offset);
+    _adapter_try_open("Writer", &mut writer)?;
+    reader.close()?;
+    println!();
+
+    Ok(())
+}
+
+/// Helper function to show the DataReaderWriter reporting a failure partway
+/// through a read that crosses chunk boundaries, using a MockBackend scripted
+/// to fail the third chunk read.
+fn _adapter_exercise_injected_error() -> Result<(), String> {
+    println!("  Reading 10 bytes from byte offset 2 with a mock back-end scripted");
+    println!("  to fail the third chunk read...");
+    let backend = MockBackend::new();
+    let mut reader_writer = DataReaderWriter::with_backend(
+        MemoryBlockNumber::MemoryBlock0, Box::new(backend.clone()));
+    reader_writer.open()?;
+
+    backend.clear_call_log();
+    backend.inject_error(MockBackEndFunction::GetDataChunk, 2, DDR_ErrorCode_Block_Not_Opened);
+    match reader_writer.read(2, 10) {
+        Ok(_) => println!("    Unexpectedly succeeded!"),
+        Err(error) => println!("    {error}"),
+    }
+    println!("  Back-end calls made by the read:");
+    _adapter_show_calls(&backend.get_call_log());
+    println!();
+
     reader_writer.close()?;
+    Ok(())
+}
+
+/// Example of using the "Adapter" design pattern in rust.
+/// 
+/// This example adapts functions that:
+/// 1. Accesses memory in 32-bit chunks instead of bytes
+/// 2. Returns error codes but no human-readable error messages
+/// 
+/// The Adapter functions translate the 32-bit chunk access into arrays of bytes.
+/// The Adapter functions also provide human-readable messages for error codes.
+
+// ! [Using Adapter in Rust]
+pub fn adapter_exercise() -> Result<(), String> {
+    println!("");
+    println!("Adapter Exercise");
+
+    // Report a missing Adapter_BackEnd DLL before anything asks it for the
+    // memory block names.
+    adapter_backend::load_backend()?;
+
+    if has_exercise_option("--shared") {
+        let blocks = _adapter_selected_blocks()?;
+        if let Some(block_number) = blocks.first() {
+            _adapter_exercise_shared(*block_number)?;
+        }
+        println!("  Done.");
+        return Ok(());
+    }
+
+    // Open all of the selected memory blocks at the same time; each gets its
+    // own handle.
+    let mut reader_writers = Vec::new();
+    for block_number in _adapter_selected_blocks()? {
+        let mut reader_writer = DataReaderWriter::new(block_number)?;
+        reader_writer.open()?;
+        reader_writers.push(reader_writer);
+    }
+
+    for reader_writer in reader_writers.iter() {
+        _adapter_exercise_block(reader_writer)?;
+    }
+
+    if let Some(reader_writer) = reader_writers.first() {
+        _adapter_exercise_typed_access(reader_writer)?;
+    }
+
+    _adapter_exercise_streams(&reader_writers)?;
+
+    if let Some(reader_writer) = reader_writers.first() {
+        _adapter_exercise_vectored(reader_writer)?;
+    }
+
+    for reader_writer in reader_writers.iter_mut() {
+        reader_writer.close()?;
+    }
+
+    _adapter_exercise_injected_error()?;
 
     println!("  Done.");
     Ok(())
 }
 
 // ! [Using Adapter in Rust]
-
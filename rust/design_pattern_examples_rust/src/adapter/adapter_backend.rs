@@ -4,29 +4,22 @@
 //! to remove unused components, clean up documentation, simplify names, provide
 //! Rust-compatible names for the functions in the DLL, and to add #\[allow()\]
 //! so as to remove compiler warnings.
+//!
+//! The DLL is not linked to the program.  Instead, load_backend() loads it at
+//! run time, looking first in the directory containing the program and then
+//! on the system's library search path.  This way the program starts even
+//! when the DLL is missing, and only the Adapter exercise reports the
+//! problem.
 
 #![allow(non_camel_case_types, non_upper_case_globals)]
 
-#[link(name="Adapter_BackEnd")]
-extern "C" {
-    #[doc = " Name of the first block."]
-    #[link_name="BLOCK_NAME_0"]
-    pub static block_name_0: *const ::std::os::raw::c_char;
-}
+use std::env;
+use std::ffi::{CStr, OsString};
+use std::os::raw::{c_char, c_int};
+use std::sync::OnceLock;
 
-#[link(name="Adapter_BackEnd")]
-extern "C" {
-    #[doc = " Name of the second block."]
-    #[link_name="BLOCK_NAME_1"]
-    pub static block_name_1: *const ::std::os::raw::c_char;
-}
+use libloading::{library_filename, Library};
 
-#[link(name="Adapter_BackEnd")]
-extern "C" {
-    #[doc = " Name of the third block."]
-    #[link_name="BLOCK_NAME_2"]
-    pub static block_name_2: *const ::std::os::raw::c_char;
-}
 #[doc = "All offsets must from 0 to 1 less than this value."]
 pub const DDR_MAX_OFFSET: _bindgen_ty_1 = 32;
 #[doc = "Value indicating the handle is invalid"]
@@ -49,46 +42,265 @@ pub const DDR_ErrorCode_Null_Argument: DDR_ErrorCode = 6;
 #[doc = "Represents the possible errors that can be returned from the memory block\n access functions.\n "]
 pub type DDR_ErrorCode = ::std::os::raw::c_int;
 
-extern "C" {
-    #[doc = "Open access to a memory block for exclusive use, given the name of the\n memory block.\n\n# Parameters\n- blockName\n\n    Name of the block to access (one of the predefined\n names, @p BLOCK_NAME_0, @p BLOCK_NAME_1, or @p BLOCK_NAME_2)\n - dataHandle\n\n    Returns a handle to be used for accessing the\n specific memory block.\n\n# Returns\n  Returns an integer code (DDR_ErrorCode_X) indicating\n success or failure."]
-    #[link_name="DDR_OpenMemoryBlock"]
-    pub fn ddr_open_memory_block(
-        blockName: *const ::std::os::raw::c_char,
-        dataHandle: *mut ::std::os::raw::c_int,
-    ) -> DDR_ErrorCode;
+//-----------------------------------------------------------------------------
+
+/// Name of the back-end library, without the platform's prefix and suffix.
+const BACKEND_LIBRARY_NAME: &str = "Adapter_BackEnd";
+
+type OpenMemoryBlockFn = unsafe extern "C" fn(*const c_char, *mut c_int) -> DDR_ErrorCode;
+type CloseMemoryBlockFn = unsafe extern "C" fn(c_int) -> DDR_ErrorCode;
+type GetMemorySizeFn = unsafe extern "C" fn(c_int, *mut c_int) -> DDR_ErrorCode;
+type GetDataChunkFn = unsafe extern "C" fn(c_int, c_int, *mut u32) -> DDR_ErrorCode;
+type SetDataChunkFn = unsafe extern "C" fn(c_int, c_int, u32) -> DDR_ErrorCode;
+
+/// The loaded back-end library and the items found in it.
+struct BackEndLibrary {
+    /// Names of the three memory blocks.
+    block_names: [&'static CStr; 3],
+    open_memory_block: OpenMemoryBlockFn,
+    close_memory_block: CloseMemoryBlockFn,
+    get_memory_size: GetMemorySizeFn,
+    get_data_chunk: GetDataChunkFn,
+    set_data_chunk: SetDataChunkFn,
+    /// The library itself, kept loaded for the life of the program so the
+    /// items above stay valid.
+    _library: Library,
+}
+
+/// The result of loading the back-end library, filled in on the first call
+/// to load_backend().
+static BACKEND_LIBRARY: OnceLock<Result<BackEndLibrary, String>> = OnceLock::new();
+
+/// Helper function to find the items needed from a loaded back-end library.
+///
+/// # Parameters
+/// - library
+///
+///   The loaded library.
+/// - file_name
+///
+///   Name of the library file, for error messages.
+///
+/// # Returns
+/// Returns `Ok(BackEndLibrary)` if all items were found; otherwise, returns
+/// `Err(String)` naming the item that is missing.
+fn _find_backend_items(library: Library, file_name: &str) -> Result<BackEndLibrary, String> {
+    let missing = |name: &str, error: libloading::Error| format!("{file_name} does not provide {name}: {error}");
+    unsafe {
+        let mut block_names = [c""; 3];
+        for (index, block_name) in block_names.iter_mut().enumerate() {
+            let name = format!("BLOCK_NAME_{index}");
+            let symbol = library.get::<*const *const c_char>(name.as_bytes())
+                .map_err(|error| missing(&name, error))?;
+            *block_name = CStr::from_ptr(**symbol);
+        }
+        let open_memory_block = *library.get::<OpenMemoryBlockFn>(b"DDR_OpenMemoryBlock")
+            .map_err(|error| missing("DDR_OpenMemoryBlock", error))?;
+        let close_memory_block = *library.get::<CloseMemoryBlockFn>(b"DDR_CloseMemoryBlock")
+            .map_err(|error| missing("DDR_CloseMemoryBlock", error))?;
+        let get_memory_size = *library.get::<GetMemorySizeFn>(b"DDR_GetMemorySize")
+            .map_err(|error| missing("DDR_GetMemorySize", error))?;
+        let get_data_chunk = *library.get::<GetDataChunkFn>(b"DDR_GetDataChunk")
+            .map_err(|error| missing("DDR_GetDataChunk", error))?;
+        let set_data_chunk = *library.get::<SetDataChunkFn>(b"DDR_SetDataChunk")
+            .map_err(|error| missing("DDR_SetDataChunk", error))?;
+        Ok(BackEndLibrary {
+            block_names,
+            open_memory_block,
+            close_memory_block,
+            get_memory_size,
+            get_data_chunk,
+            set_data_chunk,
+            _library: library,
+        })
+    }
+}
+
+/// Helper function to load the back-end library, looking first in the
+/// directory containing the program and then on the library search path.
+///
+/// # Returns
+/// Returns `Ok(BackEndLibrary)` if the library was loaded; otherwise, returns
+/// `Err(String)` describing the problem and how to fix it.
+fn _load_backend_library() -> Result<BackEndLibrary, String> {
+    let file_name: OsString = library_filename(BACKEND_LIBRARY_NAME);
+    let display_name = file_name.to_string_lossy().into_owned();
+
+    let mut candidates = vec![];
+    if let Some(program_dir) = env::current_exe().ok().as_deref().and_then(|path| path.parent()) {
+        candidates.push(program_dir.join(&file_name).into_os_string());
+    }
+    candidates.push(file_name);
+
+    let mut last_error = String::new();
+    for candidate in candidates.iter() {
+        match unsafe { Library::new(candidate) } {
+            Ok(library) => return _find_backend_items(library, &display_name),
+            Err(error) => last_error = error.to_string(),
+        }
+    }
+    Err(format!("Unable to load the {BACKEND_LIBRARY_NAME} library ({last_error}).\n  \
+        Build the {BACKEND_LIBRARY_NAME} project and copy {display_name} next to the program,\n  \
+        or add the directory containing it to the library search path\n  \
+        (LD_LIBRARY_PATH on Linux, DYLD_LIBRARY_PATH on macOS, PATH on Windows)."))
+}
+
+/// Load the back-end library, if it has not already been loaded.  Must
+/// succeed before any other function in this module is called.
+///
+/// # Returns
+/// Returns `Ok(())` if the library is loaded; otherwise, returns
+/// `Err(String)` describing the problem and how to fix it.  The same result
+/// is returned on every call.
+pub fn load_backend() -> Result<(), String> {
+    match BACKEND_LIBRARY.get_or_init(_load_backend_library) {
+        Ok(_) => Ok(()),
+        Err(message) => Err(message.clone()),
+    }
+}
+
+/// Helper function to get the loaded back-end library.  Panics if
+/// load_backend() has not succeeded, which is a programming error.
+fn _backend() -> &'static BackEndLibrary {
+    match BACKEND_LIBRARY.get() {
+        Some(Ok(library)) => library,
+        _ => panic!("load_backend() must succeed before the back-end is used"),
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Retrieve the names of the three memory blocks, as defined by the
+/// back-end: BLOCK_NAME_0, BLOCK_NAME_1, and BLOCK_NAME_2.
+///
+/// load_backend() must have succeeded.
+pub fn block_names() -> [&'static CStr; 3] {
+    _backend().block_names
+}
+
+/// Open access to a memory block for exclusive use, given the name of the
+/// memory block.
+///
+/// # Parameters
+/// - blockName
+///
+///   Name of the block to access (one of the predefined
+///   names, @p BLOCK_NAME_0, @p BLOCK_NAME_1, or @p BLOCK_NAME_2)
+/// - dataHandle
+///
+///   Returns a handle to be used for accessing the
+///   specific memory block.
+///
+/// # Returns
+///   Returns an integer code (DDR_ErrorCode_X) indicating
+///   success or failure.
+///
+/// # Safety
+/// Pointer arguments must be valid, the same as for DDR_OpenMemoryBlock() in the
+/// DLL.  load_backend() must have succeeded.
+#[allow(non_snake_case)]
+pub unsafe fn ddr_open_memory_block(blockName: *const c_char, dataHandle: *mut c_int) -> DDR_ErrorCode {
+    (_backend().open_memory_block)(blockName, dataHandle)
 }
 
-extern "C" {
-    #[doc = "Close access to a previously opened memory block, thus releasing it for\n others to open.\n\n# Parameters\n- dataHandle\n\n    Handle to a previously opened memory block as\n obtained from the ddr_open_memory_block() function.\n\n# Returns\n  Returns an integer code (DDR_ErrorCode_X) indicating\n success or failure."]
-    #[link_name="DDR_CloseMemoryBlock"]
-    pub fn ddr_close_memory_block(dataHandle: ::std::os::raw::c_int) -> DDR_ErrorCode;
+/// Close access to a previously opened memory block, thus releasing it for
+/// others to open.
+///
+/// # Parameters
+/// - dataHandle
+///
+///   Handle to a previously opened memory block as
+///   obtained from the ddr_open_memory_block() function.
+///
+/// # Returns
+///   Returns an integer code (DDR_ErrorCode_X) indicating
+///   success or failure.
+///
+/// # Safety
+/// Pointer arguments must be valid, the same as for DDR_CloseMemoryBlock() in the
+/// DLL.  load_backend() must have succeeded.
+#[allow(non_snake_case)]
+pub unsafe fn ddr_close_memory_block(dataHandle: c_int) -> DDR_ErrorCode {
+    (_backend().close_memory_block)(dataHandle)
 }
 
-extern "C" {
-    #[doc = "Retrieve the number of chunks in the memory block indicated by the handle\n to the successfully opened memory block.\n\n# Parameters\n- dataHandle\n\n    Handle to a previously opened memory block as\n obtained from the ddr_open_memory_block() function.\n - memorySizeInChunks\n\n    Returns the number of 32-bit chunks in the\n memory block.\n\n# Returns\n  Returns an integer code (DDR_ErrorCode_X) indicating\n success or failure."]
-    #[link_name="DDR_GetMemorySize"]
-    pub fn ddr_get_memory_size(
-        dataHandle: ::std::os::raw::c_int,
-        memorySizeInChunks: *mut ::std::os::raw::c_int,
-    ) -> DDR_ErrorCode;
+/// Retrieve the number of chunks in the memory block indicated by the handle
+/// to the successfully opened memory block.
+///
+/// # Parameters
+/// - dataHandle
+///
+///   Handle to a previously opened memory block as
+///   obtained from the ddr_open_memory_block() function.
+/// - memorySizeInChunks
+///
+///   Returns the number of 32-bit chunks in the
+///   memory block.
+///
+/// # Returns
+///   Returns an integer code (DDR_ErrorCode_X) indicating
+///   success or failure.
+///
+/// # Safety
+/// Pointer arguments must be valid, the same as for DDR_GetMemorySize() in the
+/// DLL.  load_backend() must have succeeded.
+#[allow(non_snake_case)]
+pub unsafe fn ddr_get_memory_size(dataHandle: c_int, memorySizeInChunks: *mut c_int) -> DDR_ErrorCode {
+    (_backend().get_memory_size)(dataHandle, memorySizeInChunks)
 }
 
-extern "C" {
-    #[doc = "Read a single 32-bit value at the given offset in the memory block indicated\n by the specified handle.\n\n# Parameters\n- dataHandle\n\n    Handle to a previously opened memory block as\n obtained from the ddr_open_memory_block() function.\n - chunkOffset\n\n    Offset into the memory block from which to get\n the value (range is 0 to DDR_MAX_OFFSET-1).\n - value\n\n    Returns the requested value.\n\n# Returns\n  Returns an integer code (DDR_ErrorCode_X) indicating\n success or failure."]
-    #[link_name="DDR_GetDataChunk"]
-    pub fn ddr_get_data_chunk(
-        dataHandle: ::std::os::raw::c_int,
-        chunkOffset: ::std::os::raw::c_int,
-        value: *mut u32,
-    ) -> DDR_ErrorCode;
+/// Read a single 32-bit value at the given offset in the memory block indicated
+/// by the specified handle.
+///
+/// # Parameters
+/// - dataHandle
+///
+///   Handle to a previously opened memory block as
+///   obtained from the ddr_open_memory_block() function.
+/// - chunkOffset
+///
+///   Offset into the memory block from which to get
+///   the value (range is 0 to DDR_MAX_OFFSET-1).
+/// - value
+///
+///   Returns the requested value.
+///
+/// # Returns
+///   Returns an integer code (DDR_ErrorCode_X) indicating
+///   success or failure.
+///
+/// # Safety
+/// Pointer arguments must be valid, the same as for DDR_GetDataChunk() in the
+/// DLL.  load_backend() must have succeeded.
+#[allow(non_snake_case)]
+pub unsafe fn ddr_get_data_chunk(dataHandle: c_int, chunkOffset: c_int, value: *mut u32) -> DDR_ErrorCode {
+    (_backend().get_data_chunk)(dataHandle, chunkOffset, value)
 }
 
-extern "C" {
-    #[doc = "Writes a single 32-bit value to the given offset in the memory block indicated\n by the specified handle.\n\n# Parameters\n- dataHandle\n\n    Handle to a previously opened memory block as\n obtained from the ddr_open_memory_block() function.\n - chunkOffset\n\n    Offset into the memory block to which to set\n the value (range is 0 to DDR_MAX_OFFSET-1).\n - value\n\n    The value to write to the memory block\n\n# Returns\n  Returns an integer code (DDR_ErrorCode_X) indicating\n success or failure."]
-    #[link_name="DDR_SetDataChunk"]
-    pub fn ddr_set_data_chunk(
-        dataHandle: ::std::os::raw::c_int,
-        chunkOffset: ::std::os::raw::c_int,
-        value: u32,
-    ) -> DDR_ErrorCode;
+/// Writes a single 32-bit value to the given offset in the memory block indicated
+/// by the specified handle.
+///
+/// # Parameters
+/// - dataHandle
+///
+///   Handle to a previously opened memory block as
+///   obtained from the ddr_open_memory_block() function.
+/// - chunkOffset
+///
+///   Offset into the memory block to which to set
+///   the value (range is 0 to DDR_MAX_OFFSET-1).
+/// - value
+///
+///   The value to write to the memory block
+///
+/// # Returns
+///   Returns an integer code (DDR_ErrorCode_X) indicating
+///   success or failure.
+///
+/// # Safety
+/// Pointer arguments must be valid, the same as for DDR_SetDataChunk() in the
+/// DLL.  load_backend() must have succeeded.
+#[allow(non_snake_case)]
+pub unsafe fn ddr_set_data_chunk(dataHandle: c_int, chunkOffset: c_int, value: u32) -> DDR_ErrorCode {
+    (_backend().set_data_chunk)(dataHandle, chunkOffset, value)
 }
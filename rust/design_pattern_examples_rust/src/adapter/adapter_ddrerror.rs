@@ -0,0 +1,168 @@
+//! Contains the DdrError enum that represents an error reported by the
+//! Adapter_BackEnd DLL, along with the context in which it occurred.
+
+#![allow(non_upper_case_globals)] // For DDR_ErrorCode items
+
+//-----------------------------------------------------------------------------
+
+use std::error::Error;
+use std::fmt::Display;
+
+use crate::adapter::adapter_backend::*;
+use crate::adapter::adapter_functions::DataHandle;
+
+//-----------------------------------------------------------------------------
+
+/// Represents an error returned from one of the back-end functions.  There
+/// is one variant for each DDR_ErrorCode so callers can match on the kind of
+/// error.  Every variant carries:
+/// - context: what the DataReaderWriter was doing when the error occurred
+/// - handle: the handle to the memory block, which is DDR_INVALID_HANDLE if
+///   the memory block was not open
+/// - offset: the chunk offset being accessed, if any
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DdrError {
+    /// The memory block is already open and cannot be opened again.
+    BlockAlreadyOpened { context: String, handle: DataHandle, offset: Option<i32> },
+    /// The memory block is closed and cannot be accessed.
+    BlockNotOpened { context: String, handle: DataHandle, offset: Option<i32> },
+    /// The given name is not a recognized memory block name.
+    InvalidBlockName { context: String, handle: DataHandle, offset: Option<i32> },
+    /// The handle does not correspond to a valid open memory block.
+    InvalidHandle { context: String, handle: DataHandle, offset: Option<i32> },
+    /// The given offset is out of bounds.
+    InvalidOffset { context: String, handle: DataHandle, offset: Option<i32> },
+    /// The block name pointer or return handle pointer argument is NULL.
+    NullArgument { context: String, handle: DataHandle, offset: Option<i32> },
+    /// An error code not known to this module.
+    Unknown { error_code: DDR_ErrorCode, context: String, handle: DataHandle, offset: Option<i32> },
+}
+
+impl DdrError {
+    /// Constructor.  Converts an error code returned from a back-end function
+    /// into the matching DdrError variant.
+    ///
+    /// # Parameters
+    /// - error_code
+    ///
+    ///   A value from the DDR_ErrorCode enumeration indicating the error that
+    ///   occurred.  Must not be DDR_ErrorCode_Success.
+    /// - context
+    ///
+    ///   A prompt that indicates the context in which the error occurred.
+    /// - handle
+    ///
+    ///   The handle to the memory block being accessed.
+    /// - offset
+    ///
+    ///   The chunk offset being accessed, or None if no offset was involved.
+    ///
+    /// # Returns
+    /// Returns a new instance of the DdrError enum.
+    pub fn new(error_code: DDR_ErrorCode, context: &str, handle: DataHandle, offset: Option<i32>) -> DdrError {
+        let context = context.to_string();
+        match error_code {
+            DDR_ErrorCode_Block_Already_Opened => DdrError::BlockAlreadyOpened { context, handle, offset },
+            DDR_ErrorCode_Block_Not_Opened => DdrError::BlockNotOpened { context, handle, offset },
+            DDR_ErrorCode_Invalid_Block_Name => DdrError::InvalidBlockName { context, handle, offset },
+            DDR_ErrorCode_Invalid_Handle => DdrError::InvalidHandle { context, handle, offset },
+            DDR_ErrorCode_Invalid_Offset => DdrError::InvalidOffset { context, handle, offset },
+            DDR_ErrorCode_Null_Argument => DdrError::NullArgument { context, handle, offset },
+            _ => DdrError::Unknown { error_code, context, handle, offset },
+        }
+    }
+
+    /// Retrieve the DDR_ErrorCode value this error represents.
+    pub fn error_code(&self) -> DDR_ErrorCode {
+        match self {
+            DdrError::BlockAlreadyOpened { .. } => DDR_ErrorCode_Block_Already_Opened,
+            DdrError::BlockNotOpened { .. } => DDR_ErrorCode_Block_Not_Opened,
+            DdrError::InvalidBlockName { .. } => DDR_ErrorCode_Invalid_Block_Name,
+            DdrError::InvalidHandle { .. } => DDR_ErrorCode_Invalid_Handle,
+            DdrError::InvalidOffset { .. } => DDR_ErrorCode_Invalid_Offset,
+            DdrError::NullArgument { .. } => DDR_ErrorCode_Null_Argument,
+            DdrError::Unknown { error_code, .. } => *error_code,
+        }
+    }
+
+    /// Retrieve the context in which the error occurred.
+    pub fn context(&self) -> &str {
+        match self {
+            DdrError::BlockAlreadyOpened { context, .. }
+            | DdrError::BlockNotOpened { context, .. }
+            | DdrError::InvalidBlockName { context, .. }
+            | DdrError::InvalidHandle { context, .. }
+            | DdrError::InvalidOffset { context, .. }
+            | DdrError::NullArgument { context, .. }
+            | DdrError::Unknown { context, .. } => context,
+        }
+    }
+
+    /// Retrieve the handle to the memory block being accessed.
+    pub fn handle(&self) -> DataHandle {
+        match self {
+            DdrError::BlockAlreadyOpened { handle, .. }
+            | DdrError::BlockNotOpened { handle, .. }
+            | DdrError::InvalidBlockName { handle, .. }
+            | DdrError::InvalidHandle { handle, .. }
+            | DdrError::InvalidOffset { handle, .. }
+            | DdrError::NullArgument { handle, .. }
+            | DdrError::Unknown { handle, .. } => *handle,
+        }
+    }
+
+    /// Retrieve the chunk offset being accessed, if any.
+    pub fn offset(&self) -> Option<i32> {
+        match self {
+            DdrError::BlockAlreadyOpened { offset, .. }
+            | DdrError::BlockNotOpened { offset, .. }
+            | DdrError::InvalidBlockName { offset, .. }
+            | DdrError::InvalidHandle { offset, .. }
+            | DdrError::InvalidOffset { offset, .. }
+            | DdrError::NullArgument { offset, .. }
+            | DdrError::Unknown { offset, .. } => *offset,
+        }
+    }
+
+    /// Retrieve a human-readable description of the kind of error.
+    pub fn description(&self) -> &'static str {
+        match self {
+            DdrError::BlockAlreadyOpened { .. } => "Memory block is already open and cannot be opened again",
+            DdrError::BlockNotOpened { .. } => "Memory block is closed and cannot be accessed",
+            DdrError::InvalidBlockName { .. } => "The given name is not a recognized memory block name",
+            DdrError::InvalidHandle { .. } => "The handle argument does not correspond to a valid open memory block",
+            DdrError::InvalidOffset { .. } => "The given offset is out of bounds",
+            DdrError::NullArgument { .. } => "The block name pointer or return handle pointer argument is NULL",
+            DdrError::Unknown { .. } => "Unknown error",
+        }
+    }
+}
+
+impl Display for DdrError {
+    /// Formats the error as "Error! context: description (details)."
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Error! {}: {} (", self.context(), self.description())?;
+        if let DdrError::Unknown { error_code, .. } = self {
+            write!(f, "code {error_code}, ")?;
+        }
+        if self.handle() == DDR_INVALID_HANDLE {
+            write!(f, "no handle")?;
+        } else {
+            write!(f, "handle {}", self.handle())?;
+        }
+        if let Some(offset) = self.offset() {
+            write!(f, ", chunk offset {offset}")?;
+        }
+        write!(f, ").")
+    }
+}
+
+impl Error for DdrError {}
+
+impl From<DdrError> for String {
+    /// Allows a DdrError to be returned with `?` from functions that report
+    /// errors as strings.
+    fn from(error: DdrError) -> String {
+        error.to_string()
+    }
+}
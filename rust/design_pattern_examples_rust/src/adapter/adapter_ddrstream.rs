@@ -0,0 +1,101 @@
+//! Contains the DdrStream struct that adapts an open DataReaderWriter to the
+//! standard std::io::Read, std::io::Write and std::io::Seek traits.
+
+//-----------------------------------------------------------------------------
+
+use std::io::{self, Read, Seek, SeekFrom, Write};
+
+use crate::adapter::adapter_functions::DataReaderWriter;
+
+//-----------------------------------------------------------------------------
+
+/// Represents a stream over an open memory block.  The stream keeps a cursor
+/// that starts at the beginning of the memory block and moves forward with
+/// each read and write, so the memory block can be used anywhere a standard
+/// reader, writer or seekable stream is expected, such as std::io::copy().
+///
+/// Like std::io::Cursor over a fixed-size buffer, reading at the end of the
+/// memory block returns 0 bytes and writing at the end writes 0 bytes.
+pub struct DdrStream<'a> {
+    /// The DataReaderWriter for the memory block, which must be open.
+    reader_writer: &'a DataReaderWriter,
+    /// Byte offset into the memory block of the next read or write.
+    position: u64,
+}
+
+impl<'a> DdrStream<'a> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - reader_writer
+    ///
+    ///   The DataReaderWriter for the memory block to stream, which must be
+    ///   open for as long as the stream is used.
+    ///
+    /// # Returns
+    /// Returns a new instance of the DdrStream struct with the cursor at the
+    /// start of the memory block.
+    pub fn new(reader_writer: &'a DataReaderWriter) -> DdrStream<'a> {
+        DdrStream { reader_writer, position: 0 }
+    }
+
+    /// Helper method to get the number of bytes from the cursor to the end
+    /// of the memory block, limited to the given maximum.
+    fn _bytes_available(&self, max_bytes: usize) -> usize {
+        let size = self.reader_writer.memory_block_byte_size as u64;
+        (size.saturating_sub(self.position) as usize).min(max_bytes)
+    }
+}
+
+impl Read for DdrStream<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let count = self._bytes_available(buf.len());
+        if count == 0 {
+            return Ok(0);
+        }
+        let data = self.reader_writer.read(self.position as i32, count)
+            .map_err(io::Error::other)?;
+        buf[..count].copy_from_slice(&data);
+        self.position += count as u64;
+        Ok(count)
+    }
+}
+
+impl Write for DdrStream<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let count = self._bytes_available(buf.len());
+        if count == 0 {
+            return Ok(0);
+        }
+        self.reader_writer.write(self.position as i32, &buf[..count].to_vec())
+            .map_err(io::Error::other)?;
+        self.position += count as u64;
+        Ok(count)
+    }
+
+    /// Writes go straight to the memory block, so there is nothing to flush.
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for DdrStream<'_> {
+    /// Moves the cursor.  The cursor can be moved past the end of the memory
+    /// block but not before the start.
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let size = self.reader_writer.memory_block_byte_size as u64;
+        let new_position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => size.checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+        };
+        match new_position {
+            Some(position) => {
+                self.position = position;
+                Ok(position)
+            }
+            None => Err(io::Error::new(io::ErrorKind::InvalidInput,
+                "cannot seek before the start of the memory block")),
+        }
+    }
+}
@@ -1,11 +1,15 @@
 //! Contains the DataReaderWriter struct that acts as an adapter to the
-//! Adapter_BackEnd DLL (via the function wrappers in adapter_backend.rs).
+//! Adapter_BackEnd DLL (via an implementation of the IDdrBackend trait).
 
 #![allow(non_upper_case_globals)] // For DDR_ErrorCode items
 
+use std::collections::BTreeMap;
 use std::ffi::{CStr};
 
 use crate::adapter::adapter_backend::*;
+use crate::adapter::adapter_ddrerror::DdrError;
+use crate::adapter::adapter_iddrbackend_trait::IDdrBackend;
+use crate::adapter::adapter_nativebackend::NativeBackend;
 
 
 /// Alias representing the handle to the memory block being accessed.
@@ -13,22 +17,71 @@ pub type DataHandle = i32;
 
 /// Represents the memory blocks that can be accessed.  Hides how memory blocks
 /// are actually identified.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum MemoryBlockNumber
 {
     // First block
     MemoryBlock0,
     // Second block
-    #[allow(dead_code)] // Not currently used
     MemoryBlock1,
     // Third block
-    #[allow(dead_code)] // Not currently used
     MemoryBlock2,
 }
 
+impl MemoryBlockNumber {
+    /// Retrieve an iterator over all of the memory blocks available in the
+    /// back-end, in order.
+    pub fn iter() -> impl Iterator<Item = MemoryBlockNumber> {
+        [
+            MemoryBlockNumber::MemoryBlock0,
+            MemoryBlockNumber::MemoryBlock1,
+            MemoryBlockNumber::MemoryBlock2,
+        ].into_iter()
+    }
+
+    /// Retrieve the memory block with the given index.
+    ///
+    /// # Parameters
+    /// - index
+    ///
+    ///   Index of the memory block, starting at 0.
+    ///
+    /// # Returns
+    /// Returns the memory block, or None if there is no block with that index.
+    pub fn from_index(index: usize) -> Option<MemoryBlockNumber> {
+        MemoryBlockNumber::iter().nth(index)
+    }
+
+    /// Retrieve the index of the memory block, starting at 0.
+    pub fn index(&self) -> usize {
+        match self {
+            MemoryBlockNumber::MemoryBlock0 => 0,
+            MemoryBlockNumber::MemoryBlock1 => 1,
+            MemoryBlockNumber::MemoryBlock2 => 2,
+        }
+    }
+
+    /// Retrieve the name the back-end uses for the memory block.
+    pub fn name(&self) -> &'static str {
+        _block_number_to_name(self)
+    }
+}
+
+/// Represents the order in which the bytes of an integer are stored in a
+/// memory block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endianness {
+    /// Least significant byte first.
+    Little,
+    /// Most significant byte first.
+    Big,
+}
+
 /// Represents a data reader/writer to a caller.
-/// Adapts the functions from the Adapter_BackEnd DLL (as wrapped by the functions
-/// in adapter_backend.rs).
+/// Adapts the functions from the Adapter_BackEnd DLL (as represented by the
+/// IDdrBackend trait).
 pub struct DataReaderWriter {
+    _backend: Box<dyn IDdrBackend>,
     _data_handle: DataHandle,
     _block_number: MemoryBlockNumber,
 
@@ -40,44 +93,73 @@ impl DataReaderWriter {
     /// Constructor for DataReaderWriter struct.
     ///
     /// # Parameters
-    /// - blockNumber
+    /// - block_number
+    ///
+    ///   A value from the MemoryBlockNumber enumeration indicating the block
+    ///   of memory to open.
+    ///
+    /// # Returns
+    /// Returns `Ok(DataReaderWriter)` with a new instance of the
+    /// DataReaderWriter struct that uses the Adapter_BackEnd DLL; otherwise,
+    /// returns `Err(String)` if the DLL could not be loaded.
+    pub fn new(block_number: MemoryBlockNumber) -> Result<DataReaderWriter, String> {
+        Ok(DataReaderWriter::with_backend(block_number, NativeBackend::new()?))
+    }
+
+    /// Constructor for DataReaderWriter struct that uses the given back-end
+    /// instead of the Adapter_BackEnd DLL.
+    ///
+    /// # Parameters
+    /// - block_number
     ///
     ///   A value from the MemoryBlockNumber enumeration indicating the block
     ///   of memory to open.
-    pub fn new(block_number: MemoryBlockNumber) -> DataReaderWriter {
+    /// - backend
+    ///
+    ///   The back-end to use, as represented by the IDdrBackend trait.
+    ///
+    /// # Returns
+    /// Returns a new instance of the DataReaderWriter struct.
+    pub fn with_backend(block_number: MemoryBlockNumber, backend: Box<dyn IDdrBackend>) -> DataReaderWriter {
         DataReaderWriter {
+            _backend: backend,
             _data_handle: DDR_INVALID_HANDLE,
             _block_number: block_number,
             memory_block_byte_size: 0
         }
     }
 
+    /// The memory block this DataReaderWriter accesses.
+    pub fn block_number(&self) -> MemoryBlockNumber {
+        self._block_number
+    }
+
     /// Open a memory block for access.  Which memory block to open is specified
     /// in the constructor.  If this is successful, the memory block is open for
     /// reading and writing.  Call close() to shut down access to the memory
     /// block.
-    pub fn open(&mut self) -> Result<(), String> {
-        let block_name = _block_number_to_name(&self._block_number);
-        let name_ptr = block_name.as_ptr() as *const ::std::os::raw::c_char;
-   
-        let mut error_code = unsafe {
-            ddr_open_memory_block(name_ptr, &mut self._data_handle)
-        };
+    ///
+    /// # Returns
+    /// If successful, returns `Ok(())`; otherwise, returns `Err(DdrError)`
+    /// describing the failure.
+    pub fn open(&mut self) -> Result<(), DdrError> {
+        let block_name = self._backend.block_name(self._block_number);
+        let mut error_code = self._backend.open_memory_block(block_name, &mut self._data_handle);
 
         if let DDR_ErrorCode_Success = error_code {
             let mut memory_size: ::std::os::raw::c_int = 0;
-            error_code = unsafe { ddr_get_memory_size(self._data_handle, &mut memory_size) };
+            error_code = self._backend.get_memory_size(self._data_handle, &mut memory_size);
             if error_code == DDR_ErrorCode_Success {
                 self.memory_block_byte_size = (memory_size as usize) * 4;
                 Ok(())
             }
             else {
-                Err(_report_ddr_error(error_code, "Opening memory block"))
+                Err(DdrError::new(error_code, "Opening memory block", self._data_handle, None))
             }
         }
         else
         {
-            Err(_report_ddr_error(error_code, "Opening memory block"))
+            Err(DdrError::new(error_code, "Opening memory block", self._data_handle, None))
         }
     }
 
@@ -86,15 +168,19 @@ impl DataReaderWriter {
     /// memory block can be opened again by a call to open().  Otherwise,
     /// instantiate the DataReaderWriter structure again to specify a different
     /// memory block.
-    pub fn close(&mut self) -> Result<(), String> {
-        let error_code = unsafe { ddr_close_memory_block(self._data_handle) };
+    ///
+    /// # Returns
+    /// If successful, returns `Ok(())`; otherwise, returns `Err(DdrError)`
+    /// describing the failure.
+    pub fn close(&mut self) -> Result<(), DdrError> {
+        let error_code = self._backend.close_memory_block(self._data_handle);
 
         if let DDR_ErrorCode_Success = error_code {
             self._data_handle = DDR_INVALID_HANDLE;
             self.memory_block_byte_size = 0;
             Ok(())
         } else {
-            Err(_report_ddr_error(error_code, "Attempting to close memory block"))
+            Err(DdrError::new(error_code, "Attempting to close memory block", self._data_handle, None))
         }
     }
 
@@ -120,16 +206,18 @@ impl DataReaderWriter {
     ///
     /// # Returns
     /// If successful, returns `Ok(Vec<u8>)` containing the bytes that were actually
-    /// read; otherwise, returns `Err(String)` containing the reason for the failure.
-    pub fn read(&self, byte_offset: i32, max_bytes: usize) -> Result<Vec<u8>, String> {
+    /// read; otherwise, returns `Err(DdrError)` describing the failure.
+    pub fn read(&self, byte_offset: i32, max_bytes: usize) -> Result<Vec<u8>, DdrError> {
         let mut data: Vec<u8> = vec![0; max_bytes];
 
         let mut chunk_offset = byte_offset / 4; // 4 bytes per 32-bit chunk
         let mut value: u32 = 0;
-        let mut error_code = unsafe { ddr_get_data_chunk(self._data_handle, chunk_offset, &mut value) };
+        let mut error_code = self._backend.get_data_chunk(self._data_handle, chunk_offset, &mut value);
         if let DDR_ErrorCode_Success = error_code {
             let mut buffer_index = 0;
             let mut byte_offset_in_chunk = byte_offset % 4;
+            // Skip the bytes in the first chunk that come before byte_offset.
+            value >>= byte_offset_in_chunk * 8;
             while buffer_index < max_bytes {
                 data[buffer_index] = value as u8;
                 buffer_index += 1;
@@ -141,18 +229,18 @@ impl DataReaderWriter {
                         break;
                     }
                     byte_offset_in_chunk = 0;
-                    error_code = unsafe { ddr_get_data_chunk(self._data_handle, chunk_offset, &mut value)};
+                    error_code = self._backend.get_data_chunk(self._data_handle, chunk_offset, &mut value);
                     if let DDR_ErrorCode_Success = error_code {
                         continue;
                     } else {
-                        return Err(_report_ddr_error(error_code, "Reading successive memory chunk"));
+                        return Err(DdrError::new(error_code, "Reading successive memory chunk", self._data_handle, Some(chunk_offset)));
                     }
                 }
             }
 
             Ok(data)
         } else {
-            Err(_report_ddr_error(error_code, "Reading initial memory chunk"))
+            Err(DdrError::new(error_code, "Reading initial memory chunk", self._data_handle, Some(chunk_offset)))
         }
     }
 
@@ -170,9 +258,8 @@ impl DataReaderWriter {
     ///
     /// # Returns
     /// If successful, returns `Ok(i32)` containing the number of bytes actually
-    /// written; otherwise, returns `Err(String)` containing the reason for the
-    /// failure.
-    pub fn write(&self, byte_offset: i32, bytes_to_write: &Vec<u8>) -> Result<usize, String> {
+    /// written; otherwise, returns `Err(DdrError)` describing the failure.
+    pub fn write(&self, byte_offset: i32, bytes_to_write: &Vec<u8>) -> Result<usize, DdrError> {
         let mut chunk_offset = byte_offset / 4;
         let mut value: u32 = 0;
         let mut byte_offset_in_chunk = byte_offset % 4;
@@ -180,7 +267,7 @@ impl DataReaderWriter {
         let mut byte_mask : u32 = 0xffu32 << (byte_offset_in_chunk * 8);
         let mut error_code = DDR_ErrorCode_Success;
         if byte_offset_in_chunk != 0 {
-            error_code = unsafe { ddr_get_data_chunk(self._data_handle, chunk_offset, &mut value) };
+            error_code = self._backend.get_data_chunk(self._data_handle, chunk_offset, &mut value);
         }
         if let DDR_ErrorCode_Success = error_code {
             while buffer_index < bytes_to_write.len() {
@@ -190,7 +277,7 @@ impl DataReaderWriter {
                 byte_mask <<= 8;
                 byte_offset_in_chunk += 1;
                 if byte_offset_in_chunk == 4 {
-                    error_code = unsafe { ddr_set_data_chunk(self._data_handle, chunk_offset, value) };
+                    error_code = self._backend.set_data_chunk(self._data_handle, chunk_offset, value);
                     if let DDR_ErrorCode_Success = error_code {
                         byte_mask = 0xffu32;
                         byte_offset_in_chunk = 0;
@@ -198,31 +285,306 @@ impl DataReaderWriter {
                         if chunk_offset >= DDR_MAX_OFFSET {
                             break;
                         }
-                        error_code = unsafe { ddr_get_data_chunk(self._data_handle, chunk_offset, &mut value) };
+                        error_code = self._backend.get_data_chunk(self._data_handle, chunk_offset, &mut value);
                         if let DDR_ErrorCode_Success = error_code {
 
                         } else {
-                            return Err(_report_ddr_error(error_code, "Reading memory in preparation to writing memory"));
+                            return Err(DdrError::new(error_code, "Reading memory in preparation to writing memory", self._data_handle, Some(chunk_offset)));
                         }
                     
                     } else {
-                        return Err(_report_ddr_error(error_code, "Writing memory"));
+                        return Err(DdrError::new(error_code, "Writing memory", self._data_handle, Some(chunk_offset)));
                     }
                 }
             }
             if byte_offset_in_chunk != 0 {
-                error_code = unsafe { ddr_set_data_chunk(self._data_handle, chunk_offset, value) };
+                error_code = self._backend.set_data_chunk(self._data_handle, chunk_offset, value);
                 if let DDR_ErrorCode_Success = error_code {
 
                 } else {
-                    return Err(_report_ddr_error(error_code, "Writing memory"));
+                    return Err(DdrError::new(error_code, "Writing memory", self._data_handle, Some(chunk_offset)));
                 }
             }
             Ok(buffer_index)
         } else {
-            Err(_report_ddr_error(error_code, "Reading memory in preparation to writing memory"))
+            Err(DdrError::new(error_code, "Reading memory in preparation to writing memory", self._data_handle, Some(chunk_offset)))
         }
     }
+
+    /// Read several spans of bytes from the currently opened memory block in
+    /// one operation (a scatter/gather read).  Each 32-bit chunk covered by
+    /// the spans is read from the back-end only once, even when adjacent or
+    /// overlapping spans share it.
+    ///
+    /// # Parameters
+    /// - regions
+    ///
+    ///   List of (byte offset, number of bytes) pairs describing the spans
+    ///   to read.  Every span must lie entirely within the memory block.
+    ///
+    /// # Returns
+    /// If successful, returns `Ok(Vec<Vec<u8>>)` containing the bytes read
+    /// for each span, in the same order as the spans; otherwise, returns
+    /// `Err(DdrError)` describing the failure.  Nothing is read if any span
+    /// is out of bounds.
+    pub fn read_v(&self, regions: &[(i32, usize)]) -> Result<Vec<Vec<u8>>, DdrError> {
+        let mut chunks: BTreeMap<i32, u32> = BTreeMap::new();
+        for &(byte_offset, byte_count) in regions {
+            self._check_value_range(byte_offset, byte_count, "Checking region to read")?;
+            for position in byte_offset..byte_offset + byte_count as i32 {
+                chunks.insert(position.div_euclid(4), 0);
+            }
+        }
+
+        for (&chunk_offset, value) in chunks.iter_mut() {
+            let error_code = self._backend.get_data_chunk(self._data_handle, chunk_offset, value);
+            if error_code != DDR_ErrorCode_Success {
+                return Err(DdrError::new(error_code, "Reading memory chunk for region", self._data_handle, Some(chunk_offset)));
+            }
+        }
+
+        Ok(regions.iter()
+            .map(|&(byte_offset, byte_count)| {
+                (byte_offset..byte_offset + byte_count as i32)
+                    .map(|position| (chunks[&position.div_euclid(4)] >> (position.rem_euclid(4) * 8)) as u8)
+                    .collect()
+            })
+            .collect())
+    }
+
+    /// Write several spans of bytes to the currently opened memory block in
+    /// one operation (a scatter/gather write).  Each 32-bit chunk covered by
+    /// the spans is written to the back-end only once, and is read first
+    /// only if the spans do not cover all of its bytes.  Where spans
+    /// overlap, the later span wins.
+    ///
+    /// # Parameters
+    /// - regions
+    ///
+    ///   List of (byte offset, bytes) pairs describing the spans to write.
+    ///   Every span must lie entirely within the memory block.
+    ///
+    /// # Returns
+    /// If successful, returns `Ok(usize)` containing the total number of
+    /// bytes written; otherwise, returns `Err(DdrError)` describing the
+    /// failure.  Nothing is written if any span is out of bounds.
+    pub fn write_v(&self, regions: &[(i32, &[u8])]) -> Result<usize, DdrError> {
+        // Chunk offset -> (new value, mask of the bytes set in new value)
+        let mut chunks: BTreeMap<i32, (u32, u32)> = BTreeMap::new();
+        let mut bytes_written = 0;
+        for &(byte_offset, bytes) in regions {
+            self._check_value_range(byte_offset, bytes.len(), "Checking region to write")?;
+            for (position, &byte) in (byte_offset..).zip(bytes.iter()) {
+                let shift = position.rem_euclid(4) * 8;
+                let (value, mask) = chunks.entry(position.div_euclid(4)).or_default();
+                *value = (*value & !(0xffu32 << shift)) | ((byte as u32) << shift);
+                *mask |= 0xffu32 << shift;
+            }
+            bytes_written += bytes.len();
+        }
+
+        for (&chunk_offset, &(mut value, mask)) in chunks.iter() {
+            if mask != u32::MAX {
+                let mut old_value: u32 = 0;
+                let error_code = self._backend.get_data_chunk(self._data_handle, chunk_offset, &mut old_value);
+                if error_code != DDR_ErrorCode_Success {
+                    return Err(DdrError::new(error_code, "Reading memory in preparation to writing memory", self._data_handle, Some(chunk_offset)));
+                }
+                value |= old_value & !mask;
+            }
+            let error_code = self._backend.set_data_chunk(self._data_handle, chunk_offset, value);
+            if error_code != DDR_ErrorCode_Success {
+                return Err(DdrError::new(error_code, "Writing memory", self._data_handle, Some(chunk_offset)));
+            }
+        }
+
+        Ok(bytes_written)
+    }
+
+    /// Helper method to make sure a value of the given size fits in the
+    /// currently opened memory block at the given offset.  If the memory
+    /// block is not open, the back-end is left to report the problem.
+    ///
+    /// # Parameters
+    /// - byte_offset
+    ///
+    ///   Byte offset into the memory block where the value starts.
+    /// - size
+    ///
+    ///   Size of the value, in bytes.
+    /// - context
+    ///
+    ///   A prompt that indicates the context in which an error occurred.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the value fits; otherwise, returns
+    /// `Err(DdrError::InvalidOffset)`.
+    fn _check_value_range(&self, byte_offset: i32, size: usize, context: &str) -> Result<(), DdrError> {
+        let out_of_range = byte_offset < 0 || byte_offset as usize + size > self.memory_block_byte_size;
+        if self._data_handle != DDR_INVALID_HANDLE && out_of_range {
+            return Err(DdrError::new(DDR_ErrorCode_Invalid_Offset, context, self._data_handle, Some(byte_offset / 4)));
+        }
+        Ok(())
+    }
+
+    /// Helper method to read exactly N bytes from the currently opened memory
+    /// block.
+    fn _read_exact<const N: usize>(&self, byte_offset: i32, context: &str) -> Result<[u8; N], DdrError> {
+        self._check_value_range(byte_offset, N, context)?;
+        let data = self.read(byte_offset, N)?;
+        let mut bytes = [0u8; N];
+        bytes.copy_from_slice(&data);
+        Ok(bytes)
+    }
+
+    /// Helper method to write all of the given bytes to the currently opened
+    /// memory block.
+    fn _write_exact(&self, byte_offset: i32, bytes: &[u8], context: &str) -> Result<(), DdrError> {
+        self._check_value_range(byte_offset, bytes.len(), context)?;
+        self.write(byte_offset, &bytes.to_vec())?;
+        Ok(())
+    }
+
+    /// Read a 16-bit unsigned integer from the currently opened memory block.
+    ///
+    /// # Parameters
+    /// - byte_offset
+    ///
+    ///   Byte offset into the memory block where the value starts.  The
+    ///   value does not need to be aligned.
+    /// - endianness
+    ///
+    ///   The order in which the bytes of the value are stored.
+    ///
+    /// # Returns
+    /// If successful, returns `Ok(u16)` containing the value; otherwise,
+    /// returns `Err(DdrError)` describing the failure.
+    pub fn read_u16(&self, byte_offset: i32, endianness: Endianness) -> Result<u16, DdrError> {
+        let bytes = self._read_exact(byte_offset, "Reading 16-bit value")?;
+        Ok(match endianness {
+            Endianness::Little => u16::from_le_bytes(bytes),
+            Endianness::Big => u16::from_be_bytes(bytes),
+        })
+    }
+
+    /// Read a 32-bit unsigned integer from the currently opened memory block.
+    ///
+    /// # Parameters
+    /// - byte_offset
+    ///
+    ///   Byte offset into the memory block where the value starts.  The
+    ///   value does not need to be aligned.
+    /// - endianness
+    ///
+    ///   The order in which the bytes of the value are stored.
+    ///
+    /// # Returns
+    /// If successful, returns `Ok(u32)` containing the value; otherwise,
+    /// returns `Err(DdrError)` describing the failure.
+    pub fn read_u32(&self, byte_offset: i32, endianness: Endianness) -> Result<u32, DdrError> {
+        let bytes = self._read_exact(byte_offset, "Reading 32-bit value")?;
+        Ok(match endianness {
+            Endianness::Little => u32::from_le_bytes(bytes),
+            Endianness::Big => u32::from_be_bytes(bytes),
+        })
+    }
+
+    /// Read a 64-bit unsigned integer from the currently opened memory block.
+    ///
+    /// # Parameters
+    /// - byte_offset
+    ///
+    ///   Byte offset into the memory block where the value starts.  The
+    ///   value does not need to be aligned.
+    /// - endianness
+    ///
+    ///   The order in which the bytes of the value are stored.
+    ///
+    /// # Returns
+    /// If successful, returns `Ok(u64)` containing the value; otherwise,
+    /// returns `Err(DdrError)` describing the failure.
+    pub fn read_u64(&self, byte_offset: i32, endianness: Endianness) -> Result<u64, DdrError> {
+        let bytes = self._read_exact(byte_offset, "Reading 64-bit value")?;
+        Ok(match endianness {
+            Endianness::Little => u64::from_le_bytes(bytes),
+            Endianness::Big => u64::from_be_bytes(bytes),
+        })
+    }
+
+    /// Write a 16-bit unsigned integer to the currently opened memory block.
+    ///
+    /// # Parameters
+    /// - byte_offset
+    ///
+    ///   Byte offset into the memory block where the value starts.  The
+    ///   value does not need to be aligned.
+    /// - value
+    ///
+    ///   The value to write.
+    /// - endianness
+    ///
+    ///   The order in which to store the bytes of the value.
+    ///
+    /// # Returns
+    /// If successful, returns `Ok(())`; otherwise, returns `Err(DdrError)`
+    /// describing the failure.
+    pub fn write_u16(&self, byte_offset: i32, value: u16, endianness: Endianness) -> Result<(), DdrError> {
+        let bytes = match endianness {
+            Endianness::Little => value.to_le_bytes(),
+            Endianness::Big => value.to_be_bytes(),
+        };
+        self._write_exact(byte_offset, &bytes, "Writing 16-bit value")
+    }
+
+    /// Write a 32-bit unsigned integer to the currently opened memory block.
+    ///
+    /// # Parameters
+    /// - byte_offset
+    ///
+    ///   Byte offset into the memory block where the value starts.  The
+    ///   value does not need to be aligned.
+    /// - value
+    ///
+    ///   The value to write.
+    /// - endianness
+    ///
+    ///   The order in which to store the bytes of the value.
+    ///
+    /// # Returns
+    /// If successful, returns `Ok(())`; otherwise, returns `Err(DdrError)`
+    /// describing the failure.
+    pub fn write_u32(&self, byte_offset: i32, value: u32, endianness: Endianness) -> Result<(), DdrError> {
+        let bytes = match endianness {
+            Endianness::Little => value.to_le_bytes(),
+            Endianness::Big => value.to_be_bytes(),
+        };
+        self._write_exact(byte_offset, &bytes, "Writing 32-bit value")
+    }
+
+    /// Write a 64-bit unsigned integer to the currently opened memory block.
+    ///
+    /// # Parameters
+    /// - byte_offset
+    ///
+    ///   Byte offset into the memory block where the value starts.  The
+    ///   value does not need to be aligned.
+    /// - value
+    ///
+    ///   The value to write.
+    /// - endianness
+    ///
+    ///   The order in which to store the bytes of the value.
+    ///
+    /// # Returns
+    /// If successful, returns `Ok(())`; otherwise, returns `Err(DdrError)`
+    /// describing the failure.
+    pub fn write_u64(&self, byte_offset: i32, value: u64, endianness: Endianness) -> Result<(), DdrError> {
+        let bytes = match endianness {
+            Endianness::Little => value.to_le_bytes(),
+            Endianness::Big => value.to_be_bytes(),
+        };
+        self._write_exact(byte_offset, &bytes, "Writing 64-bit value")
+    }
 }
 
 impl Drop for DataReaderWriter {
@@ -234,8 +596,8 @@ impl Drop for DataReaderWriter {
     }
 }
 
-/// Helper function to convert the MemoryBlockNumber enumeration to a string
-/// that names the memory block to be opened in the back-end functions.
+/// Helper function to convert the MemoryBlockNumber enumeration to the name
+/// of the memory block to be opened in the back-end functions.
 ///
 /// # Parameters
 /// - block_number
@@ -244,81 +606,60 @@ impl Drop for DataReaderWriter {
 ///   block to be opened.
 ///
 /// # Returns
-/// Returns the name of the memory block as a reference to a string slice.
-fn _block_number_to_name<'a>(block_number: &MemoryBlockNumber) -> &'a str {
-    match block_number {
-        MemoryBlockNumber::MemoryBlock0 => {
-            let var_name = unsafe { CStr::from_ptr(block_name_0) };
-            var_name.to_str().unwrap()
-        },
-        MemoryBlockNumber::MemoryBlock1 => {
-            let var_name = unsafe { CStr::from_ptr(block_name_1) };
-            var_name.to_str().unwrap()
-        },
-        MemoryBlockNumber::MemoryBlock2 => {
-            let var_name = unsafe { CStr::from_ptr(block_name_2) };
-            var_name.to_str().unwrap()
-        },
-    }
+/// Returns the name of the memory block as a reference to a C string.
+fn _block_number_to_c_name(block_number: &MemoryBlockNumber) -> &'static CStr {
+    block_names()[block_number.index()]
 }
 
-/// Helper function to convert a DDR_ErrorCode to a human-readable representation.
+/// Helper function to convert the MemoryBlockNumber enumeration to a string
+/// that names the memory block to be opened in the back-end functions.
 ///
 /// # Parameters
-/// - error_code
+/// - block_number
 ///
-///   A value from the DDR_ErrorCode enumeration representing the error code.
+///   A value from the MemoryBlockNumber enumeration indicating the memory
+///   block to be opened.
 ///
 /// # Returns
-/// Returns a string containing the human-readable representation of the given
-/// error code.
-fn _errorcode_to_string(error_code: DDR_ErrorCode) -> String {
-    match error_code {
-        DDR_ErrorCode_Success => String::from("Operation succeeded"),
-        DDR_ErrorCode_Block_Already_Opened => String::from("Memory block is already open and cannot be opened again"),
-        DDR_ErrorCode_Block_Not_Opened => String::from("Memory block is closed and cannot be accessed"),
-        DDR_ErrorCode_Invalid_Block_Name => String::from("The given name is not a recognized memory block name"),
-        DDR_ErrorCode_Invalid_Handle => String::from("The handle argument does not correspond to a valid open memory block"),
-        DDR_ErrorCode_Invalid_Offset => String::from("The given offset is out of bounds"),
-        DDR_ErrorCode_Null_Argument => String::from("The block name pointer or return handle pointer argument is NULL"),
-        _ => String::from("Unknown error"),
-    }
+/// Returns the name of the memory block as a reference to a string slice.
+fn _block_number_to_name(block_number: &MemoryBlockNumber) -> &'static str {
+    _block_number_to_c_name(block_number).to_str().unwrap()
 }
 
-
-/// Helper function to format the given message and prompt into a single error
-/// message string, which is returned.
-///
-/// # Parameters
-/// - message
-///   
-///    The error message to report.
-/// - prompt
-///    
-///    A prompt that indicates the context in which the error occurred.
-fn _report_error_message(message: String, prompt: &str) -> String {
-    format!("Error! {prompt}: {message}.")
+/// Describes how adapter_buffer_to_string_with() formats a hex dump.  The
+/// default is the format produced by adapter_buffer_to_string().
+#[derive(Clone, Debug)]
+pub struct HexDumpOptions {
+    /// Number of spaces to indent each line.
+    pub indent: usize,
+    /// Number of bytes shown on each line.  Must be greater than 0.
+    pub bytes_per_row: usize,
+    /// Number of bytes in each group on a line.  Groups are separated by an
+    /// extra space.  0 means the bytes are not grouped.
+    pub group_size: usize,
+    /// true to show the printable ASCII characters for the bytes at the end
+    /// of each line, with a '.' for each byte that is not printable.
+    pub show_ascii: bool,
+    /// Offset shown for the first byte of the data.  Useful when the data
+    /// came from the middle of a larger buffer.
+    pub base_offset: usize,
+    /// true to show hexadecimal digits in uppercase.
+    pub uppercase: bool,
 }
 
-
-/// Helper function to create a human-readable error message for the given
-/// error code, adding a prompt to provide some context to the error.  Returns
-/// the string.
-///
-/// # Parameters
-/// - error_code
-///    
-///    A value from the DDR_ErrorCode enumeration indicating the error that
-///    occurred.
-/// - "prompt
-///    
-///    A prompt that indicates the context in which the error occurred.
-fn _report_ddr_error(error_code: DDR_ErrorCode, prompt: &str) -> String {
-    let message = _errorcode_to_string(error_code);
-    _report_error_message(message, prompt)
+impl Default for HexDumpOptions {
+    fn default() -> Self {
+        HexDumpOptions {
+            indent: 0,
+            bytes_per_row: 32,
+            group_size: 0,
+            show_ascii: false,
+            base_offset: 0,
+            uppercase: false,
+        }
+    }
 }
 
-
 /// Convert the specified data up to the specified number of bytes into
 /// a string by performing a "hex dump" on the data.
 ///
@@ -335,25 +676,160 @@ fn _report_ddr_error(error_code: DDR_ErrorCode, prompt: &str) -> String {
 /// hex dump, possibly multiple lines; otherwise, retursn `Err(String)` containing
 /// the reason for the failure.
 pub fn adapter_buffer_to_string(data: &Vec<u8>, indent: usize) -> Result<String, String> {
+    adapter_buffer_to_string_with(data, &HexDumpOptions { indent, ..Default::default() })
+}
+
+/// Convert the specified data into a string by performing a "hex dump" on
+/// the data, formatted as described by the given options.
+///
+/// # Parameters
+/// - data
+///
+///   The bytes to process.
+/// - options
+///
+///   A HexDumpOptions object describing how to format the hex dump.
+///
+/// # Returns
+/// If successful, returns `Ok(String)` containing the data in the form of a
+/// hex dump, possibly multiple lines; otherwise, returns `Err(String)`
+/// containing the reason for the failure.
+pub fn adapter_buffer_to_string_with(data: &[u8], options: &HexDumpOptions) -> Result<String, String> {
+    if options.bytes_per_row == 0 {
+        return Err(String::from("A hex dump needs at least 1 byte per row"));
+    }
     let mut output = String::from("");
 
-    let bytes_per_row = 32;
+    let bytes_per_row = options.bytes_per_row;
+    let indent = options.indent;
 
     let mut row = 0;
     while row < data.len() {
-        let row_start = format!("{:>indent$}0x{row:04x} --", "");
+        let row_offset = options.base_offset + row;
+        let row_start = if options.uppercase {
+            format!("{:>indent$}0x{row_offset:04X} --", "")
+        } else {
+            format!("{:>indent$}0x{row_offset:04x} --", "")
+        };
         output.push_str(&row_start);
 
         let mut col = 0;
-        while col < bytes_per_row && (row + col) < data.len() {
+        while col < bytes_per_row {
             let data_index = row + col;
-            let space = if col > 0 { " " } else { "" };
-            let field = format!("{space}{:02x}", data[data_index]);
-            output.push_str(&field);
+            let mut space = if col > 0 { String::from(" ") } else { String::new() };
+            if col > 0 && options.group_size > 0 && col.is_multiple_of(options.group_size) {
+                space.push(' ');
+            }
+            if data_index < data.len() {
+                let field = if options.uppercase {
+                    format!("{space}{:02X}", data[data_index])
+                } else {
+                    format!("{space}{:02x}", data[data_index])
+                };
+                output.push_str(&field);
+            } else if options.show_ascii {
+                // Pad a short last row so the ASCII column lines up.
+                output.push_str(&format!("{space}  "));
+            } else {
+                break;
+            }
             col += 1;
         }
-        output.push_str("\n");
+        if options.show_ascii {
+            let row_end = (row + bytes_per_row).min(data.len());
+            let ascii: String = data[row..row_end].iter()
+                .map(|byte| if byte.is_ascii_graphic() || *byte == b' ' { *byte as char } else { '.' })
+                .collect();
+            output.push_str(&format!("  |{ascii}|"));
+        }
+        output.push('\n');
         row += bytes_per_row;
     }
     Ok(output)
 }
+
+//-----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::adapter_mockbackend::{MockBackEndFunction, MockBackend};
+
+    /// Construct a DataReaderWriter on a MockBackend, with the memory block
+    /// open and its first chunks holding the bytes 0x00, 0x01, 0x02, and so
+    /// on, so each byte shows where it came from.
+    ///
+    /// # Returns
+    /// Returns the MockBackend, for scripting errors and looking at the
+    /// chunks, and the DataReaderWriter using it.
+    fn _open_with_mock() -> (MockBackend, DataReaderWriter) {
+        let backend = MockBackend::new();
+        let mut reader_writer = DataReaderWriter::with_backend(
+            MemoryBlockNumber::MemoryBlock0, Box::new(backend.clone()));
+        reader_writer.open().expect("the mock memory block opens");
+        for (chunk_offset, value) in [0x03020100u32, 0x07060504, 0x0b0a0908, 0x0f0e0d0c].iter().enumerate() {
+            backend.set_data_chunk(reader_writer._data_handle, chunk_offset as i32, *value);
+        }
+        backend.clear_call_log();
+        (backend, reader_writer)
+    }
+
+    /// Helper function to read a chunk straight from the MockBackend.
+    fn _chunk(backend: &MockBackend, reader_writer: &DataReaderWriter, chunk_offset: i32) -> u32 {
+        let mut value = 0;
+        backend.get_data_chunk(reader_writer._data_handle, chunk_offset, &mut value);
+        value
+    }
+
+    #[test]
+    fn read_spans_chunk_boundary() {
+        let (backend, reader_writer) = _open_with_mock();
+        let data = reader_writer.read(2, 4).expect("the read succeeds");
+        assert_eq!(data, vec![0x02, 0x03, 0x04, 0x05]);
+        let chunks_read: Vec<String> = backend.get_call_log().iter()
+            .map(|call| format!("{} {}", call.function, call.arguments))
+            .collect();
+        assert_eq!(chunks_read, vec![
+            "DDR_GetDataChunk dataHandle=0, chunkOffset=0 -> value=0x03020100",
+            "DDR_GetDataChunk dataHandle=0, chunkOffset=1 -> value=0x07060504",
+        ]);
+    }
+
+    #[test]
+    fn write_spans_chunk_boundary() {
+        let (backend, reader_writer) = _open_with_mock();
+        let bytes_written = reader_writer.write(2, &vec![0xaa, 0xbb, 0xcc, 0xdd]).expect("the write succeeds");
+        assert_eq!(bytes_written, 4);
+        // The bytes around the ones written are left as they were.
+        assert_eq!(_chunk(&backend, &reader_writer, 0), 0xbbaa0100);
+        assert_eq!(_chunk(&backend, &reader_writer, 1), 0x0706ddcc);
+        assert_eq!(_chunk(&backend, &reader_writer, 2), 0x0b0a0908);
+    }
+
+    #[test]
+    fn read_reports_error_partway_through() {
+        let (backend, reader_writer) = _open_with_mock();
+        backend.inject_error(MockBackEndFunction::GetDataChunk, 2, DDR_ErrorCode_Block_Not_Opened);
+        let error = reader_writer.read(2, 10).err();
+        assert_eq!(error, Some(DdrError::BlockNotOpened {
+            context: String::from("Reading successive memory chunk"),
+            handle: reader_writer._data_handle,
+            offset: Some(2),
+        }));
+    }
+
+    #[test]
+    fn write_reports_error_partway_through() {
+        let (backend, reader_writer) = _open_with_mock();
+        backend.inject_error(MockBackEndFunction::SetDataChunk, 1, DDR_ErrorCode_Invalid_Offset);
+        let error = reader_writer.write(0, &vec![0xa0, 0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7]).err();
+        assert_eq!(error, Some(DdrError::InvalidOffset {
+            context: String::from("Writing memory"),
+            handle: reader_writer._data_handle,
+            offset: Some(1),
+        }));
+        // The first chunk was written before the error; the second was not.
+        assert_eq!(_chunk(&backend, &reader_writer, 0), 0xa3a2a1a0);
+        assert_eq!(_chunk(&backend, &reader_writer, 1), 0x07060504);
+    }
+}
@@ -0,0 +1,91 @@
+//! Contains the IDdrBackend trait that represents the functions of the
+//! Adapter_BackEnd DLL, so the DataReaderWriter can work with either the real
+//! back-end or a mock.
+
+//-----------------------------------------------------------------------------
+
+use std::ffi::CStr;
+
+use crate::adapter::adapter_backend::DDR_ErrorCode;
+use crate::adapter::adapter_functions::{DataHandle, MemoryBlockNumber};
+
+//-----------------------------------------------------------------------------
+
+/// Represents the memory block access functions of the back-end.  Each
+/// method matches one function in the Adapter_BackEnd DLL, except that the
+/// methods are safe to call: pointers are replaced with references.  Like the
+/// DLL, each method returns a DDR_ErrorCode value indicating success or
+/// failure.
+pub trait IDdrBackend {
+    /// Retrieve the name the back-end uses for the given memory block, to
+    /// pass to open_memory_block().
+    ///
+    /// # Parameters
+    /// - block_number
+    ///
+    ///   A value from the MemoryBlockNumber enumeration indicating the memory
+    ///   block.
+    fn block_name(&self, block_number: MemoryBlockNumber) -> &'static CStr;
+
+    /// Open access to a memory block for exclusive use, given the name of the
+    /// memory block.
+    ///
+    /// # Parameters
+    /// - block_name
+    ///
+    ///   Name of the block to access.
+    /// - data_handle
+    ///
+    ///   Returns a handle to be used for accessing the memory block.
+    fn open_memory_block(&self, block_name: &CStr, data_handle: &mut DataHandle) -> DDR_ErrorCode;
+
+    /// Close access to a previously opened memory block, thus releasing it
+    /// for others to open.
+    ///
+    /// # Parameters
+    /// - data_handle
+    ///
+    ///   Handle to a previously opened memory block.
+    fn close_memory_block(&self, data_handle: DataHandle) -> DDR_ErrorCode;
+
+    /// Retrieve the number of chunks in the memory block.
+    ///
+    /// # Parameters
+    /// - data_handle
+    ///
+    ///   Handle to a previously opened memory block.
+    /// - memory_size_in_chunks
+    ///
+    ///   Returns the number of 32-bit chunks in the memory block.
+    fn get_memory_size(&self, data_handle: DataHandle, memory_size_in_chunks: &mut i32) -> DDR_ErrorCode;
+
+    /// Read a single 32-bit value at the given offset in the memory block.
+    ///
+    /// # Parameters
+    /// - data_handle
+    ///
+    ///   Handle to a previously opened memory block.
+    /// - chunk_offset
+    ///
+    ///   Offset into the memory block from which to get the value (range is
+    ///   0 to DDR_MAX_OFFSET-1).
+    /// - value
+    ///
+    ///   Returns the requested value.
+    fn get_data_chunk(&self, data_handle: DataHandle, chunk_offset: i32, value: &mut u32) -> DDR_ErrorCode;
+
+    /// Write a single 32-bit value to the given offset in the memory block.
+    ///
+    /// # Parameters
+    /// - data_handle
+    ///
+    ///   Handle to a previously opened memory block.
+    /// - chunk_offset
+    ///
+    ///   Offset into the memory block to which to set the value (range is 0
+    ///   to DDR_MAX_OFFSET-1).
+    /// - value
+    ///
+    ///   The value to write to the memory block.
+    fn set_data_chunk(&self, data_handle: DataHandle, chunk_offset: i32, value: u32) -> DDR_ErrorCode;
+}
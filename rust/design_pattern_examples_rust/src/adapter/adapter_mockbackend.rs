@@ -0,0 +1,487 @@
+//! Contains the MockBackend struct, a simulation of the Adapter_BackEnd DLL
+//! that implements the IDdrBackend trait.
+//!
+//! The memory blocks are simulated in Rust, behaving the same as the real
+//! back-end, and every call is recorded, along with its arguments and
+//! result, in a call log that can be retrieved with get_call_log().  Errors
+//! can be scripted with inject_error() so that a chosen call fails, which
+//! makes it possible to exercise the error paths of the DataReaderWriter,
+//! such as a failure partway through reading across chunk boundaries.
+//!
+//! When the `mock_backend` feature is enabled, this module also provides the
+//! same names as adapter_backend.rs, backed by a MockBackend for each thread,
+//! so it can be used in place of the DLL without change to the rest of the
+//! code.
+
+#![allow(non_camel_case_types, non_upper_case_globals)]
+
+//-----------------------------------------------------------------------------
+
+use std::cell::RefCell;
+use std::ffi::CStr;
+use std::rc::Rc;
+
+#[cfg(not(feature = "mock_backend"))]
+use crate::adapter::adapter_backend::{
+    DDR_ErrorCode, DDR_ErrorCode_Block_Already_Opened, DDR_ErrorCode_Block_Not_Opened,
+    DDR_ErrorCode_Invalid_Block_Name, DDR_ErrorCode_Invalid_Handle, DDR_ErrorCode_Invalid_Offset,
+    DDR_ErrorCode_Null_Argument, DDR_ErrorCode_Success, DDR_MAX_OFFSET,
+};
+use crate::adapter::adapter_functions::{DataHandle, MemoryBlockNumber};
+use crate::adapter::adapter_iddrbackend_trait::IDdrBackend;
+
+//-----------------------------------------------------------------------------
+
+/// Names of the simulated memory blocks, the same as in the real back-end.
+const MOCK_BLOCK_NAMES: [&CStr; 3] = [c"gorp", c"baba", c"yaga"];
+
+/// The names and values provided by adapter_backend.rs, for use when this
+/// module replaces it.
+#[cfg(feature = "mock_backend")]
+mod backend_names {
+    use std::ffi::CStr;
+    use std::os::raw::c_int;
+
+    /// There is no library to load so this always succeeds.
+    pub fn load_backend() -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Retrieve the names of the three memory blocks.
+    pub fn block_names() -> [&'static CStr; 3] {
+        super::MOCK_BLOCK_NAMES
+    }
+
+    /// All offsets must from 0 to 1 less than this value.
+    pub const DDR_MAX_OFFSET: _bindgen_ty_1 = 32;
+    /// Value indicating the handle is invalid
+    pub const DDR_INVALID_HANDLE: _bindgen_ty_1 = -1;
+    pub type _bindgen_ty_1 = c_int;
+    /// Operation succeeded
+    pub const DDR_ErrorCode_Success: DDR_ErrorCode = 0;
+    /// Memory block is already open and cannot be opened again
+    pub const DDR_ErrorCode_Block_Already_Opened: DDR_ErrorCode = 1;
+    /// Memory block is closed and cannot be accessed
+    pub const DDR_ErrorCode_Block_Not_Opened: DDR_ErrorCode = 2;
+    /// The given name is not a recognized memory block name
+    pub const DDR_ErrorCode_Invalid_Block_Name: DDR_ErrorCode = 3;
+    /// The handle argument does not correspond to a valid open memory block
+    pub const DDR_ErrorCode_Invalid_Handle: DDR_ErrorCode = 4;
+    /// The given offset is out of bounds
+    pub const DDR_ErrorCode_Invalid_Offset: DDR_ErrorCode = 5;
+    /// The block name pointer or return handle pointer argument is NULL
+    pub const DDR_ErrorCode_Null_Argument: DDR_ErrorCode = 6;
+    /// Represents the possible errors that can be returned from the memory
+    /// block access functions.
+    pub type DDR_ErrorCode = c_int;
+}
+#[cfg(feature = "mock_backend")]
+pub use backend_names::*;
+
+//-----------------------------------------------------------------------------
+
+/// Represents the functions in the back-end, for scripting errors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MockBackEndFunction {
+    OpenMemoryBlock,
+    CloseMemoryBlock,
+    GetMemorySize,
+    GetDataChunk,
+    SetDataChunk,
+}
+
+impl MockBackEndFunction {
+    /// Retrieve the name of the function as named in the DLL.
+    pub fn name(&self) -> &'static str {
+        match self {
+            MockBackEndFunction::OpenMemoryBlock => "DDR_OpenMemoryBlock",
+            MockBackEndFunction::CloseMemoryBlock => "DDR_CloseMemoryBlock",
+            MockBackEndFunction::GetMemorySize => "DDR_GetMemorySize",
+            MockBackEndFunction::GetDataChunk => "DDR_GetDataChunk",
+            MockBackEndFunction::SetDataChunk => "DDR_SetDataChunk",
+        }
+    }
+}
+
+/// Represents one call to the mock back-end.
+#[derive(Clone)]
+pub struct MockBackEndCall {
+    /// Name of the back-end function called, as named in the DLL.
+    pub function: &'static str,
+    /// The arguments passed in, and values passed back, formatted for
+    /// display.
+    pub arguments: String,
+    /// The error code returned from the call.
+    pub result: DDR_ErrorCode,
+}
+
+/// Represents one simulated memory block.
+struct MockMemoryBlock {
+    /// Name of the memory block.
+    name: &'static str,
+    /// true if the memory block is open.
+    locked: bool,
+    /// The contents of the memory block.
+    data: [u32; DDR_MAX_OFFSET as usize],
+}
+
+/// Represents an error scripted with MockBackend::inject_error().
+struct InjectedError {
+    /// The function that is to fail.
+    function: MockBackEndFunction,
+    /// Number of calls to the function still to let through before failing.
+    calls_to_skip: usize,
+    /// The error code the failing call returns.
+    error_code: DDR_ErrorCode,
+}
+
+/// The state of a mock back-end, shared by all clones of a MockBackend.
+struct MockBackEndState {
+    /// The simulated memory blocks.  A handle is an index into this list.
+    blocks: Vec<MockMemoryBlock>,
+    /// Every call made to the mock back-end, in order.
+    calls: Vec<MockBackEndCall>,
+    /// Errors still waiting to be returned.
+    injected_errors: Vec<InjectedError>,
+}
+
+impl MockBackEndState {
+    /// Record a call and return its result.
+    fn record_call(&mut self, function: MockBackEndFunction, arguments: String, result: DDR_ErrorCode) -> DDR_ErrorCode {
+        self.calls.push(MockBackEndCall { function: function.name(), arguments, result });
+        result
+    }
+
+    /// Count a call to the given function against the injected errors.
+    ///
+    /// # Returns
+    /// Returns the error code the call must fail with, or None if the call
+    /// is to proceed normally.
+    fn take_injected_error(&mut self, function: MockBackEndFunction) -> Option<DDR_ErrorCode> {
+        let index = self.injected_errors.iter()
+            .position(|injected| injected.function == function && injected.calls_to_skip == 0);
+        for injected in self.injected_errors.iter_mut() {
+            if injected.function == function && injected.calls_to_skip > 0 {
+                injected.calls_to_skip -= 1;
+            }
+        }
+        index.map(|index| self.injected_errors.remove(index).error_code)
+    }
+
+    /// Get the index of the open memory block for a handle.
+    ///
+    /// # Returns
+    /// Returns `Ok(usize)` containing the index of the memory block;
+    /// otherwise, returns `Err(DDR_ErrorCode)` if the handle is invalid or
+    /// the block is not open.
+    fn open_block_index(&self, data_handle: DataHandle) -> Result<usize, DDR_ErrorCode> {
+        if data_handle < 0 || data_handle as usize >= self.blocks.len() {
+            Err(DDR_ErrorCode_Invalid_Handle)
+        } else if !self.blocks[data_handle as usize].locked {
+            Err(DDR_ErrorCode_Block_Not_Opened)
+        } else {
+            Ok(data_handle as usize)
+        }
+    }
+}
+
+/// Helper function to determine if a chunk offset is in range.
+fn _is_valid_offset(chunk_offset: i32) -> bool {
+    (0..DDR_MAX_OFFSET).contains(&chunk_offset)
+}
+
+//-----------------------------------------------------------------------------
+
+/// Represents a simulated Adapter_BackEnd DLL.  Clones of a MockBackend share
+/// the same memory blocks, call log and injected errors, so a clone can be
+/// given to a DataReaderWriter while the original is used to script errors
+/// and inspect the calls made.
+#[derive(Clone)]
+pub struct MockBackend {
+    /// The shared state of the mock back-end.
+    state: Rc<RefCell<MockBackEndState>>,
+}
+
+impl MockBackend {
+    /// Constructor.  The simulated memory blocks have the same names as the
+    /// memory blocks in the real back-end, so the DLL does not need to be
+    /// loaded.
+    ///
+    /// # Returns
+    /// Returns a new instance of the MockBackend struct.
+    pub fn new() -> MockBackend {
+        let blocks = MOCK_BLOCK_NAMES.iter()
+            .map(|name| MockMemoryBlock {
+                name: name.to_str().unwrap(),
+                locked: false,
+                data: [0; DDR_MAX_OFFSET as usize],
+            })
+            .collect();
+        MockBackend {
+            state: Rc::new(RefCell::new(MockBackEndState {
+                blocks,
+                calls: vec![],
+                injected_errors: vec![],
+            })),
+        }
+    }
+
+    /// Retrieve a copy of every call made to the mock back-end since the
+    /// last call to clear_call_log().
+    pub fn get_call_log(&self) -> Vec<MockBackEndCall> {
+        self.state.borrow().calls.clone()
+    }
+
+    /// Forget all calls made to the mock back-end so far.
+    pub fn clear_call_log(&self) {
+        self.state.borrow_mut().calls.clear();
+    }
+
+    /// Script a call to a back-end function to fail.
+    ///
+    /// # Parameters
+    /// - function
+    ///
+    ///   The function that is to fail.
+    /// - calls_to_skip
+    ///
+    ///   Number of calls to the function to let through first.  0 makes the
+    ///   next call fail.
+    /// - error_code
+    ///
+    ///   The error code the failing call returns.  The call has no other
+    ///   effect.
+    pub fn inject_error(&self, function: MockBackEndFunction, calls_to_skip: usize, error_code: DDR_ErrorCode) {
+        self.state.borrow_mut().injected_errors.push(InjectedError { function, calls_to_skip, error_code });
+    }
+}
+
+impl IDdrBackend for MockBackend {
+    fn block_name(&self, block_number: MemoryBlockNumber) -> &'static CStr {
+        MOCK_BLOCK_NAMES[block_number.index()]
+    }
+
+    /// Opens the named block, filling it with 0xff bytes, and returns its
+    /// handle through data_handle.
+    fn open_memory_block(&self, block_name: &CStr, data_handle: &mut DataHandle) -> DDR_ErrorCode {
+        let function = MockBackEndFunction::OpenMemoryBlock;
+        let name = block_name.to_string_lossy().into_owned();
+        let mut state = self.state.borrow_mut();
+        let result = match state.take_injected_error(function) {
+            Some(error_code) => error_code,
+            None => match state.blocks.iter().position(|block| block.name == name) {
+                None => DDR_ErrorCode_Invalid_Block_Name,
+                Some(index) if state.blocks[index].locked => DDR_ErrorCode_Block_Already_Opened,
+                Some(index) => {
+                    state.blocks[index].locked = true;
+                    state.blocks[index].data = [0xffffffff; DDR_MAX_OFFSET as usize];
+                    *data_handle = index as DataHandle;
+                    DDR_ErrorCode_Success
+                }
+            },
+        };
+        let arguments = match result {
+            DDR_ErrorCode_Success => format!("blockName=\"{name}\" -> dataHandle={}", *data_handle),
+            _ => format!("blockName=\"{name}\""),
+        };
+        state.record_call(function, arguments, result)
+    }
+
+    /// Closes the block for the handle.
+    fn close_memory_block(&self, data_handle: DataHandle) -> DDR_ErrorCode {
+        let function = MockBackEndFunction::CloseMemoryBlock;
+        let mut state = self.state.borrow_mut();
+        let result = match state.take_injected_error(function) {
+            Some(error_code) => error_code,
+            None => match state.open_block_index(data_handle) {
+                Ok(index) => {
+                    state.blocks[index].locked = false;
+                    DDR_ErrorCode_Success
+                }
+                Err(error_code) => error_code,
+            },
+        };
+        state.record_call(function, format!("dataHandle={data_handle}"), result)
+    }
+
+    /// Returns the size of the block, in 32-bit chunks, through
+    /// memory_size_in_chunks.
+    fn get_memory_size(&self, data_handle: DataHandle, memory_size_in_chunks: &mut i32) -> DDR_ErrorCode {
+        let function = MockBackEndFunction::GetMemorySize;
+        let mut state = self.state.borrow_mut();
+        let result = match state.take_injected_error(function) {
+            Some(error_code) => error_code,
+            None => match state.open_block_index(data_handle) {
+                Ok(_) => {
+                    *memory_size_in_chunks = DDR_MAX_OFFSET;
+                    DDR_ErrorCode_Success
+                }
+                Err(error_code) => error_code,
+            },
+        };
+        let arguments = match result {
+            DDR_ErrorCode_Success => format!("dataHandle={data_handle} -> memorySizeInChunks={}", *memory_size_in_chunks),
+            _ => format!("dataHandle={data_handle}"),
+        };
+        state.record_call(function, arguments, result)
+    }
+
+    /// Returns the 32-bit value at the chunk offset through value.
+    fn get_data_chunk(&self, data_handle: DataHandle, chunk_offset: i32, value: &mut u32) -> DDR_ErrorCode {
+        let function = MockBackEndFunction::GetDataChunk;
+        let mut state = self.state.borrow_mut();
+        let result = match state.take_injected_error(function) {
+            Some(error_code) => error_code,
+            None => match state.open_block_index(data_handle) {
+                Ok(_) if !_is_valid_offset(chunk_offset) => DDR_ErrorCode_Invalid_Offset,
+                Ok(index) => {
+                    *value = state.blocks[index].data[chunk_offset as usize];
+                    DDR_ErrorCode_Success
+                }
+                Err(error_code) => error_code,
+            },
+        };
+        let arguments = format!("dataHandle={data_handle}, chunkOffset={chunk_offset}");
+        let arguments = match result {
+            DDR_ErrorCode_Success => format!("{arguments} -> value=0x{:08x}", *value),
+            _ => arguments,
+        };
+        state.record_call(function, arguments, result)
+    }
+
+    /// Writes the 32-bit value to the chunk offset.
+    fn set_data_chunk(&self, data_handle: DataHandle, chunk_offset: i32, value: u32) -> DDR_ErrorCode {
+        let function = MockBackEndFunction::SetDataChunk;
+        let mut state = self.state.borrow_mut();
+        let result = match state.take_injected_error(function) {
+            Some(error_code) => error_code,
+            None => match state.open_block_index(data_handle) {
+                Ok(_) if !_is_valid_offset(chunk_offset) => DDR_ErrorCode_Invalid_Offset,
+                Ok(index) => {
+                    state.blocks[index].data[chunk_offset as usize] = value;
+                    DDR_ErrorCode_Success
+                }
+                Err(error_code) => error_code,
+            },
+        };
+        let arguments = format!("dataHandle={data_handle}, chunkOffset={chunk_offset}, value=0x{value:08x}");
+        state.record_call(function, arguments, result)
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Convert an error code to its name, for display.
+///
+/// # Parameters
+/// - error_code
+///
+///   The error code to convert.
+///
+/// # Returns
+/// Returns the name of the error code without the DDR_ErrorCode_ prefix.
+pub fn error_code_name(error_code: DDR_ErrorCode) -> &'static str {
+    match error_code {
+        DDR_ErrorCode_Success => "Success",
+        DDR_ErrorCode_Block_Already_Opened => "Block_Already_Opened",
+        DDR_ErrorCode_Block_Not_Opened => "Block_Not_Opened",
+        DDR_ErrorCode_Invalid_Block_Name => "Invalid_Block_Name",
+        DDR_ErrorCode_Invalid_Handle => "Invalid_Handle",
+        DDR_ErrorCode_Invalid_Offset => "Invalid_Offset",
+        DDR_ErrorCode_Null_Argument => "Null_Argument",
+        _ => "Unknown",
+    }
+}
+
+//-----------------------------------------------------------------------------
+// Replacements for the functions in adapter_backend.rs, used when the
+// `mock_backend` feature is enabled.
+//-----------------------------------------------------------------------------
+
+#[cfg(feature = "mock_backend")]
+thread_local! {
+    /// The mock back-end that replaces the DLL.  Each thread gets its own, so
+    /// separate threads (such as tests running in parallel) do not see each
+    /// other's calls.
+    static MOCK_BACKEND: MockBackend = MockBackend::new();
+}
+
+/// Retrieve a copy of every call made to the mock back-end that replaces the
+/// DLL since the last call to clear_call_log().
+#[cfg(feature = "mock_backend")]
+pub fn get_call_log() -> Vec<MockBackEndCall> {
+    MOCK_BACKEND.with(|backend| backend.get_call_log())
+}
+
+/// Forget all calls made to the mock back-end that replaces the DLL so far.
+#[cfg(feature = "mock_backend")]
+pub fn clear_call_log() {
+    MOCK_BACKEND.with(|backend| backend.clear_call_log());
+}
+
+/// Helper function to record a call that was rejected because of a NULL
+/// pointer argument.
+#[cfg(feature = "mock_backend")]
+fn _record_null_argument(function: MockBackEndFunction, arguments: String) -> DDR_ErrorCode {
+    MOCK_BACKEND.with(|backend| {
+        backend.state.borrow_mut().record_call(function, arguments, DDR_ErrorCode_Null_Argument)
+    })
+}
+
+/// Mock of DDR_OpenMemoryBlock().
+///
+/// # Safety
+/// block_name must be NULL or point to a null-terminated string and
+/// data_handle must be NULL or point to a writable integer, the same as for
+/// the real back-end.
+#[cfg(feature = "mock_backend")]
+pub unsafe fn ddr_open_memory_block(block_name: *const std::os::raw::c_char, data_handle: *mut DataHandle) -> DDR_ErrorCode {
+    if block_name.is_null() || data_handle.is_null() {
+        return _record_null_argument(MockBackEndFunction::OpenMemoryBlock,
+            String::from("blockName=NULL or dataHandle=NULL"));
+    }
+    MOCK_BACKEND.with(|backend| backend.open_memory_block(CStr::from_ptr(block_name), &mut *data_handle))
+}
+
+/// Mock of DDR_CloseMemoryBlock().
+///
+/// # Safety
+/// Always safe to call; unsafe only to match the real back-end.
+#[cfg(feature = "mock_backend")]
+pub unsafe fn ddr_close_memory_block(data_handle: DataHandle) -> DDR_ErrorCode {
+    MOCK_BACKEND.with(|backend| backend.close_memory_block(data_handle))
+}
+
+/// Mock of DDR_GetMemorySize().
+///
+/// # Safety
+/// memory_size_in_chunks must be NULL or point to a writable integer.
+#[cfg(feature = "mock_backend")]
+pub unsafe fn ddr_get_memory_size(data_handle: DataHandle, memory_size_in_chunks: *mut i32) -> DDR_ErrorCode {
+    if memory_size_in_chunks.is_null() {
+        return _record_null_argument(MockBackEndFunction::GetMemorySize, format!("dataHandle={data_handle}"));
+    }
+    MOCK_BACKEND.with(|backend| backend.get_memory_size(data_handle, &mut *memory_size_in_chunks))
+}
+
+/// Mock of DDR_GetDataChunk().
+///
+/// # Safety
+/// value must be NULL or point to a writable u32.
+#[cfg(feature = "mock_backend")]
+pub unsafe fn ddr_get_data_chunk(data_handle: DataHandle, chunk_offset: i32, value: *mut u32) -> DDR_ErrorCode {
+    if value.is_null() {
+        return _record_null_argument(MockBackEndFunction::GetDataChunk,
+            format!("dataHandle={data_handle}, chunkOffset={chunk_offset}"));
+    }
+    MOCK_BACKEND.with(|backend| backend.get_data_chunk(data_handle, chunk_offset, &mut *value))
+}
+
+/// Mock of DDR_SetDataChunk().
+///
+/// # Safety
+/// Always safe to call; unsafe only to match the real back-end.
+#[cfg(feature = "mock_backend")]
+pub unsafe fn ddr_set_data_chunk(data_handle: DataHandle, chunk_offset: i32, value: u32) -> DDR_ErrorCode {
+    MOCK_BACKEND.with(|backend| backend.set_data_chunk(data_handle, chunk_offset, value))
+}
@@ -0,0 +1,58 @@
+//! Contains the NativeBackend struct that implements the IDdrBackend trait by
+//! calling the functions in the Adapter_BackEnd DLL (via the function
+//! wrappers in adapter_backend.rs).
+
+//-----------------------------------------------------------------------------
+
+use std::ffi::CStr;
+
+use crate::adapter::adapter_backend::*;
+use crate::adapter::adapter_functions::{DataHandle, MemoryBlockNumber};
+use crate::adapter::adapter_iddrbackend_trait::IDdrBackend;
+
+//-----------------------------------------------------------------------------
+
+/// Represents the Adapter_BackEnd DLL.  This is the only place the unsafe
+/// back-end functions are called.
+pub struct NativeBackend {}
+
+impl NativeBackend {
+    /// Constructor
+    ///
+    /// Loads the Adapter_BackEnd DLL, if not already loaded.
+    ///
+    /// # Returns
+    /// Returns `Ok(Box<dyn IDdrBackend>)` with a new instance of the
+    /// NativeBackend struct as represented by the IDdrBackend trait;
+    /// otherwise, returns `Err(String)` if the DLL could not be loaded.
+    pub fn new() -> Result<Box<dyn IDdrBackend>, String> {
+        load_backend()?;
+        Ok(Box::new(NativeBackend {}))
+    }
+}
+
+impl IDdrBackend for NativeBackend {
+    fn block_name(&self, block_number: MemoryBlockNumber) -> &'static CStr {
+        block_names()[block_number.index()]
+    }
+
+    fn open_memory_block(&self, block_name: &CStr, data_handle: &mut DataHandle) -> DDR_ErrorCode {
+        unsafe { ddr_open_memory_block(block_name.as_ptr(), data_handle) }
+    }
+
+    fn close_memory_block(&self, data_handle: DataHandle) -> DDR_ErrorCode {
+        unsafe { ddr_close_memory_block(data_handle) }
+    }
+
+    fn get_memory_size(&self, data_handle: DataHandle, memory_size_in_chunks: &mut i32) -> DDR_ErrorCode {
+        unsafe { ddr_get_memory_size(data_handle, memory_size_in_chunks) }
+    }
+
+    fn get_data_chunk(&self, data_handle: DataHandle, chunk_offset: i32, value: &mut u32) -> DDR_ErrorCode {
+        unsafe { ddr_get_data_chunk(data_handle, chunk_offset, value) }
+    }
+
+    fn set_data_chunk(&self, data_handle: DataHandle, chunk_offset: i32, value: u32) -> DDR_ErrorCode {
+        unsafe { ddr_set_data_chunk(data_handle, chunk_offset, value) }
+    }
+}
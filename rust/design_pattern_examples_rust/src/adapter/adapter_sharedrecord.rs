@@ -0,0 +1,115 @@
+//! Contains the SharedRecord struct, a small structured record that one
+//! endpoint writes to a memory block and another endpoint reads back and
+//! validates.
+
+//-----------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use crate::adapter::adapter_functions::{DataReaderWriter, Endianness};
+
+//-----------------------------------------------------------------------------
+
+/// Marks the start of a record in a memory block ("SREC").
+const SHARED_RECORD_MAGIC: u32 = 0x53524543;
+
+/// Represents a sensor reading passed between two endpoints through a memory
+/// block.  In the memory block, the record is stored as big-endian values:
+///
+/// | Byte offset | Size | Contents                                  |
+/// |-------------|------|-------------------------------------------|
+/// | 0           | 4    | SHARED_RECORD_MAGIC                       |
+/// | 4           | 4    | sequence                                  |
+/// | 8           | 4    | temperature                               |
+/// | 12          | 8    | timestamp                                 |
+/// | 20          | 4    | checksum of the fields above              |
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SharedRecord {
+    /// Increases by one with each record the writer produces.
+    pub sequence: u32,
+    /// Temperature, in hundredths of a degree.
+    pub temperature: i32,
+    /// Time of the reading, in seconds.
+    pub timestamp: u64,
+}
+
+impl SharedRecord {
+    /// Number of bytes the record occupies in a memory block.
+    pub const BYTE_SIZE: usize = 24;
+
+    /// Helper method to calculate the checksum stored after the fields.
+    fn _checksum(&self) -> u32 {
+        let words = [
+            SHARED_RECORD_MAGIC,
+            self.sequence,
+            self.temperature as u32,
+            (self.timestamp >> 32) as u32,
+            self.timestamp as u32,
+        ];
+        words.iter().fold(0u32, |checksum, word| checksum.rotate_left(5) ^ word)
+    }
+
+    /// Write this record to an open memory block.
+    ///
+    /// # Parameters
+    /// - reader_writer
+    ///
+    ///   The DataReaderWriter for the memory block, which must be open.
+    /// - byte_offset
+    ///
+    ///   Byte offset into the memory block where the record starts.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the record was written; otherwise, returns
+    /// `Err(String)` describing the failure.
+    pub fn write_to(&self, reader_writer: &DataReaderWriter, byte_offset: i32) -> Result<(), String> {
+        reader_writer.write_u32(byte_offset, SHARED_RECORD_MAGIC, Endianness::Big)?;
+        reader_writer.write_u32(byte_offset + 4, self.sequence, Endianness::Big)?;
+        reader_writer.write_u32(byte_offset + 8, self.temperature as u32, Endianness::Big)?;
+        reader_writer.write_u64(byte_offset + 12, self.timestamp, Endianness::Big)?;
+        reader_writer.write_u32(byte_offset + 20, self._checksum(), Endianness::Big)?;
+        Ok(())
+    }
+
+    /// Read a record from an open memory block and validate it.
+    ///
+    /// # Parameters
+    /// - reader_writer
+    ///
+    ///   The DataReaderWriter for the memory block, which must be open.
+    /// - byte_offset
+    ///
+    ///   Byte offset into the memory block where the record starts.
+    ///
+    /// # Returns
+    /// Returns `Ok(SharedRecord)` containing the record if one was found and
+    /// its checksum matches; otherwise, returns `Err(String)` describing
+    /// why the record is not valid.
+    pub fn read_from(reader_writer: &DataReaderWriter, byte_offset: i32) -> Result<SharedRecord, String> {
+        let magic = reader_writer.read_u32(byte_offset, Endianness::Big)?;
+        if magic != SHARED_RECORD_MAGIC {
+            return Err(format!("No record at byte offset {byte_offset} (found 0x{magic:08x} instead of 0x{SHARED_RECORD_MAGIC:08x})"));
+        }
+        let record = SharedRecord {
+            sequence: reader_writer.read_u32(byte_offset + 4, Endianness::Big)?,
+            temperature: reader_writer.read_u32(byte_offset + 8, Endianness::Big)? as i32,
+            timestamp: reader_writer.read_u64(byte_offset + 12, Endianness::Big)?,
+        };
+        let checksum = reader_writer.read_u32(byte_offset + 20, Endianness::Big)?;
+        if checksum != record._checksum() {
+            return Err(format!("Record {} is corrupt (checksum 0x{checksum:08x}, expected 0x{:08x})",
+                record.sequence, record._checksum()));
+        }
+        Ok(record)
+    }
+}
+
+impl Display for SharedRecord {
+    /// Formats the record as "record N: T degrees at Ss".
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.temperature < 0 { "-" } else { "" };
+        let hundredths = self.temperature.unsigned_abs();
+        write!(f, "record {}: {sign}{}.{:02} degrees at {}s", self.sequence,
+            hundredths / 100, hundredths % 100, self.timestamp)
+    }
+}
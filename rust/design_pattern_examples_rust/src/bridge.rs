@@ -7,6 +7,47 @@
 //! Take note of how the calls into the logger are the same regardless of the
 //! logger used.
 //!
+//! What each line looks like is a second, independent choice: each logger
+//! is given a formatter (plain text, JSON, or key=value) through the
+//! ILogFormatter trait, so any logger can be combined with any formatter.
+//!
+//! The ColorConsoleLogger shows each level in its own color, falling back to
+//! plain text when the console does not support colors.
+//!
+//! One of the loggers writes to the operating system's log, which on each
+//! platform is a completely different technology (syslog or the Windows Event
+//! Log), yet is used through the same calls as the others.
+//!
+//! Another logger streams lines to a log server over TCP; the exercise runs
+//! a small log server on the local machine to show the lines arriving.
+//!
+//! Several loggers can be combined into one with a MultiLogger, which passes
+//! each line to all of them.
+//!
+//! A MemoryLogger keeps each line in memory, so what was logged can be
+//! examined afterwards, which is handy for checking output in tests.
+//!
+//! Loggers with more options (appending to files, leaving out time stamps,
+//! colors, and so on) are described with a LoggerBuilder, which the factory
+//! functions also use.
+//!
+//! Any logger can also be made asynchronous, queuing lines for a background
+//! thread to write, again without changing the calls that log.
+//!
+//! Finally, the LogBridge (in bridge_logbridge.rs) puts any logger behind the
+//! standard log crate, so code logging with `log::info!()` and the other log
+//! macros also writes through the bridge.
+//!
+//! Each logger has a minimum log level; messages below that level are
+//! skipped, and the level can be changed at any time.
+//!
+//! The same idea is then scaled up to the whole program with a logger
+//! registry that maps module names to loggers.  A module asks the registry
+//! for its logger by name (for example, `get_logger("flyweight")`) and logs
+//! to it without knowing where the output goes; modules without a logger of
+//! their own fall back to a default logger.  The Flyweight and Mediator
+//! exercises log their errors this way.
+//!
 //! Accessed through the bridge_exercise() function.
 
 pub mod bridge_ilogger_trait;
@@ -14,10 +55,37 @@ pub mod bridge_logger;
 pub mod bridge_nulllogger;
 pub mod bridge_filelogger;
 pub mod bridge_consolelogger;
+pub mod bridge_colorconsolelogger;
+pub mod bridge_systemlogger;
+pub mod bridge_tcplogger;
+pub mod bridge_asynclogger;
+pub mod bridge_logbridge;
+pub mod bridge_multilogger;
+pub mod bridge_memorylogger;
+pub mod bridge_loggerbuilder;
 pub mod bridge_loghelper;
+pub mod bridge_loggerregistry;
+pub mod bridge_ilogformatter_trait;
+pub mod bridge_logformatter;
+pub mod bridge_plainformatter;
+pub mod bridge_jsonformatter;
+pub mod bridge_keyvalueformatter;
+
+use std::env;
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::net::TcpListener;
+use std::path::Path;
+use std::thread;
 
-use bridge_ilogger_trait::ILogger;
-use bridge_logger::{create_logger, LoggerType};
+use bridge_ilogger_trait::{ILogger, LogLevel};
+use bridge_logformatter::FormatterType;
+use bridge_logger::{create_async_logger, create_logger, create_logger_with_formatter, try_create_logger, LoggerType};
+use bridge_loggerbuilder::LoggerBuilder;
+use bridge_loggerregistry::{get_logger, has_logger, register_logger};
+use bridge_memorylogger::MemoryLogger;
+use bridge_multilogger::MultiLogger;
+use crate::helpers::exercise_options::get_exercise_option_value;
 
 //-----------------------------------------------------------------------------
 
@@ -45,6 +113,42 @@ fn _bridge_exercise_demonstrate_logging(logger: &mut Box<dyn ILogger>, logger_ty
 }
 
 
+/// Helper function to start a log server on the local machine that accepts
+/// one connection and collects the lines sent over it until the connection
+/// is closed.
+///
+/// # Returns
+/// Returns `Ok((String, JoinHandle))` containing the address of the log
+/// server, as "host:port", and the thread running the server, which returns
+/// the lines received; otherwise, returns `Err(String)` if the server could
+/// not be started.
+fn _bridge_start_log_server() -> Result<(String, thread::JoinHandle<Vec<String>>), String> {
+    let listener = TcpListener::bind("127.0.0.1:0").map_err(|error| error.to_string())?;
+    let address = listener.local_addr().map_err(|error| error.to_string())?.to_string();
+    let server = thread::spawn(move || match listener.accept() {
+        Ok((stream, _)) => BufReader::new(stream).lines().map_while(Result::ok).collect(),
+        Err(_) => Vec::new(),
+    });
+    Ok((address, server))
+}
+
+/// Helper function to read a log file written by the exercise in the
+/// temporary directory and then delete it, so no log files are left behind.
+///
+/// # Parameters
+/// - file_path
+///
+///   Path of the log file.
+///
+/// # Returns
+/// Returns `Ok(String)` containing the contents of the log file; otherwise,
+/// returns `Err(String)` if the log file could not be read.
+fn _bridge_take_log_file(file_path: &Path) -> Result<String, String> {
+    let contents = fs::read_to_string(file_path).map_err(|error| error.to_string())?;
+    let _ = fs::remove_file(file_path);
+    Ok(contents)
+}
+
 //-----------------------------------------------------------------------------
 
 
@@ -54,17 +158,29 @@ fn _bridge_exercise_demonstrate_logging(logger: &mut Box<dyn ILogger>, logger_ty
 /// multiple ways to perform logging without changing how the logging is
 /// used throughout the program.
 ///
+/// The log file is bridge.log in the current directory unless another path
+/// is given with the --log-file option; any missing directories in that
+/// path are created.
+///
 /// In this exercise, note how the calls into the logger are the
-/// same regardless of the logger used.
+/// same regardless of the logger used, and how changing a logger's minimum
+/// level changes what it writes without changing those calls.  Loggers and
+/// formatters are then mixed freely, since each side of the bridge varies
+/// independently of the other.
+///
+/// Finally, loggers are looked up by module name through the program's logger
+/// registry, showing a module logging without knowing where its output goes.
 
 // ! [Using Bridge in Rust]
 pub fn bridge_exercise() -> Result<(), String> {
     println!("");
     println!("Bridge Exercise");
 
+    let log_file = get_exercise_option_value("--log-file").unwrap_or("bridge.log".to_string());
+
     {
-        let mut logger = create_logger(LoggerType::ToFile, "bridge.log");
-        println!("  Example of writing to a log file...");
+        let mut logger = try_create_logger(LoggerType::ToFile, &log_file)?;
+        println!("  Example of writing to a log file ({log_file})...");
         _bridge_exercise_demonstrate_logging(&mut logger, "file");
     }
 
@@ -74,12 +190,180 @@ pub fn bridge_exercise() -> Result<(), String> {
         _bridge_exercise_demonstrate_logging(&mut logger, "console");
     }
 
+    {
+        let mut logger = create_logger(LoggerType::ToColorConsole, "");
+        println!("  Example of writing to the console in color...");
+        _bridge_exercise_demonstrate_logging(&mut logger, "color console");
+    }
+
+    {
+        let mut logger = create_logger(LoggerType::ToConsole, "").with_min_level(LogLevel::Error);
+        println!("  Example of changing the minimum log level while logging to the console...");
+        println!("    Minimum level {}:", logger.min_level().name());
+        _bridge_exercise_demonstrate_logging(&mut logger, "console");
+        for level in [LogLevel::Info, LogLevel::Trace] {
+            logger.set_min_level(level);
+            println!("    Minimum level {}:", logger.min_level().name());
+            _bridge_exercise_demonstrate_logging(&mut logger, "console");
+        }
+    }
+
+    {
+        println!("  Example of building loggers with the LoggerBuilder...");
+        println!("    Colored console lines without time stamps:");
+        let mut logger = LoggerBuilder::new().to_console().colored().with_timestamps(false).build();
+        _bridge_exercise_demonstrate_logging(&mut logger, "colored console");
+
+        println!("    Adding information and error lines without time stamps to {log_file}:");
+        {
+            let mut logger = LoggerBuilder::new()
+                .to_file(&log_file)
+                .append(true)
+                .with_level(LogLevel::Info)
+                .with_timestamps(false)
+                .build();
+            _bridge_exercise_demonstrate_logging(&mut logger, "file");
+        }
+        let contents = fs::read_to_string(&log_file).map_err(|error| error.to_string())?;
+        for line in contents.lines() {
+            println!("      {line}");
+        }
+    }
+
+    {
+        println!("  Example of mixing loggers with formatters...");
+        for formatter_type in [FormatterType::Plain, FormatterType::Json, FormatterType::KeyValue] {
+            println!("    Writing {} lines to the console...", formatter_type.name());
+            let mut logger = create_logger_with_formatter(LoggerType::ToConsole, "", formatter_type);
+            _bridge_exercise_demonstrate_logging(&mut logger, "console");
+        }
+        let json_file = env::temp_dir().join("bridge_json.log");
+        println!("    Writing {} lines to the file bridge_json.log...", FormatterType::Json.name());
+        {
+            let mut logger = create_logger_with_formatter(LoggerType::ToFile, &json_file.to_string_lossy(), FormatterType::Json);
+            _bridge_exercise_demonstrate_logging(&mut logger, "file");
+        }
+        for line in _bridge_take_log_file(&json_file)?.lines() {
+            println!("      {line}");
+        }
+    }
+
+    {
+        let mut logger = create_logger(LoggerType::ToSystem, "");
+        println!("  Example of writing to the system log (syslog or the Windows Event Log)...");
+        _bridge_exercise_demonstrate_logging(&mut logger, "system");
+    }
+
+    {
+        let (address, server) = _bridge_start_log_server()?;
+        println!("  Example of writing to a log server at {address}...");
+        {
+            let mut logger = create_logger(LoggerType::ToNetwork, &address);
+            _bridge_exercise_demonstrate_logging(&mut logger, "network");
+        }
+        let lines = server.join().map_err(|_| "The log server failed".to_string())?;
+        println!("    The log server received {} lines:", lines.len());
+        for line in lines.iter() {
+            println!("      {line}");
+        }
+
+        // Nothing listens on the address once the server is done, so the
+        // logger drops the lines until the reconnect interval has passed.
+        println!("  Example of writing to a log server that cannot be reached...");
+        let mut logger = create_logger(LoggerType::ToNetwork, &address);
+        _bridge_exercise_demonstrate_logging(&mut logger, "network");
+    }
+
+    {
+        println!("  Example of writing to the console and a log file at the same time...");
+        let multi_file = env::temp_dir().join("bridge_multi.log");
+        {
+            let mut multi_logger = MultiLogger::new();
+            multi_logger.add_logger(create_logger(LoggerType::ToConsole, ""));
+            multi_logger.add_logger(create_logger(LoggerType::ToFile, &multi_file.to_string_lossy()));
+            let mut logger: Box<dyn ILogger> = Box::new(multi_logger);
+            _bridge_exercise_demonstrate_logging(&mut logger, "console and file");
+        }
+        let contents = _bridge_take_log_file(&multi_file)?;
+        println!("    bridge_multi.log also contains {} lines", contents.lines().count());
+    }
+
+    {
+        println!("  Example of capturing logging in memory...");
+        let memory_logger = MemoryLogger::new();
+        let mut logger: Box<dyn ILogger> = Box::new(memory_logger.clone());
+        _bridge_exercise_demonstrate_logging(&mut logger, "memory");
+        for entry in memory_logger.entries() {
+            println!("    {:<5} {}", entry.level.name(), entry.message);
+        }
+        let found = memory_logger.contains(LogLevel::Error, "error log entry");
+        println!("    An error line was logged: {found}");
+        memory_logger.clear();
+        println!("    After clearing, {} lines remain", memory_logger.entries().len());
+    }
+
+    {
+        println!("  Example of writing to a log file in the background...");
+        let async_file = env::temp_dir().join("bridge_async.log");
+        {
+            let mut logger = create_async_logger(LoggerType::ToFile, &async_file.to_string_lossy());
+            _bridge_exercise_demonstrate_logging(&mut logger, "asynchronous file");
+            // Dropping the logger waits for the background thread to write
+            // everything still queued.
+        }
+        let contents = _bridge_take_log_file(&async_file)?;
+        println!("    bridge_async.log contains {} lines:", contents.lines().count());
+        for line in contents.lines() {
+            println!("      {line}");
+        }
+    }
+
     {
         let mut logger = create_logger(LoggerType::ToNull, "");
         println!("  Example of writing to a Null object (no output)...");
         _bridge_exercise_demonstrate_logging(&mut logger, "null");
     }
 
+    {
+        println!("  Example of routing logging by module through the logger registry...");
+        let registry_file = env::temp_dir().join("bridge_registry.log");
+        register_logger("bridge.file", create_logger(LoggerType::ToFile, &registry_file.to_string_lossy()));
+        register_logger("bridge.null", create_logger(LoggerType::ToNull, ""));
+        for module_name in ["bridge.file", "bridge.null", "flyweight"] {
+            let route = match has_logger(module_name) {
+                true => "its own logger",
+                false => "the default logger",
+            };
+            println!("    Module \"{module_name}\" logs to {route}");
+            let mut logger = get_logger(module_name);
+            _bridge_exercise_demonstrate_logging(&mut logger, module_name);
+        }
+        // Replacing the file logger closes the log file so it can be deleted.
+        register_logger("bridge.file", create_logger(LoggerType::ToNull, ""));
+        let contents = _bridge_take_log_file(&registry_file)?;
+        println!("    bridge_registry.log contains {} lines", contents.lines().count());
+    }
+
+    {
+        println!("  Example of logging with the log crate's macros through the bridge...");
+        // The log crate writes through the logger registered for "log", so
+        // its output can be redirected at any time.
+        if let Err(message) = bridge_logbridge::install(get_logger("log")) {
+            println!("    {message}");
+        }
+        let loggers = [
+            ("console", create_logger(LoggerType::ToConsole, "")),
+            ("console as key=value", create_logger_with_formatter(LoggerType::ToConsole, "", FormatterType::KeyValue)),
+        ];
+        for (logger_name, logger) in loggers {
+            register_logger("log", logger);
+            println!("    The log crate writes to the {logger_name}:");
+            log::info!("An informational line from log::info!()");
+            log::warn!("A warning from log::warn!()");
+            log::debug!("A debugging line from log::debug!()");
+        }
+    }
+
     println!("  Done.");
 
     Ok(())
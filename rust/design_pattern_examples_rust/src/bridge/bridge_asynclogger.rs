@@ -0,0 +1,91 @@
+//! Contains the AsyncLogger implementation.
+
+use std::boxed::Box;
+use std::sync::mpsc::{channel, Sender};
+use std::thread::{self, JoinHandle};
+
+use super::bridge_ilogger_trait::{ILogger, LogLevel};
+
+/// Represents one line waiting to be written by the background thread.
+struct QueuedLine {
+    /// Level of logging ("TRACE", "INFO", "ERROR")
+    loglevel: String,
+    /// Message to log
+    message: String,
+}
+
+/// Represents a logger that queues each line and returns immediately,
+/// leaving a background thread to pass the lines to another logger.  All
+/// queued lines are written before the AsyncLogger is dropped.
+///
+/// The other logger is created and used only on the background thread.
+/// Because the lines are formatted there, time stamps show when each line
+/// was written, not when it was logged.
+pub struct AsyncLogger {
+    /// Where lines are queued; None once the AsyncLogger is being dropped.
+    sender: Option<Sender<QueuedLine>>,
+    /// The background thread writing the lines.
+    writer_thread: Option<JoinHandle<()>>,
+    /// Messages below this level are skipped, without being queued.
+    min_level: LogLevel,
+}
+
+impl AsyncLogger {
+    /// Create an instance of an asynchronous logger.
+    ///
+    /// # Parameters
+    /// - create_logger
+    ///
+    ///   Function called on the background thread to create the logger that
+    ///   writes the lines.
+    ///
+    /// # Returns
+    ///   An instance of an ILogger object.
+    pub fn new<F>(create_logger: F) -> Box<dyn ILogger>
+        where F: FnOnce() -> Box<dyn ILogger> + Send + 'static
+    {
+        let (sender, receiver) = channel::<QueuedLine>();
+        let writer_thread = thread::spawn(move || {
+            let mut logger = create_logger();
+            // Ends when the sender is dropped and the queue is empty.
+            for line in receiver {
+                logger.write_line(&line.loglevel, &line.message);
+            }
+        });
+        Box::new(AsyncLogger {
+            sender: Some(sender),
+            writer_thread: Some(writer_thread),
+            min_level: LogLevel::Trace,
+        })
+    }
+}
+
+impl ILogger for AsyncLogger {
+    fn write_line(&mut self, loglevel: &str, message: &str) {
+        if let Some(sender) = &self.sender {
+            let line = QueuedLine { loglevel: loglevel.to_string(), message: message.to_string() };
+            if sender.send(line).is_err() {
+                eprintln!("Failed to queue log line: the background logger has stopped.");
+            }
+        }
+    }
+
+    fn min_level(&self) -> LogLevel {
+        self.min_level
+    }
+
+    fn set_min_level(&mut self, level: LogLevel) {
+        self.min_level = level;
+    }
+}
+
+impl Drop for AsyncLogger {
+    /// Closes the queue and waits for the background thread to write all
+    /// of the lines still in it.
+    fn drop(&mut self) {
+        self.sender = None;
+        if let Some(writer_thread) = self.writer_thread.take() {
+            let _ = writer_thread.join();
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! Contains the ColorConsoleLogger implementation.
+
+use std::boxed::Box;
+use std::io::{stdout, IsTerminal};
+
+use crossterm::style::Stylize;
+
+use crate::helpers::ansi::use_colors;
+use super::bridge_ilogger_trait::{ILogger, LogLevel};
+use super::bridge_ilogformatter_trait::ILogFormatter;
+
+/// Represents a logger that writes logging to the standard output, showing
+/// error lines in red, information lines in green and trace lines in grey.
+///
+/// If the console window does not understand ANSI escape sequences, or the
+/// standard output is redirected (to a file, for example), the lines are
+/// written as plain text instead, so no escape sequences end up in the output.
+pub struct ColorConsoleLogger {
+    /// Decides what each line looks like.
+    formatter: Box<dyn ILogFormatter>,
+    /// true to color each line; false to fall back to plain text.
+    use_colors: bool,
+    /// Messages below this level are skipped.
+    min_level: LogLevel,
+}
+
+impl ColorConsoleLogger {
+    /// Create an instance of a color console logger, which writes to the
+    /// standard output using the given formatter.
+    ///
+    /// # Parameters
+    /// - formatter
+    ///
+    ///   The formatter that decides what each line looks like.
+    ///
+    /// # Returns
+    ///   An instance of an ILogger object.
+    pub fn new(formatter: Box<dyn ILogFormatter>) -> Box<dyn ILogger> {
+        let use_colors = use_colors() && stdout().is_terminal();
+        Box::new(ColorConsoleLogger { formatter, use_colors, min_level: LogLevel::Trace })
+    }
+}
+
+impl ILogger for ColorConsoleLogger {
+    fn write_line(&mut self, loglevel:&str, message: &str) {
+        let output = self.formatter.format_line(loglevel, message);
+        if self.use_colors {
+            let line = output.trim_end_matches('\n');
+            match LogLevel::from_name(loglevel) {
+                Some(LogLevel::Error) => println!("{}", line.red()),
+                Some(LogLevel::Info) => println!("{}", line.green()),
+                _ => println!("{}", line.dark_grey()),
+            }
+        } else {
+            print!("{output}");
+        }
+    }
+
+    fn min_level(&self) -> LogLevel {
+        self.min_level
+    }
+
+    fn set_min_level(&mut self, level: LogLevel) {
+        self.min_level = level;
+    }
+}
@@ -1,26 +1,55 @@
 //! Contains the ConsoleLogger implementation.
 
 use std::boxed::Box;
-use super::bridge_ilogger_trait::ILogger;
-use super::bridge_loghelper::loghelper_formatlogline;
+
+use super::bridge_ilogger_trait::{ILogger, LogLevel};
+use super::bridge_ilogformatter_trait::ILogFormatter;
+use super::bridge_plainformatter::PlainFormatter;
 
 /// Represents a logger that writes logging to the standard output.
-pub struct ConsoleLogger { }
+pub struct ConsoleLogger {
+    /// Decides what each line looks like.
+    formatter: Box<dyn ILogFormatter>,
+    /// Messages below this level are skipped.
+    min_level: LogLevel,
+}
 
 impl ConsoleLogger {
-    /// Create an instance of a console logger, which writes to the standard
-    /// output.
+    /// Create an instance of a console logger, which writes plain text lines
+    /// to the standard output.
     ///
     /// # Returns
     ///   An instance of an ILogger object.
     pub fn new() -> Box<dyn ILogger> {
-        Box::new(ConsoleLogger{})
+        ConsoleLogger::with_formatter(PlainFormatter::new(true))
+    }
+
+    /// Create an instance of a console logger, which writes to the standard
+    /// output using the given formatter.
+    ///
+    /// # Parameters
+    /// - formatter
+    ///
+    ///   The formatter that decides what each line looks like.
+    ///
+    /// # Returns
+    ///   An instance of an ILogger object.
+    pub fn with_formatter(formatter: Box<dyn ILogFormatter>) -> Box<dyn ILogger> {
+        Box::new(ConsoleLogger { formatter, min_level: LogLevel::Trace })
     }
 }
 
 impl ILogger for ConsoleLogger {
     fn write_line(&mut self, loglevel:&str, message: &str) {
-        let output = loghelper_formatlogline(loglevel, message);
+        let output = self.formatter.format_line(loglevel, message);
         print!("{output}");
     }
+
+    fn min_level(&self) -> LogLevel {
+        self.min_level
+    }
+
+    fn set_min_level(&mut self, level: LogLevel) {
+        self.min_level = level;
+    }
 }
@@ -1,40 +1,75 @@
 //! Contains the FileLogger implementation.
 
-use std::fs::File;
+use std::fs::{self, File, OpenOptions};
 use std::io::Write;
+use std::path::Path;
 
 use std::boxed::Box;
-use super::bridge_ilogger_trait::ILogger;
-use super::bridge_loghelper::loghelper_formatlogline;
+use super::bridge_ilogger_trait::{ILogger, LogLevel};
+use super::bridge_ilogformatter_trait::ILogFormatter;
 
 /// Represents a logger that writes logging to a file.
 pub struct FileLogger {
     filename: String,
     file: File,
+    /// Decides what each line looks like.
+    formatter: Box<dyn ILogFormatter>,
+    /// Messages below this level are skipped.
+    min_level: LogLevel,
 }
 
 impl FileLogger {
-    /// Create an instance of a file logger, which writes to a file.  The file
-    /// is always overwritten each time the bridge example is run.
+    /// Create an instance of a file logger, which writes to a file using the
+    /// given formatter.
     ///
     /// # Parameters
     /// - filename
     ///
     ///   Name of the log file to output to.
+    /// - append
+    ///
+    ///   true to add to the end of the file if it already exists; false to
+    ///   overwrite it.
+    /// - create_dirs
+    ///
+    ///   true to create any missing directories in the path to the file;
+    ///   false to require that they already exist.
+    /// - formatter
+    ///
+    ///   The formatter that decides what each line looks like.
     ///
     /// # Returns
-    ///   An instance of an ILogger object.
-    pub fn new(filename: &str) -> Box<dyn ILogger> {
-        Box::new(FileLogger {
+    /// Returns `Ok(Box<dyn ILogger>)` containing the new logger; otherwise,
+    /// returns `Err(String)` describing why the file could not be opened.
+    pub fn new(filename: &str, append: bool, create_dirs: bool, formatter: Box<dyn ILogFormatter>) -> Result<Box<dyn ILogger>, String> {
+        if filename.is_empty() {
+            return Err("No log file name was given".to_string());
+        }
+        if create_dirs {
+            if let Some(parent) = Path::new(filename).parent().filter(|parent| !parent.as_os_str().is_empty()) {
+                fs::create_dir_all(parent)
+                    .map_err(|error| format!("Cannot create the directory \"{}\": {error}", parent.display()))?;
+            }
+        }
+        let file: File = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(append)
+            .truncate(!append)
+            .open(filename)
+            .map_err(|error| format!("Cannot open the log file \"{filename}\": {error}"))?;
+        Ok(Box::new(FileLogger {
             filename: filename.to_string(),
-            file: File::create(filename).unwrap(),
-        })
+            file,
+            formatter,
+            min_level: LogLevel::Trace,
+        }))
     }
 }
 
 impl ILogger for FileLogger {
     fn write_line(&mut self, loglevel:&str, message: &str) {
-        let output = loghelper_formatlogline(loglevel, message);
+        let output = self.formatter.format_line(loglevel, message);
         match self.file.write_all(output.as_bytes()) {
             Ok(_) => { },
             Err(ioerror) => {
@@ -42,4 +77,12 @@ impl ILogger for FileLogger {
             }
         }
     }
+
+    fn min_level(&self) -> LogLevel {
+        self.min_level
+    }
+
+    fn set_min_level(&mut self, level: LogLevel) {
+        self.min_level = level;
+    }
 }
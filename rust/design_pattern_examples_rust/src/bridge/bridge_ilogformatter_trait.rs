@@ -0,0 +1,26 @@
+//! Contains the ILogFormatter trait that log formatters can implement.
+//!
+//! The ILogFormatter interface is the second bridge in this example: loggers
+//! decide where a line goes and formatters decide what the line looks like,
+//! so either side can change without affecting the other.
+
+
+/// Represents the ability to turn a log message into a complete line of
+/// text, ready for a logger to write.  Formatters can be sent to other
+/// threads along with the loggers that use them.
+pub trait ILogFormatter: Send {
+    /// Format a message as a complete line, including a time stamp and a
+    /// trailing newline.
+    ///
+    /// # Parameters
+    /// - loglevel
+    ///
+    ///   Level of logging ("TRACE", "INFO", "ERROR")
+    /// - message
+    ///
+    ///   Message to log
+    ///
+    /// # Returns
+    ///   A string containing the formatted log line.
+    fn format_line(&self, loglevel: &str, message: &str) -> String;
+}
@@ -5,9 +5,60 @@
 //! pattern.
 
 
+/// Represents the levels of logging, from least to most severe.  A logger
+/// writes only the messages at or above its minimum level.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    /// Detailed messages for following what the program is doing.
+    Trace,
+    /// Messages about normal but significant events.
+    Info,
+    /// Messages about failures.
+    Error,
+}
+
+impl LogLevel {
+    /// Retrieve the name of the level as it appears in a log line.
+    ///
+    /// # Returns
+    /// Returns "TRACE", "INFO", or "ERROR".
+    pub fn name(&self) -> &'static str {
+        match self {
+            LogLevel::Trace => "TRACE",
+            LogLevel::Info => "INFO",
+            LogLevel::Error => "ERROR",
+        }
+    }
+
+    /// Convert the name of a level, as it appears in a log line, back to the
+    /// level.
+    ///
+    /// # Parameters
+    /// - name
+    ///
+    ///   "TRACE", "INFO", or "ERROR".
+    ///
+    /// # Returns
+    /// Returns Some(LogLevel) if the name is recognized; otherwise, returns
+    /// None.
+    pub fn from_name(name: &str) -> Option<LogLevel> {
+        match name {
+            "TRACE" => Some(LogLevel::Trace),
+            "INFO" => Some(LogLevel::Info),
+            "ERROR" => Some(LogLevel::Error),
+            _ => None,
+        }
+    }
+}
+
+//-----------------------------------------------------------------------------
+
 /// Represents the ability to send logging messages to some kind of output,
 /// which is dictated by the required implementation of ILogger::write_line().
-pub trait ILogger {
+///
+/// Loggers can be sent to other threads, so any logger can be used from a
+/// background thread or behind the standard log crate (see bridge_logbridge.rs).
+pub trait ILogger: Send {
     /// Send a formatted line to the logger.  Must be implemented by any struct
     /// implementing the ILogger trait.
     ///
@@ -20,39 +71,89 @@ pub trait ILogger {
     ///   Message to log
     fn write_line(&mut self, loglevel: &str, message: &str);
 
+    /// Retrieve the minimum level of messages written by this logger.  Must
+    /// be implemented by any struct implementing the ILogger trait.
+    ///
+    /// # Returns
+    /// Returns the minimum LogLevel.  Messages below this level are skipped.
+    fn min_level(&self) -> LogLevel;
+
+    /// Set the minimum level of messages written by this logger.  Must be
+    /// implemented by any struct implementing the ILogger trait.
+    ///
+    /// # Parameters
+    /// - level
+    ///
+    ///   The new minimum level.  Messages below this level are skipped.
+    fn set_min_level(&mut self, level: LogLevel);
+
+    /// Helper method to write a message at the given level, if the level is
+    /// at or above the minimum level.
+    ///
+    /// # Parameters
+    /// - level
+    ///
+    ///   The level of the message.
+    /// - message
+    ///
+    ///   The message to write to the log.
+    fn log_at_level(&mut self, level: LogLevel, message: &str) {
+        if level >= self.min_level() {
+            self.write_line(level.name(), message);
+        }
+    }
+
     /// Log trace messages to the configured output.  A newline will always be
     /// added to the message when writing to the log.  Default behavior is to
-    /// send the message to ILogger::write_line().
+    /// send the message to ILogger::write_line() if the minimum level allows.
     ///
     /// # Parameters
     /// - message
     ///
     ///   The message to write to the log.
     fn log_trace(&mut self, message: &str) {
-        self.write_line("TRACE", message);
+        self.log_at_level(LogLevel::Trace, message);
     }
 
     /// Log information messages to the configured output.  A newline will
     /// always be added to the message when writing to the log.  Default
-    /// behavior is to send the message to ILogger::write_line().
+    /// behavior is to send the message to ILogger::write_line() if the
+    /// minimum level allows.
     ///
     /// # Parameters
     /// - message
     ///
     ///   The message to write to the log.
     fn log_info(&mut self, message: &str) {
-        self.write_line("INFO", message);
+        self.log_at_level(LogLevel::Info, message);
     }
 
     /// Log error messages to the configured output.  A newline will always be
     /// added to the message when writing to the log.  Default behavior is to
-    /// send the message to ILogger::write_line().
+    /// send the message to ILogger::write_line() if the minimum level allows.
     ///
     /// # Parameters
     /// - message
     ///
     ///   The message to write to the log.
     fn log_error(&mut self, message: &str) {
-        self.write_line("ERROR", message);
+        self.log_at_level(LogLevel::Error, message);
+    }
+}
+
+impl dyn ILogger {
+    /// Set the minimum level of messages written by this logger, for use
+    /// when creating a logger.
+    ///
+    /// # Parameters
+    /// - level
+    ///
+    ///   The new minimum level.  Messages below this level are skipped.
+    ///
+    /// # Returns
+    /// Returns the same logger, with the new minimum level.
+    pub fn with_min_level(mut self: Box<Self>, level: LogLevel) -> Box<dyn ILogger> {
+        self.set_min_level(level);
+        self
     }
 }
@@ -0,0 +1,63 @@
+//! Contains the JsonFormatter implementation.
+
+use std::boxed::Box;
+use super::bridge_ilogformatter_trait::ILogFormatter;
+use super::bridge_loghelper::loghelper_gettimestamp;
+
+/// Represents a formatter that writes each line as a JSON object, such as
+/// `{"time":"01/02/2024  10:11:12 AM","level":"INFO","message":"Message"}`.
+pub struct JsonFormatter {
+    /// true to include a time stamp in each line.
+    timestamps: bool,
+}
+
+impl JsonFormatter {
+    /// Create an instance of a JSON formatter.
+    ///
+    /// # Parameters
+    /// - timestamps
+    ///
+    ///   true to start each line with a time stamp; false to leave it out.
+    ///
+    /// # Returns
+    ///   An instance of an ILogFormatter object.
+    pub fn new(timestamps: bool) -> Box<dyn ILogFormatter> {
+        Box::new(JsonFormatter { timestamps })
+    }
+}
+
+/// Helper function to convert text to a JSON string, including the quotes.
+///
+/// # Parameters
+/// - text
+///
+///   The text to convert.
+///
+/// # Returns
+///   A string containing the text as a JSON string.
+fn _json_string(text: &str) -> String {
+    let mut output = String::from("\"");
+    for character in text.chars() {
+        match character {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            c if (c as u32) < 0x20 => output.push_str(&format!("\\u{:04x}", c as u32)),
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+    output
+}
+
+impl ILogFormatter for JsonFormatter {
+    fn format_line(&self, loglevel: &str, message: &str) -> String {
+        let time = match self.timestamps {
+            true => format!("\"time\":{},", _json_string(&loghelper_gettimestamp())),
+            false => String::new(),
+        };
+        format!("{{{time}\"level\":{},\"message\":{}}}\n", _json_string(loglevel), _json_string(message))
+    }
+}
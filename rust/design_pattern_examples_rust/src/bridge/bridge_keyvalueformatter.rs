@@ -0,0 +1,51 @@
+//! Contains the KeyValueFormatter implementation.
+
+use std::boxed::Box;
+use super::bridge_ilogformatter_trait::ILogFormatter;
+use super::bridge_loghelper::loghelper_gettimestamp;
+
+/// Represents a formatter that writes each line as key=value pairs, such as
+/// `time="01/02/2024  10:11:12 AM" level=INFO message="Message"`.
+pub struct KeyValueFormatter {
+    /// true to include a time stamp in each line.
+    timestamps: bool,
+}
+
+impl KeyValueFormatter {
+    /// Create an instance of a key=value formatter.
+    ///
+    /// # Parameters
+    /// - timestamps
+    ///
+    ///   true to start each line with a time stamp; false to leave it out.
+    ///
+    /// # Returns
+    ///   An instance of an ILogFormatter object.
+    pub fn new(timestamps: bool) -> Box<dyn ILogFormatter> {
+        Box::new(KeyValueFormatter { timestamps })
+    }
+}
+
+/// Helper function to quote a value, escaping any quotes or backslashes in
+/// it.
+///
+/// # Parameters
+/// - value
+///
+///   The value to quote.
+///
+/// # Returns
+///   A string containing the quoted value.
+fn _quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+impl ILogFormatter for KeyValueFormatter {
+    fn format_line(&self, loglevel: &str, message: &str) -> String {
+        let time = match self.timestamps {
+            true => format!("time={} ", _quote(&loghelper_gettimestamp())),
+            false => String::new(),
+        };
+        format!("{time}level={loglevel} message={}\n", _quote(message))
+    }
+}
@@ -0,0 +1,86 @@
+//! Contains the LogBridge struct that lets the standard log crate write
+//! through any ILogger, along with the install() function that makes a
+//! LogBridge the log crate's logger.
+//!
+//! Once installed, any code can log with the log crate's macros, such as
+//! `log::info!("...")`, and the output goes wherever the ILogger sends it.
+
+//-----------------------------------------------------------------------------
+
+use std::sync::Mutex;
+
+use log::{Level, LevelFilter, Metadata, Record};
+
+use super::bridge_ilogger_trait::{ILogger, LogLevel};
+
+//-----------------------------------------------------------------------------
+
+/// Helper function to convert a log crate level to the nearest LogLevel.
+/// The log crate has more levels: warnings are treated as errors and debug
+/// messages as trace messages.
+fn _to_log_level(level: Level) -> LogLevel {
+    match level {
+        Level::Error | Level::Warn => LogLevel::Error,
+        Level::Info => LogLevel::Info,
+        Level::Debug | Level::Trace => LogLevel::Trace,
+    }
+}
+
+/// Represents the log crate's logger, passing every record to an ILogger.
+pub struct LogBridge {
+    /// The logger that writes the records.  The log crate can call from any
+    /// thread, so the logger is used by one thread at a time.
+    logger: Mutex<Box<dyn ILogger>>,
+}
+
+impl LogBridge {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - logger
+    ///
+    ///   The logger that writes the records.
+    ///
+    /// # Returns
+    /// Returns a new instance of the LogBridge struct.
+    pub fn new(logger: Box<dyn ILogger>) -> LogBridge {
+        LogBridge { logger: Mutex::new(logger) }
+    }
+}
+
+impl log::Log for LogBridge {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        let logger = self.logger.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        _to_log_level(metadata.level()) >= logger.min_level()
+    }
+
+    /// Writes the record as "target: message", where the target is usually
+    /// the path of the module that logged the record.
+    fn log(&self, record: &Record) {
+        let mut logger = self.logger.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let message = format!("{}: {}", record.target(), record.args());
+        logger.log_at_level(_to_log_level(record.level()), &message);
+    }
+
+    fn flush(&self) {}
+}
+
+/// Make a LogBridge around the given logger the log crate's logger.  This
+/// can only be done once while the program runs; to change where the log
+/// crate's output goes afterwards, install a logger from get_logger() and
+/// register different loggers for its module name.
+///
+/// # Parameters
+/// - logger
+///
+///   The logger that writes the log crate's records.
+///
+/// # Returns
+/// Returns `Ok(())` if installed; otherwise, returns `Err(String)` if the
+/// log crate already has a logger.
+pub fn install(logger: Box<dyn ILogger>) -> Result<(), String> {
+    log::set_boxed_logger(Box::new(LogBridge::new(logger)))
+        .map_err(|error| format!("Cannot install the log bridge: {error}"))?;
+    log::set_max_level(LevelFilter::Trace);
+    Ok(())
+}
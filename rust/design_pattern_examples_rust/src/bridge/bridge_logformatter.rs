@@ -0,0 +1,57 @@
+//! Contains the FormatterType enumeration and the create_formatter() factory
+//! function for instantiating specific kinds of formatters that implement
+//! the ILogFormatter trait.
+
+use crate::bridge::bridge_ilogformatter_trait::ILogFormatter;
+use super::bridge_plainformatter::PlainFormatter;
+use super::bridge_jsonformatter::JsonFormatter;
+use super::bridge_keyvalueformatter::KeyValueFormatter;
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FormatterType {
+    /// Format lines as plain text.
+    Plain,
+
+    /// Format each line as a JSON object.
+    Json,
+
+    /// Format each line as key=value pairs.
+    KeyValue,
+}
+
+impl FormatterType {
+    /// Retrieve the name of the formatter type, for display.
+    ///
+    /// # Returns
+    /// Returns "plain", "JSON", or "key=value".
+    pub fn name(&self) -> &'static str {
+        match self {
+            FormatterType::Plain => "plain",
+            FormatterType::Json => "JSON",
+            FormatterType::KeyValue => "key=value",
+        }
+    }
+}
+
+/// Factory function that takes a FormatterType value to create a new
+/// formatter instance.
+///
+/// # Parameters
+/// - formatter_type
+///
+///   A value from the FormatterType enumeration specifying the type of
+///   formatter object to create.
+/// - timestamps
+///
+///   true to start each line with a time stamp; false to leave it out.
+///
+/// # Returns
+/// Returns an object represented by the ILogFormatter trait.
+pub fn create_formatter(formatter_type: FormatterType, timestamps: bool) -> Box<dyn ILogFormatter> {
+    match formatter_type {
+        FormatterType::Plain => PlainFormatter::new(timestamps),
+        FormatterType::Json => JsonFormatter::new(timestamps),
+        FormatterType::KeyValue => KeyValueFormatter::new(timestamps),
+    }
+}
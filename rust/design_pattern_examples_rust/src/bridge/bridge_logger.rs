@@ -1,11 +1,13 @@
 //! Contains the LoggerType enumeration and the create_logger() factory function
 //! for instantiating specific kinds of loggers that implement the ILogger
-//! trait.
+//! trait.  The factory functions are shortcuts for the most common uses of
+//! the LoggerBuilder (in bridge_loggerbuilder.rs), which offers more
+//! options.
 
 use crate::bridge::bridge_ilogger_trait::ILogger;
-use super::bridge_nulllogger::NullLogger;
-use super::bridge_consolelogger::ConsoleLogger;
-use super::bridge_filelogger::FileLogger;
+use super::bridge_asynclogger::AsyncLogger;
+use super::bridge_logformatter::FormatterType;
+use super::bridge_loggerbuilder::LoggerBuilder;
 
 
 pub enum LoggerType {
@@ -18,6 +20,18 @@ pub enum LoggerType {
     /// Log to the console.  No additional parameters.
     ToConsole = 2,
 
+    /// Log to the operating system's log: syslog on Linux and macOS, the
+    /// Event Log on Windows.  No additional parameters.
+    ToSystem = 3,
+
+    /// Log to a log server over TCP.  One additional parameter: the address
+    /// of the log server, as "host:port".
+    ToNetwork = 4,
+
+    /// Log to the console, coloring each line according to its level.  No
+    /// additional parameters.
+    ToColorConsole = 5,
+
 }
 
 /// Factor function that takes a LoggerTypes value to create a new Logger instance.
@@ -30,15 +44,106 @@ pub enum LoggerType {
 /// - filename
 ///
 ///   If creating a LoggerType::ToFile, then `filename` specifies the path and
-///   name of the file to write to.  Otherwise, this parameter is ignored (just
-///   pass an empty string).
+///   name of the file to write to.  If creating a LoggerType::ToNetwork, then
+///   `filename` specifies the address of the log server, as "host:port".
+///   Otherwise, this parameter is ignored (just pass an empty string).
 ///
 /// # Returns
-/// Returns an object represented by the ILogger trait.
+/// Returns an object represented by the ILogger trait that writes plain text
+/// lines.
 pub fn create_logger(logger_type: LoggerType, filename: &str) -> Box<dyn ILogger> {
+    create_logger_with_formatter(logger_type, filename, FormatterType::Plain)
+}
+
+/// Factory function that takes a LoggerTypes value to create a new Logger
+/// instance, reporting any failure instead of falling back to a logger that
+/// writes nothing.
+///
+/// # Parameters
+/// - logger_type
+///
+///   A value from the LoggerTypes enumeration specifying the type of logger
+///   object to create.
+/// - filename
+///
+///   The same as for create_logger().  For LoggerType::ToFile, any missing
+///   directories in the path are created.
+///
+/// # Returns
+/// Returns `Ok(Box<dyn ILogger>)` containing an object represented by the
+/// ILogger trait that writes plain text lines; otherwise, returns
+/// `Err(String)` describing why the logger could not be created (for
+/// example, the log file could not be opened).
+pub fn try_create_logger(logger_type: LoggerType, filename: &str) -> Result<Box<dyn ILogger>, String> {
+    _logger_builder(logger_type, filename).create_dirs(true).try_build()
+}
+
+/// Factory function that takes a LoggerTypes value to create a new Logger
+/// instance that writes in the background.  Logging calls queue the line and
+/// return at once; all queued lines are written before the logger is dropped.
+///
+/// # Parameters
+/// - logger_type
+///
+///   A value from the LoggerTypes enumeration specifying the type of logger
+///   object that writes the lines.
+/// - filename
+///
+///   The same as for create_logger().
+///
+/// # Returns
+/// Returns an object represented by the ILogger trait.
+pub fn create_async_logger(logger_type: LoggerType, filename: &str) -> Box<dyn ILogger> {
+    let filename = filename.to_string();
+    AsyncLogger::new(move || create_logger(logger_type, &filename))
+}
+
+/// Factory function that takes a LoggerType value and a FormatterType value
+/// to create a new Logger instance.  The two choices are independent of each
+/// other, so any logger can use any formatter.
+///
+/// # Parameters
+/// - logger_type
+///
+///   A value from the LoggerTypes enumeration specifying the type of logger
+///   object to create.
+/// - filename
+///
+///   If creating a LoggerType::ToFile, then `filename` specifies the path and
+///   name of the file to write to.  If creating a LoggerType::ToNetwork, then
+///   `filename` specifies the address of the log server, as "host:port".
+///   Otherwise, this parameter is ignored (just pass an empty string).
+/// - formatter_type
+///
+///   A value from the FormatterType enumeration specifying what each line
+///   written by the logger looks like.  Ignored for LoggerType::ToNull and
+///   LoggerType::ToSystem.
+///
+/// # Returns
+/// Returns an object represented by the ILogger trait.
+pub fn create_logger_with_formatter(logger_type: LoggerType, filename: &str, formatter_type: FormatterType) -> Box<dyn ILogger> {
+    _logger_builder(logger_type, filename).formatted(formatter_type).build()
+}
+
+/// Helper function to start a LoggerBuilder for the given type of logger.
+///
+/// # Parameters
+/// - logger_type
+///
+///   A value from the LoggerTypes enumeration specifying the type of logger.
+/// - filename
+///
+///   The same as for create_logger().
+///
+/// # Returns
+/// Returns a LoggerBuilder describing the logger.
+fn _logger_builder(logger_type: LoggerType, filename: &str) -> LoggerBuilder {
     match logger_type {
-        LoggerType::ToNull => NullLogger::new(),
-        LoggerType::ToConsole => ConsoleLogger::new(),
-        LoggerType::ToFile => FileLogger::new(&filename),
+        LoggerType::ToNull => LoggerBuilder::new().to_null(),
+        LoggerType::ToConsole => LoggerBuilder::new().to_console(),
+        LoggerType::ToFile => LoggerBuilder::new().to_file(filename),
+        LoggerType::ToSystem => LoggerBuilder::new().to_system(),
+        LoggerType::ToNetwork => LoggerBuilder::new().to_network(filename),
+        LoggerType::ToColorConsole => LoggerBuilder::new().to_console().colored(),
     }
 }
\ No newline at end of file
@@ -0,0 +1,207 @@
+//! Contains the LoggerBuilder struct for describing a logger one option at
+//! a time and then creating it.
+
+use super::bridge_colorconsolelogger::ColorConsoleLogger;
+use super::bridge_consolelogger::ConsoleLogger;
+use super::bridge_filelogger::FileLogger;
+use super::bridge_ilogger_trait::{ILogger, LogLevel};
+use super::bridge_logformatter::{create_formatter, FormatterType};
+use super::bridge_nulllogger::NullLogger;
+use super::bridge_systemlogger::SystemLogger;
+use super::bridge_tcplogger::TcpLogger;
+
+/// Where the logger built by a LoggerBuilder writes to.
+enum Destination {
+    Null,
+    Console,
+    File(String),
+    System,
+    Network(String),
+}
+
+/// Represents the options for a logger, set one at a time with chained
+/// calls, such as
+/// `LoggerBuilder::new().to_file("app.log").append(true).with_level(LogLevel::Info).build()`.
+///
+/// Options that do not apply to the chosen destination are ignored; for
+/// example, append() only affects file loggers and colored() only affects
+/// console loggers.
+///
+/// build() always returns a logger, falling back to a null logger if the
+/// logger described cannot be created; try_build() returns the failure
+/// instead.
+pub struct LoggerBuilder {
+    /// Where the logger writes to.  Defaults to the console.
+    destination: Destination,
+    /// What each line looks like.  Defaults to plain text.
+    formatter_type: FormatterType,
+    /// true to add to an existing log file instead of overwriting it.
+    append: bool,
+    /// true to create any missing directories in the path to a log file.
+    create_dirs: bool,
+    /// Messages below this level are skipped.  Defaults to LogLevel::Trace.
+    min_level: LogLevel,
+    /// true to include a time stamp in each line (the default).
+    timestamps: bool,
+    /// true to color lines on the console according to their level.
+    colored: bool,
+}
+
+// The to_*() methods name where the logger writes to, not conversions.
+#[allow(clippy::wrong_self_convention)]
+impl LoggerBuilder {
+    /// Constructor.
+    ///
+    /// # Returns
+    /// Returns a new instance of the LoggerBuilder struct describing a
+    /// console logger that writes plain text lines with time stamps, at all
+    /// levels, without colors.
+    pub fn new() -> LoggerBuilder {
+        LoggerBuilder {
+            destination: Destination::Console,
+            formatter_type: FormatterType::Plain,
+            append: false,
+            create_dirs: false,
+            min_level: LogLevel::Trace,
+            timestamps: true,
+            colored: false,
+        }
+    }
+
+    /// Throw out all logging.
+    pub fn to_null(mut self) -> LoggerBuilder {
+        self.destination = Destination::Null;
+        self
+    }
+
+    /// Write to the standard output.
+    pub fn to_console(mut self) -> LoggerBuilder {
+        self.destination = Destination::Console;
+        self
+    }
+
+    /// Write to a file.
+    ///
+    /// # Parameters
+    /// - path
+    ///
+    ///   Path and name of the file to write to.
+    pub fn to_file(mut self, path: &str) -> LoggerBuilder {
+        self.destination = Destination::File(path.to_string());
+        self
+    }
+
+    /// Write to the operating system's log.
+    pub fn to_system(mut self) -> LoggerBuilder {
+        self.destination = Destination::System;
+        self
+    }
+
+    /// Write to a log server over TCP.
+    ///
+    /// # Parameters
+    /// - address
+    ///
+    ///   Address of the log server, as "host:port".
+    pub fn to_network(mut self, address: &str) -> LoggerBuilder {
+        self.destination = Destination::Network(address.to_string());
+        self
+    }
+
+    /// Choose what each line looks like.
+    ///
+    /// # Parameters
+    /// - formatter_type
+    ///
+    ///   A value from the FormatterType enumeration.
+    pub fn formatted(mut self, formatter_type: FormatterType) -> LoggerBuilder {
+        self.formatter_type = formatter_type;
+        self
+    }
+
+    /// Choose whether a file logger adds to an existing file.
+    ///
+    /// # Parameters
+    /// - append
+    ///
+    ///   true to add to the end of the file; false to overwrite it.
+    pub fn append(mut self, append: bool) -> LoggerBuilder {
+        self.append = append;
+        self
+    }
+
+    /// Choose whether a file logger creates any missing directories in the
+    /// path to the file.
+    ///
+    /// # Parameters
+    /// - create_dirs
+    ///
+    ///   true to create missing directories; false to fail if a directory
+    ///   does not exist.
+    pub fn create_dirs(mut self, create_dirs: bool) -> LoggerBuilder {
+        self.create_dirs = create_dirs;
+        self
+    }
+
+    /// Set the minimum level of messages written by the logger.
+    ///
+    /// # Parameters
+    /// - level
+    ///
+    ///   Messages below this level are skipped.
+    pub fn with_level(mut self, level: LogLevel) -> LoggerBuilder {
+        self.min_level = level;
+        self
+    }
+
+    /// Choose whether each line includes a time stamp.
+    ///
+    /// # Parameters
+    /// - timestamps
+    ///
+    ///   true to include a time stamp in each line; false to leave it out.
+    pub fn with_timestamps(mut self, timestamps: bool) -> LoggerBuilder {
+        self.timestamps = timestamps;
+        self
+    }
+
+    /// Color lines on the console according to their level (falls back to
+    /// plain text if the console does not support colors).
+    pub fn colored(mut self) -> LoggerBuilder {
+        self.colored = true;
+        self
+    }
+
+    /// Create the logger described by the options.  If the logger cannot be
+    /// created (for example, the log file cannot be opened), the reason is
+    /// shown and a null logger is returned instead, so logging goes nowhere
+    /// instead of failing.
+    ///
+    /// # Returns
+    /// Returns an object represented by the ILogger trait.
+    pub fn build(self) -> Box<dyn ILogger> {
+        let min_level = self.min_level;
+        self.try_build().unwrap_or_else(|message| {
+            eprintln!("{message}; logging is disabled.");
+            NullLogger::new().with_min_level(min_level)
+        })
+    }
+
+    /// Create the logger described by the options.
+    ///
+    /// # Returns
+    /// Returns `Ok(Box<dyn ILogger>)` containing the new logger; otherwise,
+    /// returns `Err(String)` describing why the logger could not be created.
+    pub fn try_build(self) -> Result<Box<dyn ILogger>, String> {
+        let formatter = create_formatter(self.formatter_type, self.timestamps);
+        let logger = match &self.destination {
+            Destination::Null => NullLogger::new(),
+            Destination::Console if self.colored => ColorConsoleLogger::new(formatter),
+            Destination::Console => ConsoleLogger::with_formatter(formatter),
+            Destination::File(path) => FileLogger::new(path, self.append, self.create_dirs, formatter)?,
+            Destination::System => SystemLogger::new(),
+            Destination::Network(address) => TcpLogger::new(address, formatter),
+        };
+        Ok(logger.with_min_level(self.min_level))
+    }
+}
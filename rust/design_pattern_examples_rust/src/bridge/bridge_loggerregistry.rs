@@ -0,0 +1,198 @@
+//! Contains the LoggerRegistry struct that routes logging from named modules
+//! to different ILogger implementations, along with the register_logger()
+//! and get_logger() functions that give the whole program, on every thread,
+//! access to a single registry.
+
+//-----------------------------------------------------------------------------
+
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
+
+use super::bridge_consolelogger::ConsoleLogger;
+use super::bridge_ilogger_trait::{ILogger, LogLevel};
+
+//-----------------------------------------------------------------------------
+
+/// A logger held by the LoggerRegistry, which can be used from any thread.
+pub type SharedLogger = Arc<Mutex<Box<dyn ILogger>>>;
+
+/// Represents a mapping from module (or topic) names to the loggers that
+/// handle logging for those modules.  Any module without its own logger uses
+/// the default logger.
+///
+/// Each logger has its own lock, so a module can be logging while another
+/// module is looking up or registering a logger.
+pub struct LoggerRegistry {
+    /// The registered loggers, as (module name, logger) pairs.
+    loggers: Vec<(String, SharedLogger)>,
+    /// The logger used by modules that do not have their own logger.
+    default_logger: SharedLogger,
+}
+
+impl LoggerRegistry {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - default_logger
+    ///
+    ///   The logger to use for any module without its own logger.
+    ///
+    /// # Returns
+    /// Returns a new instance of the LoggerRegistry struct.
+    pub fn new(default_logger: Box<dyn ILogger>) -> LoggerRegistry {
+        LoggerRegistry { loggers: vec![], default_logger: Arc::new(Mutex::new(default_logger)) }
+    }
+
+    /// Set the logger to use for the given module, replacing any logger
+    /// already set for that module.
+    ///
+    /// # Parameters
+    /// - module_name
+    ///
+    ///   Name of the module.
+    /// - logger
+    ///
+    ///   The logger to use for the module.  The registry takes ownership of
+    ///   the logger.
+    pub fn register(&mut self, module_name: &str, logger: Box<dyn ILogger>) {
+        let logger = Arc::new(Mutex::new(logger));
+        match self.loggers.iter().position(|(name, _)| name == module_name) {
+            Some(index) => self.loggers[index].1 = logger,
+            None => self.loggers.push((module_name.to_string(), logger)),
+        }
+    }
+
+    /// Determine if the given module has its own logger.
+    ///
+    /// # Parameters
+    /// - module_name
+    ///
+    ///   Name of the module.
+    ///
+    /// # Returns
+    /// Returns true if the module has its own logger; otherwise, returns
+    /// false, meaning the module uses the default logger.
+    pub fn has_logger(&self, module_name: &str) -> bool {
+        self.loggers.iter().any(|(name, _)| name == module_name)
+    }
+
+    /// Retrieve the logger for the given module.
+    ///
+    /// # Parameters
+    /// - module_name
+    ///
+    ///   Name of the module.
+    ///
+    /// # Returns
+    /// Returns the logger registered for the module or the default logger if
+    /// the module has no logger of its own.
+    pub fn logger_for(&self, module_name: &str) -> SharedLogger {
+        match self.loggers.iter().find(|(name, _)| name == module_name) {
+            Some((_, logger)) => Arc::clone(logger),
+            None => Arc::clone(&self.default_logger),
+        }
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// The registry used by the whole program, shared by all threads.  Modules
+/// without their own logger log to the console.
+static LOGGER_REGISTRY: OnceLock<Mutex<LoggerRegistry>> = OnceLock::new();
+
+/// Helper function to lock the program's registry, creating it the first
+/// time it is used.
+///
+/// # Returns
+/// Returns the locked registry.
+fn _lock_registry() -> MutexGuard<'static, LoggerRegistry> {
+    // A panic on another thread cannot leave the registry half changed, so
+    // it is still usable.
+    LOGGER_REGISTRY.get_or_init(|| Mutex::new(LoggerRegistry::new(ConsoleLogger::new())))
+        .lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Helper function to lock the logger registered for the given module.  The
+/// registry itself is unlocked before the logger is used.
+///
+/// # Parameters
+/// - module_name
+///
+///   Name of the module.
+/// - action
+///
+///   Function to call with the locked logger.
+///
+/// # Returns
+/// Returns what the function returns.
+fn _with_logger<T>(module_name: &str, action: impl FnOnce(&mut Box<dyn ILogger>) -> T) -> T {
+    let logger = _lock_registry().logger_for(module_name);
+    let mut logger = logger.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    action(&mut logger)
+}
+
+/// Represents a logger for a named module.  Each line written to this logger
+/// is passed to whichever logger is registered for the module at the time
+/// the line is written.
+struct RoutedLogger {
+    /// Name of the module this logger writes for.
+    module_name: String,
+}
+
+impl ILogger for RoutedLogger {
+    fn write_line(&mut self, loglevel: &str, message: &str) {
+        _with_logger(&self.module_name, |logger| logger.write_line(loglevel, message));
+    }
+
+    /// Retrieves the minimum level of the logger registered for the module.
+    fn min_level(&self) -> LogLevel {
+        _with_logger(&self.module_name, |logger| logger.min_level())
+    }
+
+    /// Sets the minimum level of the logger registered for the module.
+    fn set_min_level(&mut self, level: LogLevel) {
+        _with_logger(&self.module_name, |logger| logger.set_min_level(level));
+    }
+}
+
+/// Set the logger to use for the given module in the program's registry.
+///
+/// # Parameters
+/// - module_name
+///
+///   Name of the module.
+/// - logger
+///
+///   The logger to use for the module.  The registry takes ownership of the
+///   logger.
+pub fn register_logger(module_name: &str, logger: Box<dyn ILogger>) {
+    _lock_registry().register(module_name, logger);
+}
+
+/// Determine if the given module has its own logger in the program's
+/// registry.
+///
+/// # Parameters
+/// - module_name
+///
+///   Name of the module.
+///
+/// # Returns
+/// Returns true if the module has its own logger; otherwise, returns false.
+pub fn has_logger(module_name: &str) -> bool {
+    _lock_registry().has_logger(module_name)
+}
+
+/// Get a logger for the given module.  The module does not need to know
+/// which logger, if any, is registered for it.
+///
+/// # Parameters
+/// - module_name
+///
+///   Name of the module, for example, "flyweight".
+///
+/// # Returns
+/// Returns an object represented by the ILogger trait that writes to the
+/// logger registered for the module.
+pub fn get_logger(module_name: &str) -> Box<dyn ILogger> {
+    Box::new(RoutedLogger { module_name: module_name.to_string() })
+}
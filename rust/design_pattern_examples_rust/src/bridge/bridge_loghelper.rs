@@ -1,5 +1,6 @@
 //! Contains the loghelper_formatlogline() function for formatting a complete
-//! line to be written to a log.
+//! line to be written to a log, and the loghelper_gettimestamp() function
+//! that provides the time stamp for each line.
 
 use time::OffsetDateTime;
 use time::macros::format_description;
@@ -7,9 +8,14 @@ use time::macros::format_description;
 /// The string with which to format a timestamp for logging.
 const DATE_FORMAT_STR: &[time::format_description::FormatItem<'static>] = format_description!(version = 2, "[month]/[day]/[year]  [hour repr:12]:[minute]:[second] [period]");
 
-/// Return a regular time stamp of the current time in local time.
-fn _gettimestamp() -> String {
-    let local_time = OffsetDateTime::now_local().unwrap();
+/// Return a regular time stamp of the current time in local time.  If the
+/// local time cannot be determined, which can happen when the program is
+/// running more than one thread, the time stamp is in UTC instead.
+///
+/// # Returns
+///   A string containing the time stamp.
+pub fn loghelper_gettimestamp() -> String {
+    let local_time = OffsetDateTime::now_local().unwrap_or_else(|_| OffsetDateTime::now_utc());
     local_time.format(&DATE_FORMAT_STR).unwrap()
 }
 
@@ -26,6 +32,6 @@ fn _gettimestamp() -> String {
 /// # Returns
 ///   A string containing the formatted log line.
 pub fn loghelper_formatlogline(loglevel: &str, message: &str) -> String {
-    let timestamp = _gettimestamp();
+    let timestamp = loghelper_gettimestamp();
     format!("{timestamp} [{loglevel:<5}] {message}\n")
 }
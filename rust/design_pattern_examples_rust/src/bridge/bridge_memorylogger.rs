@@ -0,0 +1,91 @@
+//! Contains the MemoryLogger implementation and the LogEntry struct that it
+//! stores.
+
+use std::sync::{Arc, Mutex};
+
+use super::bridge_ilogger_trait::{ILogger, LogLevel};
+use super::bridge_loghelper::loghelper_gettimestamp;
+
+/// Represents a single line written to a MemoryLogger.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogEntry {
+    /// The level of the line.
+    pub level: LogLevel,
+    /// When the line was logged, as returned by loghelper_gettimestamp().
+    pub timestamp: String,
+    /// The message that was logged, without any formatting.
+    pub message: String,
+}
+
+/// Represents a logger that keeps every line in memory instead of writing it
+/// out, so what was logged can be examined afterwards without touching the
+/// filesystem.
+///
+/// Clones of a MemoryLogger share the same entries, so one clone can be given
+/// away as a `Box<dyn ILogger>` while another is kept to look at the entries.
+#[derive(Clone)]
+pub struct MemoryLogger {
+    /// The lines logged so far, shared by all clones.
+    entries: Arc<Mutex<Vec<LogEntry>>>,
+    /// Messages below this level are skipped.
+    min_level: LogLevel,
+}
+
+impl MemoryLogger {
+    /// Create an instance of a memory logger with no entries.
+    ///
+    /// # Returns
+    ///   A new instance of the MemoryLogger struct.
+    pub fn new() -> MemoryLogger {
+        MemoryLogger { entries: Arc::new(Mutex::new(vec![])), min_level: LogLevel::Trace }
+    }
+
+    /// Retrieve a copy of the lines logged so far, oldest first.
+    ///
+    /// # Returns
+    /// Returns a list of LogEntry objects.
+    pub fn entries(&self) -> Vec<LogEntry> {
+        self.entries.lock().unwrap().clone()
+    }
+
+    /// Determine whether a line at the given level containing the given text
+    /// has been logged.
+    ///
+    /// # Parameters
+    /// - level
+    ///
+    ///   The level of the line to look for.
+    /// - text
+    ///
+    ///   Text to look for in the message of the line.
+    ///
+    /// # Returns
+    /// Returns true if a matching line was logged; otherwise, returns false.
+    pub fn contains(&self, level: LogLevel, text: &str) -> bool {
+        self.entries.lock().unwrap().iter().any(|entry| entry.level == level && entry.message.contains(text))
+    }
+
+    /// Throw out all lines logged so far.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+}
+
+impl ILogger for MemoryLogger {
+    fn write_line(&mut self, loglevel: &str, message: &str) {
+        let entry = LogEntry {
+            level: LogLevel::from_name(loglevel).unwrap_or(LogLevel::Trace),
+            timestamp: loghelper_gettimestamp(),
+            message: message.to_string(),
+        };
+        self.entries.lock().unwrap().push(entry);
+    }
+
+    fn min_level(&self) -> LogLevel {
+        self.min_level
+    }
+
+    fn set_min_level(&mut self, level: LogLevel) {
+        self.min_level = level;
+    }
+}
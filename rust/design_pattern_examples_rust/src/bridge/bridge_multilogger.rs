@@ -0,0 +1,60 @@
+//! Contains the MultiLogger implementation.
+
+use std::boxed::Box;
+use super::bridge_ilogger_trait::{ILogger, LogLevel};
+
+/// Represents a logger that passes everything sent its way to any number of
+/// other loggers, such as the console and a file at the same time.  Each of
+/// the other loggers still applies its own minimum level.
+pub struct MultiLogger {
+    /// The loggers that receive every line.
+    loggers: Vec<Box<dyn ILogger>>,
+    /// Messages below this level are not passed on to any logger.
+    min_level: LogLevel,
+}
+
+impl MultiLogger {
+    /// Create an instance of a multi-logger with no loggers.  Use
+    /// add_logger() to add loggers.
+    ///
+    /// # Returns
+    ///   A new instance of the MultiLogger struct.
+    pub fn new() -> MultiLogger {
+        MultiLogger { loggers: vec![], min_level: LogLevel::Trace }
+    }
+
+    /// Add a logger to receive every line.
+    ///
+    /// # Parameters
+    /// - logger
+    ///
+    ///   The logger to add.  The multi-logger takes ownership of the logger.
+    pub fn add_logger(&mut self, logger: Box<dyn ILogger>) {
+        self.loggers.push(logger);
+    }
+}
+
+impl ILogger for MultiLogger {
+    fn write_line(&mut self, loglevel: &str, message: &str) {
+        for logger in self.loggers.iter_mut() {
+            logger.write_line(loglevel, message);
+        }
+    }
+
+    /// Passes the message to each logger at its own minimum level.
+    fn log_at_level(&mut self, level: LogLevel, message: &str) {
+        if level >= self.min_level {
+            for logger in self.loggers.iter_mut() {
+                logger.log_at_level(level, message);
+            }
+        }
+    }
+
+    fn min_level(&self) -> LogLevel {
+        self.min_level
+    }
+
+    fn set_min_level(&mut self, level: LogLevel) {
+        self.min_level = level;
+    }
+}
@@ -1,10 +1,13 @@
 //! Contains the NullLogger implementation.
 
 use std::boxed::Box;
-use super::bridge_ilogger_trait::ILogger;
+use super::bridge_ilogger_trait::{ILogger, LogLevel};
 
 /// Represents a logger that throws away anything sent its way.
-pub struct NullLogger {}
+pub struct NullLogger {
+    /// Kept only so the level can be set and retrieved like any logger.
+    min_level: LogLevel,
+}
 
 impl NullLogger {
     /// Create an instance of a null logger, a logger that doesn't do anything.
@@ -12,11 +15,19 @@ impl NullLogger {
     /// # Returns
     ///   An instance of an ILogger object.
     pub fn new() -> Box<dyn ILogger> {
-        Box::new(NullLogger{})
+        Box::new(NullLogger { min_level: LogLevel::Trace })
     }
 }
 impl ILogger for NullLogger {
     fn write_line(&mut self, _loglevel:&str, _message: &str) {
         // do nothing
     }
+
+    fn min_level(&self) -> LogLevel {
+        self.min_level
+    }
+
+    fn set_min_level(&mut self, level: LogLevel) {
+        self.min_level = level;
+    }
 }
@@ -0,0 +1,36 @@
+//! Contains the PlainFormatter implementation.
+
+use std::boxed::Box;
+use super::bridge_ilogformatter_trait::ILogFormatter;
+use super::bridge_loghelper::loghelper_formatlogline;
+
+/// Represents a formatter that writes lines as plain text, such as
+/// `01/02/2024  10:11:12 AM [INFO ] Message`.
+pub struct PlainFormatter {
+    /// true to include a time stamp in each line.
+    timestamps: bool,
+}
+
+impl PlainFormatter {
+    /// Create an instance of a plain text formatter.
+    ///
+    /// # Parameters
+    /// - timestamps
+    ///
+    ///   true to start each line with a time stamp; false to leave it out.
+    ///
+    /// # Returns
+    ///   An instance of an ILogFormatter object.
+    pub fn new(timestamps: bool) -> Box<dyn ILogFormatter> {
+        Box::new(PlainFormatter { timestamps })
+    }
+}
+
+impl ILogFormatter for PlainFormatter {
+    fn format_line(&self, loglevel: &str, message: &str) -> String {
+        match self.timestamps {
+            true => loghelper_formatlogline(loglevel, message),
+            false => format!("[{loglevel:<5}] {message}\n"),
+        }
+    }
+}
@@ -0,0 +1,206 @@
+//! Contains the SystemLogger implementation.
+//!
+//! The system logger is the one logger in this example that writes through a
+//! completely different technology on each platform: syslog on Linux and
+//! macOS, and the Windows Event Log on Windows (falling back to
+//! OutputDebugString if the event source cannot be registered).  The rest of
+//! the program sees only the ILogger trait.
+
+use std::boxed::Box;
+use super::bridge_ilogger_trait::{ILogger, LogLevel};
+
+/// Name under which the program's messages appear in the system log.
+const SYSTEM_LOG_SOURCE: &str = "DesignPatternExamples_rust";
+
+//-----------------------------------------------------------------------------
+
+/// Access to syslog on Unix-like systems.
+#[cfg(unix)]
+mod platform {
+    use std::ffi::CString;
+
+    use super::LogLevel;
+
+    /// Represents an open connection to syslog.
+    pub struct SystemLog {
+        /// Identifier passed to openlog(), which syslog keeps a pointer to
+        /// until closelog() is called.
+        _ident: CString,
+    }
+
+    impl SystemLog {
+        /// Open the connection to syslog.
+        pub fn open(source: &str) -> SystemLog {
+            let ident = CString::new(source).unwrap_or_default();
+            unsafe { libc::openlog(ident.as_ptr(), libc::LOG_PID, libc::LOG_USER) };
+            SystemLog { _ident: ident }
+        }
+
+        /// Write a message to syslog with the priority matching the level.
+        pub fn write(&mut self, level: LogLevel, message: &str) {
+            let priority = match level {
+                LogLevel::Trace => libc::LOG_DEBUG,
+                LogLevel::Info => libc::LOG_INFO,
+                LogLevel::Error => libc::LOG_ERR,
+            };
+            // Pass the message through "%s" so any % in it is not treated as
+            // a format specifier.
+            let message = CString::new(message.replace('\0', " ")).unwrap_or_default();
+            unsafe { libc::syslog(priority, c"%s".as_ptr(), message.as_ptr()) };
+        }
+    }
+
+    impl Drop for SystemLog {
+        fn drop(&mut self) {
+            unsafe { libc::closelog() };
+        }
+    }
+}
+
+/// Access to the Event Log on Windows.
+#[cfg(windows)]
+mod platform {
+    use std::ffi::c_void;
+    use std::ptr;
+
+    use super::LogLevel;
+
+    const EVENTLOG_ERROR_TYPE: u16 = 0x0001;
+    const EVENTLOG_INFORMATION_TYPE: u16 = 0x0004;
+
+    #[link(name = "advapi32")]
+    extern "system" {
+        fn RegisterEventSourceW(server_name: *const u16, source_name: *const u16) -> *mut c_void;
+        fn ReportEventW(event_log: *mut c_void, event_type: u16, category: u16, event_id: u32,
+            user_sid: *mut c_void, num_strings: u16, data_size: u32, strings: *const *const u16,
+            raw_data: *mut c_void) -> i32;
+        fn DeregisterEventSource(event_log: *mut c_void) -> i32;
+    }
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn OutputDebugStringW(output_string: *const u16);
+    }
+
+    /// Helper function to convert a string to a zero-terminated wide string.
+    fn _to_wide(text: &str) -> Vec<u16> {
+        text.encode_utf16().chain(std::iter::once(0)).collect()
+    }
+
+    /// Represents a registered event source, or the debugger output if the
+    /// event source could not be registered.
+    pub struct SystemLog {
+        /// Handle to the event source; null if not registered.
+        event_log: *mut c_void,
+        /// Name of the event source, used to label debugger output.
+        source: String,
+    }
+
+    // Event Log handles can be used from any thread.
+    unsafe impl Send for SystemLog {}
+
+    impl SystemLog {
+        /// Register the event source.
+        pub fn open(source: &str) -> SystemLog {
+            let source_name = _to_wide(source);
+            let event_log = unsafe { RegisterEventSourceW(ptr::null(), source_name.as_ptr()) };
+            SystemLog { event_log, source: source.to_string() }
+        }
+
+        /// Write a message to the Event Log with the event type matching the
+        /// level.  Trace messages, which the Event Log has no type for, and
+        /// all messages when there is no event source, go to the debugger
+        /// output instead.
+        pub fn write(&mut self, level: LogLevel, message: &str) {
+            let event_type = match level {
+                LogLevel::Trace => None,
+                LogLevel::Info => Some(EVENTLOG_INFORMATION_TYPE),
+                LogLevel::Error => Some(EVENTLOG_ERROR_TYPE),
+            };
+            let wide_message = _to_wide(message);
+            if let (false, Some(event_type)) = (self.event_log.is_null(), event_type) {
+                let strings = [wide_message.as_ptr()];
+                unsafe {
+                    ReportEventW(self.event_log, event_type, 0, 0, ptr::null_mut(), 1, 0,
+                        strings.as_ptr(), ptr::null_mut());
+                }
+            } else {
+                let output = _to_wide(&format!("{}: [{}] {message}\n", self.source, level.name()));
+                unsafe { OutputDebugStringW(output.as_ptr()) };
+            }
+        }
+    }
+
+    impl Drop for SystemLog {
+        fn drop(&mut self) {
+            if !self.event_log.is_null() {
+                unsafe { DeregisterEventSource(self.event_log) };
+            }
+        }
+    }
+}
+
+/// Stand-in for platforms without a supported system log: writes to the
+/// standard error.
+#[cfg(not(any(unix, windows)))]
+mod platform {
+    use super::LogLevel;
+
+    /// Represents the standard error, standing in for a system log.
+    pub struct SystemLog {
+        /// Name written at the start of each line.
+        source: String,
+    }
+
+    impl SystemLog {
+        /// Nothing to open; just remember the source name.
+        pub fn open(source: &str) -> SystemLog {
+            SystemLog { source: source.to_string() }
+        }
+
+        /// Write a message to the standard error.
+        pub fn write(&mut self, level: LogLevel, message: &str) {
+            eprintln!("{}: [{}] {message}", self.source, level.name());
+        }
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Represents a logger that writes logging to the operating system's log.
+/// The system log adds its own time stamp, so lines are not formatted.
+pub struct SystemLogger {
+    /// The platform's system log.
+    system_log: platform::SystemLog,
+    /// Messages below this level are skipped.
+    min_level: LogLevel,
+}
+
+impl SystemLogger {
+    /// Create an instance of a system logger, which writes to syslog or the
+    /// Windows Event Log.
+    ///
+    /// # Returns
+    ///   An instance of an ILogger object.
+    pub fn new() -> Box<dyn ILogger> {
+        Box::new(SystemLogger {
+            system_log: platform::SystemLog::open(SYSTEM_LOG_SOURCE),
+            min_level: LogLevel::Trace,
+        })
+    }
+}
+
+impl ILogger for SystemLogger {
+    fn write_line(&mut self, loglevel: &str, message: &str) {
+        let level = LogLevel::from_name(loglevel).unwrap_or(LogLevel::Trace);
+        self.system_log.write(level, message);
+    }
+
+    fn min_level(&self) -> LogLevel {
+        self.min_level
+    }
+
+    fn set_min_level(&mut self, level: LogLevel) {
+        self.min_level = level;
+    }
+}
@@ -0,0 +1,161 @@
+//! Contains the TcpLogger implementation.
+
+use std::boxed::Box;
+use std::io::Write;
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::{Duration, Instant};
+
+use super::bridge_ilogger_trait::{ILogger, LogLevel};
+use super::bridge_ilogformatter_trait::ILogFormatter;
+use super::bridge_loggerregistry::get_logger;
+
+/// How long to wait for a connection to the log server.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// How long to wait after failing to connect to the log server before trying
+/// to connect again.  Lines written in the meantime are dropped.
+const RECONNECT_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Name of the module the TcpLogger reports its own failures as, through the
+/// logger registry.
+const MODULE_NAME: &str = "tcplogger";
+
+/// Helper function to connect to the log server.
+///
+/// # Parameters
+/// - address
+///
+///   Address of the log server, as "host:port".
+///
+/// # Returns
+/// Returns `Ok(TcpStream)` if connected; otherwise, returns `Err(String)`
+/// describing why the connection failed.
+fn _connect(address: &str) -> Result<TcpStream, String> {
+    let socket_addresses = address.to_socket_addrs()
+        .map_err(|error| format!("Cannot resolve \"{address}\": {error}"))?;
+    let mut last_error = format!("\"{address}\" has no addresses");
+    for socket_address in socket_addresses {
+        match TcpStream::connect_timeout(&socket_address, CONNECT_TIMEOUT) {
+            Ok(stream) => return Ok(stream),
+            Err(error) => last_error = format!("Cannot connect to {socket_address}: {error}"),
+        }
+    }
+    Err(last_error)
+}
+
+/// Represents a logger that streams logging to a log server over TCP, one
+/// line at a time.  If the connection is lost, or the log server could not
+/// be reached when the logger was created, the logger connects again before
+/// writing the next line; if that fails, the line is dropped and no
+/// further attempt to connect is made until the reconnect interval has
+/// passed.
+pub struct TcpLogger {
+    /// Address of the log server, as "host:port".
+    address: String,
+    /// The connection to the log server; None after the connection is lost.
+    stream: Option<TcpStream>,
+    /// When to next try connecting to the log server; None if the last
+    /// attempt to connect succeeded.
+    retry_at: Option<Instant>,
+    /// Decides what each line looks like.
+    formatter: Box<dyn ILogFormatter>,
+    /// Messages below this level are skipped.
+    min_level: LogLevel,
+}
+
+impl TcpLogger {
+    /// Create an instance of a network logger, which writes to a log server
+    /// using the given formatter.
+    ///
+    /// # Parameters
+    /// - address
+    ///
+    ///   Address of the log server, as "host:port".
+    /// - formatter
+    ///
+    ///   The formatter that decides what each line looks like.
+    ///
+    /// # Returns
+    ///   An instance of an ILogger object.  If the log server cannot be
+    ///   reached, lines are dropped until the logger manages to connect,
+    ///   which it next tries after the reconnect interval.
+    pub fn new(address: &str, formatter: Box<dyn ILogFormatter>) -> Box<dyn ILogger> {
+        let (stream, retry_at) = match _connect(address) {
+            Ok(stream) => (Some(stream), None),
+            Err(message) => {
+                get_logger(MODULE_NAME).log_error(&format!("{message}; lines are dropped until the log server can be reached."));
+                (None, Some(Instant::now() + RECONNECT_INTERVAL))
+            }
+        };
+        Box::new(TcpLogger {
+            address: address.to_string(),
+            stream,
+            retry_at,
+            formatter,
+            min_level: LogLevel::Trace,
+        })
+    }
+
+    /// Helper method to determine if the logger is waiting out the reconnect
+    /// interval after failing to connect to the log server.
+    ///
+    /// # Returns
+    /// Returns true if it is too soon to try connecting again; otherwise,
+    /// returns false.
+    fn _waiting_to_reconnect(&self) -> bool {
+        self.retry_at.is_some_and(|retry_at| Instant::now() < retry_at)
+    }
+
+    /// Helper method to write a line to the log server.  A failed write may
+    /// just mean the server dropped the connection, so the logger connects
+    /// again and retries the line once.
+    ///
+    /// # Parameters
+    /// - output
+    ///
+    ///   The formatted line to write.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the line was written; otherwise, returns
+    /// `Err(String)` describing the failure, and the connection is dropped so
+    /// a later line connects again.
+    fn _send(&mut self, output: &str) -> Result<(), String> {
+        if let Some(stream) = self.stream.as_mut() {
+            if stream.write_all(output.as_bytes()).is_ok() {
+                return Ok(());
+            }
+            self.stream = None;
+        }
+        let mut stream = match _connect(&self.address) {
+            Ok(stream) => stream,
+            Err(message) => {
+                self.retry_at = Some(Instant::now() + RECONNECT_INTERVAL);
+                return Err(message);
+            }
+        };
+        self.retry_at = None;
+        stream.write_all(output.as_bytes()).map_err(|error| error.to_string())?;
+        self.stream = Some(stream);
+        Ok(())
+    }
+}
+
+impl ILogger for TcpLogger {
+    fn write_line(&mut self, loglevel: &str, message: &str) {
+        if self._waiting_to_reconnect() {
+            return;
+        }
+        let output = self.formatter.format_line(loglevel, message);
+        if let Err(error) = self._send(&output) {
+            get_logger(MODULE_NAME).log_error(&format!("Failed to write to log server \"{}\": {error}", self.address));
+        }
+    }
+
+    fn min_level(&self) -> LogLevel {
+        self.min_level
+    }
+
+    fn set_min_level(&mut self, level: LogLevel) {
+        self.min_level = level;
+    }
+}
@@ -9,15 +9,77 @@
 //! commands are applied to the text object in succession then
 //! effectively undone.
 //!
+//! Commands are generic over the kind of object they are applied to (the
+//! receiver), so the same undo list also holds commands that change a
+//! counter.  Each command in the undo list is bound to its receiver through
+//! the IBoundCommand trait, which hides what kind of receiver it is.
+//!
+//! Each command can also undo itself with an inverse operation (subtracting
+//! undoes adding, reversing undoes reversing, and a replace remembers where
+//! it replaced text), so undoing the last command does not require applying
+//! all the commands before it again.  If the `--benchmark` exercise option is
+//! given, the two ways of undoing are timed against each other.
+//!
+//! Commands can also be made from closures with CommandFromClosure, which is
+//! handy for one-off operations that do not deserve a struct of their own.
+//!
+//! Commands can fail (replacing text that is not there, for example), so a
+//! batch of commands can be applied as a transaction: if any command in the
+//! batch fails, the commands already applied are rolled back.
+//!
+//! The undo list can be limited to a number of commands, in which case the
+//! oldest commands are forgotten as new commands are applied.
+//!
+//! Finally, the list of commands applied to a text object is saved to a file
+//! as JSON and replayed on a fresh text object, with a CommandRegistry
+//! creating each command again from its name and arguments.
+//!
+//! If the `--interactive` exercise option is given, the user types commands
+//! instead (such as `replace foo bar`, `reverse`, `undo` and `redo`), which
+//! are applied to a text object as they are typed.
+//!
 //! Accessed through the command_exercise() function.
 
 pub mod command_icommand_trait;
+pub mod command_icommandreceiver_trait;
+pub mod command_iboundcommand_trait;
+pub mod command_boundcommand;
 pub mod command_commands;
 pub mod command_textobject;
+pub mod command_counter;
+pub mod command_history;
+pub mod command_registry;
+pub mod command_benchmark;
+pub mod command_interactive;
+
+use std::cell::RefCell;
+use std::env;
+use std::fs;
+use std::rc::Rc;
 
 use command_textobject::CommandTextObject;
+use command_counter::CommandCounter;
+use command_boundcommand::BoundCommand;
+use command_iboundcommand_trait::IBoundCommand;
+use command_commands::{CommandFromClosure, CommandNoParameters, CommandOneParameter, CommandReplaceText};
+use command_history::{history_from_json, history_to_json};
 use command_icommand_trait::ICommand;
-use command_commands::{CommandNoParameters, CommandTwoParameters};
+use command_icommandreceiver_trait::ICommandReceiver;
+use command_registry::CommandRegistry;
+use crate::helpers::exercise_options::has_exercise_option;
+
+/// The text that the text objects in the exercise start with.
+const STARTING_TEXT: &str = "This is a line of text on which to experiment.";
+
+/// Name of the file the command history is saved to, in the temporary
+/// directory.
+const HISTORY_FILE: &str = "command_history.json";
+
+/// The most commands kept in the limited undo list in the exercise.
+const MAX_HISTORY: usize = 3;
+
+/// Number of commands undone by each kind of undo in the benchmark.
+const NUM_BENCHMARK_COMMANDS: usize = 1000;
 
 //-----------------------------------------------------------------------------
 
@@ -27,49 +89,179 @@ use command_commands::{CommandNoParameters, CommandTwoParameters};
 /// all the methods on this context have ready access to the undo list in the
 /// context.
 struct CommandContext {
-    /// The list of command objects that describe the changes made to the text
-    /// object.
-    command_list: Vec<Box<dyn ICommand>>,
+    /// The list of command objects that describe the changes made to the
+    /// receivers, in the order the changes were made.
+    command_list: Vec<Box<dyn IBoundCommand>>,
+    /// The commands that have been undone, most recently undone last, so
+    /// they can be redone.  Emptied whenever a new command is applied.
+    redo_list: Vec<Box<dyn IBoundCommand>>,
+    /// The most commands kept in the undo list, or None for no limit.
+    max_history: Option<usize>,
 }
 
 
 impl CommandContext {
     /// Constructor.
     fn new() -> CommandContext {
-        CommandContext { command_list: vec![] }
+        CommandContext { command_list: vec![], redo_list: vec![], max_history: None }
     }
 
 
-    /// Execute the given command on the given text object then save the
-    /// command on the given undo list.
+    /// Set the most commands kept in the undo list.  When a command is
+    /// applied to a full undo list, the oldest command is forgotten and can
+    /// no longer be undone.  The undo list is trimmed right away if it holds
+    /// more commands than that.
     ///
     /// # Parameters
-    /// - command_list
+    /// - max_history
+    ///
+    ///   The most commands to keep, or None for no limit.
+    fn set_max_history(&mut self, max_history: Option<usize>) {
+        self.max_history = max_history;
+        self.trim_history();
+    }
+
+
+    /// Forget the oldest commands in the undo list until it holds no more
+    /// than the maximum number of commands.
     ///
-    ///   The list that holds the commands for later undoing.
+    /// The receiver of each forgotten command takes the state it had right
+    /// after that command as its starting state, so undoing the remaining
+    /// commands by applying them again from the starting state still works.
+    fn trim_history(&mut self) {
+        let Some(max_history) = self.max_history else {
+            return;
+        };
+        if self.command_list.len() <= max_history {
+            return;
+        }
+        let num_forgotten = self.command_list.len() - max_history;
+        let forgotten: Vec<Box<dyn IBoundCommand>> = self.command_list.drain(..num_forgotten).collect();
+        for command in forgotten.iter() {
+            command.reset_receiver();
+        }
+        for command in self.command_list.iter() {
+            command.reset_receiver();
+        }
+        // The commands succeeded when first applied to these same states,
+        // so they succeed again.
+        for command in forgotten.iter() {
+            let _ = command.execute();
+        }
+        for command in forgotten.iter() {
+            command.set_receiver_starting_state();
+            println!("    history is full, forgetting command {}", command.to_string());
+        }
+        for command in self.command_list.iter() {
+            let _ = command.execute();
+        }
+    }
+
+
+    /// Show the commands in the undo list with their indices, oldest first,
+    /// and how many undone commands can be redone.
+    fn show_history(&self) {
+        if self.command_list.is_empty() {
+            println!("    No commands to undo");
+        }
+        for (index, command) in self.command_list.iter().enumerate() {
+            println!("    {index:>3}: {}", command.to_string());
+        }
+        if !self.redo_list.is_empty() {
+            println!("    ({} commands can be redone)", self.redo_list.len());
+        }
+    }
+
+
+    /// Execute the given command on its receiver then save the command on
+    /// the undo list.  Any undone commands can no longer be redone.  Finally,
+    /// it shows off what was done.  A command that fails is not saved.
+    ///
+    /// # Parameters
     /// - command
     ///
-    ///   The command to apply to the text.
-    /// - text
+    ///   The command, bound to its receiver, to apply.
     ///
-    ///   The CommandTextObject to affect.
-    fn execute_and_save(&mut self, command: Box<dyn ICommand>, text: &mut CommandTextObject) {
+    /// # Returns
+    /// Returns true if the command was applied; otherwise, returns false.
+    fn execute_and_save(&mut self, command: Box<dyn IBoundCommand>) -> bool {
         // Execute the command first because the command will be given to the command list
         // and we won't be able to reference it after that.
-        command.execute(text);
+        if let Err(message) = command.execute() {
+            println!("    command {:<31}failed: {message}", command.to_string());
+            return false;
+        }
+        println!("    command {:<31}==> \"{}\"", command.to_string(), command.receiver_to_string());
         self.command_list.push(command);
+        self.redo_list.clear();
+        self.trim_history();
+        true
+    }
+
+
+    /// Apply a batch of commands to the given receiver as a whole.  If any
+    /// command fails, the commands in the batch that were already applied are
+    /// rolled back, leaving the receiver as it was before the batch.  If all
+    /// the commands succeed, they are saved on the undo list.
+    ///
+    /// # Parameters
+    /// - commands
+    ///
+    ///   The commands to apply, in order.
+    /// - receiver
+    ///
+    ///   The receiver to apply the commands to.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if all the commands were applied; otherwise, returns
+    /// `Err(String)` describing the command that failed.
+    fn execute_transaction<R: ICommandReceiver + std::fmt::Display + 'static>(&mut self, commands: Vec<Box<dyn ICommand<R>>>, receiver: &Rc<RefCell<R>>) -> Result<(), String> {
+        let mut applied: Vec<Box<dyn IBoundCommand>> = vec![];
+        for command in commands {
+            let command = BoundCommand::new(command, receiver);
+            if let Err(message) = command.execute() {
+                let message = format!("command {} failed: {message}", command.to_string());
+                self.roll_back(&applied);
+                return Err(message);
+            }
+            println!("    command {:<31}==> \"{}\"", command.to_string(), command.receiver_to_string());
+            applied.push(command);
+        }
+        self.command_list.append(&mut applied);
+        self.redo_list.clear();
+        self.trim_history();
+        Ok(())
+    }
+
+
+    /// Undo the given commands, most recent first, with their inverse
+    /// operations.  If a command has no inverse operation, the receivers are
+    /// instead rebuilt from the undo list, which also undoes all the given
+    /// commands.
+    ///
+    /// # Parameters
+    /// - applied
+    ///
+    ///   The commands to undo, which must have been applied after all the
+    ///   commands in the undo list.
+    fn roll_back(&self, applied: &[Box<dyn IBoundCommand>]) {
+        for command in applied.iter().rev() {
+            println!("    rolling back command {}", command.to_string());
+        }
+        if !applied.iter().rev().all(|command| command.unexecute()) {
+            for command in applied.iter() {
+                command.reset_receiver();
+            }
+            self.reapply_commands();
+        }
     }
 
 
     /// Helper method to create a Command object that replaces text in the
     /// given CommandTextObject, applies the command to the CommandTextObject,
-    /// and then adds the command to the given undo list.  Finally, it shows
-    /// off what was done.
+    /// and then adds the command to the undo list.
     ///
     /// # Parameters
-    /// - command_list
-    ///
-    ///   The list that holds the commands for later undoing.
     /// - text
     ///
     ///   The CommandTextObject to affect.
@@ -79,88 +271,265 @@ impl CommandContext {
     /// - replace_text
     ///
     ///   What to replace `search_pattern` with.
-    fn apply_replace_command(&mut self, text: &mut CommandTextObject, search_pattern: &str, replace_text: &str)
+    fn apply_replace_command(&mut self, text: &Rc<RefCell<CommandTextObject>>, search_pattern: &str, replace_text: &str)
     {
-        let command = CommandTwoParameters::new("Replace", operation_replace, &search_pattern, &replace_text);
-        // Get the command as a string before it is given to command_save_and_execute()
-        let command_name = command.to_string();
-        self.execute_and_save(command, text);
-        println!("    command {:<31}==> \"{}\"", command_name, text);
+        let command = CommandReplaceText::new(search_pattern, replace_text);
+        self.execute_and_save(BoundCommand::new(command, text));
     }
 
     /// Helper method to create a Command object that reverses the order of the
     /// characters in the given CommandTextObject, applies the command to the
-    /// CommandTextObject, and then adds the command to the given undo list.
-    /// Finally, it shows what was done.
+    /// CommandTextObject, and then adds the command to the undo list.
     ///
     /// # Parameters
-    /// - command_list
-    ///
-    ///   The list that holds the commands for later undoing.
     /// - text
     ///
     ///   The CommandTextObject to affect.
-    fn apply_reverse_command(&mut self, text: &mut CommandTextObject) {
-        let command = CommandNoParameters::new("Reverse", operation_reverse);
-        // Get the command as a string before it is given to command_save_and_execute()
-        let command_name = command.to_string();
-        self.execute_and_save(command, text);
-        println!("    command {:<31}==> \"{}\"", command_name, text);
+    fn apply_reverse_command(&mut self, text: &Rc<RefCell<CommandTextObject>>) {
+        let command = CommandNoParameters::new("Reverse", operation_reverse, Some(operation_reverse));
+        self.execute_and_save(BoundCommand::new(command, text));
     }
 
-    /// Perform an undo on the given CommandTextObject, using the commands in
-    /// the given undo list.  If the undo list is empty, nothing happens.
+    /// Helper method to create a Command object that adds an amount to the
+    /// given CommandCounter, applies the command to the CommandCounter, and
+    /// then adds the command to the undo list.
     ///
     /// # Parameters
-    /// - command_list
+    /// - counter
     ///
-    ///   The list that holds the commands for later undoing.
-    /// - text
+    ///   The CommandCounter to affect.
+    /// - amount
     ///
-    ///   The CommandTextObject to affect.
-    fn undo(&mut self, text: &mut CommandTextObject) {
-        if !self.command_list.is_empty() {
-            let last_command = self.command_list.pop().unwrap();
-            text.reset();
-            for command in self.command_list.iter() {
-                command.execute(text);
+    ///   The amount to add to the counter.
+    fn apply_add_command(&mut self, counter: &Rc<RefCell<CommandCounter>>, amount: i32) {
+        let command = CommandOneParameter::new("Add", operation_add, Some(operation_subtract), amount);
+        self.execute_and_save(BoundCommand::new(command, counter));
+    }
+
+    /// Helper method to create a Command object that multiplies the given
+    /// CommandCounter by a factor, applies the command to the CommandCounter,
+    /// and then adds the command to the undo list.
+    ///
+    /// Multiplying by zero cannot be undone by dividing, so that command has
+    /// no inverse operation and is undone by applying the commands before it
+    /// again.
+    ///
+    /// # Parameters
+    /// - counter
+    ///
+    ///   The CommandCounter to affect.
+    /// - factor
+    ///
+    ///   The factor to multiply the counter by.
+    fn apply_multiply_command(&mut self, counter: &Rc<RefCell<CommandCounter>>, factor: i32) {
+        let inverse: Option<fn(&mut CommandCounter, &i32)> = match factor {
+            0 => None,
+            _ => Some(operation_divide),
+        };
+        let command = CommandOneParameter::new("Multiply by", operation_multiply, inverse, factor);
+        self.execute_and_save(BoundCommand::new(command, counter));
+    }
+
+    /// Create again each command in a saved history, apply it to the given
+    /// receiver, and add it to the undo list, as if the commands had been
+    /// applied one at a time.
+    ///
+    /// # Parameters
+    /// - json
+    ///
+    ///   The saved history, as written by history_to_json().
+    /// - registry
+    ///
+    ///   The registry that creates the commands by name.
+    /// - receiver
+    ///
+    ///   The receiver to apply the commands to.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if all commands were applied; otherwise, returns
+    /// `Err(String)` describing the first command that could not be created
+    /// (the commands before it have already been applied).
+    fn replay<R: ICommandReceiver + std::fmt::Display + 'static>(&mut self, json: &str, registry: &CommandRegistry<R>, receiver: &Rc<RefCell<R>>) -> Result<(), String> {
+        for record in history_from_json(json)? {
+            let command = registry.create(&record)?;
+            if !self.execute_and_save(BoundCommand::new(command, receiver)) {
+                return Err(format!("Cannot replay the {} command", record.name));
             }
-            println!("    undoing command {:<31}==> \"{}\"", last_command.to_string(), text);
+        }
+        Ok(())
+    }
+
+    /// Reset the receivers of all the commands in the undo list to their
+    /// starting state then apply all the commands again.  The commands
+    /// succeeded when first applied to these same states, so they succeed
+    /// again.
+    fn reapply_commands(&self) {
+        for command in self.command_list.iter() {
+            command.reset_receiver();
+        }
+        for command in self.command_list.iter() {
+            let _ = command.execute();
         }
     }
+
+    /// Perform an undo of the last command in the undo list, using the
+    /// command's inverse operation.  If the command has no inverse
+    /// operation, the remaining commands are applied again from the start
+    /// instead, as with undo_by_replay().  The command can then be redone.
+    ///
+    /// # Returns
+    /// Returns the command that was undone, or None if the undo list is
+    /// empty.
+    fn undo(&mut self) -> Option<&dyn IBoundCommand> {
+        let last_command = self.command_list.pop()?;
+        if !last_command.unexecute() {
+            last_command.reset_receiver();
+            self.reapply_commands();
+        }
+        self.redo_list.push(last_command);
+        self.redo_list.last().map(|command| command.as_ref())
+    }
+
+    /// Perform an undo of the last command in the undo list, without using
+    /// any inverse operations: the receivers of all the commands are reset
+    /// to their starting state and the remaining commands are applied again.
+    /// This takes longer the more commands there are in the undo list.  The
+    /// command can then be redone.
+    ///
+    /// # Returns
+    /// Returns the command that was undone, or None if the undo list is
+    /// empty.
+    fn undo_by_replay(&mut self) -> Option<&dyn IBoundCommand> {
+        let last_command = self.command_list.pop()?;
+        last_command.reset_receiver();
+        self.reapply_commands();
+        self.redo_list.push(last_command);
+        self.redo_list.last().map(|command| command.as_ref())
+    }
+
+    /// Apply again the command that was most recently undone and put it back
+    /// on the undo list.
+    ///
+    /// # Returns
+    /// Returns the command that was redone, or None if there are no undone
+    /// commands.
+    fn redo(&mut self) -> Option<&dyn IBoundCommand> {
+        let command = self.redo_list.pop()?;
+        // The receiver is back in the state the command was first applied
+        // to, so the command succeeds again.
+        let _ = command.execute();
+        self.command_list.push(command);
+        self.command_list.last().map(|command| command.as_ref())
+    }
 }
 
 
 //#############################################################################
 //#############################################################################
 
-/// An operation to search and replace text in a CommandTextObject.
+/// An operation to reverse the characters in the given CommandTextObject.
 ///
 /// # Parameters
 /// - text
 ///
 ///   The CommandTextObject to affect.
-/// - search_pattern
+fn operation_reverse(text: &mut CommandTextObject) {
+    // Technically, this will work only with ASCII strings since char() does
+    // not iterate over graphemes but Unicode points.
+    text.text = text.text.chars().rev().collect::<String>();
+}
+
+
+/// An operation to add an amount to a CommandCounter.
 ///
-///   What to look for in the CommandTextObject.
-/// - replace_text
+/// # Parameters
+/// - counter
 ///
-///   What to replace `search_pattern` with.
-fn operation_replace(text: &mut CommandTextObject, search_pattern: &str, replace_text: &str) {
-    text.text = text.text.replace(search_pattern, replace_text);
+///   The CommandCounter to affect.
+/// - amount
+///
+///   The amount to add to the counter.
+fn operation_add(counter: &mut CommandCounter, amount: &i32) {
+    counter.value += amount;
 }
 
 
-/// An operation to reverse the characters in the given CommandTextObject.
+/// An operation to subtract an amount from a CommandCounter, which undoes
+/// operation_add().
 ///
 /// # Parameters
-/// - text
+/// - counter
 ///
-///   The CommandTextObject to affect.
-fn operation_reverse(text: &mut CommandTextObject) {
-    // Technically, this will work only with ASCII strings since char() does
-    // not iterate over graphemes but Unicode points.
-    text.text = text.text.chars().rev().collect::<String>();
+///   The CommandCounter to affect.
+/// - amount
+///
+///   The amount to subtract from the counter.
+fn operation_subtract(counter: &mut CommandCounter, amount: &i32) {
+    counter.value -= amount;
+}
+
+
+/// An operation to multiply a CommandCounter by a factor.
+///
+/// # Parameters
+/// - counter
+///
+///   The CommandCounter to affect.
+/// - factor
+///
+///   The factor to multiply the counter by.
+fn operation_multiply(counter: &mut CommandCounter, factor: &i32) {
+    counter.value *= factor;
+}
+
+
+/// An operation to divide a CommandCounter by a factor, which undoes
+/// operation_multiply() for any factor other than zero.
+///
+/// # Parameters
+/// - counter
+///
+///   The CommandCounter to affect.
+/// - factor
+///
+///   The factor to divide the counter by.  Must not be zero.
+fn operation_divide(counter: &mut CommandCounter, factor: &i32) {
+    counter.value /= factor;
+}
+
+/// Create a Replace command from the arguments saved in a history.
+///
+/// # Parameters
+/// - args
+///
+///   The text to look for and the text to replace it with.
+///
+/// # Returns
+/// Returns `Ok(Box<dyn ICommand>)` containing the command; otherwise, returns
+/// `Err(String)` if there are not exactly two arguments.
+fn create_replace_command(args: &[String]) -> Result<Box<dyn ICommand<CommandTextObject>>, String> {
+    match args {
+        [search_pattern, replace_text] => Ok(CommandReplaceText::new(search_pattern, replace_text)),
+        _ => Err(format!("Replace takes 2 arguments but was given {}", args.len())),
+    }
+}
+
+
+/// Create a Reverse command from the arguments saved in a history.
+///
+/// # Parameters
+/// - args
+///
+///   No arguments are expected.
+///
+/// # Returns
+/// Returns `Ok(Box<dyn ICommand>)` containing the command; otherwise, returns
+/// `Err(String)` if any arguments are given.
+fn create_reverse_command(args: &[String]) -> Result<Box<dyn ICommand<CommandTextObject>>, String> {
+    match args {
+        [] => Ok(CommandNoParameters::new("Reverse", operation_reverse, Some(operation_reverse))),
+        _ => Err(format!("Reverse takes no arguments but was given {}", args.len())),
+    }
 }
 
 //#############################################################################
@@ -174,35 +543,148 @@ fn operation_reverse(text: &mut CommandTextObject) {
 /// the object at a later time.
 /// 
 /// In this exercise, an undo list is implemented using Commands that
-/// associate commands defined in this file with a text object.  The
-/// commands are applied to the text object in succession then
-/// effectively undone.
+/// associate commands defined in this file with a text object and a counter.
+/// The commands are applied to the objects in succession then undone, each
+/// with its inverse operation.
+///
+/// If the `--benchmark` exercise option is given, undoing with inverse
+/// operations is then timed against undoing by applying the remaining
+/// commands again.
+///
+/// The undo list is then limited to a few commands, so the oldest commands
+/// are forgotten and can no longer be undone.
+///
+/// Commands made from closures are then applied along with a command made
+/// from a struct, to contrast the two ways of making commands.
+///
+/// A batch of commands is applied as a transaction, which is rolled back
+/// when one of its commands fails.
+///
+/// Then a few commands are applied to a text object, saved to a file, and
+/// replayed from the file on a fresh text object.
+///
+/// If the `--interactive` exercise option is given, the user types the
+/// commands to apply instead of seeing the examples above.
 // ! [Using Command in Rust]
 pub fn command_exercise() -> Result<(), String> {
     println!("");
     println!("Command Exercise");
 
+    if has_exercise_option("--interactive") {
+        command_interactive::run_interactive()?;
+        println!("  Done.");
+        return Ok(());
+    }
+
     // Note: The context's undo list owns the commands.  When an undo operation
     // is done, the command is removed from the list and goes away at the end
     // of the undo function.
     let mut command_context = CommandContext::new();
 
-    let mut text_object = CommandTextObject::new("This is a line of text on which to experiment.");
+    // The receivers are shared with the commands in the undo list.
+    let text_object = Rc::new(RefCell::new(CommandTextObject::new(STARTING_TEXT)));
+    let counter = Rc::new(RefCell::new(CommandCounter::new(1)));
 
-    println!("  Starting text: \"{text_object}\"");
+    println!("  Starting text: \"{}\"", text_object.borrow());
+    println!("  Starting count: {}", counter.borrow());
 
-    command_context.apply_replace_command(&mut text_object, "text", "painting");
-    command_context.apply_replace_command(&mut text_object, "on", "off");
-    command_context.apply_reverse_command(&mut text_object);
-    command_context.apply_replace_command(&mut text_object, "i", "!");
+    command_context.apply_replace_command(&text_object, "text", "painting");
+    command_context.apply_add_command(&counter, 5);
+    command_context.apply_replace_command(&text_object, "on", "off");
+    command_context.apply_multiply_command(&counter, 3);
+    command_context.apply_reverse_command(&text_object);
+    command_context.apply_replace_command(&text_object, "i", "!");
+    command_context.apply_multiply_command(&counter, 0);
 
     println!("  Now perform undo until back to original");
-    command_context.undo(&mut text_object);
-    command_context.undo(&mut text_object);
-    command_context.undo(&mut text_object);
-    command_context.undo(&mut text_object);
+    while let Some(command) = command_context.undo() {
+        println!("    undoing command {:<31}==> \"{}\"", command.to_string(), command.receiver_to_string());
+    }
+
+    println!("  Final text   : \"{}\"", text_object.borrow());
+    println!("  Final count  : {}", counter.borrow());
+
+    println!("  Now keep only the last {MAX_HISTORY} commands while applying 5 commands");
+    let mut limited_context = CommandContext::new();
+    limited_context.set_max_history(Some(MAX_HISTORY));
+    let limited_text = Rc::new(RefCell::new(CommandTextObject::new(STARTING_TEXT)));
+    limited_context.apply_replace_command(&limited_text, "text", "painting");
+    limited_context.apply_replace_command(&limited_text, "on", "off");
+    limited_context.apply_reverse_command(&limited_text);
+    limited_context.apply_replace_command(&limited_text, "i", "!");
+    limited_context.apply_replace_command(&limited_text, "a", "@");
+    println!("  Commands that can be undone:");
+    limited_context.show_history();
+    println!("  Now perform undo until the history is empty");
+    while let Some(command) = limited_context.undo() {
+        println!("    undoing command {:<31}==> \"{}\"", command.to_string(), command.receiver_to_string());
+    }
+    println!("  Final text   : \"{}\"", limited_text.borrow());
+
+    println!("  Now mix commands made from closures with a command made from a struct");
+    let mut closure_context = CommandContext::new();
+    let closure_text = Rc::new(RefCell::new(CommandTextObject::new(STARTING_TEXT)));
+    let uppercase = CommandFromClosure::new("Uppercase", |text: &mut CommandTextObject| {
+        text.text = text.text.to_uppercase();
+    });
+    closure_context.execute_and_save(BoundCommand::new(uppercase, &closure_text));
+    closure_context.apply_reverse_command(&closure_text);
+    let surround = CommandFromClosure::with_args("Surround with", &["<<", ">>"], |text: &mut CommandTextObject, args| {
+        text.text = format!("{}{}{}", args[0], text.text, args[1]);
+    });
+    closure_context.execute_and_save(BoundCommand::new(surround, &closure_text));
+    println!("  Now perform undo until back to original");
+    while let Some(command) = closure_context.undo() {
+        println!("    undoing command {:<31}==> \"{}\"", command.to_string(), command.receiver_to_string());
+    }
+
+    println!("  Now apply a batch of commands as a transaction, where the last command fails");
+    let mut transaction_context = CommandContext::new();
+    let transaction_text = Rc::new(RefCell::new(CommandTextObject::new(STARTING_TEXT)));
+    let batch: Vec<Box<dyn ICommand<CommandTextObject>>> = vec![
+        CommandReplaceText::new("text", "words"),
+        CommandNoParameters::new("Reverse", operation_reverse, Some(operation_reverse)),
+        CommandReplaceText::new("missing", "found"),
+    ];
+    if let Err(message) = transaction_context.execute_transaction(batch, &transaction_text) {
+        println!("  The transaction failed: {message}");
+    }
+    println!("  Text after the transaction: \"{}\"", transaction_text.borrow());
+    println!("  Now apply the same batch without the failing command");
+    let batch: Vec<Box<dyn ICommand<CommandTextObject>>> = vec![
+        CommandReplaceText::new("text", "words"),
+        CommandNoParameters::new("Reverse", operation_reverse, Some(operation_reverse)),
+    ];
+    transaction_context.execute_transaction(batch, &transaction_text)?;
+    println!("  Commands that can be undone:");
+    transaction_context.show_history();
+
+    if has_exercise_option("--benchmark") {
+        command_benchmark::run_benchmark(NUM_BENCHMARK_COMMANDS);
+    }
+
+    println!("  Now apply commands to a new text object and save them to {HISTORY_FILE}");
+    let mut session_context = CommandContext::new();
+    let session_text = Rc::new(RefCell::new(CommandTextObject::new(STARTING_TEXT)));
+    session_context.apply_replace_command(&session_text, "line", "sentence");
+    session_context.apply_reverse_command(&session_text);
+    session_context.apply_replace_command(&session_text, "e", "3");
+    let history_path = env::temp_dir().join(HISTORY_FILE);
+    fs::write(&history_path, history_to_json(&session_context.command_list))
+        .map_err(|error| format!("Cannot write \"{HISTORY_FILE}\": {error}"))?;
 
-    println!("  Final text   : \"{text_object}\"");
+    println!("  Replay the commands from {HISTORY_FILE} on a fresh text object");
+    let mut registry = CommandRegistry::new();
+    registry.register("Replace", create_replace_command);
+    registry.register("Reverse", create_reverse_command);
+    let json = fs::read_to_string(&history_path)
+        .map_err(|error| format!("Cannot read \"{HISTORY_FILE}\": {error}"))?;
+    let _ = fs::remove_file(&history_path);
+    let mut replay_context = CommandContext::new();
+    let replay_text = Rc::new(RefCell::new(CommandTextObject::new(STARTING_TEXT)));
+    replay_context.replay(&json, &registry, &replay_text)?;
+    let matches = replay_text.borrow().text == session_text.borrow().text;
+    println!("  Replayed text matches the saved session: {matches}");
 
     println!("  Done.");
 
@@ -0,0 +1,92 @@
+//! Contains run_benchmark() for timing the two ways the CommandContext can
+//! undo commands: with each command's inverse operation, and by resetting
+//! the text and applying all the remaining commands again.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use super::command_boundcommand::BoundCommand;
+use super::command_commands::{CommandNoParameters, CommandReplaceText};
+use super::command_iboundcommand_trait::IBoundCommand;
+use super::command_textobject::CommandTextObject;
+use super::{operation_reverse, CommandContext, STARTING_TEXT};
+
+//-----------------------------------------------------------------------------
+
+/// Alias for one of the CommandContext's undo methods.
+type UndoMethod = fn(&mut CommandContext) -> Option<&dyn IBoundCommand>;
+
+/// Apply the given number of commands to a new text object, keeping them in
+/// a new undo list.  The commands cycle through replacing and reversing
+/// text, so the text does not grow.
+///
+/// # Parameters
+/// - num_commands
+///
+///   Number of commands to apply.
+///
+/// # Returns
+/// Returns a tuple (CommandContext, text object) containing the undo list
+/// and the text object the commands were applied to.
+fn _apply_commands(num_commands: usize) -> (CommandContext, Rc<RefCell<CommandTextObject>>) {
+    let mut context = CommandContext::new();
+    let text = Rc::new(RefCell::new(CommandTextObject::new(STARTING_TEXT)));
+    for index in 0..num_commands {
+        let command = match index % 4 {
+            0 => CommandReplaceText::new("i", "!"),
+            2 => CommandReplaceText::new("!", "i"),
+            _ => CommandNoParameters::new("Reverse", operation_reverse, Some(operation_reverse)),
+        };
+        let command = BoundCommand::new(command, &text);
+        if command.execute().is_ok() {
+            context.command_list.push(command);
+        }
+    }
+    (context, text)
+}
+
+/// Undo all the commands in a new undo list with the given undo method.
+///
+/// # Parameters
+/// - num_commands
+///
+///   Number of commands to apply and then undo.
+/// - undo
+///
+///   The CommandContext method that undoes the last command.
+///
+/// # Returns
+/// Returns a tuple (Duration, bool) containing the time taken to undo all
+/// the commands and whether the text was back to the starting text
+/// afterwards.
+fn _time_undo(num_commands: usize, undo: UndoMethod) -> (Duration, bool) {
+    let (mut context, text) = _apply_commands(num_commands);
+    let start = Instant::now();
+    while undo(&mut context).is_some() {}
+    let elapsed = start.elapsed();
+    let restored = text.borrow().text == STARTING_TEXT;
+    (elapsed, restored)
+}
+
+//-----------------------------------------------------------------------------
+
+/// Apply the same list of commands twice, then undo them all one at a time,
+/// once with inverse operations and once by applying the remaining commands
+/// again, and show how long each took.
+///
+/// # Parameters
+/// - num_commands
+///
+///   Number of commands to apply and undo with each kind of undo.
+pub fn run_benchmark(num_commands: usize) {
+    println!("  Benchmark: undoing {num_commands} commands one at a time");
+    let runs: [(&str, UndoMethod); 2] = [
+        ("inverse operations", CommandContext::undo),
+        ("applying commands again", CommandContext::undo_by_replay),
+    ];
+    for (label, undo) in runs {
+        let (elapsed, restored) = _time_undo(num_commands, undo);
+        println!("    {:<24} {:>10.3} ms  (text restored: {restored})", label, elapsed.as_secs_f64() * 1000.0);
+    }
+}
@@ -0,0 +1,78 @@
+//! Contains the BoundCommand implementation.
+
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::rc::Rc;
+
+use super::command_iboundcommand_trait::IBoundCommand;
+use super::command_icommand_trait::ICommand;
+use super::command_icommandreceiver_trait::ICommandReceiver;
+
+/// Represents a command for receivers of type `R` bound to one particular
+/// receiver.  The receiver is shared with the rest of the program so its
+/// state can be shown after the command is applied.
+///
+/// This struct implements the IBoundCommand trait so commands for any kind of
+/// receiver can be kept in the same undo list.
+pub struct BoundCommand<R> {
+    /// The command to apply.
+    command: Box<dyn ICommand<R>>,
+    /// The receiver to apply the command to.
+    receiver: Rc<RefCell<R>>,
+}
+
+impl<R: ICommandReceiver + Display + 'static> BoundCommand<R> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - command
+    ///
+    ///   The command to apply.
+    /// - receiver
+    ///
+    ///   The receiver to apply the command to.
+    ///
+    /// # Returns
+    /// Returns an IBoundCommand object representing the command and its
+    /// receiver.
+    pub fn new(command: Box<dyn ICommand<R>>, receiver: &Rc<RefCell<R>>) -> Box<dyn IBoundCommand> {
+        Box::new(BoundCommand {
+            command,
+            receiver: receiver.clone(),
+        })
+    }
+}
+
+impl<R: ICommandReceiver + Display> IBoundCommand for BoundCommand<R> {
+    fn execute(&self) -> Result<(), String> {
+        self.command.execute(&mut self.receiver.borrow_mut())
+    }
+
+    fn unexecute(&self) -> bool {
+        self.command.unexecute(&mut self.receiver.borrow_mut())
+    }
+
+    fn reset_receiver(&self) {
+        self.receiver.borrow_mut().reset();
+    }
+
+    fn set_receiver_starting_state(&self) {
+        self.receiver.borrow_mut().set_starting_state();
+    }
+
+    fn name(&self) -> String {
+        self.command.name()
+    }
+
+    fn args(&self) -> Vec<String> {
+        self.command.args()
+    }
+
+    fn to_string(&self) -> String {
+        self.command.to_string()
+    }
+
+    fn receiver_to_string(&self) -> String {
+        self.receiver.borrow().to_string()
+    }
+}
@@ -1,34 +1,45 @@
-//! Contains the CommandNoParameters and CommandTwoParameters implementations.
+//! Contains the CommandNoParameters, CommandOneParameter and
+//! CommandFromClosure implementations, which work with any kind of receiver,
+//! given by the type parameter `R`, and the CommandReplaceText
+//! implementation, which works with a CommandTextObject.
+
+use std::cell::RefCell;
+use std::fmt::Display;
 
 use super::command_icommand_trait::ICommand;
-use super::CommandTextObject;
+use super::command_textobject::CommandTextObject;
 
 //-----------------------------------------------------------------------------
 
-/// Alias for a function that takes no parameters other than a CommandTextObject.
-type NoParameterOperation = fn(source: &mut CommandTextObject);
+/// Alias for a function that takes no parameters other than a receiver.
+type NoParameterOperation<R> = fn(source: &mut R);
+
+/// Alias for a function that takes one parameter along with a receiver.
+type OneParameterOperation<R, T> = fn(source: &mut R, arg: &T);
 
-/// Alias for a function that takes two string parameters along with a
-/// CommandTextObject.
-type TwoParametersOperation = fn(source: &mut CommandTextObject, arg1:&str, arg2:&str);
+/// Alias for a closure that takes a list of string arguments along with a
+/// receiver.
+type ClosureOperation<R> = Box<dyn Fn(&mut R, &[String])>;
 
 //-----------------------------------------------------------------------------
 
-/// Represents an operation that can be applied to a CommandTextObject with
-/// no additional parameters.
+/// Represents an operation that can be applied to a receiver with no
+/// additional parameters.
 ///
 /// This struct implements the ICommand trait so it can be treated the same as
 /// any other kind of command.
-pub struct CommandNoParameters {
+pub struct CommandNoParameters<R> {
     /// Easy-to-read command name.
     name: String,
-    /// No parameter operation to apply to a CommandTextObject.
-    operation: NoParameterOperation,
+    /// No parameter operation to apply to a receiver.
+    operation: NoParameterOperation<R>,
+    /// Operation that undoes `operation`, if there is one.
+    inverse: Option<NoParameterOperation<R>>,
 }
 
-impl CommandNoParameters {
-    /// Constructor for a command that applies an operation to a
-    /// CommandTextObject but does not require any additional parameters.
+impl<R: 'static> CommandNoParameters<R> {
+    /// Constructor for a command that applies an operation to a receiver but
+    /// does not require any additional parameters.
     ///
     /// # Parameters
     /// - name
@@ -36,22 +47,43 @@ impl CommandNoParameters {
     ///   Easy-to-read name of the command.
     /// - operation
     ///
-    ///   The operation to apply to a CommandTextObject and that takes no
-    ///   parameters.
+    ///   The operation to apply to a receiver and that takes no parameters.
+    /// - inverse
+    ///
+    ///   The operation that undoes `operation`, or None if there is no such
+    ///   operation.  An operation that is its own inverse (such as reversing
+    ///   text) is given again here.
     ///
     /// # Returns
     /// Returns an ICommand object representing the command.
-    pub fn new(name: &str, operation: NoParameterOperation) -> Box<dyn ICommand> {
+    pub fn new(name: &str, operation: NoParameterOperation<R>, inverse: Option<NoParameterOperation<R>>) -> Box<dyn ICommand<R>> {
         Box::new(CommandNoParameters{
             name: name.to_string(),
             operation: operation,
+            inverse,
         })
     }
 }
 
-impl ICommand for CommandNoParameters {
-    fn execute(&self, receiver: &mut CommandTextObject) {
+impl<R> ICommand<R> for CommandNoParameters<R> {
+    fn execute(&self, receiver: &mut R) -> Result<(), String> {
         (self.operation)(receiver);
+        Ok(())
+    }
+    fn unexecute(&self, receiver: &mut R) -> bool {
+        match self.inverse {
+            Some(inverse) => {
+                inverse(receiver);
+                true
+            }
+            None => false,
+        }
+    }
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+    fn args(&self) -> Vec<String> {
+        vec![]
     }
     fn to_string(&self) -> String {
         self.name.clone()
@@ -61,26 +93,103 @@ impl ICommand for CommandNoParameters {
 //=============================================================================
 //=============================================================================
 
+/// Represents an operation that can be applied to a receiver that requires
+/// one additional parameter of type `T`.
+///
+/// This struct implements the ICommand trait so it can be treated the same as
+/// any other kind of command.
+pub struct CommandOneParameter<R, T> {
+    /// Easy-to-read command name.
+    name: String,
+    /// One parameter operation to apply to a receiver.
+    operation: OneParameterOperation<R, T>,
+    /// Operation that undoes `operation` when given the same argument, if
+    /// there is one.
+    inverse: Option<OneParameterOperation<R, T>>,
+    /// The argument to the operation.
+    arg: T,
+}
+
+impl<R: 'static, T: Display + 'static> CommandOneParameter<R, T> {
+    /// Constructor for a command that applies an operation to a receiver,
+    /// where the operation takes one parameter.
+    ///
+    /// # Parameters
+    /// - name
+    ///
+    ///   Easy-to-read name of the command.
+    /// - operation
+    ///
+    ///   The operation to apply to a receiver and that takes one parameter.
+    /// - inverse
+    ///
+    ///   The operation that undoes `operation` when given the same argument,
+    ///   or None if there is no such operation.
+    /// - arg
+    ///
+    ///   Argument to the operation (after the receiver).
+    /// # Returns
+    /// Returns an ICommand object representing the command.
+    pub fn new(name: &str, operation: OneParameterOperation<R, T>, inverse: Option<OneParameterOperation<R, T>>, arg: T) -> Box<dyn ICommand<R>> {
+        Box::new(CommandOneParameter{
+            name: name.to_string(),
+            operation,
+            inverse,
+            arg,
+        })
+    }
+}
+
+impl<R, T: Display> ICommand<R> for CommandOneParameter<R, T> {
+    fn execute(&self, receiver: &mut R) -> Result<(), String> {
+        (self.operation)(receiver, &self.arg);
+        Ok(())
+    }
+
+    fn unexecute(&self, receiver: &mut R) -> bool {
+        match self.inverse {
+            Some(inverse) => {
+                inverse(receiver, &self.arg);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn args(&self) -> Vec<String> {
+        vec![self.arg.to_string()]
+    }
+
+    fn to_string(&self) -> String {
+        format!("{0} {1}", self.name, self.arg)
+    }
+}
+
+//=============================================================================
+//=============================================================================
 
-/// Represents an operation that can be applied to a CommandTextObject that
-/// requires two additional parameters.
+/// Represents an operation given as a closure, which can be applied to a
+/// receiver.  Any arguments the closure needs are captured by the closure or
+/// given to with_args(), so no separate struct is needed for each number of
+/// parameters.
 ///
 /// This struct implements the ICommand trait so it can be treated the same as
 /// any other kind of command.
-pub struct CommandTwoParameters {
+pub struct CommandFromClosure<R> {
     /// Easy-to-read command name.
     name: String,
-    /// Two parameter operation to apply to a CommandTextObject.
-    operation: TwoParametersOperation,
-    /// The first argument to the operation.
-    arg1: String,
-    /// The second argument to the operation.
-    arg2: String,
+    /// The arguments given to the closure, shown as part of the command.
+    args: Vec<String>,
+    /// The operation to apply to a receiver, given the arguments.
+    operation: ClosureOperation<R>,
 }
 
-impl CommandTwoParameters {
-    /// Constructor for a command that applies an operation to a
-    /// CommandTextObject, where the operation takes two parameters.
+impl<R: 'static> CommandFromClosure<R> {
+    /// Constructor for a command that applies a closure to a receiver.
     ///
     /// # Parameters
     /// - name
@@ -88,32 +197,148 @@ impl CommandTwoParameters {
     ///   Easy-to-read name of the command.
     /// - operation
     ///
-    ///   The operation to apply to a CommandTextObject and that takes two
-    ///   parameters.
-    /// - arg1
+    ///   The closure to apply to a receiver.
+    ///
+    /// # Returns
+    /// Returns an ICommand object representing the command.
+    pub fn new<F>(name: &str, operation: F) -> Box<dyn ICommand<R>>
+    where F: Fn(&mut R) + 'static {
+        CommandFromClosure::with_args(name, &[], move |receiver, _args| operation(receiver))
+    }
+
+    /// Constructor for a command that applies a closure to a receiver and
+    /// passes the given arguments to the closure.  Unlike arguments captured
+    /// by the closure, these arguments are shown as part of the command.
+    ///
+    /// # Parameters
+    /// - name
+    ///
+    ///   Easy-to-read name of the command.
+    /// - args
+    ///
+    ///   Arguments to pass to the closure (after the receiver).
+    /// - operation
     ///
-    ///   First argument to the operation (after the CommandTextObject).
-    /// - arg2
+    ///   The closure to apply to a receiver.
     ///
-    ///   Second argument to the operation (after the CommandTextObject).
     /// # Returns
     /// Returns an ICommand object representing the command.
-    pub fn new(name: &str, operation: TwoParametersOperation, arg1: &str, arg2: &str) -> Box<dyn ICommand> {
-        Box::new(CommandTwoParameters{
+    pub fn with_args<F>(name: &str, args: &[&str], operation: F) -> Box<dyn ICommand<R>>
+    where F: Fn(&mut R, &[String]) + 'static {
+        Box::new(CommandFromClosure {
             name: name.to_string(),
-            operation: operation,
-            arg1: arg1.to_string(),
-            arg2: arg2.to_string(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+            operation: Box::new(operation),
+        })
+    }
+}
+
+impl<R> ICommand<R> for CommandFromClosure<R> {
+    fn execute(&self, receiver: &mut R) -> Result<(), String> {
+        (self.operation)(receiver, &self.args);
+        Ok(())
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn args(&self) -> Vec<String> {
+        self.args.clone()
+    }
+
+    fn to_string(&self) -> String {
+        let mut output = self.name.clone();
+        for arg in self.args.iter() {
+            output.push_str(&format!(" \"{arg}\""));
+        }
+        output
+    }
+}
+
+//=============================================================================
+//=============================================================================
+
+/// Represents a command that replaces all occurrences of some text in a
+/// CommandTextObject with other text.
+///
+/// The command fails if the text to look for is not in the text object.
+///
+/// Replacing cannot be undone by another replace (the replacement text may
+/// already have been in the text), so the command remembers where each
+/// replacement was made and puts the original text back in those places.
+///
+/// This struct implements the ICommand trait so it can be treated the same as
+/// any other kind of command.
+pub struct CommandReplaceText {
+    /// What to look for in the CommandTextObject.
+    search_pattern: String,
+    /// What to replace `search_pattern` with.
+    replace_text: String,
+    /// Where each replacement starts in the text after the last execute(),
+    /// as byte offsets.
+    replaced_at: RefCell<Vec<usize>>,
+}
+
+impl CommandReplaceText {
+    /// Constructor for a command that replaces text in a CommandTextObject.
+    ///
+    /// # Parameters
+    /// - search_pattern
+    ///
+    ///   What to look for in the CommandTextObject.
+    /// - replace_text
+    ///
+    ///   What to replace `search_pattern` with.
+    ///
+    /// # Returns
+    /// Returns an ICommand object representing the command.
+    pub fn new(search_pattern: &str, replace_text: &str) -> Box<dyn ICommand<CommandTextObject>> {
+        Box::new(CommandReplaceText {
+            search_pattern: search_pattern.to_string(),
+            replace_text: replace_text.to_string(),
+            replaced_at: RefCell::new(vec![]),
         })
     }
 }
 
-impl ICommand for CommandTwoParameters {
-    fn execute(&self, receiver: &mut CommandTextObject) {
-        (self.operation)(receiver, &self.arg1, &self.arg2);
+impl ICommand<CommandTextObject> for CommandReplaceText {
+    fn execute(&self, receiver: &mut CommandTextObject) -> Result<(), String> {
+        if self.search_pattern.is_empty() || !receiver.text.contains(&self.search_pattern) {
+            return Err(format!("\"{}\" is not in the text", self.search_pattern));
+        }
+        let mut replaced_at = self.replaced_at.borrow_mut();
+        replaced_at.clear();
+        let mut new_text = String::with_capacity(receiver.text.len());
+        let mut last_end = 0;
+        for (start, _) in receiver.text.match_indices(&self.search_pattern) {
+            new_text.push_str(&receiver.text[last_end..start]);
+            replaced_at.push(new_text.len());
+            new_text.push_str(&self.replace_text);
+            last_end = start + self.search_pattern.len();
+        }
+        new_text.push_str(&receiver.text[last_end..]);
+        receiver.text = new_text;
+        Ok(())
+    }
+
+    fn unexecute(&self, receiver: &mut CommandTextObject) -> bool {
+        // Work from the end so the earlier offsets stay valid.
+        for start in self.replaced_at.borrow().iter().rev() {
+            receiver.text.replace_range(*start..*start + self.replace_text.len(), &self.search_pattern);
+        }
+        true
+    }
+
+    fn name(&self) -> String {
+        "Replace".to_string()
+    }
+
+    fn args(&self) -> Vec<String> {
+        vec![self.search_pattern.clone(), self.replace_text.clone()]
     }
 
     fn to_string(&self) -> String {
-        format!("{0} \"{1}\" with \"{2}\"", self.name, self.arg1, self.arg2)
+        format!("Replace \"{0}\" with \"{1}\"", self.search_pattern, self.replace_text)
     }
 }
@@ -0,0 +1,47 @@
+//! Contains the CommandCounter implementation.
+
+use std::fmt;
+
+use super::command_icommandreceiver_trait::ICommandReceiver;
+
+/// Container for a number that commands can change, as a second kind of
+/// receiver alongside the CommandTextObject.
+pub struct CommandCounter {
+    /// Starting value so we can reset the counter to a known point.
+    starting_value: i32,
+
+    /// The value that can change.
+    pub value: i32,
+}
+
+impl CommandCounter {
+    /// Constructs a counter with an initial value.
+    ///
+    /// # Parameters
+    /// - value
+    ///
+    ///   The initial value for the counter.
+    pub fn new(value: i32) -> CommandCounter {
+        CommandCounter {
+            starting_value: value,
+            value,
+        }
+    }
+}
+
+impl ICommandReceiver for CommandCounter {
+    /// Resets the counter to the starting value.
+    fn reset(&mut self) {
+        self.value = self.starting_value;
+    }
+
+    fn set_starting_state(&mut self) {
+        self.starting_value = self.value;
+    }
+}
+
+impl fmt::Display for CommandCounter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
@@ -0,0 +1,98 @@
+//! Contains the CommandRecord struct and the history_to_json() and
+//! history_from_json() functions for saving a list of commands as JSON and
+//! reading it back, so a session can be replayed later.
+//!
+//! A saved history looks like this:
+//! ```text
+//! [
+//!   {"name":"Replace","args":["text","painting"]},
+//!   {"name":"Reverse","args":[]}
+//! ]
+//! ```
+
+use crate::helpers::json::{json_string, JsonReader};
+
+use super::command_iboundcommand_trait::IBoundCommand;
+
+//-----------------------------------------------------------------------------
+
+/// Represents a single command in a saved history: the name of the command
+/// and the arguments it was created with.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommandRecord {
+    /// Name of the command, such as "Replace".
+    pub name: String,
+    /// Arguments to the command, as strings.
+    pub args: Vec<String>,
+}
+
+//-----------------------------------------------------------------------------
+
+/// Convert a list of commands to JSON, one command per line.
+///
+/// # Parameters
+/// - commands
+///
+///   The commands to convert, in the order they were applied.
+///
+/// # Returns
+/// Returns a string containing the commands as a JSON array.
+pub fn history_to_json(commands: &[Box<dyn IBoundCommand>]) -> String {
+    let records: Vec<String> = commands.iter().map(|command| {
+        let args: Vec<String> = command.args().iter().map(|arg| json_string(arg)).collect();
+        format!("  {{\"name\":{},\"args\":[{}]}}", json_string(&command.name()), args.join(","))
+    }).collect();
+    if records.is_empty() {
+        "[]\n".to_string()
+    } else {
+        format!("[\n{}\n]\n", records.join(",\n"))
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Helper function to read a command record, which is an object with a
+/// "name" string and an "args" array of strings, starting at the next
+/// character.
+///
+/// # Parameters
+/// - reader
+///
+///   The JsonReader to read from.
+///
+/// # Returns
+/// Returns `Ok(CommandRecord)` containing the command; otherwise, returns
+/// `Err(String)` describing what is wrong with the JSON.
+fn _read_record(reader: &mut JsonReader) -> Result<CommandRecord, String> {
+    let mut name = None;
+    let mut args = vec![];
+    reader.read_object(|reader, key| {
+        match key {
+            "name" => name = Some(reader.read_string()?),
+            "args" => args = reader.read_array(JsonReader::read_string)?,
+            _ => return Err(format!("Unknown field \"{key}\" in a command")),
+        }
+        Ok(())
+    })?;
+    let name = name.ok_or("A command has no \"name\" field".to_string())?;
+    Ok(CommandRecord { name, args })
+}
+
+
+/// Read a list of commands from JSON, as written by history_to_json().
+///
+/// # Parameters
+/// - json
+///
+///   The JSON text to read.
+///
+/// # Returns
+/// Returns `Ok(Vec<CommandRecord>)` containing the commands in the order they
+/// were applied; otherwise, returns `Err(String)` describing what is wrong
+/// with the JSON.
+pub fn history_from_json(json: &str) -> Result<Vec<CommandRecord>, String> {
+    let mut reader = JsonReader::new(json);
+    let records = reader.read_array(_read_record)?;
+    reader.expect_end("the list of commands")?;
+    Ok(records)
+}
@@ -0,0 +1,25 @@
+//! Contains the IBoundCommand trait that hides which kind of receiver a
+//! command is applied to, so commands for different receivers can be kept in
+//! the same undo list.
+
+/// Represents a command together with the receiver it is applied to.
+pub trait IBoundCommand {
+    /// Execute the command on its receiver, returning `Err(String)` if the
+    /// command could not be applied.
+    fn execute(&self) -> Result<(), String>;
+    /// Undo what the last call to execute() did to its receiver, if the
+    /// command knows how; returns false if it does not.
+    fn unexecute(&self) -> bool;
+    /// Reset the command's receiver to its starting state.
+    fn reset_receiver(&self);
+    /// Make the current state of the command's receiver its starting state.
+    fn set_receiver_starting_state(&self);
+    /// Retrieve the name of the command.
+    fn name(&self) -> String;
+    /// Retrieve the arguments of the command as strings.
+    fn args(&self) -> Vec<String>;
+    /// Convert the command to a string representation.
+    fn to_string(&self) -> String;
+    /// Convert the current state of the command's receiver to a string.
+    fn receiver_to_string(&self) -> String;
+}
@@ -1,12 +1,32 @@
 //! Contains the ICommand trait that commands implement so they look the same
 //! to the main program.
 
-use super::command_textobject::CommandTextObject;
-
-/// Represents a general command that does something.
-pub trait ICommand {
-    /// Execute the command on the given CommandTextObject.
-    fn execute(&self, receiver: &mut CommandTextObject);
+/// Represents a general command that does something to a receiver of type
+/// `R`, such as a CommandTextObject or a CommandCounter.
+pub trait ICommand<R> {
+    /// Execute the command on the given receiver.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the command was applied; otherwise, returns
+    /// `Err(String)` describing why the command could not be applied, in
+    /// which case the receiver is left unchanged.
+    fn execute(&self, receiver: &mut R) -> Result<(), String>;
+    /// Undo what the last call to execute() did to the given receiver, if
+    /// the command knows how.  The default does not know how.
+    ///
+    /// # Returns
+    /// Returns true if the command was undone; otherwise, returns false and
+    /// the receiver is left unchanged, in which case the receiver must be
+    /// reset and the commands before this one applied again.
+    fn unexecute(&self, _receiver: &mut R) -> bool {
+        false
+    }
+    /// Retrieve the name of the command, which is used to create the command
+    /// again from a saved history (see CommandRegistry).
+    fn name(&self) -> String;
+    /// Retrieve the arguments of the command as strings, in the order the
+    /// command was created with them.
+    fn args(&self) -> Vec<String>;
     /// Convert the command to a string representation.
     fn to_string(&self) -> String;
 }
@@ -0,0 +1,14 @@
+//! Contains the ICommandReceiver trait that receivers of commands implement
+//! so they can be put back to their starting point when undoing commands.
+
+/// Represents an object that commands are applied to.
+pub trait ICommandReceiver {
+    /// Reset the receiver to its starting state, before any commands were
+    /// applied to it.
+    fn reset(&mut self);
+
+    /// Make the current state the starting state, so reset() returns to it.
+    /// Used when the oldest commands are forgotten and can no longer be
+    /// undone.
+    fn set_starting_state(&mut self);
+}
@@ -0,0 +1,117 @@
+//! Contains run_interactive() for editing a CommandTextObject by typing
+//! commands, which are applied as they are typed and can be undone and
+//! redone.
+
+use std::cell::RefCell;
+use std::io::{stdin, stdout, BufRead, Write};
+use std::rc::Rc;
+
+use super::command_textobject::CommandTextObject;
+use super::{CommandContext, STARTING_TEXT};
+
+//-----------------------------------------------------------------------------
+
+/// The text shown for the "help" command.
+const HELP_TEXT: &str = "    Commands:
+      replace <search> <replacement>  Replace all occurrences of <search>.
+      reverse                         Reverse the order of the characters.
+      undo                            Undo the last command.
+      redo                            Apply again the last command undone.
+      history                         Show the commands that can be undone.
+      help                            Show this list of commands.
+      quit                            Leave interactive mode.
+    Put quotes around text containing spaces, such as: replace \"a line\" \"the line\"";
+
+/// Split a typed line into words at white space, keeping text in double
+/// quotes together as one word (without the quotes).
+///
+/// # Parameters
+/// - line
+///
+///   The line typed by the user.
+///
+/// # Returns
+/// Returns `Ok(Vec<String>)` containing the words; otherwise, returns
+/// `Err(String)` if a quote is not closed.
+fn _split_words(line: &str) -> Result<Vec<String>, String> {
+    let mut words = vec![];
+    let mut chars = line.chars().peekable();
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        let Some(first) = chars.next() else {
+            return Ok(words);
+        };
+        let mut word = String::new();
+        if first == '"' {
+            loop {
+                match chars.next() {
+                    Some('"') => break,
+                    Some(c) => word.push(c),
+                    None => return Err("A quote is not closed".to_string()),
+                }
+            }
+        } else {
+            word.push(first);
+            while let Some(c) = chars.next_if(|c| !c.is_whitespace()) {
+                word.push(c);
+            }
+        }
+        words.push(word);
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Read commands typed by the user, one per line, and apply them to a text
+/// object until the user types "quit" or the input ends.
+///
+/// # Returns
+/// Returns `Ok(())` when the user is done; otherwise, returns `Err(String)`
+/// if the input could not be read.
+pub fn run_interactive() -> Result<(), String> {
+    let mut context = CommandContext::new();
+    let text_object = Rc::new(RefCell::new(CommandTextObject::new(STARTING_TEXT)));
+
+    println!("  Interactive mode.  Type \"help\" for a list of commands.");
+    println!("  Text: \"{}\"", text_object.borrow());
+    let mut lines = stdin().lock().lines();
+    loop {
+        print!("  > ");
+        stdout().flush().map_err(|error| error.to_string())?;
+        let Some(line) = lines.next() else {
+            println!();
+            break;
+        };
+        let line = line.map_err(|error| error.to_string())?;
+        let words = match _split_words(&line) {
+            Ok(words) => words,
+            Err(message) => {
+                println!("    {message}");
+                continue;
+            }
+        };
+        let words: Vec<&str> = words.iter().map(|word| word.as_str()).collect();
+        match words.as_slice() {
+            [] => {}
+            ["replace", search_pattern, replace_text] => {
+                context.apply_replace_command(&text_object, search_pattern, replace_text);
+            }
+            ["reverse"] => context.apply_reverse_command(&text_object),
+            ["undo"] => match context.undo() {
+                Some(command) => println!("    undoing command {:<31}==> \"{}\"", command.to_string(), command.receiver_to_string()),
+                None => println!("    Nothing to undo"),
+            },
+            ["redo"] => match context.redo() {
+                Some(command) => println!("    redoing command {:<31}==> \"{}\"", command.to_string(), command.receiver_to_string()),
+                None => println!("    Nothing to redo"),
+            },
+            ["history"] => context.show_history(),
+            ["help"] => println!("{HELP_TEXT}"),
+            ["quit"] | ["exit"] => break,
+            ["replace", ..] => println!("    Usage: replace <search> <replacement>"),
+            [command, ..] => println!("    Unknown command \"{command}\"; type \"help\" for a list of commands"),
+        }
+    }
+    println!("  Final text: \"{}\"", text_object.borrow());
+    Ok(())
+}
@@ -0,0 +1,61 @@
+//! Contains the CommandRegistry struct, which maps command names to the
+//! functions that create the commands, so commands can be created again from
+//! a saved history.
+
+use std::collections::HashMap;
+
+use super::command_history::CommandRecord;
+use super::command_icommand_trait::ICommand;
+
+/// Alias for a function that creates a command for receivers of type `R`
+/// from the arguments saved in a history.
+pub type CommandConstructor<R> = fn(args: &[String]) -> Result<Box<dyn ICommand<R>>, String>;
+
+/// Represents the commands that can be created by name for receivers of
+/// type `R`.
+pub struct CommandRegistry<R> {
+    /// Maps each command name to the function that creates the command.
+    constructors: HashMap<String, CommandConstructor<R>>,
+}
+
+impl<R> CommandRegistry<R> {
+    /// Constructor.
+    ///
+    /// # Returns
+    /// Returns a new instance of the CommandRegistry struct with no commands.
+    pub fn new() -> CommandRegistry<R> {
+        CommandRegistry { constructors: HashMap::new() }
+    }
+
+    /// Add a command that can be created by name, replacing any command
+    /// already added with the same name.
+    ///
+    /// # Parameters
+    /// - name
+    ///
+    ///   Name of the command, as returned by ICommand::name().
+    /// - constructor
+    ///
+    ///   The function that creates the command from its arguments.
+    pub fn register(&mut self, name: &str, constructor: CommandConstructor<R>) {
+        self.constructors.insert(name.to_string(), constructor);
+    }
+
+    /// Create the command described by a saved command record.
+    ///
+    /// # Parameters
+    /// - record
+    ///
+    ///   The name and arguments of the command.
+    ///
+    /// # Returns
+    /// Returns `Ok(Box<dyn ICommand<R>>)` containing the command; otherwise,
+    /// returns `Err(String)` if the command is not known or its arguments
+    /// are not valid.
+    pub fn create(&self, record: &CommandRecord) -> Result<Box<dyn ICommand<R>>, String> {
+        match self.constructors.get(&record.name) {
+            Some(constructor) => constructor(&record.args),
+            None => Err(format!("Unknown command \"{}\"", record.name)),
+        }
+    }
+}
@@ -2,6 +2,8 @@
 
 use std::fmt;
 
+use super::command_icommandreceiver_trait::ICommandReceiver;
+
 /// Container for a string.  Need to use a class that allows the text to
 /// be changed while the container (this class) remains constant.  This
 /// way, operations can be applied to the text and the container's contents
@@ -27,11 +29,17 @@ impl CommandTextObject {
             text: text.to_string(),
         }
     }
+}
 
+impl ICommandReceiver for CommandTextObject {
     /// Resets the TextObject to the starting string.
-    pub fn reset(&mut self) {
+    fn reset(&mut self) {
         self.text = self.starting_text.clone();
     }
+
+    fn set_starting_state(&mut self) {
+        self.starting_text = self.text.clone();
+    }
 }
 
 impl fmt::Display for CommandTextObject {
@@ -8,6 +8,17 @@
 //! This example uses a file structure of file and directories to
 //! represent each object type.
 //!
+//! Entries can be added to, removed from, and moved around the tree by
+//! "path" (see composite_fileaccess.rs), without the code making the change
+//! needing to know whether an entry is a file or a directory.
+//!
+//! Operations on the whole tree are done by visitors (see the Visitor
+//! pattern) that each entry passes itself to, such as adding up the sizes of
+//! all files or finding the largest file.
+//! The tree can also be walked depth-first or breadth-first with the
+//! iterators in composite_iterators.rs, and saved to and loaded from JSON
+//! (see composite_json.rs).
+//!
 //! Accessed through the composite_exercise() function.
 
 //-----------------------------------------------------------------------------
@@ -17,21 +28,34 @@ pub mod composite_filedirentry_trait;
 pub mod composite_fileentry;
 pub mod composite_direntry;
 pub mod composite_fileaccess;
+pub mod composite_filedirvisitor_trait;
+pub mod composite_visitors;
+pub mod composite_iterators;
+pub mod composite_json;
+pub mod composite_displayoptions;
+pub mod composite_error;
 
 //-----------------------------------------------------------------------------
 
 use std::cell::RefCell;
 use std::rc::Rc;
 
-use composite_fileaccess::{construct_tree};
-use composite_filedirentry_trait::{FileDirEntry, FileDirTypes};
+use composite_direntry::DirEntry;
+use composite_displayoptions::{CompositeDisplayOptions, SortBy};
+use composite_fileaccess::{add_entry, construct_tree, create_time_stamp, find_entries, move_entry, remove_entry, toggle_attribute};
+use composite_fileentry::FileEntry;
+use composite_iterators::{BreadthFirstIter, DepthFirstIter};
+use composite_json::{from_json, to_json};
+use composite_visitors::{LargestFileVisitor, TotalSizeVisitor};
+use composite_filedirentry_trait::{EntryAttribute, FileDirEntry, FileDirTypes};
+use crate::helpers::profiler::profile_span;
 
 //-----------------------------------------------------------------------------
 
-/// Helper function to format the specified entry for display.  Returns the
-/// fully-built string ready for output.
-/// 
-/// Note: This is a recursive call.
+/// Format the specified entry for display, one line per entry with each
+/// level of the hierarchy indented further.  Each line shows the name,
+/// length, attributes and timestamp of an entry.  Returns the fully-built
+/// string ready for output.
 ///
 /// # Parameters
 /// - root
@@ -39,31 +63,109 @@ use composite_filedirentry_trait::{FileDirEntry, FileDirTypes};
 ///   The FileDirEntry object to format, including any children of the object.
 /// - indent
 ///
-///   The number of spaces to indent each line of the display.
-fn composite_format_entry(root: Rc<RefCell<dyn FileDirEntry>>, indent: usize) -> String {
-    /// Maximum length of a name field in a hierarchical display
+///   The number of levels to indent each line of the display, where each
+///   level is two spaces.
+/// - options
+///
+///   The CompositeDisplayOptions that give the order of the children and
+///   how the hierarchy is drawn.
+pub fn composite_format_entry(root: Rc<RefCell<dyn FileDirEntry>>, indent: usize, options: &CompositeDisplayOptions) -> String {
+    let _span = profile_span("format entry");
+    /// Minimum length of a name field in a hierarchical display
     const NAME_PADDING_SIZE: usize = 20;
-    let mut output = String::new();
-    let indent_spaces = "  ".repeat(indent);
-    let mut padding = NAME_PADDING_SIZE - root.borrow().name().len() - indent_spaces.len();
-    output.push_str(&format!("{}{}", indent_spaces, root.borrow().name()));
-    if let FileDirTypes::DirType = root.borrow().entry_type() {
-        output.push_str("/");
-        padding -= 1;
-    }
-    output.push_str(&" ".repeat(padding));
-    let root_length = root.borrow_mut().length();
-    output.push_str(&format!("{:4}  {}\n", root_length, root.borrow().timestamp()));
-
-    if let Some(children) = root.borrow().children() {
-        for child in children {
-            output.push_str(&composite_format_entry(child.clone(), indent + 1));
+    let mut iter = DepthFirstIter::with_order(root, options.ordering());
+    if !options.show_hidden {
+        iter = iter.skipping_hidden();
+    }
+    let entries: Vec<(String, Rc<RefCell<dyn FileDirEntry>>)> = iter.collect();
+    let depths: Vec<usize> = entries.iter().map(|(path, _)| path.matches('/').count()).collect();
+
+    // An entry is the last in its directory if no entry at the same depth
+    // comes after it before the directory ends.  Working backwards, that is
+    // an entry with no sibling seen yet.
+    let mut is_last = vec![false; entries.len()];
+    let mut sibling_seen: Vec<bool> = vec![];
+    for (index, &depth) in depths.iter().enumerate().rev() {
+        sibling_seen.resize(depth + 1, false);
+        is_last[index] = !sibling_seen[depth];
+        sibling_seen[depth] = true;
+    }
+
+    // Build the text in front of each name: indentation and, if wanted, the
+    // lines joining each entry to its directory.
+    let mut prefixes = vec![];
+    let mut ancestor_is_last: Vec<bool> = vec![];
+    for (index, &depth) in depths.iter().enumerate() {
+        let mut prefix = "  ".repeat(indent);
+        if !options.tree_lines {
+            prefix.push_str(&"  ".repeat(depth));
+        } else if depth > 0 {
+            for &last in &ancestor_is_last[1..depth] {
+                prefix.push_str(if last { "    " } else { "│   " });
+            }
+            prefix.push_str(if is_last[index] { "└── " } else { "├── " });
+        }
+        ancestor_is_last.truncate(depth);
+        ancestor_is_last.push(is_last[index]);
+        prefixes.push(prefix);
+    }
+
+    let mut names = vec![];
+    for ((_, entry), prefix) in entries.iter().zip(prefixes) {
+        let mut name = format!("{prefix}{}", entry.borrow().name());
+        if let FileDirTypes::DirType = entry.borrow().entry_type() {
+            name.push('/');
         }
+        names.push(name);
+    }
+    let name_width = names.iter().map(|name| name.chars().count() + 1).max().unwrap_or(0).max(NAME_PADDING_SIZE);
+
+    let mut output = String::new();
+    for ((_, entry), name) in entries.iter().zip(names) {
+        let padding = name_width - name.chars().count();
+        output.push_str(&name);
+        output.push_str(&" ".repeat(padding));
+        let entry_length = entry.borrow_mut().length();
+        let entry = entry.borrow();
+        output.push_str(&format!("{:4}  {}  {}\n", entry_length, entry.attributes(), entry.timestamp()));
     }
     output
 }
 
 
+/// Helper function to display the lengths of the entries with the given
+/// "paths" and whether each length was remembered from before or had to be
+/// computed.  Which lengths are remembered is noted before any length is
+/// computed, since computing a directory's length also computes the lengths
+/// of the directories in it.
+///
+/// # Parameters
+/// - root
+///
+///   The FileDirEntry object representing the root of the directory tree.
+/// - entry_paths
+///
+///   The "paths" of the entries to display the lengths of.
+///
+/// # Returns
+/// Returns `Ok(())` if the lengths were displayed; otherwise, returns
+/// `Err(String)` if a path was not found.
+fn composite_show_lengths(root: Rc<RefCell<dyn FileDirEntry>>, entry_paths: &[&str]) -> Result<(), String> {
+    let mut entries = vec![];
+    for entry_path in entry_paths {
+        let entry = composite_fileaccess::get_entry(root.clone(), entry_path)?;
+        let is_cached = entry.borrow().length_is_cached();
+        entries.push((entry_path, entry, is_cached));
+    }
+    for (entry_path, entry, is_cached) in entries {
+        let length = entry.borrow_mut().length();
+        let source = if is_cached { "from cache" } else { "computed" };
+        println!("    {entry_path:<22}{length:4}  ({source})");
+    }
+    Ok(())
+}
+
+
 /// Helper function to display the contents of the hierarchical list of objects
 /// starting with the given object.
 ///
@@ -72,7 +174,7 @@ fn composite_format_entry(root: Rc<RefCell<dyn FileDirEntry>>, indent: usize) ->
 ///
 ///   The FileDirEntry object to display, including any children of the object.
 fn composite_show_entry(root: Rc<RefCell<dyn FileDirEntry>>) {
-    let entries_as_string = composite_format_entry(root, 2);
+    let entries_as_string = composite_format_entry(root, 2, &CompositeDisplayOptions::new());
     println!("{entries_as_string}");
 }
 
@@ -86,7 +188,12 @@ fn composite_show_entry(root: Rc<RefCell<dyn FileDirEntry>>) {
 /// objects.
 /// 
 /// This example uses a file structure of file and directories to
-/// represent each object type.
+/// represent each object type.  Entries are then added, removed and moved
+/// by "path", showing the tree before and after, and the tree is shown
+/// sorted in different ways and with tree lines.  Finally, visitors gather
+/// information about the whole tree, the tree is walked breadth-first,
+/// entries are found with a wildcard pattern, and the tree is saved to JSON
+/// and loaded back.
 // ! [Using Composite in Rust]
 pub fn composite_exercise() -> Result<(), String> {
     println!("");
@@ -98,14 +205,134 @@ pub fn composite_exercise() -> Result<(), String> {
     composite_show_entry(root.clone());
 
     file_path = "root/subdir1/FileD.txt";
-    let path_entry = match composite_fileaccess::get_entry(root.clone(), &file_path) {
-        Some(entry) => entry,
-        None =>  return Err(String::from("Could not find path \"{file_path}\"")),
+    let path_entry = {
+        let _span = profile_span("get entry");
+        composite_fileaccess::get_entry(root.clone(), file_path)?
     };
 
     println!("  Showing object '{file_path}'");
     composite_show_entry(path_entry.clone());
 
+    println!("  Looking up paths that do not lead to an entry");
+    for bad_path in ["root/subdir1/FileZ.txt", "root/FileA.txt/FileD.txt"] {
+        if let Err(error) = composite_fileaccess::get_entry(root.clone(), bad_path) {
+            println!("    {error}");
+        }
+    }
+    println!();
+
+    // Directory lengths are remembered once computed and are computed
+    // again only along the path to a directory whose children changed, so
+    // a large tree is not walked every time a length is needed.
+    let length_paths = ["root", "root/subdir1", "root/subdir1/subdir2"];
+    println!("  Showing directory lengths in 'root'");
+    composite_show_lengths(root.clone(), &length_paths)?;
+    let timestamp = create_time_stamp();
+    add_entry(root.clone(), "root/subdir1", Rc::new(RefCell::new(FileEntry::new("FileH.txt", 108, &timestamp))))?;
+    println!("  Showing directory lengths after adding 'root/subdir1/FileH.txt'");
+    composite_show_lengths(root.clone(), &length_paths)?;
+    println!();
+
+    println!("  Adding, removing and moving entries in 'root'");
+    println!("    Added 'root/subdir1/FileH.txt'");
+    add_entry(root.clone(), "root", Rc::new(RefCell::new(DirEntry::new("subdir3", &timestamp))))?;
+    println!("    Added 'root/subdir3'");
+    // An empty directory can be found but not looked into.
+    composite_fileaccess::get_entry(root.clone(), "root/subdir3")?;
+    if let Err(error) = composite_fileaccess::get_entry(root.clone(), "root/subdir3/FileA.txt") {
+        println!("    Looked for 'root/subdir3/FileA.txt' too early: {error}");
+    }
+    move_entry(root.clone(), "root/FileA.txt", "root/subdir3")?;
+    println!("    Moved 'root/FileA.txt' to 'root/subdir3'");
+    remove_entry(root.clone(), "root/subdir1/subdir2")?;
+    println!("    Removed 'root/subdir1/subdir2'");
+    if let Err(message) = add_entry(root.clone(), "root/FileB.txt", Rc::new(RefCell::new(FileEntry::new("FileI.txt", 109, &timestamp)))) {
+        println!("    Could not add 'root/FileB.txt/FileI.txt': {message}");
+    }
+    if let Err(message) = move_entry(root.clone(), "root/subdir1", "root/subdir1/FileD.txt") {
+        println!("    Could not move 'root/subdir1': {message}");
+    }
+    if let Err(message) = remove_entry(root.clone(), "root/subdir2") {
+        println!("    Could not remove 'root/subdir2': {message}");
+    }
+    println!("  Showing object 'root' after the changes");
+    composite_show_entry(root.clone());
+
+    let mut display_options = CompositeDisplayOptions::new();
+    display_options.sort_by = SortBy::Size;
+    display_options.descending = true;
+    display_options.tree_lines = true;
+    println!("  Showing object 'root' largest first, with tree lines");
+    println!("{}", composite_format_entry(root.clone(), 2, &display_options));
+    display_options.sort_by = SortBy::Name;
+    display_options.descending = false;
+    println!("  Showing object 'root' by name, with tree lines");
+    println!("{}", composite_format_entry(root.clone(), 2, &display_options));
+    display_options.sort_by = SortBy::Date;
+    display_options.descending = true;
+    display_options.tree_lines = false;
+    file_path = "root/subdir1";
+    let path_entry = composite_fileaccess::get_entry(root.clone(), file_path)?;
+    println!("  Showing object '{file_path}' newest first");
+    println!("{}", composite_format_entry(path_entry, 2, &display_options));
+
+    println!("  Changing attributes of entries in 'root'");
+    let attribute_changes = [
+        ("root/FileB.txt", EntryAttribute::Executable),
+        ("root/subdir1/FileE.txt", EntryAttribute::ReadOnly),
+        ("root/subdir3", EntryAttribute::Hidden),
+    ];
+    for (attribute_path, attribute) in attribute_changes {
+        let is_on = toggle_attribute(root.clone(), attribute_path, attribute)?;
+        println!("    {attribute:?} is now {} for '{attribute_path}'", if is_on { "on" } else { "off" });
+    }
+    if let Err(message) = remove_entry(root.clone(), "root/subdir1/FileE.txt") {
+        println!("    Could not remove 'root/subdir1/FileE.txt': {message}");
+    }
+    let mut display_options = CompositeDisplayOptions::new();
+    println!("  Showing object 'root' without hidden entries");
+    println!("{}", composite_format_entry(root.clone(), 2, &display_options));
+    display_options.show_hidden = true;
+    println!("  Showing object 'root' with hidden entries");
+    println!("{}", composite_format_entry(root.clone(), 2, &display_options));
+
+    println!("  Visiting every entry in 'root'");
+    let mut total_size_visitor = TotalSizeVisitor::new();
+    root.borrow_mut().accept(&mut total_size_visitor);
+    println!("    {} files in {} directories, {} in total",
+        total_size_visitor.num_files, total_size_visitor.num_dirs, total_size_visitor.total_size);
+    let mut largest_file_visitor = LargestFileVisitor::new();
+    root.borrow_mut().accept(&mut largest_file_visitor);
+    if let Some((name, length)) = largest_file_visitor.largest {
+        println!("    The largest file is {name} ({length})");
+    }
+    println!();
+
+    println!("  Listing the paths in 'root' breadth-first");
+    for (path, _) in BreadthFirstIter::new(root.clone()) {
+        println!("    {path}");
+    }
+    println!();
+
+    let pattern = "root/subdir1/*.txt";
+    println!("  Searching for '{pattern}'");
+    for (path, entry) in find_entries(root.clone(), pattern) {
+        let entry_length = entry.borrow_mut().length();
+        println!("    {path} ({entry_length})");
+    }
+    println!();
+
+    println!("  Saving 'root' to JSON and loading it back");
+    let json = to_json(root.clone());
+    let loaded_root = from_json(&json)?;
+    let loaded_output = composite_format_entry(loaded_root, 2, &display_options);
+    println!("{loaded_output}");
+    if loaded_output == composite_format_entry(root.clone(), 2, &display_options) {
+        println!("    The loaded tree matches the original ({} characters of JSON)", json.len());
+    } else {
+        println!("    The loaded tree does not match the original!");
+    }
+
     println!("  Done.");
 
     Ok(())
@@ -3,7 +3,8 @@
 
 use std::cell::RefCell;
 use std::rc::Rc;
-use super::composite_filedirentry_trait::{FileDirTypes, FileDirEntry};
+use super::composite_filedirentry_trait::{EntryAttributes, FileDirTypes, FileDirEntry};
+use super::composite_filedirvisitor_trait::FileDirVisitor;
 
 //-----------------------------------------------------------------------------
 
@@ -16,13 +17,16 @@ pub struct DirEntry {
     /// Timestamp of the directory (expressed as a string).
     timestamp: String,
     /// Length of all children of the directory (computed on first call to the
-    /// length() method.)
+    /// length() method and remembered until the children change.)
     length: i32,
     /// True if the length has been computed (on the first call to the length()
-    /// method).
+    /// method).  Cleared by reset_length() whenever the children change, so
+    /// the length is computed again only when needed.
     length_set: bool,
     /// Type of the entry as a value from the FileDirTypes enumeration.
     entry_type: FileDirTypes,
+    /// Read-only, hidden and executable attributes of the entry.
+    attributes: EntryAttributes,
     /// Vector of FileDirEntry objects representing the children of this
     /// directory.
     children: Vec<Rc<RefCell<dyn FileDirEntry>>>,
@@ -48,6 +52,7 @@ impl DirEntry {
             length_set: false,
             timestamp: timestamp.to_string(),
             entry_type: FileDirTypes::DirType,
+            attributes: EntryAttributes::new(),
             children: vec![]
         }
     }
@@ -84,7 +89,47 @@ impl FileDirEntry for DirEntry {
         }
     }
     
+    fn attributes(&self) -> EntryAttributes {
+        self.attributes
+    }
+
+    fn set_attributes(&mut self, attributes: EntryAttributes) {
+        self.attributes = attributes;
+    }
+
     fn children(&self) -> Option<&Vec<Rc<RefCell<dyn FileDirEntry>>>> {
         Some(&self.children)
     }
+
+    fn insert_child(&mut self, entry: Rc<RefCell<dyn FileDirEntry>>) -> Result<(), String> {
+        let name = entry.borrow().name().to_string();
+        if self.children.iter().any(|child| child.borrow().name() == name) {
+            return Err(format!("\"{}\" already contains an entry named \"{name}\"", self.name));
+        }
+        self.add_child(entry);
+        self.reset_length();
+        Ok(())
+    }
+
+    fn remove_child(&mut self, name: &str) -> Option<Rc<RefCell<dyn FileDirEntry>>> {
+        let index = self.children.iter().position(|child| child.borrow().name() == name)?;
+        self.reset_length();
+        Some(self.children.remove(index))
+    }
+
+    fn reset_length(&mut self) {
+        self.length = 0;
+        self.length_set = false;
+    }
+
+    fn length_is_cached(&self) -> bool {
+        self.length_set
+    }
+
+    fn accept(&mut self, visitor: &mut dyn FileDirVisitor) {
+        visitor.visit_dir(self);
+        for child in self.children.iter() {
+            child.borrow_mut().accept(visitor);
+        }
+    }
 }
@@ -0,0 +1,93 @@
+//! Contains the CompositeDisplayOptions struct and the SortBy enum that
+//! control how composite_format_entry() displays a hierarchical list of
+//! files and directories.
+
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+use super::composite_fileaccess::parse_time_stamp;
+use super::composite_filedirentry_trait::FileDirEntry;
+use super::composite_iterators::EntryOrdering;
+
+//-----------------------------------------------------------------------------
+
+/// Represents the order in which the children of a directory are displayed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SortBy {
+    /// Children are displayed in the order they were added.
+    Unsorted,
+    /// Children are sorted by name.
+    Name,
+    /// Children are sorted by length.
+    Size,
+    /// Children are sorted by timestamp.
+    Date,
+}
+
+//-----------------------------------------------------------------------------
+
+/// Represents the options for displaying a hierarchical list of files and
+/// directories with composite_format_entry().
+#[derive(Clone, Copy, Debug)]
+pub struct CompositeDisplayOptions {
+    /// The order in which the children of each directory are displayed.
+    pub sort_by: SortBy,
+    /// True to reverse the order given by sort_by, so the largest name, size
+    /// or timestamp comes first.  Ignored if sort_by is SortBy::Unsorted.
+    pub descending: bool,
+    /// True to connect each entry to its directory with box-drawing lines
+    /// (├── and └──); false to show each level of the hierarchy with plain
+    /// indentation.
+    pub tree_lines: bool,
+    /// True to show hidden entries and everything in them; false to leave
+    /// them out.
+    pub show_hidden: bool,
+}
+
+impl CompositeDisplayOptions {
+    /// Constructor.
+    ///
+    /// # Returns
+    /// Returns a new instance of the CompositeDisplayOptions struct that
+    /// displays the children in the order they were added, with plain
+    /// indentation and no hidden entries.
+    pub fn new() -> CompositeDisplayOptions {
+        CompositeDisplayOptions {
+            sort_by: SortBy::Unsorted,
+            descending: false,
+            tree_lines: false,
+            show_hidden: false,
+        }
+    }
+
+    /// Get the function that compares two entries in the order given by
+    /// these options, for use with DepthFirstIter::with_order().
+    ///
+    /// # Returns
+    /// Returns an Option<> containing the function that compares two
+    /// entries, or None if the entries are not to be sorted.
+    pub fn ordering(&self) -> Option<EntryOrdering> {
+        let sort_by = self.sort_by;
+        let descending = self.descending;
+        if sort_by == SortBy::Unsorted {
+            return None;
+        }
+        Some(Box::new(move |left: &Rc<RefCell<dyn FileDirEntry>>, right: &Rc<RefCell<dyn FileDirEntry>>| {
+            let ordering = match sort_by {
+                SortBy::Unsorted => Ordering::Equal,
+                SortBy::Name => left.borrow().name().cmp(right.borrow().name()),
+                SortBy::Size => {
+                    let left_length = left.borrow_mut().length();
+                    left_length.cmp(&right.borrow_mut().length())
+                }
+                SortBy::Date => {
+                    // Timestamps that cannot be read sort first.
+                    let left_time = parse_time_stamp(left.borrow().timestamp());
+                    left_time.cmp(&parse_time_stamp(right.borrow().timestamp()))
+                }
+            };
+            if descending { ordering.reverse() } else { ordering }
+        }))
+    }
+}
@@ -0,0 +1,56 @@
+//! Contains the CompositeError enum that represents the ways looking up a
+//! "path" in a hierarchical list of files and directories can fail.
+
+use std::error::Error;
+use std::fmt::Display;
+
+//-----------------------------------------------------------------------------
+
+/// Represents an error from looking up a "path" in a hierarchical list of
+/// files and directories, so callers can tell a bad path from one that
+/// simply does not exist.  Every variant carries the path that was looked up.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CompositeError {
+    /// No entry has the given name.  `name` is the first component of the
+    /// path that could not be found.
+    NotFound { path: String, name: String },
+    /// The path continues past a file, which cannot contain other entries.
+    /// `file_path` is the path of that file.
+    NotADirectory { path: String, file_path: String },
+    /// The path continues into a directory that contains no entries.
+    /// `dir_path` is the path of that directory.
+    EmptyDirectory { path: String, dir_path: String },
+}
+
+impl CompositeError {
+    /// Retrieve the path that was looked up.
+    pub fn path(&self) -> &str {
+        match self {
+            CompositeError::NotFound { path, .. }
+            | CompositeError::NotADirectory { path, .. }
+            | CompositeError::EmptyDirectory { path, .. } => path,
+        }
+    }
+}
+
+impl Display for CompositeError {
+    /// Formats the error as "Could not find path "path": reason".
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Could not find path \"{}\": ", self.path())?;
+        match self {
+            CompositeError::NotFound { name, .. } => write!(f, "there is no entry named \"{name}\""),
+            CompositeError::NotADirectory { file_path, .. } => write!(f, "\"{file_path}\" is a file, not a directory"),
+            CompositeError::EmptyDirectory { dir_path, .. } => write!(f, "\"{dir_path}\" is an empty directory"),
+        }
+    }
+}
+
+impl Error for CompositeError {}
+
+impl From<CompositeError> for String {
+    /// Allows a CompositeError to be returned with `?` from functions that
+    /// report errors as strings.
+    fn from(error: CompositeError) -> String {
+        error.to_string()
+    }
+}
@@ -4,27 +4,43 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
-use time::OffsetDateTime;
+use time::{OffsetDateTime, PrimitiveDateTime};
 use time::macros::format_description;
 
 use super::composite_direntry::DirEntry;
+use super::composite_error::CompositeError;
 use super::composite_fileentry::FileEntry;
-use super::composite_filedirentry_trait::FileDirEntry;
+use super::composite_filedirentry_trait::{EntryAttribute, FileDirEntry};
+use super::composite_iterators::DepthFirstIter;
 
 /// Template for constructing a timestamp of the form: 06/04/2023 04:08:26 PM.
 const DATE_FORMAT_STR: &[time::format_description::FormatItem<'static>] = format_description!(version = 2, "[month]/[day]/[year]  [hour repr:12]:[minute]:[second] [period]");
 
 /// Construct a string containing the current time expressed as a timestamp
 /// in the form "mm/dd/yyy HH:MM:SS AM|PM".
-fn _create_time_stamp() -> String {
+pub fn create_time_stamp() -> String {
     let local_time = OffsetDateTime::now_local().unwrap();
     local_time.format(&DATE_FORMAT_STR).unwrap()
 }
 
+/// Read a timestamp of the form created by create_time_stamp().
+///
+/// # Parameters
+/// - timestamp
+///
+///   The timestamp to read.
+///
+/// # Returns
+/// Returns an Option<> containing the date and time, or None if the
+/// timestamp is not in the expected form.
+pub fn parse_time_stamp(timestamp: &str) -> Option<PrimitiveDateTime> {
+    PrimitiveDateTime::parse(timestamp, &DATE_FORMAT_STR).ok()
+}
+
 /// Construct a file/directory tree with a predefined set of files and
 /// directories.  Out of memory errors will crash the program with a panic.
 pub fn construct_tree() -> Rc<RefCell<dyn FileDirEntry>> {
-    let timestamp = _create_time_stamp();
+    let timestamp = create_time_stamp();
     let mut root_entry = DirEntry::new("root", &timestamp);
     root_entry.add_child(Rc::new(RefCell::new(FileEntry::new("FileA.txt", 101, &timestamp))));
     root_entry.add_child(Rc::new(RefCell::new(FileEntry::new("FileB.txt", 102, &timestamp))));
@@ -46,6 +62,70 @@ pub fn construct_tree() -> Rc<RefCell<dyn FileDirEntry>> {
 }
 
 
+/// Helper function to find the entries along the specified "path" in the
+/// given directory tree.
+///
+/// # Parameters
+/// - root
+///
+///   The FileDirEntry object representing the root of the directory tree to
+///   search.
+/// - entry_path
+///
+///   The "path" to search for in the given directory tree.
+///
+/// # Returns
+/// Returns `Ok(Vec<Rc<RefCell<dyn FileDirEntry>>>)` containing the list of
+/// entries named in the path, starting with the root and ending with the
+/// entry the path refers to.  Returns `Err(CompositeError)` describing why
+/// the path could not be followed if no entry was found.
+fn _get_entry_chain(root: Rc<RefCell<dyn FileDirEntry>>, entry_path: &str) -> Result<Vec<Rc<RefCell<dyn FileDirEntry>>>, CompositeError> {
+    let file_path = entry_path.replace("\\", "/");
+    let mut path_components = file_path.split('/');
+    let root_component = path_components.next().unwrap_or_default();
+    if root_component != root.borrow().name() {
+        // Mismatch in path to the root, bad path
+        return Err(CompositeError::NotFound { path: entry_path.to_string(), name: root_component.to_string() });
+    }
+
+    let mut chain = vec![root.clone()];
+    let mut working_path = root_component.to_string();
+    for child_component in path_components {
+        let new_root = {
+            let working_root = chain[chain.len() - 1].borrow();
+            match working_root.children() {
+                // A file in the middle of the path has no children, bad path.
+                None => return Err(CompositeError::NotADirectory { path: entry_path.to_string(), file_path: working_path }),
+                Some(children) if children.is_empty() => {
+                    return Err(CompositeError::EmptyDirectory { path: entry_path.to_string(), dir_path: working_path });
+                }
+                Some(children) => match children.iter().find(|x| x.borrow().name().eq(child_component)) {
+                    Some(child) => child.clone(),
+                    None => return Err(CompositeError::NotFound { path: entry_path.to_string(), name: child_component.to_string() }),
+                },
+            }
+        };
+        chain.push(new_root);
+        working_path = format!("{working_path}/{child_component}");
+    }
+    Ok(chain)
+}
+
+
+/// Helper function to make each of the given entries compute its length
+/// again, after one of the entries has changed its children.
+///
+/// # Parameters
+/// - chain
+///
+///   The entries from the root down to the entry that changed.
+fn _reset_lengths(chain: &[Rc<RefCell<dyn FileDirEntry>>]) {
+    for entry in chain.iter() {
+        entry.borrow_mut().reset_length();
+    }
+}
+
+
 /// Return a FileDirEntry object representing the specified file "path" in an
 /// internal list of data entries that is organized in a file/directory
 /// structure. The root and returned object are reference-counted smart pointers
@@ -62,42 +142,292 @@ pub fn construct_tree() -> Rc<RefCell<dyn FileDirEntry>> {
 ///   The "path" to search for in the given directory tree.
 ///
 /// # Returns
-/// Returns an Option<> containing the found FileDirEntry object.  Returns
-/// None if no entry was found.
-pub fn get_entry(root: Rc<RefCell<dyn FileDirEntry>>, entry_path: &str) -> Option<Rc<RefCell<dyn FileDirEntry>>> {
-    let mut working_root = root.clone();
-    let file_path = entry_path.replace("\\", "/");
-    let path_components: Vec<&str> = file_path.split('/').collect();
-    let num_elements = path_components.len();
+/// Returns `Ok(Rc<RefCell<dyn FileDirEntry>>)` containing the found
+/// FileDirEntry object.  Returns `Err(CompositeError)` if no entry was found,
+/// saying whether the path names something that does not exist, continues
+/// past a file, or continues into an empty directory.
+pub fn get_entry(root: Rc<RefCell<dyn FileDirEntry>>, entry_path: &str) -> Result<Rc<RefCell<dyn FileDirEntry>>, CompositeError> {
+    let mut chain = _get_entry_chain(root, entry_path)?;
+    // The chain always starts with the root so is never empty.
+    Ok(chain.pop().expect("The entry chain always starts with the root"))
+}
+
 
-    for index in 0..num_elements {
-        if path_components[index] != working_root.borrow().name() {
-            // Mismatch in path to this entry, bad path
-            break;
+/// Add an entry to the directory with the specified "path" in the given
+/// directory tree.
+///
+/// # Parameters
+/// - root
+///
+///   The FileDirEntry object representing the root of the directory tree.
+/// - parent_path
+///
+///   The "path" of the directory to add the entry to.
+/// - entry
+///
+///   The file or directory entry to add.
+///
+/// # Returns
+/// Returns `Ok(())` if the entry was added; otherwise, returns `Err(String)`
+/// if the path was not found, is not a directory, is read-only, or already
+/// contains an entry with the same name.
+pub fn add_entry(root: Rc<RefCell<dyn FileDirEntry>>, parent_path: &str, entry: Rc<RefCell<dyn FileDirEntry>>) -> Result<(), String> {
+    let chain = _get_entry_chain(root, parent_path)?;
+    if let Some(parent) = chain.last() {
+        if parent.borrow().attributes().read_only {
+            return Err(format!("\"{parent_path}\" is read-only"));
         }
-        if index + 1 >= num_elements {
-            // Reached end of path so we found what was asked for.
-            return Some(working_root);
+        parent.borrow_mut().insert_child(entry)?;
+    }
+    _reset_lengths(&chain);
+    Ok(())
+}
+
+
+/// Remove the entry with the specified "path" from the given directory tree.
+/// Removing a directory removes everything in it as well.
+///
+/// # Parameters
+/// - root
+///
+///   The FileDirEntry object representing the root of the directory tree.
+/// - entry_path
+///
+///   The "path" of the entry to remove.
+///
+/// # Returns
+/// Returns `Ok(Rc<RefCell<dyn FileDirEntry>>)` containing the removed entry;
+/// otherwise, returns `Err(String)` if the path was not found, is the root
+/// of the tree, or the entry or its directory is read-only.
+pub fn remove_entry(root: Rc<RefCell<dyn FileDirEntry>>, entry_path: &str) -> Result<Rc<RefCell<dyn FileDirEntry>>, String> {
+    let mut chain = _get_entry_chain(root, entry_path)?;
+    let entry = chain.pop().ok_or(format!("Could not find path \"{entry_path}\""))?;
+    let Some(parent) = chain.last() else {
+        return Err(format!("Cannot remove \"{entry_path}\" as it is the root of the tree"));
+    };
+    if entry.borrow().attributes().read_only || parent.borrow().attributes().read_only {
+        return Err(format!("Cannot remove \"{entry_path}\" as it or its directory is read-only"));
+    }
+    let name = entry.borrow().name().to_string();
+    parent.borrow_mut().remove_child(&name);
+    _reset_lengths(&chain);
+    Ok(entry)
+}
+
+
+/// Move the entry with the specified "path" into the directory with another
+/// "path" in the given directory tree.  Nothing is changed if the move is not
+/// possible.
+///
+/// # Parameters
+/// - root
+///
+///   The FileDirEntry object representing the root of the directory tree.
+/// - from_path
+///
+///   The "path" of the entry to move.
+/// - to_path
+///
+///   The "path" of the directory to move the entry into.
+///
+/// # Returns
+/// Returns `Ok(())` if the entry was moved; otherwise, returns `Err(String)`
+/// if either path was not found, the entry cannot be removed, the
+/// destination is not a directory, is read-only or already contains an
+/// entry with the same name, or the destination is the entry itself or
+/// inside it.
+pub fn move_entry(root: Rc<RefCell<dyn FileDirEntry>>, from_path: &str, to_path: &str) -> Result<(), String> {
+    let from_normalized = from_path.replace("\\", "/");
+    let to_normalized = to_path.replace("\\", "/");
+    if to_normalized == from_normalized || to_normalized.starts_with(&format!("{from_normalized}/")) {
+        return Err(format!("Cannot move \"{from_path}\" into itself"));
+    }
+    let entry = get_entry(root.clone(), from_path)?;
+    let destination = get_entry(root.clone(), to_path)?;
+    let name = entry.borrow().name().to_string();
+    match destination.borrow().children() {
+        Some(children) if children.iter().any(|child| child.borrow().name() == name) => {
+            return Err(format!("\"{to_path}\" already contains an entry named \"{name}\""));
         }
+        Some(_) if destination.borrow().attributes().read_only => {
+            return Err(format!("\"{to_path}\" is read-only"));
+        }
+        Some(_) => {}
+        None => return Err(format!("\"{to_path}\" is a file and cannot contain other entries")),
+    }
+    let entry = remove_entry(root.clone(), from_path)?;
+    add_entry(root, to_path, entry)
+}
 
-        // Still haven't reached end of specified path, look at
-        // the current root for children.
 
-        let new_root: Rc<RefCell<dyn FileDirEntry>>;
-        if let Some(children) = working_root.borrow().children() {
-            let child_component = path_components[index + 1];
-            new_root = match children.iter().find(|x| x.borrow().name().eq(child_component)) {
-                Some(new_root) => new_root.clone(),
-                None => break,
+/// Turn the given attribute of the entry with the specified "path" on if it
+/// is off or off if it is on.  Any attribute can be changed, even on a
+/// read-only entry, so an entry can be made writable again.
+///
+/// # Parameters
+/// - root
+///
+///   The FileDirEntry object representing the root of the directory tree.
+/// - entry_path
+///
+///   The "path" of the entry to change.
+/// - attribute
+///
+///   The EntryAttribute to change.
+///
+/// # Returns
+/// Returns `Ok(bool)` that is true if the attribute is now on; otherwise,
+/// returns `Err(String)` if the path was not found.
+pub fn toggle_attribute(root: Rc<RefCell<dyn FileDirEntry>>, entry_path: &str, attribute: EntryAttribute) -> Result<bool, String> {
+    let entry = get_entry(root, entry_path)?;
+    let mut attributes = entry.borrow().attributes();
+    let is_on = attributes.toggle(attribute);
+    entry.borrow_mut().set_attributes(attributes);
+    Ok(is_on)
+}
+
+
+/// Helper function to determine if a name matches a pattern that can contain
+/// the wildcards `*` (matches zero or more characters) and `?` (matches
+/// exactly one character).
+///
+/// # Parameters
+/// - pattern
+///
+///   The pattern to match against.
+/// - name
+///
+///   The name to test.
+///
+/// # Returns
+/// Returns true if the whole name matches the pattern; otherwise, returns
+/// false.
+fn _wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let mut pattern_index = 0;
+    let mut name_index = 0;
+    // Where to resume if a match fails after the most recent '*': the
+    // pattern position just past the '*' and the name position it matched up
+    // to.
+    let mut backtrack: Option<(usize, usize)> = None;
+    while name_index < name.len() {
+        match pattern.get(pattern_index) {
+            Some('*') => {
+                pattern_index += 1;
+                backtrack = Some((pattern_index, name_index));
             }
-        } else {
-            // Path included leaf in the middle, bad path
-            // NOTE: COULD BE AN EMPTY DIRECTORY!  NEED TO ACCOMMODATE THIS!
-            break;
+            Some(&c) if c == '?' || c == name[name_index] => {
+                pattern_index += 1;
+                name_index += 1;
+            }
+            _ => match backtrack {
+                Some((star_pattern_index, star_name_index)) => {
+                    // Let the '*' match one more character and try again.
+                    pattern_index = star_pattern_index;
+                    name_index = star_name_index + 1;
+                    backtrack = Some((star_pattern_index, name_index));
+                }
+                None => return false,
+            },
         }
-
-        working_root = new_root;
     }
-    None
+    pattern[pattern_index..].iter().all(|&c| c == '*')
 }
 
+
+/// Find all entries in the given directory tree whose "path" matches the
+/// given pattern.  Each component of the pattern is matched against the
+/// corresponding component of a path and can contain the wildcards `*`
+/// (matches zero or more characters) and `?` (matches exactly one
+/// character), so "root/sub*/*.txt" matches every ".txt" entry in each
+/// directory directly under "root" whose name starts with "sub".  Wildcards
+/// never match the "/" between components.
+///
+/// # Parameters
+/// - root
+///
+///   The FileDirEntry object representing the root of the directory tree to
+///   search.
+/// - pattern
+///
+///   The "path" pattern to search for.
+///
+/// # Returns
+/// Returns a vector of (path, entry) pairs for all matching entries in
+/// depth-first order.  The vector is empty if nothing matched.
+pub fn find_entries(root: Rc<RefCell<dyn FileDirEntry>>, pattern: &str) -> Vec<(String, Rc<RefCell<dyn FileDirEntry>>)> {
+    let pattern = pattern.replace("\\", "/");
+    let pattern_components: Vec<&str> = pattern.split('/').collect();
+    DepthFirstIter::new(root)
+        .filter(|(path, _)| {
+            let path_components: Vec<&str> = path.split('/').collect();
+            path_components.len() == pattern_components.len()
+                && pattern_components
+                    .iter()
+                    .zip(path_components.iter())
+                    .all(|(pattern_component, path_component)| _wildcard_match(pattern_component, path_component))
+        })
+        .collect()
+}
+
+//-----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Timestamp given to every entry in the test tree.  A fixed timestamp
+    /// is used because the local time cannot be read while the tests are
+    /// running on several threads.
+    const TIMESTAMP: &str = "06/04/2023  04:08:26 PM";
+
+    /// Construct a small tree with a file, a directory containing a file, and
+    /// an empty directory:
+    ///
+    ///   root/FileA.txt
+    ///   root/subdir1/FileB.txt
+    ///   root/empty
+    fn _construct_test_tree() -> Rc<RefCell<dyn FileDirEntry>> {
+        let mut root_entry = DirEntry::new("root", TIMESTAMP);
+        root_entry.add_child(Rc::new(RefCell::new(FileEntry::new("FileA.txt", 101, TIMESTAMP))));
+        let mut subdir1_entry = DirEntry::new("subdir1", TIMESTAMP);
+        subdir1_entry.add_child(Rc::new(RefCell::new(FileEntry::new("FileB.txt", 102, TIMESTAMP))));
+        root_entry.add_child(Rc::new(RefCell::new(subdir1_entry)));
+        root_entry.add_child(Rc::new(RefCell::new(DirEntry::new("empty", TIMESTAMP))));
+        Rc::new(RefCell::new(root_entry))
+    }
+
+    #[test]
+    fn get_entry_finds_existing_path() {
+        let entry = get_entry(_construct_test_tree(), "root/subdir1/FileB.txt").expect("the path exists");
+        assert_eq!(entry.borrow().name(), "FileB.txt");
+    }
+
+    #[test]
+    fn get_entry_reports_not_found() {
+        let error = get_entry(_construct_test_tree(), "root/subdir1/FileZ.txt").err();
+        assert_eq!(error, Some(CompositeError::NotFound {
+            path: String::from("root/subdir1/FileZ.txt"),
+            name: String::from("FileZ.txt"),
+        }));
+    }
+
+    #[test]
+    fn get_entry_reports_not_a_directory() {
+        let error = get_entry(_construct_test_tree(), "root/FileA.txt/more").err();
+        assert_eq!(error, Some(CompositeError::NotADirectory {
+            path: String::from("root/FileA.txt/more"),
+            file_path: String::from("root/FileA.txt"),
+        }));
+    }
+
+    #[test]
+    fn get_entry_reports_empty_directory() {
+        let error = get_entry(_construct_test_tree(), "root/empty/FileC.txt").err();
+        assert_eq!(error, Some(CompositeError::EmptyDirectory {
+            path: String::from("root/empty/FileC.txt"),
+            dir_path: String::from("root/empty"),
+        }));
+    }
+}
@@ -2,6 +2,9 @@
 //! in a hierarchical list.
 
 use std::{rc::Rc, cell::RefCell};
+use std::fmt::Display;
+
+use super::composite_filedirvisitor_trait::FileDirVisitor;
 
 /// Represents the type of entries allowed in the hierarchy for the
 /// Composite design pattern example.
@@ -12,6 +15,100 @@ pub enum FileDirTypes {
     DirType,
 }
 
+/// Represents one of the attributes an entry can have.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EntryAttribute {
+    /// The entry cannot be removed or moved and, for a directory, entries
+    /// cannot be added to or removed from it.
+    ReadOnly,
+    /// The entry and, for a directory, everything in it are not displayed
+    /// unless asked for.
+    Hidden,
+    /// The entry can be run as a program.
+    Executable,
+}
+
+/// Represents the attributes of an entry: read-only, hidden and executable.
+/// All attributes are off for a new entry.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct EntryAttributes {
+    /// True if the entry is read-only.
+    pub read_only: bool,
+    /// True if the entry is hidden.
+    pub hidden: bool,
+    /// True if the entry is executable.
+    pub executable: bool,
+}
+
+impl EntryAttributes {
+    /// Constructor.
+    ///
+    /// # Returns
+    /// Returns a new instance of the EntryAttributes struct with all
+    /// attributes off.
+    pub fn new() -> EntryAttributes {
+        EntryAttributes::default()
+    }
+
+    /// Turn the given attribute on if it is off or off if it is on.
+    ///
+    /// # Parameters
+    /// - attribute
+    ///
+    ///   The EntryAttribute to change.
+    ///
+    /// # Returns
+    /// Returns true if the attribute is now on; otherwise, returns false.
+    pub fn toggle(&mut self, attribute: EntryAttribute) -> bool {
+        let value = match attribute {
+            EntryAttribute::ReadOnly => &mut self.read_only,
+            EntryAttribute::Hidden => &mut self.hidden,
+            EntryAttribute::Executable => &mut self.executable,
+        };
+        *value = !*value;
+        *value
+    }
+
+    /// Read attributes in the form written by to_string(), such as "r-x".
+    ///
+    /// # Parameters
+    /// - text
+    ///
+    ///   The attributes as text.
+    ///
+    /// # Returns
+    /// Returns an Option<> containing the attributes, or None if the text is
+    /// not in the expected form.
+    pub fn parse(text: &str) -> Option<EntryAttributes> {
+        let flags: Vec<char> = text.chars().collect();
+        let read_flag = |index: usize, on: char| match flags.get(index) {
+            Some(&c) if c == on => Some(true),
+            Some('-') => Some(false),
+            _ => None,
+        };
+        if flags.len() != 3 {
+            return None;
+        }
+        Some(EntryAttributes {
+            read_only: read_flag(0, 'r')?,
+            hidden: read_flag(1, 'h')?,
+            executable: read_flag(2, 'x')?,
+        })
+    }
+}
+
+impl Display for EntryAttributes {
+    /// Formats the attributes as three characters, one for each of
+    /// read-only ('r'), hidden ('h') and executable ('x'), with '-' for an
+    /// attribute that is off.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}{}",
+            if self.read_only { 'r' } else { '-' },
+            if self.hidden { 'h' } else { '-' },
+            if self.executable { 'x' } else { '-' })
+    }
+}
+
 /// Represents an entry in a hierarchical list of objects composed of files
 /// and directories, where the directories can contain nested files and
 /// directories.  This is a trait as it is the only way to represent a
@@ -27,7 +124,27 @@ pub trait FileDirEntry {
     fn timestamp(&self) -> &str;
     /// Returns the length of this entry.
     fn length(&mut self) -> i32;
+    /// Returns the attributes of this entry.
+    fn attributes(&self) -> EntryAttributes;
+    /// Replace the attributes of this entry.
+    fn set_attributes(&mut self, attributes: EntryAttributes);
     /// Returns an Option<> containing a reference to the vector of the
     /// children of this entry.  If there are no children, returns None.
     fn children(&self) -> Option<&Vec<Rc<RefCell<dyn FileDirEntry>>>>;
+    /// Add the given entry as a child of this entry.  Returns `Err(String)`
+    /// if this entry cannot have children.
+    fn insert_child(&mut self, entry: Rc<RefCell<dyn FileDirEntry>>) -> Result<(), String>;
+    /// Remove the child with the given name from this entry.  Returns an
+    /// Option<> containing the removed child, or None if there is no child
+    /// with that name.
+    fn remove_child(&mut self, name: &str) -> Option<Rc<RefCell<dyn FileDirEntry>>>;
+    /// Forget any length computed earlier so the length is computed again
+    /// on the next call to length().  Called when the children change.
+    fn reset_length(&mut self);
+    /// Returns true if the next call to length() will return a value
+    /// remembered from an earlier call instead of computing it.
+    fn length_is_cached(&self) -> bool;
+    /// Pass this entry to the given visitor, followed by all of the entry's
+    /// children, if any.
+    fn accept(&mut self, visitor: &mut dyn FileDirVisitor);
 }
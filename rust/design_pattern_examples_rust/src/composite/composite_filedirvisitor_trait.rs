@@ -0,0 +1,27 @@
+//! Contains the FileDirVisitor trait that represents an operation to be
+//! done on every entry in a hierarchical list of files and directories.
+
+use super::composite_filedirentry_trait::FileDirEntry;
+
+/// Represents a visitor that is passed to every entry in a hierarchical list
+/// of files and directories through FileDirEntry::accept().  Each entry calls
+/// the visitor method for its type, so new operations on the whole tree can
+/// be added without changing the entries.
+pub trait FileDirVisitor {
+    /// Called for each file entry.
+    ///
+    /// # Parameters
+    /// - file
+    ///
+    ///   The file entry being visited.
+    fn visit_file(&mut self, file: &mut dyn FileDirEntry);
+
+    /// Called for each directory entry, before any of its children are
+    /// visited.
+    ///
+    /// # Parameters
+    /// - dir
+    ///
+    ///   The directory entry being visited.
+    fn visit_dir(&mut self, dir: &mut dyn FileDirEntry);
+}
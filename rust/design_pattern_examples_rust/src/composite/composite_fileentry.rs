@@ -3,7 +3,8 @@
 
 use std::cell::RefCell;
 use std::rc::Rc;
-use super::composite_filedirentry_trait::{FileDirTypes, FileDirEntry};
+use super::composite_filedirentry_trait::{EntryAttributes, FileDirTypes, FileDirEntry};
+use super::composite_filedirvisitor_trait::FileDirVisitor;
 
 //-----------------------------------------------------------------------------
 
@@ -18,6 +19,8 @@ pub struct FileEntry {
     length: i32,
     /// Type of the entry as a value from the FileDirTypes enumeration.
     entry_type: FileDirTypes,
+    /// Read-only, hidden and executable attributes of the entry.
+    attributes: EntryAttributes,
 }
 
 
@@ -43,6 +46,7 @@ impl FileEntry {
             length: length,
             timestamp: timestamp.to_string(),
             entry_type: FileDirTypes::FileType,
+            attributes: EntryAttributes::new(),
         }
     }
 }
@@ -64,7 +68,36 @@ impl FileDirEntry for FileEntry {
         self.length
     }
     
+    fn attributes(&self) -> EntryAttributes {
+        self.attributes
+    }
+
+    fn set_attributes(&mut self, attributes: EntryAttributes) {
+        self.attributes = attributes;
+    }
+
     fn children(&self) -> Option<&Vec<Rc<RefCell<dyn FileDirEntry>>>> {
         None
     }
+
+    fn insert_child(&mut self, _entry: Rc<RefCell<dyn FileDirEntry>>) -> Result<(), String> {
+        Err(format!("\"{}\" is a file and cannot contain other entries", self.name))
+    }
+
+    fn remove_child(&mut self, _name: &str) -> Option<Rc<RefCell<dyn FileDirEntry>>> {
+        None
+    }
+
+    fn reset_length(&mut self) {
+        // The length of a file does not depend on other entries.
+    }
+
+    fn length_is_cached(&self) -> bool {
+        // The length of a file is always known.
+        true
+    }
+
+    fn accept(&mut self, visitor: &mut dyn FileDirVisitor) {
+        visitor.visit_file(self);
+    }
 }
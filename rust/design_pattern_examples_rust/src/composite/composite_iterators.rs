@@ -0,0 +1,157 @@
+//! Contains the DepthFirstIter and BreadthFirstIter structs that iterate over
+//! a hierarchical list of files and directories without recursion.
+
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+use super::composite_filedirentry_trait::FileDirEntry;
+
+//-----------------------------------------------------------------------------
+
+/// A function that compares two entries, for visiting the children of each
+/// directory in a particular order.
+pub type EntryOrdering = Box<dyn Fn(&Rc<RefCell<dyn FileDirEntry>>, &Rc<RefCell<dyn FileDirEntry>>) -> Ordering>;
+
+//-----------------------------------------------------------------------------
+
+/// Helper function to get the (path, entry) pairs for the children of the
+/// given entry, in the order the children appear in the entry.
+///
+/// # Parameters
+/// - path
+///
+///   Path to the given entry, for example "root/subdir1".
+/// - entry
+///
+///   The entry whose children are to be returned.
+///
+/// # Returns
+/// Returns a vector of (path, entry) pairs, one for each child.  The vector
+/// is empty if the entry has no children.
+fn _child_items(path: &str, entry: &Rc<RefCell<dyn FileDirEntry>>) -> Vec<(String, Rc<RefCell<dyn FileDirEntry>>)> {
+    match entry.borrow().children() {
+        Some(children) => children
+            .iter()
+            .map(|child| (format!("{path}/{}", child.borrow().name()), child.clone()))
+            .collect(),
+        None => vec![],
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Represents an iterator that visits each entry in a hierarchical list of
+/// files and directories depth-first, so every directory is followed by all
+/// of its contents before its next sibling.  Each item is a (path, entry)
+/// pair where the path is like "root/subdir1/FileD.txt".
+pub struct DepthFirstIter {
+    /// Entries yet to be returned, with the next entry at the end.
+    stack: Vec<(String, Rc<RefCell<dyn FileDirEntry>>)>,
+    /// Function that gives the order to visit the children of each
+    /// directory in, or None to visit them in the order they were added.
+    ordering: Option<EntryOrdering>,
+    /// True if hidden entries, and everything in them, are skipped.
+    skip_hidden: bool,
+}
+
+impl DepthFirstIter {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - root
+    ///
+    ///   The entry to start with.  This is the first entry returned.
+    ///
+    /// # Returns
+    /// Returns a new instance of the DepthFirstIter struct.
+    pub fn new(root: Rc<RefCell<dyn FileDirEntry>>) -> DepthFirstIter {
+        DepthFirstIter::with_order(root, None)
+    }
+
+    /// Constructor for visiting the children of each directory in a
+    /// particular order.
+    ///
+    /// # Parameters
+    /// - root
+    ///
+    ///   The entry to start with.  This is the first entry returned.
+    /// - ordering
+    ///
+    ///   Function that compares two entries, or None to visit the children
+    ///   in the order they were added.
+    ///
+    /// # Returns
+    /// Returns a new instance of the DepthFirstIter struct.
+    pub fn with_order(root: Rc<RefCell<dyn FileDirEntry>>, ordering: Option<EntryOrdering>) -> DepthFirstIter {
+        let path = root.borrow().name().to_string();
+        DepthFirstIter { stack: vec![(path, root)], ordering, skip_hidden: false }
+    }
+
+    /// Skip any hidden entries below the starting entry, along with
+    /// everything in them.  The starting entry is always returned.
+    ///
+    /// # Returns
+    /// Returns this DepthFirstIter, now skipping hidden entries.
+    pub fn skipping_hidden(mut self) -> DepthFirstIter {
+        self.skip_hidden = true;
+        self
+    }
+}
+
+impl Iterator for DepthFirstIter {
+    type Item = (String, Rc<RefCell<dyn FileDirEntry>>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (path, entry) = self.stack.pop()?;
+        let mut children = _child_items(&path, &entry);
+        if self.skip_hidden {
+            children.retain(|(_, child)| !child.borrow().attributes().hidden);
+        }
+        if let Some(ordering) = &self.ordering {
+            children.sort_by(|(_, left), (_, right)| ordering(left, right));
+        }
+        // Reversed so the first child is at the end of the stack.
+        self.stack.extend(children.into_iter().rev());
+        Some((path, entry))
+    }
+}
+
+//=============================================================================
+//=============================================================================
+
+/// Represents an iterator that visits each entry in a hierarchical list of
+/// files and directories breadth-first, so all entries at one level are
+/// returned before any entries at the next level down.  Each item is a
+/// (path, entry) pair where the path is like "root/subdir1/FileD.txt".
+pub struct BreadthFirstIter {
+    /// Entries yet to be returned, with the next entry at the front.
+    queue: VecDeque<(String, Rc<RefCell<dyn FileDirEntry>>)>,
+}
+
+impl BreadthFirstIter {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - root
+    ///
+    ///   The entry to start with.  This is the first entry returned.
+    ///
+    /// # Returns
+    /// Returns a new instance of the BreadthFirstIter struct.
+    pub fn new(root: Rc<RefCell<dyn FileDirEntry>>) -> BreadthFirstIter {
+        let path = root.borrow().name().to_string();
+        BreadthFirstIter { queue: VecDeque::from([(path, root)]) }
+    }
+}
+
+impl Iterator for BreadthFirstIter {
+    type Item = (String, Rc<RefCell<dyn FileDirEntry>>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (path, entry) = self.queue.pop_front()?;
+        self.queue.extend(_child_items(&path, &entry));
+        Some((path, entry))
+    }
+}
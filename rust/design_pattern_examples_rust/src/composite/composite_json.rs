@@ -0,0 +1,157 @@
+//! Contains the to_json() and from_json() functions for saving a
+//! hierarchical list of files and directories as JSON and reading it back.
+//!
+//! A saved tree looks like this, with each directory's length being the
+//! total of its children (it is computed again when the tree is read) and
+//! the attributes written as by EntryAttributes::to_string():
+//! ```text
+//! {"name":"root","type":"dir","length":211,"attributes":"---","timestamp":"06/04/2023  04:08:26 PM","children":[
+//!   {"name":"FileA.txt","type":"file","length":101,"attributes":"r-x","timestamp":"06/04/2023  04:08:26 PM"},
+//!   {"name":"subdir1","type":"dir","length":110,"attributes":"-h-","timestamp":"06/04/2023  04:08:26 PM","children":[
+//!     {"name":"FileB.txt","type":"file","length":110,"attributes":"---","timestamp":"06/04/2023  04:08:26 PM"}
+//!   ]}
+//! ]}
+//! ```
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::helpers::json::{json_string, JsonReader};
+
+use super::composite_direntry::DirEntry;
+use super::composite_fileentry::FileEntry;
+use super::composite_filedirentry_trait::{EntryAttributes, FileDirEntry, FileDirTypes};
+
+//-----------------------------------------------------------------------------
+
+/// Helper function to convert an entry and all of its children to JSON.
+///
+/// Note: This is a recursive call.
+///
+/// # Parameters
+/// - entry
+///
+///   The entry to convert.
+/// - indent
+///
+///   The number of levels to indent the entry, where each level is two
+///   spaces.
+///
+/// # Returns
+/// Returns a string containing the entry as a JSON object, without a
+/// trailing newline.
+fn _entry_to_json(entry: &Rc<RefCell<dyn FileDirEntry>>, indent: usize) -> String {
+    let length = entry.borrow_mut().length();
+    let entry = entry.borrow();
+    let entry_type = match entry.entry_type() {
+        FileDirTypes::FileType => "file",
+        FileDirTypes::DirType => "dir",
+    };
+    let mut output = format!("{}{{\"name\":{},\"type\":\"{entry_type}\",\"length\":{length},\"attributes\":\"{}\",\"timestamp\":{}",
+        "  ".repeat(indent), json_string(entry.name()), entry.attributes(), json_string(entry.timestamp()));
+    if let Some(children) = entry.children() {
+        if children.is_empty() {
+            output.push_str(",\"children\":[]");
+        } else {
+            let children: Vec<String> = children.iter().map(|child| _entry_to_json(child, indent + 1)).collect();
+            output.push_str(&format!(",\"children\":[\n{}\n{}]", children.join(",\n"), "  ".repeat(indent)));
+        }
+    }
+    output.push('}');
+    output
+}
+
+
+/// Convert the given directory tree to JSON, one entry per line.
+///
+/// # Parameters
+/// - root
+///
+///   The FileDirEntry object representing the root of the directory tree.
+///
+/// # Returns
+/// Returns a string containing the tree as a JSON object.
+pub fn to_json(root: Rc<RefCell<dyn FileDirEntry>>) -> String {
+    format!("{}\n", _entry_to_json(&root, 0))
+}
+
+//-----------------------------------------------------------------------------
+
+/// Helper function to read an entry, which is an object with "name", "type",
+/// "length" and "timestamp" fields, an optional "attributes" field, plus,
+/// for a directory, a "children" array of entries, starting at the next
+/// character.
+///
+/// Note: This is a recursive call (through JsonReader::read_array()).
+///
+/// # Parameters
+/// - reader
+///
+///   The JsonReader to read from.
+///
+/// # Returns
+/// Returns `Ok(Rc<RefCell<dyn FileDirEntry>>)` containing the entry;
+/// otherwise, returns `Err(String)` describing what is wrong with the JSON.
+fn _read_entry(reader: &mut JsonReader) -> Result<Rc<RefCell<dyn FileDirEntry>>, String> {
+    let mut name = None;
+    let mut entry_type = None;
+    let mut length = 0;
+    let mut timestamp = String::new();
+    let mut attributes = EntryAttributes::new();
+    let mut children = vec![];
+    reader.read_object(|reader, key| {
+        match key {
+            "name" => name = Some(reader.read_string()?),
+            "type" => entry_type = Some(reader.read_string()?),
+            "length" => {
+                length = i32::try_from(reader.read_integer()?)
+                    .map_err(|_| "An entry's \"length\" is too large".to_string())?;
+            }
+            "timestamp" => timestamp = reader.read_string()?,
+            "attributes" => {
+                let text = reader.read_string()?;
+                attributes = EntryAttributes::parse(&text)
+                    .ok_or(format!("Unknown attributes \"{text}\" in an entry"))?;
+            }
+            "children" => children = reader.read_array(_read_entry)?,
+            _ => return Err(format!("Unknown field \"{key}\" in an entry")),
+        }
+        Ok(())
+    })?;
+    let name = name.ok_or("An entry has no \"name\" field".to_string())?;
+    let entry: Rc<RefCell<dyn FileDirEntry>> = match entry_type.as_deref() {
+        Some("file") if children.is_empty() => Rc::new(RefCell::new(FileEntry::new(&name, length, &timestamp))),
+        Some("file") => return Err(format!("The file \"{name}\" cannot have children")),
+        Some("dir") => {
+            // The length of a directory always comes from its children.
+            let mut dir_entry = DirEntry::new(&name, &timestamp);
+            for child in children {
+                dir_entry.insert_child(child)?;
+            }
+            Rc::new(RefCell::new(dir_entry))
+        }
+        Some(other) => return Err(format!("The entry \"{name}\" has an unknown type \"{other}\"")),
+        None => return Err(format!("The entry \"{name}\" has no \"type\" field")),
+    };
+    entry.borrow_mut().set_attributes(attributes);
+    Ok(entry)
+}
+
+
+/// Read a directory tree from JSON, as written by to_json().
+///
+/// # Parameters
+/// - json
+///
+///   The JSON text to read.
+///
+/// # Returns
+/// Returns `Ok(Rc<RefCell<dyn FileDirEntry>>)` containing the root of the
+/// tree; otherwise, returns `Err(String)` describing what is wrong with the
+/// JSON.
+pub fn from_json(json: &str) -> Result<Rc<RefCell<dyn FileDirEntry>>, String> {
+    let mut reader = JsonReader::new(json);
+    let root = _read_entry(&mut reader)?;
+    reader.expect_end("the root entry")?;
+    Ok(root)
+}
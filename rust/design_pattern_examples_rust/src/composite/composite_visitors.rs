@@ -0,0 +1,79 @@
+//! Contains the TotalSizeVisitor and LargestFileVisitor structs, which
+//! implement the FileDirVisitor trait to gather information about a
+//! hierarchical list of files and directories.
+
+use super::composite_filedirentry_trait::FileDirEntry;
+use super::composite_filedirvisitor_trait::FileDirVisitor;
+
+//-----------------------------------------------------------------------------
+
+/// Represents a visitor that adds up the lengths of all the files it visits
+/// and counts the files and directories.
+pub struct TotalSizeVisitor {
+    /// Total length of all files visited.
+    pub total_size: i32,
+    /// Number of files visited.
+    pub num_files: usize,
+    /// Number of directories visited.
+    pub num_dirs: usize,
+}
+
+impl TotalSizeVisitor {
+    /// Constructor.
+    ///
+    /// # Returns
+    /// Returns a new instance of the TotalSizeVisitor struct that has not
+    /// visited anything.
+    pub fn new() -> TotalSizeVisitor {
+        TotalSizeVisitor { total_size: 0, num_files: 0, num_dirs: 0 }
+    }
+}
+
+impl FileDirVisitor for TotalSizeVisitor {
+    fn visit_file(&mut self, file: &mut dyn FileDirEntry) {
+        self.total_size += file.length();
+        self.num_files += 1;
+    }
+
+    fn visit_dir(&mut self, _dir: &mut dyn FileDirEntry) {
+        self.num_dirs += 1;
+    }
+}
+
+//=============================================================================
+//=============================================================================
+
+/// Represents a visitor that finds the largest of the files it visits.
+pub struct LargestFileVisitor {
+    /// Name and length of the largest file visited, or None if no files
+    /// were visited.  The first file found wins a tie.
+    pub largest: Option<(String, i32)>,
+}
+
+impl LargestFileVisitor {
+    /// Constructor.
+    ///
+    /// # Returns
+    /// Returns a new instance of the LargestFileVisitor struct that has not
+    /// visited anything.
+    pub fn new() -> LargestFileVisitor {
+        LargestFileVisitor { largest: None }
+    }
+}
+
+impl FileDirVisitor for LargestFileVisitor {
+    fn visit_file(&mut self, file: &mut dyn FileDirEntry) {
+        let length = file.length();
+        let is_larger = match &self.largest {
+            Some((_, largest_length)) => length > *largest_length,
+            None => true,
+        };
+        if is_larger {
+            self.largest = Some((file.name().to_string(), length));
+        }
+    }
+
+    fn visit_dir(&mut self, _dir: &mut dyn FileDirEntry) {
+        // Only files are of interest.
+    }
+}
@@ -8,23 +8,64 @@
 //! and decorators must ultimately wrap a non-decorator class to be of any
 //! use.
 //!
+//! The decorators do not depend on the output medium: each render() call is
+//! given a RenderTarget, and the same chain of decorators renders either ANSI
+//! escape sequences for a terminal, HTML `<span>` elements, bracketed
+//! markers such as `[red]text[/red]`, or plain text.  Markers are used on the
+//! console in place of ANSI escape sequences if the terminal does not
+//! support them or the NO_COLOR environment variable is set.
+//!
+//! Because decorators are composed at run time, the DecoratorFactory can
+//! build a chain from a specification such as "red+underline+bold", which
+//! can be given on the command line with the `--decorators` option.
+//!
+//! The same idea applies to output streams: UppercaseWriter,
+//! LineNumberingWriter and IndentingWriter each wrap a std::io::Write stream
+//! and are themselves Write streams, so they can be stacked around the
+//! console or a file.
+//!
 //! Accessed through the decorator_exercise() function.
 
 //-----------------------------------------------------------------------------
 // Sub-module definitions.
 
 pub mod decorator_irenderelement_trait;
+pub mod decorator_rendertarget;
 pub mod decorator_textelement;
 pub mod decorator_whitebackground;
 pub mod decorator_underline;
 pub mod decorator_redforeground;
+pub mod decorator_bold;
+pub mod decorator_blink;
+pub mod decorator_textcolor;
+pub mod decorator_colorpair;
+pub mod decorator_foregroundcolor;
+pub mod decorator_factory;
+pub mod decorator_uppercasewriter;
+pub mod decorator_linenumberingwriter;
+pub mod decorator_indentingwriter;
 
 //-----------------------------------------------------------------------------
 
+use std::fs::File;
+use std::io::{stdout, Write};
+use std::rc::Rc;
+
+use super::decorator::decorator_irenderelement_trait::IRenderElement;
+use super::decorator::decorator_rendertarget::RenderTarget;
 use super::decorator::decorator_textelement::TextElement;
 use super::decorator::decorator_whitebackground::WhiteBackgroundDecorator;
 use super::decorator::decorator_underline::UnderlineDecorator;
 use super::decorator::decorator_redforeground::RedForegroundDecorator;
+use super::decorator::decorator_bold::BoldDecorator;
+use super::decorator::decorator_blink::BlinkDecorator;
+use super::decorator::decorator_textcolor::TextColor;
+use super::decorator::decorator_colorpair::ColorPairDecorator;
+use super::decorator::decorator_factory::DecoratorFactory;
+use super::decorator::decorator_uppercasewriter::UppercaseWriter;
+use super::decorator::decorator_linenumberingwriter::LineNumberingWriter;
+use super::decorator::decorator_indentingwriter::IndentingWriter;
+use crate::helpers::exercise_options::get_exercise_option_value;
 
 //-----------------------------------------------------------------------------
 
@@ -37,6 +78,15 @@ use super::decorator::decorator_redforeground::RedForegroundDecorator;
 /// recursively wrap other decorators.  The base element never wraps anything
 /// and decorators must ultimately wrap a non-decorator class to be of any
 /// use.
+///
+/// The same chain of decorators is rendered for the console (with ANSI
+/// escape sequences or, if colors are not available, bracketed markers), as
+/// plain text and as HTML, showing that the decorators are independent of
+/// the output medium.  The chain then reports its decorators and is rebuilt
+/// with one of them removed or replaced.  A deeper chain is then built one decorator at a
+/// time, rendering the element after each one is added, and a chain is built
+/// from a specification given at run time.  Finally, stream decorators are
+/// stacked around the console and around a file.
 // ! [Using Decorator in Rust]
 pub fn decorator_exercise() -> Result<(), String> {
     println!("");
@@ -48,9 +98,88 @@ pub fn decorator_exercise() -> Result<(), String> {
     wrapped_element = UnderlineDecorator::new(wrapped_element.clone());
     wrapped_element = RedForegroundDecorator::new(wrapped_element.clone());
     
-    // Now render the elements to the console.
-    println!("  base Text element: \"{}\"", base_element.render());
-    println!("  Decorated element: \"{}\"", wrapped_element.render());
+    // Now render the elements to the console, once for each render target.
+    let console_target = RenderTarget::for_console();
+    for target in [console_target, RenderTarget::Plain, RenderTarget::Html] {
+        println!("  Rendered as {}:", target.name());
+        println!("    base Text element: \"{}\"", base_element.render(target));
+        println!("    Decorated element: \"{}\"", wrapped_element.render(target));
+    }
+
+    // A chain of decorators can describe itself and be rebuilt with a layer
+    // removed or replaced, leaving the original chain as it was.
+    println!("  Decorators applied, innermost first: {}", wrapped_element.chain().join(" + "));
+    let uncolored_element = wrapped_element.remove_layer("red")
+        .ok_or("The decorated element has no \"red\" decorator".to_string())?;
+    println!("    Without red:        {:<40}\"{}\"", uncolored_element.chain().join(" + "), uncolored_element.render(console_target));
+    let bold_element = wrapped_element.replace_layer("underline", &|element| BoldDecorator::new(element))
+        .ok_or("The decorated element has no \"underline\" decorator".to_string())?;
+    println!("    Underline -> bold:  {:<40}\"{}\"", bold_element.chain().join(" + "), bold_element.render(console_target));
+    println!("    Original unchanged: {:<40}\"{}\"", wrapped_element.chain().join(" + "), wrapped_element.render(console_target));
+
+    // Each decorator wraps the element built so far, so the decorations
+    // accumulate one layer at a time.
+    println!("  Building a deeper chain one decorator at a time:");
+    let show_layer = |label: &str, element: &Rc<dyn IRenderElement>| {
+        println!("    {label:<20}\"{}\"", element.render(console_target));
+    };
+    let mut layered_element = TextElement::new("This is layered text");
+    show_layer("Base text element:", &layered_element);
+    layered_element = ColorPairDecorator::new(layered_element, TextColor::Yellow, TextColor::Blue);
+    show_layer("+ Yellow on blue:", &layered_element);
+    layered_element = BoldDecorator::new(layered_element);
+    show_layer("+ Bold:", &layered_element);
+    layered_element = UnderlineDecorator::new(layered_element);
+    show_layer("+ Underline:", &layered_element);
+    layered_element = BlinkDecorator::new(layered_element);
+    show_layer("+ Blink:", &layered_element);
+    println!("    As HTML: \"{}\"", layered_element.render(RenderTarget::Html));
+
+    // Any foreground color can be paired with any background color.
+    println!("  Each color on a contrasting background:");
+    let mut swatches = String::new();
+    for color in TextColor::ALL {
+        let background = if color == TextColor::White { TextColor::Black } else { TextColor::White };
+        let swatch = ColorPairDecorator::new(TextElement::new(color.name()), color, background);
+        swatches.push_str(&format!(" {}", swatch.render(console_target)));
+    }
+    println!("   {swatches}");
+
+    // The decorators to apply can come from the command line.
+    let spec = get_exercise_option_value("--decorators").unwrap_or("red+underline+bold".to_string());
+    let spec_element = DecoratorFactory::create(&spec, TextElement::new("This is text decorated at run time"))
+        .map_err(|message| format!("--decorators: {message}"))?;
+    println!("  Built from the specification \"{spec}\":");
+    for target in [console_target, RenderTarget::Html] {
+        println!("    {:<9}\"{}\"", format!("{}:", target.name()), spec_element.render(target));
+    }
+
+    // Stream decorators work the same way: each one is a Write stream that
+    // changes what is written before passing it on to the stream it wraps.
+    let lines = "The first line\nThe second line\nThe third line\n";
+    println!("  Writing to the console through IndentingWriter and LineNumberingWriter:");
+    let mut writer = LineNumberingWriter::new(IndentingWriter::new(Box::new(stdout()), 4));
+    writer.write_all(lines.as_bytes()).map_err(|error| error.to_string())?;
+    writer.flush().map_err(|error| error.to_string())?;
+    println!("  Adding UppercaseWriter in front:");
+    writer = UppercaseWriter::new(writer);
+    writer.write_all(lines.as_bytes()).map_err(|error| error.to_string())?;
+    writer.flush().map_err(|error| error.to_string())?;
+
+    let file_path = std::env::temp_dir().join("decorator_stream.txt");
+    {
+        let file = File::create(&file_path)
+            .map_err(|error| format!("Could not create {}: {error}", file_path.display()))?;
+        let mut file_writer = UppercaseWriter::new(LineNumberingWriter::new(Box::new(file)));
+        file_writer.write_all(lines.as_bytes()).map_err(|error| error.to_string())?;
+        file_writer.flush().map_err(|error| error.to_string())?;
+    }
+    let file_contents = std::fs::read_to_string(&file_path).map_err(|error| error.to_string())?;
+    let _ = std::fs::remove_file(&file_path);
+    println!("  Wrote to a file through UppercaseWriter and LineNumberingWriter, which contains:");
+    let mut echo = IndentingWriter::new(Box::new(stdout()), 4);
+    echo.write_all(file_contents.as_bytes()).map_err(|error| error.to_string())?;
+    echo.flush().map_err(|error| error.to_string())?;
 
     println!("  Done.");
 
@@ -0,0 +1,54 @@
+//! Contains the BlinkDecorator struct representing a decorator that renders
+//! an element as blinking text.
+
+use std::rc::Rc;
+
+use super::decorator_irenderelement_trait::IRenderElement;
+use super::decorator_rendertarget::RenderTarget;
+
+/// Represents the Blink decorator, which causes the text element to be
+/// rendered as blinking text.  Not all terminals or browsers support
+/// blinking, in which case the text is shown normally.
+pub struct BlinkDecorator {
+    wrapped_element: Rc<dyn IRenderElement>,
+}
+
+impl BlinkDecorator {
+    /// Constructor that wraps the specified element.
+    ///
+    /// # Parameters
+    /// - wrapped_element
+    ///
+    ///   The IRenderElement to be decorated.
+    ///
+    /// # Returns
+    /// Returns a new BlinkDecorator structure represented by the
+    /// IRenderElement trait.
+    pub fn new(wrapped_element: Rc<dyn IRenderElement>) -> Rc<dyn IRenderElement> {
+        Rc::new(BlinkDecorator{ wrapped_element })
+    }
+}
+
+impl IRenderElement for BlinkDecorator {
+    fn render(&self, target: RenderTarget) -> String {
+        let wrapped = self.wrapped_element.render(target);
+        match target {
+            RenderTarget::Ansi => format!("\x1b[5m{}\x1b[25m", wrapped),
+            RenderTarget::Html => format!("<span style=\"text-decoration:blink\">{}</span>", wrapped),
+            RenderTarget::Markers => format!("[blink]{}[/blink]", wrapped),
+            RenderTarget::Plain => wrapped,
+        }
+    }
+
+    fn name(&self) -> String {
+        "blink".to_string()
+    }
+
+    fn wrapped_element(&self) -> Option<Rc<dyn IRenderElement>> {
+        Some(self.wrapped_element.clone())
+    }
+
+    fn rewrap(&self, wrapped_element: Rc<dyn IRenderElement>) -> Rc<dyn IRenderElement> {
+        BlinkDecorator::new(wrapped_element)
+    }
+}
@@ -0,0 +1,53 @@
+//! Contains the BoldDecorator struct representing a decorator that renders
+//! an element as bold text.
+
+use std::rc::Rc;
+
+use super::decorator_irenderelement_trait::IRenderElement;
+use super::decorator_rendertarget::RenderTarget;
+
+/// Represents the Bold decorator, which causes the text element to be
+/// rendered in bold.
+pub struct BoldDecorator {
+    wrapped_element: Rc<dyn IRenderElement>,
+}
+
+impl BoldDecorator {
+    /// Constructor that wraps the specified element.
+    ///
+    /// # Parameters
+    /// - wrapped_element
+    ///
+    ///   The IRenderElement to be decorated.
+    ///
+    /// # Returns
+    /// Returns a new BoldDecorator structure represented by the
+    /// IRenderElement trait.
+    pub fn new(wrapped_element: Rc<dyn IRenderElement>) -> Rc<dyn IRenderElement> {
+        Rc::new(BoldDecorator{ wrapped_element })
+    }
+}
+
+impl IRenderElement for BoldDecorator {
+    fn render(&self, target: RenderTarget) -> String {
+        let wrapped = self.wrapped_element.render(target);
+        match target {
+            RenderTarget::Ansi => format!("\x1b[1m{}\x1b[22m", wrapped),
+            RenderTarget::Html => format!("<span style=\"font-weight:bold\">{}</span>", wrapped),
+            RenderTarget::Markers => format!("[bold]{}[/bold]", wrapped),
+            RenderTarget::Plain => wrapped,
+        }
+    }
+
+    fn name(&self) -> String {
+        "bold".to_string()
+    }
+
+    fn wrapped_element(&self) -> Option<Rc<dyn IRenderElement>> {
+        Some(self.wrapped_element.clone())
+    }
+
+    fn rewrap(&self, wrapped_element: Rc<dyn IRenderElement>) -> Rc<dyn IRenderElement> {
+        BoldDecorator::new(wrapped_element)
+    }
+}
@@ -0,0 +1,65 @@
+//! Contains the ColorPairDecorator struct representing a decorator that
+//! renders an element in a given foreground (text) color on a given
+//! background color.
+
+use std::rc::Rc;
+
+use super::decorator_irenderelement_trait::IRenderElement;
+use super::decorator_rendertarget::RenderTarget;
+use super::decorator_textcolor::TextColor;
+
+/// Represents the ColorPair decorator, which causes the text to be rendered
+/// in one color on a background of another color.
+pub struct ColorPairDecorator {
+    wrapped_element: Rc<dyn IRenderElement>,
+    foreground: TextColor,
+    background: TextColor,
+}
+
+impl ColorPairDecorator {
+    /// Constructor that wraps the specified element.
+    ///
+    /// # Parameters
+    /// - wrapped_element
+    ///
+    ///   The IRenderElement to be decorated.
+    /// - foreground
+    ///
+    ///   The TextColor to render the text in.
+    /// - background
+    ///
+    ///   The TextColor to render behind the text.
+    ///
+    /// # Returns
+    /// Returns a new ColorPairDecorator structure represented by the
+    /// IRenderElement trait.
+    pub fn new(wrapped_element: Rc<dyn IRenderElement>, foreground: TextColor, background: TextColor) -> Rc<dyn IRenderElement> {
+        Rc::new(ColorPairDecorator{ wrapped_element, foreground, background })
+    }
+}
+
+impl IRenderElement for ColorPairDecorator {
+    fn render(&self, target: RenderTarget) -> String {
+        let wrapped = self.wrapped_element.render(target);
+        match target {
+            RenderTarget::Ansi => format!("\x1b[{};{}m{}\x1b[39;49m",
+                30 + self.foreground.ansi_offset(), 40 + self.background.ansi_offset(), wrapped),
+            RenderTarget::Html => format!("<span style=\"color:{};background-color:{}\">{}</span>",
+                self.foreground.name(), self.background.name(), wrapped),
+            RenderTarget::Markers => format!("[{0}/{1}]{2}[/{0}/{1}]", self.foreground.name(), self.background.name(), wrapped),
+            RenderTarget::Plain => wrapped,
+        }
+    }
+
+    fn name(&self) -> String {
+        format!("{}/{}", self.foreground.name(), self.background.name())
+    }
+
+    fn wrapped_element(&self) -> Option<Rc<dyn IRenderElement>> {
+        Some(self.wrapped_element.clone())
+    }
+
+    fn rewrap(&self, wrapped_element: Rc<dyn IRenderElement>) -> Rc<dyn IRenderElement> {
+        ColorPairDecorator::new(wrapped_element, self.foreground, self.background)
+    }
+}
@@ -0,0 +1,84 @@
+//! Contains the DecoratorFactory struct that builds a chain of decorators
+//! from a specification given at run time, such as "red+underline+bold".
+
+use std::rc::Rc;
+
+use super::decorator_blink::BlinkDecorator;
+use super::decorator_bold::BoldDecorator;
+use super::decorator_colorpair::ColorPairDecorator;
+use super::decorator_foregroundcolor::ForegroundColorDecorator;
+use super::decorator_irenderelement_trait::IRenderElement;
+use super::decorator_textcolor::TextColor;
+use super::decorator_underline::UnderlineDecorator;
+use super::decorator_whitebackground::WhiteBackgroundDecorator;
+
+//-----------------------------------------------------------------------------
+
+/// Represents a factory that wraps an element in the decorators named in a
+/// specification string, so the combination of decorators can be chosen at
+/// run time instead of being fixed in the code.
+///
+/// A specification is a list of decorator names separated by '+', applied
+/// from left to right so the first name is the innermost decorator.  Each
+/// name is one of:
+/// - "bold", "blink", "underline" or "whitebackground"
+/// - a color name, such as "red", for that foreground (text) color
+/// - two color names separated by '/', such as "yellow/blue", for that
+///   foreground color on that background color
+///
+/// Names are not case-sensitive and spaces around them are ignored.
+pub struct DecoratorFactory {}
+
+impl DecoratorFactory {
+    /// Helper function to look up a color by name.
+    ///
+    /// # Parameters
+    /// - name
+    ///
+    ///   Name of the color, in lowercase.
+    ///
+    /// # Returns
+    /// Returns `Ok(TextColor)` for the color; otherwise, returns
+    /// `Err(String)` if the name is not a known color.
+    fn _color(name: &str) -> Result<TextColor, String> {
+        TextColor::from_name(name).ok_or_else(|| {
+            let names: Vec<&str> = TextColor::ALL.iter().map(|color| color.name()).collect();
+            format!("\"{name}\" is not a decorator or a color (colors are {})", names.join(", "))
+        })
+    }
+
+    /// Wrap the given element in the decorators named in the given
+    /// specification.
+    ///
+    /// # Parameters
+    /// - spec
+    ///
+    ///   The specification, such as "red+underline+bold".
+    /// - element
+    ///
+    ///   The IRenderElement to be decorated.
+    ///
+    /// # Returns
+    /// Returns `Ok(Rc<dyn IRenderElement>)` containing the element wrapped in
+    /// all of the decorators; otherwise, returns `Err(String)` describing
+    /// the first name in the specification that is not recognized.
+    pub fn create(spec: &str, element: Rc<dyn IRenderElement>) -> Result<Rc<dyn IRenderElement>, String> {
+        let mut decorated = element;
+        for name in spec.split('+') {
+            let name = name.trim().to_lowercase();
+            decorated = match name.as_str() {
+                "bold" => BoldDecorator::new(decorated),
+                "blink" => BlinkDecorator::new(decorated),
+                "underline" => UnderlineDecorator::new(decorated),
+                "whitebackground" => WhiteBackgroundDecorator::new(decorated),
+                "" => return Err(format!("The decorator specification \"{spec}\" has an empty name")),
+                _ => match name.split_once('/') {
+                    Some((foreground, background)) => ColorPairDecorator::new(decorated,
+                        DecoratorFactory::_color(foreground.trim())?, DecoratorFactory::_color(background.trim())?),
+                    None => ForegroundColorDecorator::new(decorated, DecoratorFactory::_color(&name)?),
+                },
+            };
+        }
+        Ok(decorated)
+    }
+}
@@ -0,0 +1,59 @@
+//! Contains the ForegroundColorDecorator struct representing a decorator that
+//! renders an element in any of the TextColor foreground (text) colors.
+
+use std::rc::Rc;
+
+use super::decorator_irenderelement_trait::IRenderElement;
+use super::decorator_rendertarget::RenderTarget;
+use super::decorator_textcolor::TextColor;
+
+/// Represents the ForegroundColor decorator, which causes the text to be
+/// rendered in the given color.  Unlike RedForegroundDecorator, the color
+/// is chosen when the decorator is created.
+pub struct ForegroundColorDecorator {
+    wrapped_element: Rc<dyn IRenderElement>,
+    color: TextColor,
+}
+
+impl ForegroundColorDecorator {
+    /// Constructor that wraps the specified element.
+    ///
+    /// # Parameters
+    /// - wrapped_element
+    ///
+    ///   The IRenderElement to be decorated.
+    /// - color
+    ///
+    ///   The TextColor to render the text in.
+    ///
+    /// # Returns
+    /// Returns a new ForegroundColorDecorator structure represented by the
+    /// IRenderElement trait.
+    pub fn new(wrapped_element: Rc<dyn IRenderElement>, color: TextColor) -> Rc<dyn IRenderElement> {
+        Rc::new(ForegroundColorDecorator{ wrapped_element, color })
+    }
+}
+
+impl IRenderElement for ForegroundColorDecorator {
+    fn render(&self, target: RenderTarget) -> String {
+        let wrapped = self.wrapped_element.render(target);
+        match target {
+            RenderTarget::Ansi => format!("\x1b[{}m{}\x1b[39m", 30 + self.color.ansi_offset(), wrapped),
+            RenderTarget::Html => format!("<span style=\"color:{}\">{}</span>", self.color.name(), wrapped),
+            RenderTarget::Markers => format!("[{0}]{1}[/{0}]", self.color.name(), wrapped),
+            RenderTarget::Plain => wrapped,
+        }
+    }
+
+    fn name(&self) -> String {
+        self.color.name().to_string()
+    }
+
+    fn wrapped_element(&self) -> Option<Rc<dyn IRenderElement>> {
+        Some(self.wrapped_element.clone())
+    }
+
+    fn rewrap(&self, wrapped_element: Rc<dyn IRenderElement>) -> Rc<dyn IRenderElement> {
+        ForegroundColorDecorator::new(wrapped_element, self.color)
+    }
+}
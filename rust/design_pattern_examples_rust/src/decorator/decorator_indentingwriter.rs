@@ -0,0 +1,51 @@
+//! Contains the IndentingWriter struct representing a decorator for an
+//! output stream that indents each line.
+
+use std::io::{Result, Write};
+
+/// Represents the Indenting stream decorator, which puts a given number of
+/// spaces in front of each line written to it before passing the line on to
+/// the wrapped stream.
+pub struct IndentingWriter {
+    wrapped_writer: Box<dyn Write>,
+    /// The spaces to put in front of each line.
+    indent: String,
+    /// True if the next byte written starts a new line.
+    at_line_start: bool,
+}
+
+impl IndentingWriter {
+    /// Constructor that wraps the specified stream.
+    ///
+    /// # Parameters
+    /// - wrapped_writer
+    ///
+    ///   The stream to be decorated.
+    /// - indent
+    ///
+    ///   The number of spaces to indent each line.
+    ///
+    /// # Returns
+    /// Returns a new IndentingWriter structure represented by the Write
+    /// trait.
+    pub fn new(wrapped_writer: Box<dyn Write>, indent: usize) -> Box<dyn Write> {
+        Box::new(IndentingWriter { wrapped_writer, indent: " ".repeat(indent), at_line_start: true })
+    }
+}
+
+impl Write for IndentingWriter {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        for line in buf.split_inclusive(|&byte| byte == b'\n') {
+            if self.at_line_start {
+                self.wrapped_writer.write_all(self.indent.as_bytes())?;
+            }
+            self.wrapped_writer.write_all(line)?;
+            self.at_line_start = line.ends_with(b"\n");
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.wrapped_writer.flush()
+    }
+}
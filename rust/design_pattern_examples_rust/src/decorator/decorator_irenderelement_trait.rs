@@ -1,9 +1,97 @@
 //! Contains the IRenderElement trait that represents decorator and text
 //! elements.
 
+use std::rc::Rc;
+
+use super::decorator_rendertarget::RenderTarget;
+
+/// A function that creates a decorator around the given element, used to
+/// replace a layer in a chain of decorators.
+pub type LayerReplacement = dyn Fn(Rc<dyn IRenderElement>) -> Rc<dyn IRenderElement>;
+
 /// Represents an element that can be rendered in text.  All decorators
 /// and the core element class implement this interface.
 pub trait IRenderElement {
     /// Render the wrapped element with decorations, returned as a new string.
-    fn render(&self) -> String;
+    ///
+    /// # Parameters
+    /// - target
+    ///
+    ///   The output medium to render for.
+    fn render(&self, target: RenderTarget) -> String;
+
+    /// Retrieve the name of this element, such as "underline" for a
+    /// decorator.  A decorator's name is the name DecoratorFactory uses for
+    /// it.
+    fn name(&self) -> String;
+
+    /// Retrieve the element this element decorates.
+    ///
+    /// # Returns
+    /// Returns an Option<> containing the wrapped element, or None if this
+    /// is the core element and so wraps nothing.
+    fn wrapped_element(&self) -> Option<Rc<dyn IRenderElement>>;
+
+    /// Create a new element that decorates the given element the same way
+    /// this element decorates its own wrapped element.  The core element
+    /// ignores the given element and returns a copy of itself.
+    ///
+    /// # Parameters
+    /// - wrapped_element
+    ///
+    ///   The IRenderElement to be decorated.
+    fn rewrap(&self, wrapped_element: Rc<dyn IRenderElement>) -> Rc<dyn IRenderElement>;
+
+    /// Retrieve the names of the decorators in the chain ending with this
+    /// element, innermost first, in the order they were applied.  Joined with
+    /// '+', the names are a specification DecoratorFactory can build the
+    /// same chain from.
+    fn chain(&self) -> Vec<String> {
+        let Some(wrapped_element) = self.wrapped_element() else {
+            return vec![];
+        };
+        let mut names = wrapped_element.chain();
+        names.push(self.name());
+        names
+    }
+
+    /// Create a new chain of decorators like this one, but with the
+    /// outermost decorator with the given name replaced.  The chain this
+    /// element is part of is not changed.
+    ///
+    /// # Parameters
+    /// - name
+    ///
+    ///   Name of the decorator to replace.
+    /// - replacement
+    ///
+    ///   Function that wraps the element the replaced decorator wrapped,
+    ///   returning the element to take the place of the decorator.
+    ///
+    /// # Returns
+    /// Returns an Option<> containing the new chain, or None if there is no
+    /// decorator with the given name in the chain.
+    fn replace_layer(&self, name: &str, replacement: &LayerReplacement) -> Option<Rc<dyn IRenderElement>> {
+        let wrapped_element = self.wrapped_element()?;
+        if self.name() == name {
+            return Some(replacement(wrapped_element));
+        }
+        Some(self.rewrap(wrapped_element.replace_layer(name, replacement)?))
+    }
+
+    /// Create a new chain of decorators like this one, but without the
+    /// outermost decorator with the given name.  The chain this element is
+    /// part of is not changed.
+    ///
+    /// # Parameters
+    /// - name
+    ///
+    ///   Name of the decorator to remove.
+    ///
+    /// # Returns
+    /// Returns an Option<> containing the new chain, or None if there is no
+    /// decorator with the given name in the chain.
+    fn remove_layer(&self, name: &str) -> Option<Rc<dyn IRenderElement>> {
+        self.replace_layer(name, &|wrapped_element| wrapped_element)
+    }
 }
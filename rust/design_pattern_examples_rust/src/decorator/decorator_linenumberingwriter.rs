@@ -0,0 +1,49 @@
+//! Contains the LineNumberingWriter struct representing a decorator for an
+//! output stream that puts a line number in front of each line.
+
+use std::io::{Result, Write};
+
+/// Represents the LineNumbering stream decorator, which puts the line number
+/// in front of each line written to it before passing the line on to the
+/// wrapped stream.  Lines are numbered from 1.
+pub struct LineNumberingWriter {
+    wrapped_writer: Box<dyn Write>,
+    /// Number of the next line to start.
+    line_number: usize,
+    /// True if the next byte written starts a new line.
+    at_line_start: bool,
+}
+
+impl LineNumberingWriter {
+    /// Constructor that wraps the specified stream.
+    ///
+    /// # Parameters
+    /// - wrapped_writer
+    ///
+    ///   The stream to be decorated.
+    ///
+    /// # Returns
+    /// Returns a new LineNumberingWriter structure represented by the Write
+    /// trait.
+    pub fn new(wrapped_writer: Box<dyn Write>) -> Box<dyn Write> {
+        Box::new(LineNumberingWriter { wrapped_writer, line_number: 1, at_line_start: true })
+    }
+}
+
+impl Write for LineNumberingWriter {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        for line in buf.split_inclusive(|&byte| byte == b'\n') {
+            if self.at_line_start {
+                write!(self.wrapped_writer, "{:3}: ", self.line_number)?;
+                self.line_number += 1;
+            }
+            self.wrapped_writer.write_all(line)?;
+            self.at_line_start = line.ends_with(b"\n");
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.wrapped_writer.flush()
+    }
+}
@@ -4,6 +4,7 @@
 use std::rc::Rc;
 
 use super::decorator_irenderelement_trait::IRenderElement;
+use super::decorator_rendertarget::RenderTarget;
 
 /// Represents the RedForeground decorator, which causes the text to be rendered
 /// in red.
@@ -28,7 +29,25 @@ impl RedForegroundDecorator {
 }
 
 impl IRenderElement for RedForegroundDecorator {
-    fn render(&self) -> String {
-        format!("\x1b[31m{}\x1b[39m", self.wrapped_element.render())
+    fn render(&self, target: RenderTarget) -> String {
+        let wrapped = self.wrapped_element.render(target);
+        match target {
+            RenderTarget::Ansi => format!("\x1b[31m{}\x1b[39m", wrapped),
+            RenderTarget::Html => format!("<span style=\"color:red\">{}</span>", wrapped),
+            RenderTarget::Markers => format!("[red]{}[/red]", wrapped),
+            RenderTarget::Plain => wrapped,
+        }
+    }
+
+    fn name(&self) -> String {
+        "red".to_string()
+    }
+
+    fn wrapped_element(&self) -> Option<Rc<dyn IRenderElement>> {
+        Some(self.wrapped_element.clone())
+    }
+
+    fn rewrap(&self, wrapped_element: Rc<dyn IRenderElement>) -> Rc<dyn IRenderElement> {
+        RedForegroundDecorator::new(wrapped_element)
     }
 }
@@ -0,0 +1,42 @@
+//! Contains the RenderTarget enum that selects the output medium the
+//! decorators render to.
+
+use crate::helpers::ansi::use_colors;
+
+/// Represents the output medium to render an element to.  Each decorator
+/// applies the same decoration to every target, just spelled differently.
+#[derive(Clone, Copy, PartialEq)]
+pub enum RenderTarget {
+    /// Render using ANSI escape sequences for display in a terminal.
+    Ansi,
+    /// Render using HTML `<span>` elements with inline styles.
+    Html,
+    /// Render each decoration as a pair of bracketed markers around the text,
+    /// such as `[red]text[/red]`, for terminals without ANSI support.
+    Markers,
+    /// Render the text with no decorations at all.
+    Plain,
+}
+
+impl RenderTarget {
+    /// Retrieve the name of the render target, for display.
+    pub fn name(&self) -> &'static str {
+        match self {
+            RenderTarget::Ansi => "ANSI",
+            RenderTarget::Html => "HTML",
+            RenderTarget::Markers => "markers",
+            RenderTarget::Plain => "plain text",
+        }
+    }
+
+    /// Retrieve the render target to use for the console: ANSI escape
+    /// sequences if colors can be shown, otherwise bracketed markers so the
+    /// structure of the decorations can still be seen.
+    pub fn for_console() -> RenderTarget {
+        if use_colors() {
+            RenderTarget::Ansi
+        } else {
+            RenderTarget::Markers
+        }
+    }
+}
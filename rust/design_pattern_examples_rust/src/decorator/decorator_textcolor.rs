@@ -0,0 +1,74 @@
+//! Contains the TextColor enum that represents the colors the
+//! ColorPairDecorator can render text in.
+
+/// Represents one of the eight standard terminal colors, usable as either a
+/// foreground (text) color or a background color.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TextColor {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl TextColor {
+    /// All of the colors, in the order of their ANSI color codes.
+    pub const ALL: [TextColor; 8] = [
+        TextColor::Black,
+        TextColor::Red,
+        TextColor::Green,
+        TextColor::Yellow,
+        TextColor::Blue,
+        TextColor::Magenta,
+        TextColor::Cyan,
+        TextColor::White,
+    ];
+
+    /// Retrieve the offset of the color within the ANSI color codes: the
+    /// foreground code is 30 plus the offset and the background code is 40
+    /// plus the offset.
+    pub fn ansi_offset(&self) -> u8 {
+        match self {
+            TextColor::Black => 0,
+            TextColor::Red => 1,
+            TextColor::Green => 2,
+            TextColor::Yellow => 3,
+            TextColor::Blue => 4,
+            TextColor::Magenta => 5,
+            TextColor::Cyan => 6,
+            TextColor::White => 7,
+        }
+    }
+
+    /// Look up a color by name.
+    ///
+    /// # Parameters
+    /// - name
+    ///
+    ///   Name of the color, in lowercase, as returned by name().
+    ///
+    /// # Returns
+    /// Returns an Option<> containing the color, or None if there is no
+    /// color with that name.
+    pub fn from_name(name: &str) -> Option<TextColor> {
+        TextColor::ALL.into_iter().find(|color| color.name() == name)
+    }
+
+    /// Retrieve the name of the color, which is also its name in HTML.
+    pub fn name(&self) -> &'static str {
+        match self {
+            TextColor::Black => "black",
+            TextColor::Red => "red",
+            TextColor::Green => "green",
+            TextColor::Yellow => "yellow",
+            TextColor::Blue => "blue",
+            TextColor::Magenta => "magenta",
+            TextColor::Cyan => "cyan",
+            TextColor::White => "white",
+        }
+    }
+}
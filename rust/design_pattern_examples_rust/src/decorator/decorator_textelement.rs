@@ -3,6 +3,7 @@
 use std::rc::Rc;
 
 use super::decorator_irenderelement_trait::IRenderElement;
+use super::decorator_rendertarget::RenderTarget;
 
 /// Represents the core element that can be decorated.  Note that this
 /// struct implements the IRenderElement trait but otherwise has no knowledge
@@ -33,7 +34,26 @@ impl TextElement {
 
 impl IRenderElement for TextElement {
     /// Render this element as a string by returning (a clone of) the string.
-    fn render(&self) -> String {
-        self.element_text.clone()
+    /// For HTML, characters with special meaning in HTML are escaped.
+    fn render(&self, target: RenderTarget) -> String {
+        match target {
+            RenderTarget::Ansi | RenderTarget::Markers | RenderTarget::Plain => self.element_text.clone(),
+            RenderTarget::Html => self.element_text
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;"),
+        }
+    }
+
+    fn name(&self) -> String {
+        "text".to_string()
+    }
+
+    fn wrapped_element(&self) -> Option<Rc<dyn IRenderElement>> {
+        None
+    }
+
+    fn rewrap(&self, _wrapped_element: Rc<dyn IRenderElement>) -> Rc<dyn IRenderElement> {
+        TextElement::new(&self.element_text)
     }
 }
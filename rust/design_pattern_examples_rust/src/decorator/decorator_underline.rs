@@ -4,6 +4,7 @@
 use std::rc::Rc;
 
 use super::decorator_irenderelement_trait::IRenderElement;
+use super::decorator_rendertarget::RenderTarget;
 
 /// Represents the Underline decorator, which causes the text element to be
 /// rendered as underlined.
@@ -28,7 +29,25 @@ impl UnderlineDecorator {
 }
 
 impl IRenderElement for UnderlineDecorator {
-    fn render(&self) -> String {
-        format!("\x1b[4m{}\x1b[24m", self.wrapped_element.render())
+    fn render(&self, target: RenderTarget) -> String {
+        let wrapped = self.wrapped_element.render(target);
+        match target {
+            RenderTarget::Ansi => format!("\x1b[4m{}\x1b[24m", wrapped),
+            RenderTarget::Html => format!("<span style=\"text-decoration:underline\">{}</span>", wrapped),
+            RenderTarget::Markers => format!("[underline]{}[/underline]", wrapped),
+            RenderTarget::Plain => wrapped,
+        }
+    }
+
+    fn name(&self) -> String {
+        "underline".to_string()
+    }
+
+    fn wrapped_element(&self) -> Option<Rc<dyn IRenderElement>> {
+        Some(self.wrapped_element.clone())
+    }
+
+    fn rewrap(&self, wrapped_element: Rc<dyn IRenderElement>) -> Rc<dyn IRenderElement> {
+        UnderlineDecorator::new(wrapped_element)
     }
 }
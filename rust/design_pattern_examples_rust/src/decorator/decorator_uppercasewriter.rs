@@ -0,0 +1,39 @@
+//! Contains the UppercaseWriter struct representing a decorator for an output
+//! stream that converts all text written to it to uppercase.
+
+use std::io::{Result, Write};
+
+/// Represents the Uppercase stream decorator, which converts the letters
+/// written to it to uppercase before passing them on to the wrapped stream.
+/// Only ASCII letters are converted, so a character encoded as several bytes
+/// is never changed, even if it is split across calls to write().
+pub struct UppercaseWriter {
+    wrapped_writer: Box<dyn Write>,
+}
+
+impl UppercaseWriter {
+    /// Constructor that wraps the specified stream.
+    ///
+    /// # Parameters
+    /// - wrapped_writer
+    ///
+    ///   The stream to be decorated.
+    ///
+    /// # Returns
+    /// Returns a new UppercaseWriter structure represented by the Write
+    /// trait.
+    pub fn new(wrapped_writer: Box<dyn Write>) -> Box<dyn Write> {
+        Box::new(UppercaseWriter { wrapped_writer })
+    }
+}
+
+impl Write for UppercaseWriter {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.wrapped_writer.write_all(&buf.to_ascii_uppercase())?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.wrapped_writer.flush()
+    }
+}
@@ -4,6 +4,7 @@
 use std::rc::Rc;
 
 use super::decorator_irenderelement_trait::IRenderElement;
+use super::decorator_rendertarget::RenderTarget;
 
 /// Represents the WhiteBackground decorator, which changes the background
 /// color of the wrapped element to white.
@@ -28,7 +29,25 @@ impl WhiteBackgroundDecorator {
 }
 
 impl IRenderElement for WhiteBackgroundDecorator {
-    fn render(&self) -> String {
-        format!("\x1b[47m{}\x1b[49m", self.wrapped_element.render())
+    fn render(&self, target: RenderTarget) -> String {
+        let wrapped = self.wrapped_element.render(target);
+        match target {
+            RenderTarget::Ansi => format!("\x1b[47m{}\x1b[49m", wrapped),
+            RenderTarget::Html => format!("<span style=\"background-color:white\">{}</span>", wrapped),
+            RenderTarget::Markers => format!("[whitebackground]{}[/whitebackground]", wrapped),
+            RenderTarget::Plain => wrapped,
+        }
+    }
+
+    fn name(&self) -> String {
+        "whitebackground".to_string()
+    }
+
+    fn wrapped_element(&self) -> Option<Rc<dyn IRenderElement>> {
+        Some(self.wrapped_element.clone())
+    }
+
+    fn rewrap(&self, wrapped_element: Rc<dyn IRenderElement>) -> Rc<dyn IRenderElement> {
+        WhiteBackgroundDecorator::new(wrapped_element)
     }
 }
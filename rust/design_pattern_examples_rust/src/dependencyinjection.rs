@@ -0,0 +1,105 @@
+//! The Dependency Injection design pattern example module
+//!
+//! The Dependency Injection pattern gives an object the services it depends
+//! on instead of having the object create those services itself.  The object
+//! depends only on the traits of the services, so the services can be
+//! swapped (for example, for testing) without changing the object.
+//!
+//! In this exercise, an OrderProcessor depends on a logger (the ILogger
+//! trait from the Bridge example) and a payment service.  The services are
+//! first passed directly to the OrderProcessor's constructor (constructor
+//! injection).  Then a minimal service container is used: factories for each
+//! kind of service are registered with the container, which then resolves an
+//! OrderProcessor complete with its services.  Changing which logger is
+//! registered changes the OrderProcessor's output without touching the
+//! OrderProcessor.
+//!
+//! Accessed through the dependencyinjection_exercise() function.
+
+//-----------------------------------------------------------------------------
+
+pub mod dependencyinjection_ipaymentgateway_trait;
+pub mod dependencyinjection_orderprocessor;
+pub mod dependencyinjection_paymentgateway;
+pub mod dependencyinjection_servicecontainer;
+
+//-----------------------------------------------------------------------------
+
+use crate::bridge::bridge_ilogger_trait::ILogger;
+use crate::bridge::bridge_logger::{create_logger, LoggerType};
+use dependencyinjection_ipaymentgateway_trait::IPaymentGateway;
+use dependencyinjection_orderprocessor::OrderProcessor;
+use dependencyinjection_paymentgateway::TestPaymentGateway;
+use dependencyinjection_servicecontainer::ServiceContainer;
+
+//-----------------------------------------------------------------------------
+
+/// Helper function to run the same orders through the given OrderProcessor.
+///
+/// # Parameters
+/// - processor
+///
+///   The OrderProcessor to use.
+fn _dependencyinjection_process_orders(processor: &mut OrderProcessor) {
+    for (item, amount_cents) in [("book", 1999), ("television", 49999)] {
+        let paid = processor.process_order(item, amount_cents);
+        println!("    Order for {item} paid: {paid}");
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Example of using the "Dependency Injection" design pattern.
+///
+/// The Dependency Injection pattern gives an object the services it depends
+/// on instead of having the object create those services itself.  The object
+/// depends only on the traits of the services, so the services can be
+/// swapped without changing the object.
+///
+/// In this exercise, an OrderProcessor is given a logger and a payment
+/// service, first directly through its constructor and then through a
+/// minimal service container that resolves the OrderProcessor along with its
+/// services.
+// ! [Using DependencyInjection in Rust]
+pub fn dependencyinjection_exercise() -> Result<(), String> {
+    println!();
+    println!("DependencyInjection Exercise");
+
+    println!("  Constructor injection with a console logger:");
+    let mut processor = OrderProcessor::new(
+        create_logger(LoggerType::ToConsole, ""),
+        TestPaymentGateway::new(10000));
+    _dependencyinjection_process_orders(&mut processor);
+
+    println!("  Resolving an OrderProcessor from a service container:");
+    let mut container = ServiceContainer::new();
+    container.register::<dyn ILogger>(|_| Ok(create_logger(LoggerType::ToConsole, "")));
+    container.register::<dyn IPaymentGateway>(|_| Ok(TestPaymentGateway::new(100000)));
+    container.register::<OrderProcessor>(|services| {
+        Ok(Box::new(OrderProcessor::new(services.resolve()?, services.resolve()?)))
+    });
+    for service_name in container.registered_services() {
+        println!("    Registered: {service_name}");
+    }
+    let mut processor = container.resolve::<OrderProcessor>()?;
+    _dependencyinjection_process_orders(&mut processor);
+
+    println!("  Registering a null logger instead; the OrderProcessor is unchanged:");
+    container.register::<dyn ILogger>(|_| Ok(create_logger(LoggerType::ToNull, "")));
+    let mut processor = container.resolve::<OrderProcessor>()?;
+    _dependencyinjection_process_orders(&mut processor);
+
+    println!("  Resolving from a container missing a service:");
+    let mut incomplete_container = ServiceContainer::new();
+    incomplete_container.register::<OrderProcessor>(|services| {
+        Ok(Box::new(OrderProcessor::new(services.resolve()?, services.resolve()?)))
+    });
+    if let Err(message) = incomplete_container.resolve::<OrderProcessor>() {
+        println!("    {message}");
+    }
+
+    println!("  Done.");
+
+    Ok(())
+}
+// ! [Using DependencyInjection in Rust]
@@ -0,0 +1,22 @@
+//! Contains the IPaymentGateway trait that represents a service for charging
+//! payments.
+
+/// Represents a service that charges payments.  The OrderProcessor depends
+/// on this trait, not on any particular payment service.
+pub trait IPaymentGateway {
+    /// Retrieve the name of the payment service, for display.
+    fn name(&self) -> &str;
+
+    /// Charge the given amount.
+    ///
+    /// # Parameters
+    /// - amount_cents
+    ///
+    ///   The amount to charge, in cents.
+    ///
+    /// # Returns
+    /// Returns `Ok(String)` containing a confirmation code if the charge was
+    /// approved; otherwise, returns `Err(String)` describing why the charge
+    /// was declined.
+    fn charge(&mut self, amount_cents: u32) -> Result<String, String>;
+}
@@ -0,0 +1,65 @@
+//! Contains the OrderProcessor struct that processes orders using services
+//! given to it when it is created.
+
+//-----------------------------------------------------------------------------
+
+use crate::bridge::bridge_ilogger_trait::ILogger;
+use super::dependencyinjection_ipaymentgateway_trait::IPaymentGateway;
+
+//-----------------------------------------------------------------------------
+
+/// Represents the processing of orders.  The OrderProcessor does not create
+/// the services it depends on; they are passed to its constructor
+/// (constructor injection).  This means the OrderProcessor works with any
+/// logger and any payment service without being changed.
+pub struct OrderProcessor {
+    /// The logger to which to report what happens to each order.
+    logger: Box<dyn ILogger>,
+    /// The payment service used to charge for each order.
+    payment_gateway: Box<dyn IPaymentGateway>,
+}
+
+impl OrderProcessor {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - logger
+    ///
+    ///   The logger to which to report what happens to each order.
+    /// - payment_gateway
+    ///
+    ///   The payment service used to charge for each order.
+    ///
+    /// # Returns
+    /// Returns a new instance of the OrderProcessor struct.
+    pub fn new(logger: Box<dyn ILogger>, payment_gateway: Box<dyn IPaymentGateway>) -> OrderProcessor {
+        OrderProcessor { logger, payment_gateway }
+    }
+
+    /// Process an order by charging for it.
+    ///
+    /// # Parameters
+    /// - item
+    ///
+    ///   Name of the item ordered.
+    /// - amount_cents
+    ///
+    ///   Price of the item, in cents.
+    ///
+    /// # Returns
+    /// Returns true if the order was paid for; otherwise, returns false.
+    pub fn process_order(&mut self, item: &str, amount_cents: u32) -> bool {
+        let price = format!("{}.{:02}", amount_cents / 100, amount_cents % 100);
+        self.logger.log_trace(&format!("Charging {price} for {item} using {}", self.payment_gateway.name()));
+        match self.payment_gateway.charge(amount_cents) {
+            Ok(confirmation) => {
+                self.logger.log_info(&format!("Order for {item} paid, confirmation {confirmation}"));
+                true
+            }
+            Err(reason) => {
+                self.logger.log_error(&format!("Order for {item} declined: {reason}"));
+                false
+            }
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! Contains the TestPaymentGateway struct that simulates a payment service.
+
+//-----------------------------------------------------------------------------
+
+use super::dependencyinjection_ipaymentgateway_trait::IPaymentGateway;
+
+//-----------------------------------------------------------------------------
+
+/// Represents a payment service used for testing.  Charges are approved up to
+/// a set limit; anything larger is declined.  No money changes hands.
+pub struct TestPaymentGateway {
+    /// Largest amount, in cents, that will be approved.
+    limit_cents: u32,
+    /// Number of charges approved so far, used to make confirmation codes.
+    approved_count: u32,
+}
+
+impl TestPaymentGateway {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - limit_cents
+    ///
+    ///   Largest amount, in cents, that will be approved.
+    ///
+    /// # Returns
+    /// Returns a new instance of the TestPaymentGateway struct as represented
+    /// by the IPaymentGateway trait.
+    pub fn new(limit_cents: u32) -> Box<dyn IPaymentGateway> {
+        Box::new(TestPaymentGateway { limit_cents, approved_count: 0 })
+    }
+}
+
+impl IPaymentGateway for TestPaymentGateway {
+    fn name(&self) -> &str {
+        "test payment gateway"
+    }
+
+    fn charge(&mut self, amount_cents: u32) -> Result<String, String> {
+        if amount_cents > self.limit_cents {
+            return Err(format!("amount exceeds limit of {}.{:02}", self.limit_cents / 100, self.limit_cents % 100));
+        }
+        self.approved_count += 1;
+        Ok(format!("TEST-{:04}", self.approved_count))
+    }
+}
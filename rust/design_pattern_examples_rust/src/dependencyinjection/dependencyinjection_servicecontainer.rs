@@ -0,0 +1,81 @@
+//! Contains the ServiceContainer struct that creates services on request
+//! from factories registered for each kind of service.
+
+//-----------------------------------------------------------------------------
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+
+//-----------------------------------------------------------------------------
+
+/// Retrieve the name of the given type without any module paths, for
+/// example, "dyn ILogger" instead of "dyn crate::bridge::...::ILogger".
+fn _short_type_name<T: ?Sized>() -> String {
+    std::any::type_name::<T>()
+        .split(' ')
+        .map(|word| word.rsplit("::").next().unwrap_or(word))
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/// A factory that creates a service of type T.  The factory is given the
+/// container so it can resolve any services the new service depends on.
+type ServiceFactory<T> = Box<dyn Fn(&ServiceContainer) -> Result<Box<T>, String>>;
+
+/// Represents a container of service factories.  Code that needs a service
+/// asks the container to resolve it, by type, instead of creating the service
+/// itself.  Services are usually trait objects such as `dyn ILogger`, so the
+/// code asking for the service does not know which implementation it gets.
+pub struct ServiceContainer {
+    /// The factories, keyed by the type of service each one creates.  Each
+    /// value is a ServiceFactory<T> for the type given by the key.
+    factories: HashMap<TypeId, Box<dyn Any>>,
+    /// Names of the registered service types, for display.
+    names: HashMap<TypeId, String>,
+}
+
+impl ServiceContainer {
+    /// Constructor.
+    ///
+    /// # Returns
+    /// Returns a new instance of the ServiceContainer struct with no
+    /// services registered.
+    pub fn new() -> ServiceContainer {
+        ServiceContainer { factories: HashMap::new(), names: HashMap::new() }
+    }
+
+    /// Register the factory for a type of service, replacing any factory
+    /// already registered for that type.
+    ///
+    /// # Parameters
+    /// - factory
+    ///
+    ///   Function that creates a new instance of the service.
+    pub fn register<T: ?Sized + 'static>(&mut self, factory: impl Fn(&ServiceContainer) -> Result<Box<T>, String> + 'static) {
+        let factory: ServiceFactory<T> = Box::new(factory);
+        self.factories.insert(TypeId::of::<T>(), Box::new(factory));
+        self.names.insert(TypeId::of::<T>(), _short_type_name::<T>());
+    }
+
+    /// Retrieve the names of the registered types of service, sorted.
+    pub fn registered_services(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.names.values().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// Create a new instance of a type of service.
+    ///
+    /// # Returns
+    /// Returns `Ok(Box<T>)` containing the new service if a factory was
+    /// registered for the type and it succeeded; otherwise, returns
+    /// `Err(String)` describing the problem.
+    pub fn resolve<T: ?Sized + 'static>(&self) -> Result<Box<T>, String> {
+        let factory = self.factories.get(&TypeId::of::<T>())
+            .and_then(|factory| factory.downcast_ref::<ServiceFactory<T>>());
+        match factory {
+            Some(factory) => factory(self),
+            None => Err(format!("No service registered for {}", _short_type_name::<T>())),
+        }
+    }
+}
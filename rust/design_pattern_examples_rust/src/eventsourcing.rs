@@ -0,0 +1,131 @@
+//! The Event Sourcing design pattern example module
+//!
+//! The Event Sourcing pattern stores every change to an object as an event in
+//! an append-only log, rather than storing the object's current state.  The
+//! current state is derived by replaying the events from the start.  Because
+//! nothing is ever overwritten, the state as of any earlier point can be
+//! rebuilt just as easily, and the log doubles as a complete audit trail.
+//!
+//! Compare this with the Memento exercise, which saves whole snapshots of the
+//! state, and the Command exercise, which saves the operations applied.  Here
+//! the events are the source of truth, and mementos are used only as
+//! snapshots so that a long log does not need to be replayed from the start
+//! every time.
+//!
+//! In this exercise, operations on a bank account are validated against the
+//! current state and recorded as events.  The account is then rebuilt from
+//! the log, from a snapshot plus the events after it, and as of an earlier
+//! version.
+//!
+//! Accessed through the eventsourcing_exercise() function.
+
+//-----------------------------------------------------------------------------
+
+pub mod eventsourcing_account;
+pub mod eventsourcing_events;
+pub mod eventsourcing_eventstore;
+
+//-----------------------------------------------------------------------------
+
+use eventsourcing_account::BankAccount;
+use eventsourcing_events::AccountEvent;
+use eventsourcing_eventstore::EventStore;
+
+//-----------------------------------------------------------------------------
+
+/// Take a snapshot of the account after every this many events.
+const SNAPSHOT_INTERVAL: usize = 4;
+
+//-----------------------------------------------------------------------------
+
+/// Helper function to rebuild an account as of the given version, starting
+/// from the latest snapshot at or before that version, if any.
+///
+/// # Parameters
+/// - store
+///
+///   The event store to rebuild from.
+/// - version
+///
+///   The version to rebuild.
+///
+/// # Returns
+/// Returns `Ok((BankAccount, usize))` containing the rebuilt account and the
+/// number of events that were replayed; otherwise, returns `Err(String)` if
+/// a snapshot could not be restored.
+fn _eventsourcing_rebuild(store: &EventStore, version: usize) -> Result<(BankAccount, usize), String> {
+    let mut account = match store.latest_snapshot_at(version) {
+        Some(snapshot) => BankAccount::from_memento(&snapshot.memento)?,
+        None => BankAccount::new(),
+    };
+    let events = store.events_between(account.version, version);
+    account.replay(events);
+    Ok((account, events.len()))
+}
+
+/// Example of using the "Event Sourcing" design pattern.
+///
+/// The Event Sourcing pattern stores every change to an object as an event in
+/// an append-only log and derives the current state by replaying the events.
+///
+/// In this exercise, operations on a bank account are recorded as events and
+/// the account is rebuilt from the log, from a snapshot, and as of an earlier
+/// version.
+// ! [Using Event Sourcing in Rust]
+pub fn eventsourcing_exercise() -> Result<(), String> {
+    println!();
+    println!("Event Sourcing Exercise");
+
+    let mut store = EventStore::new();
+    let mut account = BankAccount::new();
+
+    let operations = vec![
+        AccountEvent::Opened { owner: String::from("Grace") },
+        AccountEvent::Deposited { amount: 10000 },
+        AccountEvent::Withdrawn { amount: 2550 },
+        AccountEvent::Withdrawn { amount: 9000 },
+        AccountEvent::Deposited { amount: 1200 },
+        AccountEvent::Deposited { amount: 0 },
+        AccountEvent::Withdrawn { amount: 4000 },
+        AccountEvent::Closed,
+        AccountEvent::Withdrawn { amount: 4650 },
+        AccountEvent::Closed,
+    ];
+
+    println!("  Recording operations as events:");
+    for operation in operations {
+        let description = operation.to_string();
+        match account.validate(operation) {
+            Ok(event) => {
+                let version = store.append(event.clone());
+                account.apply(&event);
+                println!("    {version:2}. {description:<20} -> {account}");
+                if version.is_multiple_of(SNAPSHOT_INTERVAL) {
+                    let memento = account.get_memento();
+                    println!("        (snapshot '{}' saved)", memento.name());
+                    store.save_snapshot(version, memento)?;
+                }
+            }
+            Err(message) => println!("        {description:<20} rejected: {message}"),
+        }
+    }
+
+    println!("  Rebuilding the current state:");
+    let mut from_scratch = BankAccount::new();
+    from_scratch.replay(store.events());
+    println!("    from the full log ({} events replayed): {from_scratch}", store.version());
+    let (from_snapshot, replayed) = _eventsourcing_rebuild(&store, store.version())?;
+    println!("    from latest snapshot ({replayed} events replayed): {from_snapshot}");
+    println!("    states match: {}", from_scratch == from_snapshot && from_scratch == account);
+
+    println!("  Rebuilding earlier states (time travel):");
+    for version in [2, 3, 5] {
+        let (earlier, replayed) = _eventsourcing_rebuild(&store, version)?;
+        println!("    as of version {version} ({replayed} events replayed): {earlier}");
+    }
+
+    println!("  Done.");
+
+    Ok(())
+}
+// ! [Using Event Sourcing in Rust]
@@ -0,0 +1,146 @@
+//! Contains the BankAccount struct whose state is derived by replaying the
+//! events in the Event Sourcing example.
+
+//-----------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use crate::memento::memento_textobject::Memento;
+
+use super::eventsourcing_events::{format_cents, AccountEvent};
+
+//-----------------------------------------------------------------------------
+
+/// Represents the current state of a bank account.  The state is never
+/// changed directly; it is only ever the result of applying events.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BankAccount {
+    /// Name of the account owner.  Empty until the account is opened.
+    pub owner: String,
+    /// The balance, in cents.
+    pub balance: u64,
+    /// Whether the account is open.
+    pub is_open: bool,
+    /// Number of events applied to produce this state.
+    pub version: usize,
+}
+
+impl BankAccount {
+    /// Constructor.
+    ///
+    /// # Returns
+    /// Returns a new instance of the BankAccount struct, in the state before
+    /// any events have been applied.
+    pub fn new() -> BankAccount {
+        BankAccount::default()
+    }
+
+    /// Apply a single event to the state.  Events are facts, so applying
+    /// one never fails.
+    ///
+    /// # Parameters
+    /// - event
+    ///
+    ///   The event to apply.
+    pub fn apply(&mut self, event: &AccountEvent) {
+        match event {
+            AccountEvent::Opened { owner } => {
+                self.owner = owner.clone();
+                self.is_open = true;
+            }
+            AccountEvent::Deposited { amount } => self.balance += amount,
+            AccountEvent::Withdrawn { amount } => self.balance -= amount,
+            AccountEvent::Closed => self.is_open = false,
+        }
+        self.version += 1;
+    }
+
+    /// Apply a sequence of events to the state, in order.
+    ///
+    /// # Parameters
+    /// - events
+    ///
+    ///   The events to apply.
+    pub fn replay(&mut self, events: &[AccountEvent]) {
+        for event in events.iter() {
+            self.apply(event);
+        }
+    }
+
+    /// Decide whether an operation on the account is allowed and, if so,
+    /// produce the event that records it.  The state is not changed; the
+    /// caller records the event and then applies it.
+    ///
+    /// # Parameters
+    /// - event
+    ///
+    ///   The event the operation would produce.
+    ///
+    /// # Returns
+    /// Returns `Ok(AccountEvent)` if the operation is allowed; otherwise,
+    /// returns `Err(String)` explaining why it was rejected.
+    pub fn validate(&self, event: AccountEvent) -> Result<AccountEvent, String> {
+        match &event {
+            AccountEvent::Opened { .. } if self.is_open => {
+                return Err(String::from("account is already open"));
+            }
+            AccountEvent::Opened { .. } => {}
+            _ if !self.is_open => return Err(String::from("account is not open")),
+            AccountEvent::Deposited { amount } | AccountEvent::Withdrawn { amount } if *amount == 0 => {
+                return Err(String::from("amount must be greater than zero"));
+            }
+            AccountEvent::Withdrawn { amount } if *amount > self.balance => {
+                return Err(format!("insufficient funds (balance is {})", format_cents(self.balance)));
+            }
+            AccountEvent::Closed if self.balance != 0 => {
+                return Err(String::from("balance must be zero to close"));
+            }
+            _ => {}
+        }
+        Ok(event)
+    }
+
+    /// Take a snapshot of the state.
+    ///
+    /// # Returns
+    /// Returns a Memento object containing the state, named after the
+    /// version it represents.
+    pub fn get_memento(&self) -> Memento {
+        let text = format!("{}\t{}\t{}\t{}", self.version, self.balance, self.is_open, self.owner);
+        Memento::new(&format!("version {}", self.version), &text)
+    }
+
+    /// Create a BankAccount from a snapshot taken with get_memento().
+    ///
+    /// # Parameters
+    /// - memento
+    ///
+    ///   The snapshot to restore.
+    ///
+    /// # Returns
+    /// Returns `Ok(BankAccount)` with the restored state; otherwise, returns
+    /// `Err(String)` if the snapshot could not be read.
+    pub fn from_memento(memento: &Memento) -> Result<BankAccount, String> {
+        let fields: Vec<&str> = memento.text().splitn(4, '\t').collect();
+        if fields.len() != 4 {
+            return Err(format!("Snapshot '{}' is malformed", memento.name()));
+        }
+        let bad_field = |field: &str| format!("Snapshot '{}' has a bad {field}", memento.name());
+        Ok(BankAccount {
+            version: fields[0].parse().map_err(|_| bad_field("version"))?,
+            balance: fields[1].parse().map_err(|_| bad_field("balance"))?,
+            is_open: fields[2].parse().map_err(|_| bad_field("open flag"))?,
+            owner: fields[3].to_string(),
+        })
+    }
+}
+
+impl Display for BankAccount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let owner = if self.owner.is_empty() { "(none)" } else { &self.owner };
+        write!(f, "owner={owner}, balance={}, {}, version={}",
+            format_cents(self.balance),
+            if self.is_open { "open" } else { "closed" },
+            self.version)
+    }
+}
@@ -0,0 +1,47 @@
+//! Contains the AccountEvent enum, the facts recorded in the event log of the
+//! Event Sourcing example.
+
+//-----------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+//-----------------------------------------------------------------------------
+
+/// Represents something that happened to a bank account.  Events are facts
+/// about the past: once recorded they are never changed or removed.
+/// Amounts are in cents.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccountEvent {
+    /// The account was opened for the given owner.
+    Opened { owner: String },
+    /// Money was deposited into the account.
+    Deposited { amount: u64 },
+    /// Money was withdrawn from the account.
+    Withdrawn { amount: u64 },
+    /// The account was closed.
+    Closed,
+}
+
+impl Display for AccountEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AccountEvent::Opened { owner } => write!(f, "Opened for {owner}"),
+            AccountEvent::Deposited { amount } => write!(f, "Deposited {}", format_cents(*amount)),
+            AccountEvent::Withdrawn { amount } => write!(f, "Withdrawn {}", format_cents(*amount)),
+            AccountEvent::Closed => write!(f, "Closed"),
+        }
+    }
+}
+
+/// Format an amount in cents as dollars and cents.
+///
+/// # Parameters
+/// - cents
+///
+///   The amount to format.
+///
+/// # Returns
+/// Returns a string such as "$12.50".
+pub fn format_cents(cents: u64) -> String {
+    format!("${}.{:02}", cents / 100, cents % 100)
+}
@@ -0,0 +1,124 @@
+//! Contains the EventStore struct, the append-only log of events for the
+//! Event Sourcing example, along with the snapshots taken of the log.
+
+//-----------------------------------------------------------------------------
+
+use crate::memento::memento_textobject::Memento;
+
+use super::eventsourcing_events::AccountEvent;
+
+//-----------------------------------------------------------------------------
+
+/// A snapshot of the state derived from the first `version` events in the
+/// log.
+pub struct Snapshot {
+    /// Number of events that had been applied when the snapshot was taken.
+    pub version: usize,
+    /// The saved state.
+    pub memento: Memento,
+}
+
+/// Represents an append-only log of events.  Events can be added to the end
+/// and read back, but never changed or removed.  Snapshots of the derived
+/// state can be saved so the whole log does not need to be replayed every
+/// time.
+pub struct EventStore {
+    /// The events, oldest first.
+    events: Vec<AccountEvent>,
+    /// The snapshots, oldest first.
+    snapshots: Vec<Snapshot>,
+}
+
+impl EventStore {
+    /// Constructor.
+    ///
+    /// # Returns
+    /// Returns a new, empty instance of the EventStore struct.
+    pub fn new() -> EventStore {
+        EventStore {
+            events: vec![],
+            snapshots: vec![],
+        }
+    }
+
+    /// Add an event to the end of the log.
+    ///
+    /// # Parameters
+    /// - event
+    ///
+    ///   The event to add.
+    ///
+    /// # Returns
+    /// Returns the version of the log after adding the event (that is, the
+    /// number of events in the log).
+    pub fn append(&mut self, event: AccountEvent) -> usize {
+        self.events.push(event);
+        self.events.len()
+    }
+
+    /// Retrieve the number of events in the log.
+    pub fn version(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Retrieve the events in the log, oldest first.
+    pub fn events(&self) -> &[AccountEvent] {
+        &self.events
+    }
+
+    /// Retrieve the events in the given range of versions.
+    ///
+    /// # Parameters
+    /// - from_version
+    ///
+    ///   Number of events to skip from the start of the log.
+    /// - to_version
+    ///
+    ///   Number of events to stop at.  Limited to the size of the log.
+    ///
+    /// # Returns
+    /// Returns the events after from_version up to and including to_version.
+    pub fn events_between(&self, from_version: usize, to_version: usize) -> &[AccountEvent] {
+        let end = to_version.min(self.events.len());
+        let start = from_version.min(end);
+        &self.events[start..end]
+    }
+
+    /// Save a snapshot of the state derived from the log.
+    ///
+    /// # Parameters
+    /// - version
+    ///
+    ///   Number of events that had been applied to produce the state.
+    /// - memento
+    ///
+    ///   The saved state.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the snapshot was saved; otherwise, returns
+    /// `Err(String)` if the version is beyond the end of the log.
+    pub fn save_snapshot(&mut self, version: usize, memento: Memento) -> Result<(), String> {
+        if version > self.events.len() {
+            return Err(format!("Cannot snapshot version {version}: the log has only {} events",
+                self.events.len()));
+        }
+        self.snapshots.push(Snapshot { version, memento });
+        Ok(())
+    }
+
+    /// Retrieve the most recent snapshot taken at or before the given
+    /// version.
+    ///
+    /// # Parameters
+    /// - version
+    ///
+    ///   The latest version the snapshot may have.
+    ///
+    /// # Returns
+    /// Returns the snapshot, or None if there is no suitable snapshot.
+    pub fn latest_snapshot_at(&self, version: usize) -> Option<&Snapshot> {
+        self.snapshots.iter()
+            .filter(|snapshot| snapshot.version <= version)
+            .max_by_key(|snapshot| snapshot.version)
+    }
+}
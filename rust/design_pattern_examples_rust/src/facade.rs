@@ -11,18 +11,55 @@
 //! those scan chains, resetting the scan chains and selecting a device to
 //! appear in the scan chain.
 //!
+//! The device chains are described in JSON, so a different network can be
+//! loaded from a file given with the `--topology` option.
+//!
+//! Failures in the sub-system are reported through the facade as simple
+//! DeviceError values.  Faults can be injected into the sub-system to show
+//! how the facade translates them.
+//!
+//! A client can lock a device chain with lock_chain(), which returns a guard
+//! that releases the lock when dropped.  While locked, other clients of the
+//! same network are refused access to the device chain.
+//!
+//! Devices can be found by name or idcode without knowing which device chain
+//! they are on.
+//!
+//! The device network can be shared between threads through a clone-able
+//! DeviceNetworkHandle, with the chain locks keeping the threads from
+//! interfering with each other.
+//!
+//! Callbacks registered with on_visibility_changed() are told whenever the
+//! visible devices on a device chain change, much like the observers in the
+//! Observer pattern example.
+//!
+//! Devices are selected with a DeviceSelection, which has no limit on the
+//! number of devices on a device chain.
+//!
 //! Accessed through the facade_exercise() function.
 
 //-----------------------------------------------------------------------------
 
+pub mod facade_chainlockguard;
+pub mod facade_deviceerror;
+pub mod facade_deviceselection;
 pub mod facade_idevicenetworkhighlevel_trait;
 pub mod facade_idevicenetworklowlevel_trait;
 pub mod facade_complicatedsubsystem;
 pub mod facade_devicenetworkhighlevel;
+pub mod facade_devicenetworkhandle;
+pub mod facade_sharednetwork;
 
 //-----------------------------------------------------------------------------
 
+use facade_complicatedsubsystem::FacadeComplicatedSubSystem;
 use facade_devicenetworkhighlevel::DeviceNetworkHighLevel;
+use facade_idevicenetworkhighlevel_trait::IDeviceNetworkHighLevel;
+use facade_idevicenetworklowlevel_trait::{DeviceFault, IDeviceNetworkLowLevel};
+use facade_devicenetworkhandle::DeviceNetworkHandle;
+use facade_deviceerror::DeviceError;
+use facade_deviceselection::DeviceSelection;
+use crate::helpers::exercise_options::get_exercise_option_value;
 
 //-----------------------------------------------------------------------------
 
@@ -46,6 +83,69 @@ fn _facade_show_id_codes(chain_index : usize, idcodes: &Vec<u32>)
 }
 
 
+/// Helper function to show the report on a device network, indented to fit
+/// in with the rest of the exercise's output.
+///
+/// # Parameters
+/// - network
+///
+///   The device network to report on.
+fn _facade_show_report(network: &dyn IDeviceNetworkHighLevel) {
+    for line in network.get_network_report().lines() {
+        println!("    {line}");
+    }
+}
+
+
+/// Helper function to create a sub-system with a single synthetic device
+/// chain of the given length.  The idcode of each device is 0x60000000 plus
+/// its position on the chain.
+///
+/// # Parameters
+/// - num_devices
+///
+///   Number of devices on the device chain, including the device controller.
+///
+/// # Returns
+/// Returns `Ok(Box<dyn IDeviceNetworkLowLevel>)` containing the sub-system;
+/// otherwise, returns `Err(String)` if the sub-system could not be created.
+fn _facade_large_subsystem(num_devices: usize) -> Result<Box<dyn IDeviceNetworkLowLevel>, String> {
+    let mut devices = vec![r#"{"name":"BIG_DEVCTRL","idcode":"0x60000000","type":"controller"}"#.to_string()];
+    for index in 1..num_devices {
+        devices.push(format!(r#"{{"name":"BIG_CORE{index}","idcode":"0x{:X}","type":"core"}}"#, 0x60000000 + index));
+    }
+    FacadeComplicatedSubSystem::from_json(&format!(r#"[{{"name":"BIGCHAIN","devices":[{}]}}]"#, devices.join(",")))
+}
+
+
+/// Helper function run by a worker thread to select the devices on a device
+/// chain one at a time while holding the lock on the chain.
+///
+/// # Parameters
+/// - network
+///
+///   Handle to the device network to use.
+/// - chain_index
+///
+///   Index of the device chain to select devices on.
+///
+/// # Returns
+/// Returns `Ok(Vec<u32>)` containing the idcodes of the devices visible when
+/// the worker is done; otherwise, returns `Err(DeviceError)` describing why
+/// the worker could not finish.
+fn _facade_select_devices_worker(network: DeviceNetworkHandle, chain_index: usize) -> Result<Vec<u32>, DeviceError> {
+    let mut client = network.client();
+    let chain_lock = client.lock_chain(chain_index)?;
+    chain_lock.reset()?;
+    for device_index in 1..chain_lock.num_devices() {
+        chain_lock.enable_devices(&DeviceSelection::new().select(device_index))?;
+        // Give the other worker a chance to run at the same time.
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+    chain_lock.get_idcodes()
+}
+
+
 /// Example of using the "Facade" pattern.
 /// 
 /// The Facade pattern is used when a simplified version of an
@@ -65,21 +165,110 @@ pub fn facade_exercise() -> Result<(), String> {
     println!("");
     println!("Facade Exercise");
 
-    let mut device_chain_facade = DeviceNetworkHighLevel::new();
+    let mut device_chain_facade = match get_exercise_option_value("--topology") {
+        Some(path) => {
+            println!("  Using the device chains in \"{path}\"");
+            DeviceNetworkHighLevel::from_topology_file(&path)?
+        }
+        None => DeviceNetworkHighLevel::new(),
+    };
     let chain_count = device_chain_facade.num_chains();
 
-    println!("  Showing idcodes of devices after a device reset (expect one device on each chain)...");
+    println!("  Showing the network after a device reset (expect one visible device on each chain)...");
     for chain_index in 0..chain_count {
-        device_chain_facade.disable_devices_in_device_chain(chain_index);
-        let idcodes = device_chain_facade.get_idcodes(chain_index);
-        _facade_show_id_codes(chain_index, &idcodes);
+        device_chain_facade.disable_devices_in_device_chain(chain_index)?;
     }
+    _facade_show_report(device_chain_facade.as_ref());
 
-    println!("  Showing idcodes of devices after selecting all devices...");
+    println!("  Showing the network after selecting all devices...");
     for chain_index in 0..chain_count {
-        device_chain_facade.enable_devices_in_device_chain(chain_index, 0xffffffff);
-        let idcodes = device_chain_facade.get_idcodes(chain_index);
-        _facade_show_id_codes(chain_index, &idcodes);
+        device_chain_facade.enable_devices_in_device_chain(chain_index, &DeviceSelection::new().select_all())?;
+    }
+    _facade_show_report(device_chain_facade.as_ref());
+
+    println!("  Showing idcodes after finding DDD_PCH0 and selecting only that device...");
+    match device_chain_facade.find_device_by_name("DDD_PCH0") {
+        Some(location) => {
+            println!("    DDD_PCH0 is device {} on chain {}", location.device_index, location.chain_index);
+            device_chain_facade.disable_devices_in_device_chain(location.chain_index)?;
+            device_chain_facade.enable_devices_in_device_chain(location.chain_index, &location.selection())?;
+            let idcodes = device_chain_facade.get_idcodes(location.chain_index)?;
+            _facade_show_id_codes(location.chain_index, &idcodes);
+        }
+        None => println!("    There is no DDD_PCH0 in this network"),
+    }
+    let idcode = 0x30303030;
+    match device_chain_facade.find_chain_containing(idcode) {
+        Some(location) => println!("    Idcode 0x{idcode:X} is device {} on chain {}", location.device_index, location.chain_index),
+        None => println!("    There is no device with idcode 0x{idcode:X} in this network"),
+    }
+
+    println!("  Showing that a device chain locked by one client is refused to another...");
+    let mut other_client = device_chain_facade.new_client();
+    {
+        let chain_lock = device_chain_facade.lock_chain(0)?;
+        println!("    Client 1 locked chain {}", chain_lock.chain_index());
+        chain_lock.reset()?;
+        println!("    Client 1 reset the chain");
+        _facade_show_id_codes(chain_lock.chain_index(), &chain_lock.get_idcodes()?);
+        if let Err(error) = other_client.enable_devices_in_device_chain(0, &DeviceSelection::new().select_all()) {
+            println!("    Client 2 could not select devices: {error}");
+        }
+    }
+    println!("    Client 1 released its lock");
+    other_client.enable_devices_in_device_chain(0, &DeviceSelection::new().select_all())?;
+    println!("    Client 2 selected all devices");
+    _facade_show_id_codes(0, &other_client.get_idcodes(0)?);
+
+    println!("  Showing idcodes after two threads select devices on different chains at the same time...");
+    let network = device_chain_facade.handle();
+    let workers: Vec<_> = (0..chain_count.min(2)).map(|chain_index| {
+        let network = network.clone();
+        std::thread::spawn(move || _facade_select_devices_worker(network, chain_index))
+    }).collect();
+    for (chain_index, worker) in workers.into_iter().enumerate() {
+        match worker.join() {
+            Ok(Ok(idcodes)) => _facade_show_id_codes(chain_index, &idcodes),
+            Ok(Err(error)) => println!("    On chain {chain_index}, error = {error}"),
+            Err(_) => println!("    On chain {chain_index}, the worker thread panicked"),
+        }
+    }
+
+    println!("  Showing the visibility changes reported to a callback on a new network...");
+    let mut observed_facade = DeviceNetworkHighLevel::new();
+    observed_facade.on_visibility_changed(Box::new(|chain_index, idcodes| {
+        print!("    Visibility changed on chain {chain_index}, idcodes = [ ");
+        for idcode in idcodes {
+            print!("0x{idcode:X} ");
+        }
+        println!("]");
+    }));
+    println!("    Resetting chain 0 (no change expected)");
+    observed_facade.disable_devices_in_device_chain(0)?;
+    println!("    Selecting device 1 on chain 0");
+    observed_facade.enable_devices_in_device_chain(0, &DeviceSelection::from(0b10))?;
+    println!("    Selecting device 1 on chain 0 again (no change expected)");
+    observed_facade.enable_devices_in_device_chain(0, &DeviceSelection::new().select(1))?;
+    println!("    Resetting chain 0");
+    observed_facade.disable_devices_in_device_chain(0)?;
+
+    println!("  Showing idcodes after selecting devices past the 32nd on a chain of 100 devices...");
+    let mut large_facade = DeviceNetworkHighLevel::with_subsystem(_facade_large_subsystem(100)?);
+    let selection = DeviceSelection::new().select_range(30..35).select_indices(&[64, 99]);
+    large_facade.enable_devices_in_device_chain(0, &selection)?;
+    _facade_show_id_codes(0, &large_facade.get_idcodes(0)?);
+
+    println!("  Showing the errors reported after injecting faults into the default network...");
+    let mut low_level_system = FacadeComplicatedSubSystem::new();
+    low_level_system.inject_fault(0, DeviceFault::Locked);
+    low_level_system.inject_fault(1, DeviceFault::Unresponsive);
+    let mut faulty_facade = DeviceNetworkHighLevel::with_subsystem(low_level_system);
+    let chain_count = faulty_facade.num_chains();
+    for chain_index in 0..=chain_count {
+        match faulty_facade.get_idcodes(chain_index) {
+            Ok(idcodes) => _facade_show_id_codes(chain_index, &idcodes),
+            Err(error) => println!("    On chain {chain_index}, error = {error}"),
+        }
     }
 
     println!("  Done.");
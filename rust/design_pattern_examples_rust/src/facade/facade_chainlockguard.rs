@@ -0,0 +1,151 @@
+//! Contains the ChainLockGuard struct that holds the lock on a device chain
+//! for as long as it exists.
+
+use std::sync::Arc;
+
+use super::facade_deviceerror::DeviceError;
+use super::facade_deviceselection::DeviceSelection;
+use super::facade_idevicenetworklowlevel_trait::IDeviceNetworkLowLevel;
+use super::facade_sharednetwork::SharedNetwork;
+
+//-----------------------------------------------------------------------------
+
+/// Represents exclusive access to a device chain, obtained from
+/// IDeviceNetworkHighLevel::lock_chain().  While the guard exists, any other
+/// attempt to access the device chain is refused with
+/// DeviceError::ChainLocked; the guard's own methods are the only way to
+/// access the device chain.  The lock is released when the guard is dropped.
+/// Any change the guard makes to which devices are visible is reported to
+/// the network's visibility callbacks.
+/// Part of the "Facade" pattern example.
+pub struct ChainLockGuard {
+    /// The device network containing the locked device chain.
+    network: Arc<SharedNetwork>,
+
+    /// Index of the locked device chain.
+    chain_index: usize,
+}
+
+impl ChainLockGuard {
+    /// Lock a device chain and return a guard for it.
+    ///
+    /// # Parameters
+    /// - network
+    ///
+    ///   The device network containing the device chain.
+    /// - chain_index
+    ///
+    ///   Index of the device chain (0..n-1).
+    ///
+    /// # Returns
+    /// Returns `Ok(ChainLockGuard)` holding the lock; otherwise, returns
+    /// `Err(DeviceError)` if the chain index is out of range or the device
+    /// chain is already locked.
+    pub(crate) fn lock(network: &Arc<SharedNetwork>, chain_index: usize) -> Result<ChainLockGuard, DeviceError> {
+        let mut system = network.low_level_system();
+        let num_chains = system.get_num_chains();
+        if chain_index >= num_chains {
+            return Err(DeviceError::ChainIndexOutOfRange { chain_index, num_chains });
+        }
+        if !system.lock_device_chain(chain_index) {
+            return Err(DeviceError::ChainLocked { chain_index });
+        }
+        Ok(ChainLockGuard {
+            network: network.clone(),
+            chain_index,
+        })
+    }
+
+    /// Retrieve the index of the locked device chain.
+    pub fn chain_index(&self) -> usize {
+        self.chain_index
+    }
+
+    /// Retrieve the number of devices on the locked device chain, visible
+    /// or not, including the device controller.
+    pub fn num_devices(&self) -> usize {
+        self.network.low_level_system().get_devices(self.chain_index).len()
+    }
+
+    /// Retrieve the idcodes of all visible devices in the locked device
+    /// chain.
+    ///
+    /// # Returns
+    /// Returns `Ok(Vec<u32>)` containing the idcodes; otherwise, returns
+    /// `Err(DeviceError)` if the devices did not respond.
+    pub fn get_idcodes(&self) -> Result<Vec<u32>, DeviceError> {
+        // The device controller is always visible, so a responsive chain
+        // always returns at least one idcode.
+        let idcodes = self.network.low_level_system().get_idcodes(self.chain_index);
+        match idcodes.is_empty() {
+            true => Err(DeviceError::DeviceUnresponsive { chain_index: self.chain_index }),
+            false => Ok(idcodes),
+        }
+    }
+
+    /// Make visible the specified devices in the locked device chain.
+    ///
+    /// # Parameters
+    /// - selection
+    ///
+    ///   The positions of the devices to make visible.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the devices were made visible; otherwise, returns
+    /// `Err(DeviceError)` if the devices did not respond.
+    pub fn enable_devices(&self, selection: &DeviceSelection) -> Result<(), DeviceError> {
+        self._change_visibility(|system, chain_index| system.enable_devices_in_device_chain(chain_index, selection))
+    }
+
+    /// Reset the locked device chain so only the device controller is
+    /// visible.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the device chain was reset; otherwise, returns
+    /// `Err(DeviceError)` if the devices did not respond.
+    pub fn reset(&self) -> Result<(), DeviceError> {
+        self._change_visibility(|system, chain_index| system.reset_device_chain(chain_index))
+    }
+
+    /// Helper method to change which devices are visible on the locked
+    /// device chain, report the change to the visibility callbacks if the
+    /// visible devices are different, and translate whether the devices
+    /// responded into a DeviceError.
+    ///
+    /// # Parameters
+    /// - change
+    ///
+    ///   Makes the change on the low level sub-system, given the index of the
+    ///   device chain.  Returns true if the devices responded.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the devices responded; otherwise, returns
+    /// `Err(DeviceError)`.
+    fn _change_visibility<F>(&self, change: F) -> Result<(), DeviceError>
+    where
+        F: FnOnce(&mut dyn IDeviceNetworkLowLevel, usize) -> bool,
+    {
+        let (responded, changed_idcodes) = {
+            let mut system = self.network.low_level_system();
+            let idcodes_before = system.get_idcodes(self.chain_index);
+            let responded = change(system.as_mut(), self.chain_index);
+            let idcodes_after = system.get_idcodes(self.chain_index);
+            (responded, (idcodes_after != idcodes_before).then_some(idcodes_after))
+        };
+        // The sub-system is no longer locked, so the callbacks can use it.
+        if let Some(idcodes) = changed_idcodes {
+            self.network.notify_visibility_changed(self.chain_index, &idcodes);
+        }
+        match responded {
+            true => Ok(()),
+            false => Err(DeviceError::DeviceUnresponsive { chain_index: self.chain_index }),
+        }
+    }
+}
+
+impl Drop for ChainLockGuard {
+    /// Release the lock on the device chain.
+    fn drop(&mut self) {
+        self.network.low_level_system().unlock_device_chain(self.chain_index);
+    }
+}
@@ -1,6 +1,40 @@
 //! Contains the implementation of the complicated facade sub-system.
+//!
+//! The device chains in the sub-system are described in JSON, which can be
+//! loaded from a file.  A description looks like this, where "type" is one
+//! of "controller", "core", "gte", "pch" or "pmc" (the controller is always
+//! visible and is always the first device in its chain), "idcode" is in hex,
+//! and "visible" is optional, defaulting to false:
+//! ```text
+//! [
+//!   {"name":"CHAIN0","devices":[
+//!     {"name":"DDD_DEVCTRL0","idcode":"0x10101010","type":"controller"},
+//!     {"name":"DDD_CORE0","idcode":"0x20202020","type":"core","visible":true}
+//!   ]}
+//! ]
+//! ```
+
+use crate::helpers::json::JsonReader;
+
+use super::facade_deviceselection::DeviceSelection;
+use super::facade_idevicenetworklowlevel_trait::{DeviceFault, DeviceInfo, IDeviceNetworkLowLevel};
 
-use super::facade_idevicenetworklowlevel_trait::IDeviceNetworkLowLevel;
+//-----------------------------------------------------------------------------
+
+/// The device chains the sub-system is given if no other description is
+/// provided.
+const DEFAULT_TOPOLOGY: &str = r#"[
+  {"name":"CHAIN0","devices":[
+    {"name":"DDD_DEVCTRL0","idcode":"0x10101010","type":"controller"},
+    {"name":"DDD_CORE0","idcode":"0x20202020","type":"core"},
+    {"name":"DDD_GTE0","idcode":"0x30303030","type":"gte"}
+  ]},
+  {"name":"CHAIN1","devices":[
+    {"name":"DDD_DEVCTRL1","idcode":"0x10101011","type":"controller"},
+    {"name":"DDD_PCH0","idcode":"0x40404040","type":"pch"},
+    {"name":"DDD_PMC0","idcode":"0x50505050","type":"pmc"}
+  ]}
+]"#;
 
 //-----------------------------------------------------------------------------
 
@@ -19,6 +53,42 @@ enum DeviceTypes {
     PMC,
 }
 
+impl DeviceTypes {
+    /// Retrieve the name of the device type as used in a description of the
+    /// device chains.
+    fn name(&self) -> &'static str {
+        match self {
+            DeviceTypes::DEVICECONTROLLER => "controller",
+            DeviceTypes::CORE => "core",
+            DeviceTypes::GTE => "gte",
+            DeviceTypes::PCH => "pch",
+            DeviceTypes::PMC => "pmc",
+        }
+    }
+
+    /// Look up a device type by the name used in a description of the
+    /// device chains.
+    ///
+    /// # Parameters
+    /// - name
+    ///
+    ///   Name of the device type, such as "core".  Case is ignored.
+    ///
+    /// # Returns
+    /// Returns an Option<> containing the device type, or None if the name is
+    /// not a known device type.
+    fn from_name(name: &str) -> Option<DeviceTypes> {
+        match name.to_lowercase().as_str() {
+            "controller" => Some(DeviceTypes::DEVICECONTROLLER),
+            "core" => Some(DeviceTypes::CORE),
+            "gte" => Some(DeviceTypes::GTE),
+            "pch" => Some(DeviceTypes::PCH),
+            "pmc" => Some(DeviceTypes::PMC),
+            _ => None,
+        }
+    }
+}
+
 //=============================================================================
 
 /// Represents a single device in a device chain.
@@ -80,6 +150,9 @@ struct DeviceChain {
 
     /// Whether this device chain is locked for access.
     locked: bool,
+
+    /// The fault injected into this device chain, if any.
+    fault: DeviceFault,
 }
 
 impl DeviceChain {
@@ -96,6 +169,7 @@ impl DeviceChain {
         DeviceChain {
             _name: name.to_string(),
             locked: false,
+            fault: DeviceFault::None,
             _nodes: vec![]
         }
     }
@@ -132,27 +206,26 @@ impl DeviceChain {
     /// Make visible one or more devices in the device chain.
     ///
     /// # Parameters
-    /// - node_select_mask
+    /// - selection
     ///
-    ///   A bit mask specifying which device or devices to make visible, where
-    ///   bit 0 is the first device, bit 1 is the second, etc.  Bit 0 is
+    ///   Specifies which device or devices to make visible, where position 0
+    ///   is the first device, position 1 is the second, etc.  Position 0 is
     ///   ignored as the first device is always visible.
-    pub fn select_nodes(&mut self, node_select_mask: u32) {
-        self._show_hide_nodes(node_select_mask, true);
-
+    pub fn select_nodes(&mut self, selection: &DeviceSelection) {
+        self._show_hide_nodes(selection, true);
     }
 
     /// Make invisible one or more devices in the device chain.
     ///
     ///
     /// # Parameters
-    /// - node_select_mask
+    /// - selection
     ///
-    /// A bit mask specifying which device or devices to hide, where bit 0 is
-    /// the first device, bit 1 is the second, etc.  Bit 0 is ignored as the
-    ///  first device is always visible.
-    pub fn deselect_nodes(&mut self, node_select_mask: u32) {
-        self._show_hide_nodes(node_select_mask, false);
+    ///   Specifies which device or devices to hide, where position 0 is the
+    ///   first device, position 1 is the second, etc.  Position 0 is ignored
+    ///   as the first device is always visible.
+    pub fn deselect_nodes(&mut self, selection: &DeviceSelection) {
+        self._show_hide_nodes(selection, false);
     }
    
     /// Retrieve a list of idcodes for all devices that are visible in
@@ -177,28 +250,20 @@ impl DeviceChain {
     /// Helper method to show or hide devices on the device chain.
     ///
     /// # Parameters
-    /// - node_select_mask
+    /// - selection
     ///
-    ///   A bit mask where the position of each bit corresponds to a device in
-    ///   the device chain, with bit 0 being the first device, bit 1 being the
-    ///   second device, and so on.
+    ///   Positions of the devices in the device chain, with position 0 being
+    ///   the first device, position 1 being the second device, and so on.
     /// - make_visible
     ///
     ///   true if the device is to be made visible on the device chain;
     ///   otherwise false, the device cannot be seen on the device chain.
-    fn _show_hide_nodes(&mut self, node_select_mask: u32, make_visible: bool) {
-        let mut bit_mask: u32 = 0x2; // bit 0 is always the DEVICECONTROLLER and is always selected
-        let num_nodes = self._nodes.len();
-
-        // Start at the device after the DEVICECONTROLLER
-        for index in 1..num_nodes {
-            if (bit_mask & node_select_mask) != 0 {
-                self._nodes[index].visible = make_visible;
-            }
-            bit_mask <<= 1;
-            if bit_mask == 0 {
-                // We don't allow more than 32 devices
-                break;
+    fn _show_hide_nodes(&mut self, selection: &DeviceSelection, make_visible: bool) {
+        // Start at the device after the DEVICECONTROLLER, which is always
+        // visible.
+        for (index, node) in self._nodes.iter_mut().enumerate().skip(1) {
+            if selection.is_selected(index) {
+                node.visible = make_visible;
             }
         }
     }
@@ -222,22 +287,111 @@ pub struct FacadeComplicatedSubSystem {
 }
 
 impl FacadeComplicatedSubSystem {
-    /// Constructor.
+    /// Constructor for the default set of device chains.
     pub fn new() -> Box<dyn IDeviceNetworkLowLevel> {
-        let mut device_chain0 = DeviceChain::new("CHAIN0");
-        let mut device_chain1 = DeviceChain::new("CHAIN1");
+        // The default description is part of the program so is known to be
+        // good.
+        FacadeComplicatedSubSystem::from_json(DEFAULT_TOPOLOGY).unwrap()
+    }
 
-        device_chain0.add_node(DeviceNode::new("DDD_DEVCTRL0", 0x10101010, DeviceTypes::DEVICECONTROLLER, true));
-        device_chain0.add_node(DeviceNode::new("DDD_CORE0", 0x20202020, DeviceTypes::CORE, false));
-        device_chain0.add_node(DeviceNode::new("DDD_GTE0", 0x30303030, DeviceTypes::GTE, false));
+    /// Constructor for the device chains in a JSON description (see the
+    /// top of this file for the form of the description).
+    ///
+    /// # Parameters
+    /// - json
+    ///
+    ///   The JSON description of the device chains.
+    ///
+    /// # Returns
+    /// Returns `Ok(Box<dyn IDeviceNetworkLowLevel>)` containing the new
+    /// sub-system; otherwise, returns `Err(String)` describing what is wrong
+    /// with the description.
+    pub fn from_json(json: &str) -> Result<Box<dyn IDeviceNetworkLowLevel>, String> {
+        let mut reader = JsonReader::new(json);
+        let device_chains = reader.read_array(FacadeComplicatedSubSystem::_read_chain)?;
+        reader.expect_end("the list of device chains")?;
+        if device_chains.is_empty() {
+            return Err("There are no device chains in the description".to_string());
+        }
+        Ok(Box::new(FacadeComplicatedSubSystem { device_chains }))
+    }
 
-        device_chain1.add_node(DeviceNode::new("DDD_DEVCTRL1", 0x10101011, DeviceTypes::DEVICECONTROLLER, true));
-        device_chain1.add_node(DeviceNode::new("DDD_PCH0", 0x40404040, DeviceTypes::PCH, false));
-        device_chain1.add_node(DeviceNode::new("DDD_PMC0", 0x50505050, DeviceTypes::PMC, false));
+    /// Helper method to read a device chain, which is an object with a
+    /// "name" string and a "devices" array of devices, starting at the next
+    /// character.
+    ///
+    /// # Parameters
+    /// - reader
+    ///
+    ///   The JsonReader to read from.
+    ///
+    /// # Returns
+    /// Returns `Ok(DeviceChain)` containing the device chain; otherwise,
+    /// returns `Err(String)` describing what is wrong with the JSON.
+    fn _read_chain(reader: &mut JsonReader) -> Result<DeviceChain, String> {
+        let mut name = None;
+        let mut nodes = vec![];
+        reader.read_object(|reader, key| {
+            match key {
+                "name" => name = Some(reader.read_string()?),
+                "devices" => nodes = reader.read_array(FacadeComplicatedSubSystem::_read_node)?,
+                _ => return Err(format!("Unknown field \"{key}\" in a device chain")),
+            }
+            Ok(())
+        })?;
+        let name = name.ok_or("A device chain has no \"name\" field".to_string())?;
+        let mut device_chain = DeviceChain::new(&name);
+        for node in nodes {
+            device_chain.add_node(node);
+        }
+        match device_chain._nodes.first() {
+            Some(node) if matches!(node.device_type, DeviceTypes::DEVICECONTROLLER) => Ok(device_chain),
+            _ => Err(format!("The device chain \"{name}\" has no device controller")),
+        }
+    }
 
-        Box::new(FacadeComplicatedSubSystem {
-            device_chains: vec![device_chain0, device_chain1],
-        })
+    /// Helper method to read a device, which is an object with "name",
+    /// "idcode" and "type" strings and an optional "visible" boolean,
+    /// starting at the next character.
+    ///
+    /// # Parameters
+    /// - reader
+    ///
+    ///   The JsonReader to read from.
+    ///
+    /// # Returns
+    /// Returns `Ok(DeviceNode)` containing the device; otherwise, returns
+    /// `Err(String)` describing what is wrong with the JSON.
+    fn _read_node(reader: &mut JsonReader) -> Result<DeviceNode, String> {
+        let mut name = None;
+        let mut idcode = None;
+        let mut device_type = None;
+        let mut visible = false;
+        reader.read_object(|reader, key| {
+            match key {
+                "name" => name = Some(reader.read_string()?),
+                "idcode" => {
+                    let text = reader.read_string()?;
+                    let digits = text.trim_start_matches("0x").trim_start_matches("0X");
+                    idcode = Some(u32::from_str_radix(digits, 16)
+                        .map_err(|_| format!("\"{text}\" is not a 32-bit idcode in hex"))?);
+                }
+                "type" => {
+                    let text = reader.read_string()?;
+                    device_type = Some(DeviceTypes::from_name(&text)
+                        .ok_or(format!("\"{text}\" is not a device type"))?);
+                }
+                "visible" => visible = reader.read_bool()?,
+                _ => return Err(format!("Unknown field \"{key}\" in a device")),
+            }
+            Ok(())
+        })?;
+        let name = name.ok_or("A device has no \"name\" field".to_string())?;
+        let idcode = idcode.ok_or(format!("The device \"{name}\" has no \"idcode\" field"))?;
+        let device_type = device_type.ok_or(format!("The device \"{name}\" has no \"type\" field"))?;
+        // The device controller is always visible.
+        let visible = visible || matches!(device_type, DeviceTypes::DEVICECONTROLLER);
+        Ok(DeviceNode::new(&name, idcode, device_type, visible))
     }
 }
 
@@ -249,7 +403,9 @@ impl IDeviceNetworkLowLevel for FacadeComplicatedSubSystem {
     fn lock_device_chain(&mut self, chain_index: usize) -> bool {
         let mut locked = false;
         if chain_index < self.device_chains.len() {
-            if !self.device_chains[chain_index].locked {
+            // A chain with a Locked fault is held by another client.
+            let held_elsewhere = self.device_chains[chain_index].fault == DeviceFault::Locked;
+            if !held_elsewhere && !self.device_chains[chain_index].locked {
                 self.device_chains[chain_index].locked = true;
                 locked = true;
             }
@@ -268,31 +424,77 @@ impl IDeviceNetworkLowLevel for FacadeComplicatedSubSystem {
         unlocked
     }
 
-    fn reset_device_chain(&mut self, chain_index: usize) {
-        if chain_index < self.device_chains.len() {
+    fn reset_device_chain(&mut self, chain_index: usize) -> bool {
+        let mut responded = false;
+        if chain_index < self.device_chains.len() && self.device_chains[chain_index].fault != DeviceFault::Unresponsive {
             self.device_chains[chain_index].reset_visibility();
+            responded = true;
         }
+        responded
     }
 
-    fn enable_devices_in_device_chain(&mut self, chain_index: usize, devices_select_mask: u32) {
-        if chain_index < self.device_chains.len() {
-            self.device_chains[chain_index].select_nodes(devices_select_mask);
+    fn enable_devices_in_device_chain(&mut self, chain_index: usize, selection: &DeviceSelection) -> bool {
+        let mut responded = false;
+        if chain_index < self.device_chains.len() && self.device_chains[chain_index].fault != DeviceFault::Unresponsive {
+            self.device_chains[chain_index].select_nodes(selection);
+            responded = true;
         }
+        responded
     }
 
-    fn disable_devices_in_device_chain(&mut self, chain_index: usize, devices_select_mask: u32) {
-        if chain_index < self.device_chains.len() {
-            self.device_chains[chain_index].deselect_nodes(devices_select_mask);
+    fn disable_devices_in_device_chain(&mut self, chain_index: usize, selection: &DeviceSelection) -> bool {
+        let mut responded = false;
+        if chain_index < self.device_chains.len() && self.device_chains[chain_index].fault != DeviceFault::Unresponsive {
+            self.device_chains[chain_index].deselect_nodes(selection);
+            responded = true;
         }
+        responded
     }
 
     fn get_idcodes(&self, chain_index: usize) -> Vec<u32> {
         let mut idcodes: Vec<u32> = vec![];
 
-        if chain_index < self.device_chains.len() {
+        if chain_index < self.device_chains.len() && self.device_chains[chain_index].fault != DeviceFault::Unresponsive {
             idcodes = self.device_chains[chain_index].get_id_codes_for_visible_nodes();
         }
 
         idcodes
     }
+
+    fn get_chain_name(&self, chain_index: usize) -> String {
+        match self.device_chains.get(chain_index) {
+            Some(device_chain) => device_chain._name.clone(),
+            None => String::new(),
+        }
+    }
+
+    fn is_device_chain_locked(&self, chain_index: usize) -> bool {
+        match self.device_chains.get(chain_index) {
+            Some(device_chain) => device_chain.locked || device_chain.fault == DeviceFault::Locked,
+            None => false,
+        }
+    }
+
+    fn get_devices(&self, chain_index: usize) -> Vec<DeviceInfo> {
+        let mut devices: Vec<DeviceInfo> = vec![];
+
+        if chain_index < self.device_chains.len() {
+            for node in self.device_chains[chain_index]._nodes.iter() {
+                devices.push(DeviceInfo {
+                    name: node._name.clone(),
+                    idcode: node.idcode,
+                    device_type: node.device_type.name().to_string(),
+                    visible: node.visible,
+                });
+            }
+        }
+
+        devices
+    }
+
+    fn inject_fault(&mut self, chain_index: usize, fault: DeviceFault) {
+        if chain_index < self.device_chains.len() {
+            self.device_chains[chain_index].fault = fault;
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,48 @@
+//! Contains the DeviceError enum that represents the ways an operation on the
+//! simplified view of the device network can fail.
+
+use std::error::Error;
+use std::fmt::Display;
+
+//-----------------------------------------------------------------------------
+
+/// Represents an error from an operation on the IDeviceNetworkHighLevel
+/// interface.  The low level sub-system reports failures in a variety of
+/// ways (a lock that could not be taken, a chain that returns nothing); the
+/// facade translates all of these into one of these simple errors.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DeviceError {
+    /// The chain index is not less than the number of device chains.
+    ChainIndexOutOfRange { chain_index: usize, num_chains: usize },
+    /// The device chain is locked by someone else.
+    ChainLocked { chain_index: usize },
+    /// The devices on the device chain did not respond.
+    DeviceUnresponsive { chain_index: usize },
+}
+
+impl Display for DeviceError {
+    /// Formats the error as a sentence describing what went wrong.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeviceError::ChainIndexOutOfRange { chain_index, num_chains } => {
+                write!(f, "Chain index {chain_index} is out of range (there are {num_chains} device chains)")
+            }
+            DeviceError::ChainLocked { chain_index } => {
+                write!(f, "Device chain {chain_index} is locked by someone else")
+            }
+            DeviceError::DeviceUnresponsive { chain_index } => {
+                write!(f, "The devices on chain {chain_index} are not responding")
+            }
+        }
+    }
+}
+
+impl Error for DeviceError {}
+
+impl From<DeviceError> for String {
+    /// Allows a DeviceError to be returned with `?` from functions that
+    /// report errors as strings.
+    fn from(error: DeviceError) -> String {
+        error.to_string()
+    }
+}
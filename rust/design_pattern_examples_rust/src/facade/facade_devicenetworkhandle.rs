@@ -0,0 +1,41 @@
+//! Contains the DeviceNetworkHandle struct that lets a device network be
+//! shared between threads.
+
+use std::sync::Arc;
+
+use super::facade_devicenetworkhighlevel::DeviceNetworkHighLevel;
+use super::facade_idevicenetworkhighlevel_trait::IDeviceNetworkHighLevel;
+use super::facade_sharednetwork::SharedNetwork;
+
+//-----------------------------------------------------------------------------
+
+/// Represents a device network that can be cloned and sent to other threads.
+/// Each thread creates its own client from the handle; all clients share
+/// the same device chains, so the chain locks keep threads from interfering
+/// with each other.  Obtained from IDeviceNetworkHighLevel::handle().
+/// Part of the "Facade" pattern example.
+#[derive(Clone)]
+pub struct DeviceNetworkHandle {
+    /// The device network shared by all clients.
+    network: Arc<SharedNetwork>,
+}
+
+impl DeviceNetworkHandle {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - network
+    ///
+    ///   The shared device network.
+    pub(crate) fn new(network: Arc<SharedNetwork>) -> DeviceNetworkHandle {
+        DeviceNetworkHandle { network }
+    }
+
+    /// Create a client of the device network for use on the current thread.
+    ///
+    /// # Returns
+    /// Returns the IDeviceNetworkHighLevel interface for the new client.
+    pub fn client(&self) -> Box<dyn IDeviceNetworkHighLevel> {
+        DeviceNetworkHighLevel::from_shared(self.network.clone())
+    }
+}
@@ -2,54 +2,169 @@
 //! complicated facade sub-system and which is exposed by the IDeviceNetworkHighLevel
 //! trait.
 
+use std::sync::Arc;
+
+use super::facade_chainlockguard::ChainLockGuard;
 use super::facade_complicatedsubsystem::FacadeComplicatedSubSystem;
-use super::facade_idevicenetworklowlevel_trait::IDeviceNetworkLowLevel;
-use super::facade_idevicenetworkhighlevel_trait::IDeviceNetworkHighLevel;
+use super::facade_idevicenetworklowlevel_trait::{DeviceInfo, IDeviceNetworkLowLevel};
+use super::facade_idevicenetworkhighlevel_trait::{DeviceLocation, IDeviceNetworkHighLevel, VisibilityCallback};
+use super::facade_deviceerror::DeviceError;
+use super::facade_devicenetworkhandle::DeviceNetworkHandle;
+use super::facade_deviceselection::DeviceSelection;
+use super::facade_sharednetwork::SharedNetwork;
 
 /// This struct wraps the IDeviceNetworkLowLevel interface and implements
 /// the high level IDeviceNetworkHighLevel interface, which is a simpler
 /// interface.  All calls on the high level interface are forwarded to the
-/// appropriate low level interface.
+/// appropriate low level interface, with any failure translated into a
+/// DeviceError.  Clients created with new_client() or from a
+/// DeviceNetworkHandle share the same sub-system, so a device chain locked
+/// by one client is refused to the others, even on other threads.
 /// Part of the "Facade" pattern example.
 pub struct DeviceNetworkHighLevel {
-    low_level_system: Box<dyn IDeviceNetworkLowLevel>,
+    network: Arc<SharedNetwork>,
 }
 
 impl DeviceNetworkHighLevel {
     /// Constructor.
     pub fn new() -> Box<dyn IDeviceNetworkHighLevel> {
-        Box::new(DeviceNetworkHighLevel {
-            low_level_system : FacadeComplicatedSubSystem::new(),
-        })
+        DeviceNetworkHighLevel::with_subsystem(FacadeComplicatedSubSystem::new())
+    }
+
+    /// Constructor for a facade over the given low level sub-system.
+    ///
+    /// # Parameters
+    /// - low_level_system
+    ///
+    ///   The low level sub-system to wrap.
+    ///
+    /// # Returns
+    /// Returns the IDeviceNetworkHighLevel interface over the sub-system.
+    pub fn with_subsystem(low_level_system: Box<dyn IDeviceNetworkLowLevel>) -> Box<dyn IDeviceNetworkHighLevel> {
+        DeviceNetworkHighLevel::from_shared(SharedNetwork::new(low_level_system))
+    }
+
+    /// Constructor for another client of an already shared device network.
+    ///
+    /// # Parameters
+    /// - network
+    ///
+    ///   The shared device network.
+    ///
+    /// # Returns
+    /// Returns the IDeviceNetworkHighLevel interface over the network.
+    pub(crate) fn from_shared(network: Arc<SharedNetwork>) -> Box<dyn IDeviceNetworkHighLevel> {
+        Box::new(DeviceNetworkHighLevel { network })
+    }
+
+    /// Constructor for a network whose device chains are described in a
+    /// JSON file (see facade_complicatedsubsystem.rs for the form of the
+    /// description).
+    ///
+    /// # Parameters
+    /// - path
+    ///
+    ///   Path to the file describing the device chains.
+    ///
+    /// # Returns
+    /// Returns `Ok(Box<dyn IDeviceNetworkHighLevel>)` containing the new
+    /// network; otherwise, returns `Err(String)` if the file cannot be read
+    /// or does not describe a valid network.
+    pub fn from_topology_file(path: &str) -> Result<Box<dyn IDeviceNetworkHighLevel>, String> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|error| format!("Could not read the topology file \"{path}\": {error}"))?;
+        let low_level_system = FacadeComplicatedSubSystem::from_json(&json)
+            .map_err(|message| format!("The topology file \"{path}\" is not valid: {message}"))?;
+        Ok(DeviceNetworkHighLevel::with_subsystem(low_level_system))
+    }
+
+    /// Helper method to scan every device in every device chain for the
+    /// first one that matches.
+    ///
+    /// # Parameters
+    /// - matches
+    ///
+    ///   Returns true if the given device is the device to find.
+    ///
+    /// # Returns
+    /// Returns an Option<> containing the location of the device, or None
+    /// if no device matches.
+    fn _find_device(&self, matches: impl Fn(&DeviceInfo) -> bool) -> Option<DeviceLocation> {
+        let low_level_system = self.network.low_level_system();
+        for chain_index in 0..low_level_system.get_num_chains() {
+            let devices = low_level_system.get_devices(chain_index);
+            if let Some(device_index) = devices.iter().position(&matches) {
+                return Some(DeviceLocation { chain_index, device_index });
+            }
+        }
+        None
     }
 }
 
 impl IDeviceNetworkHighLevel for DeviceNetworkHighLevel {
     fn num_chains(&self) -> usize {
-        self.low_level_system.get_num_chains()
+        self.network.low_level_system().get_num_chains()
     }
 
-    fn get_idcodes(&mut self, chain_index: usize) -> Vec<u32> {
-        let mut idcodes: Vec<u32> = vec![];
-        if self.low_level_system.lock_device_chain(chain_index) {
-            idcodes = self.low_level_system.get_idcodes(chain_index);
-            self.low_level_system.unlock_device_chain(chain_index);
-        }
+    fn get_idcodes(&mut self, chain_index: usize) -> Result<Vec<u32>, DeviceError> {
+        self.lock_chain(chain_index)?.get_idcodes()
+    }
 
-        idcodes
+    fn enable_devices_in_device_chain(&mut self, chain_index: usize, selection: &DeviceSelection) -> Result<(), DeviceError> {
+        self.lock_chain(chain_index)?.enable_devices(selection)
     }
 
-    fn enable_devices_in_device_chain(&mut self, chain_index: usize, select_mask: u32) {
-        if self.low_level_system.lock_device_chain(chain_index) {
-            self.low_level_system.enable_devices_in_device_chain(chain_index, select_mask);
-            self.low_level_system.unlock_device_chain(chain_index);
-        }
+    fn disable_devices_in_device_chain(&mut self, chain_index: usize) -> Result<(), DeviceError> {
+        self.lock_chain(chain_index)?.reset()
     }
 
-    fn disable_devices_in_device_chain(&mut self, chain_index: usize) {
-        if self.low_level_system.lock_device_chain(chain_index) {
-            self.low_level_system.reset_device_chain(chain_index);
-            self.low_level_system.unlock_device_chain(chain_index);
+    fn lock_chain(&mut self, chain_index: usize) -> Result<ChainLockGuard, DeviceError> {
+        ChainLockGuard::lock(&self.network, chain_index)
+    }
+
+    fn find_device_by_name(&self, name: &str) -> Option<DeviceLocation> {
+        self._find_device(|device| device.name == name)
+    }
+
+    fn find_chain_containing(&self, idcode: u32) -> Option<DeviceLocation> {
+        self._find_device(|device| device.idcode == idcode)
+    }
+
+    fn new_client(&self) -> Box<dyn IDeviceNetworkHighLevel> {
+        DeviceNetworkHighLevel::from_shared(self.network.clone())
+    }
+
+    fn handle(&self) -> DeviceNetworkHandle {
+        DeviceNetworkHandle::new(self.network.clone())
+    }
+
+    fn get_network_report(&self) -> String {
+        let low_level_system = self.network.low_level_system();
+        let num_chains = low_level_system.get_num_chains();
+        let mut report = format!("Device network with {num_chains} device chain(s)\n");
+        for chain_index in 0..num_chains {
+            let state = match low_level_system.is_device_chain_locked(chain_index) {
+                true => "locked",
+                false => "unlocked",
+            };
+            report.push_str(&format!("  Chain {chain_index} \"{}\" ({state})\n",
+                low_level_system.get_chain_name(chain_index)));
+            let devices = low_level_system.get_devices(chain_index);
+            let name_width = devices.iter().map(|device| device.name.len()).max().unwrap_or(0);
+            let type_width = devices.iter().map(|device| device.device_type.len()).max().unwrap_or(0);
+            for (device_index, device) in devices.iter().enumerate() {
+                let visibility = match device.visible {
+                    true => "visible",
+                    false => "hidden",
+                };
+                report.push_str(&format!("    {device_index:>2}  {:name_width$}  {:type_width$}  0x{:08X}  {visibility}\n",
+                    device.name, device.device_type, device.idcode));
+            }
         }
+        report
+    }
+
+    fn on_visibility_changed(&mut self, callback: Box<VisibilityCallback>) {
+        self.network.add_visibility_callback(Arc::from(callback));
     }
 }
@@ -0,0 +1,103 @@
+//! Contains the DeviceSelection struct that specifies which devices on a
+//! device chain to make visible or invisible.
+
+use std::ops::Range;
+
+//-----------------------------------------------------------------------------
+
+/// Number of device positions held in each word of the selection.
+const BITS_PER_WORD: usize = u64::BITS as usize;
+
+//-----------------------------------------------------------------------------
+
+/// Represents a set of device positions on a device chain, with position 0
+/// being the device controller (which is always visible, so is ignored when
+/// selecting), position 1 the first TAP, and so on.  There is no limit on
+/// the number of positions.  Positions past the end of a device chain are
+/// ignored.
+/// Part of the "Facade" pattern example.
+///
+/// Build a selection by chaining the select methods:
+/// ```text
+/// let selection = DeviceSelection::new().select_range(1..4).select_indices(&[40, 70]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DeviceSelection {
+    /// Bit set with a bit for each selected position.
+    bits: Vec<u64>,
+
+    /// true if every position is selected, however long the device chain.
+    all: bool,
+}
+
+impl DeviceSelection {
+    /// Constructor for an empty selection.
+    pub fn new() -> DeviceSelection {
+        DeviceSelection::default()
+    }
+
+    /// Select every device, however many there are on the device chain.
+    pub fn select_all(mut self) -> DeviceSelection {
+        self.all = true;
+        self
+    }
+
+    /// Select a single device.
+    ///
+    /// # Parameters
+    /// - index
+    ///
+    ///   Position of the device on the device chain.
+    pub fn select(mut self, index: usize) -> DeviceSelection {
+        let word = index / BITS_PER_WORD;
+        if word >= self.bits.len() {
+            self.bits.resize(word + 1, 0);
+        }
+        self.bits[word] |= 1 << (index % BITS_PER_WORD);
+        self
+    }
+
+    /// Select a range of devices.
+    ///
+    /// # Parameters
+    /// - range
+    ///
+    ///   Positions of the devices on the device chain, such as `1..4`.
+    pub fn select_range(self, range: Range<usize>) -> DeviceSelection {
+        range.fold(self, DeviceSelection::select)
+    }
+
+    /// Select a list of devices.
+    ///
+    /// # Parameters
+    /// - indices
+    ///
+    ///   Positions of the devices on the device chain.
+    pub fn select_indices(self, indices: &[usize]) -> DeviceSelection {
+        indices.iter().fold(self, |selection, index| selection.select(*index))
+    }
+
+    /// Determine whether the device at the given position is selected.
+    ///
+    /// # Parameters
+    /// - index
+    ///
+    ///   Position of the device on the device chain.
+    ///
+    /// # Returns
+    /// Returns true if the device is selected.
+    pub fn is_selected(&self, index: usize) -> bool {
+        self.all || match self.bits.get(index / BITS_PER_WORD) {
+            Some(word) => word & (1 << (index % BITS_PER_WORD)) != 0,
+            None => false,
+        }
+    }
+}
+
+impl From<u32> for DeviceSelection {
+    /// Convert a 32-bit select mask, where bit 0 is position 0, bit 1 is
+    /// position 1, and so on, to a selection.
+    fn from(select_mask: u32) -> DeviceSelection {
+        DeviceSelection { bits: vec![select_mask as u64], all: false }
+    }
+}
@@ -1,6 +1,41 @@
 //! Contains the IDeviceNetworkHighLevel trait that represents a simplified
 //! view of a complicated facade sub-system.
 
+use super::facade_chainlockguard::ChainLockGuard;
+use super::facade_deviceerror::DeviceError;
+use super::facade_devicenetworkhandle::DeviceNetworkHandle;
+use super::facade_deviceselection::DeviceSelection;
+
+//-----------------------------------------------------------------------------
+
+/// Represents where a device is in the device network.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DeviceLocation {
+    /// Index of the device chain containing the device.
+    pub chain_index: usize,
+    /// Position of the device in its device chain.  The device controller is
+    /// always at position 0.
+    pub device_index: usize,
+}
+
+impl DeviceLocation {
+    /// Retrieve the selection that selects only this device, for passing
+    /// to enable_devices_in_device_chain().
+    pub fn selection(&self) -> DeviceSelection {
+        DeviceSelection::new().select(self.device_index)
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// A callback called when the visible devices on a device chain change,
+/// given the index of the device chain and the idcodes of the devices now
+/// visible on it.  Callbacks can be called from any thread using the device
+/// network.
+pub type VisibilityCallback = dyn Fn(usize, &[u32]) + Send + Sync;
+
+//-----------------------------------------------------------------------------
+
 /// Represents a high level view of a complex network of device chains.
 /// A device chain can be thought of as a list of devices that can be made
 /// visible or hidden in the list but maintain the same relationship to
@@ -12,21 +47,59 @@
 /// accessing the chain.
 /// 
 /// This high level interface is a simplification and thus a facade for the
-/// low level interface and the system underneath.
+/// low level interface and the system underneath.  Any failure in the
+/// system underneath is reported as a DeviceError.
 /// 
-pub trait IDeviceNetworkHighLevel {
+/// The interface is Send so a client can be moved to another thread; see
+/// handle() for sharing a device network between threads.
+/// 
+pub trait IDeviceNetworkHighLevel: Send {
     /// The number of device chains available from the sub-system.
     fn num_chains(&self) -> usize;
 
     /// Returns a list of all idcodes from all selected devices in the
-    /// given device chain.
-    fn get_idcodes(&mut self, chain_index: usize) -> Vec<u32>;
+    /// given device chain, or a DeviceError if the list could not be read.
+    fn get_idcodes(&mut self, chain_index: usize) -> Result<Vec<u32>, DeviceError>;
 
-    /// Make visible certain devices in the given device chain.  The `selectMask`
-    /// value has a bit set for each TAP device to make visible.
-    fn enable_devices_in_device_chain(&mut self, chain_index: usize, select_mask: u32);
+    /// Make visible certain devices in the given device chain.  The
+    /// `selection` contains the position of each TAP device to make visible.
+    /// Returns a DeviceError if the devices could not be made visible.
+    fn enable_devices_in_device_chain(&mut self, chain_index: usize, selection: &DeviceSelection) -> Result<(), DeviceError>;
 
     /// Resets the given device chain so that all devices except the TAP
-    /// controller is no longer visible.
-    fn disable_devices_in_device_chain(&mut self, chain_index: usize);
+    /// controller is no longer visible.  Returns a DeviceError if the device
+    /// chain could not be reset.
+    fn disable_devices_in_device_chain(&mut self, chain_index: usize) -> Result<(), DeviceError>;
+
+    /// Lock the given device chain for exclusive access until the returned
+    /// guard is dropped.  Returns a DeviceError if the device chain is
+    /// already locked, including by this client.
+    fn lock_chain(&mut self, chain_index: usize) -> Result<ChainLockGuard, DeviceError>;
+
+    /// Find the device with the given name, visible or not.  Returns None
+    /// if there is no such device.
+    fn find_device_by_name(&self, name: &str) -> Option<DeviceLocation>;
+
+    /// Find the device with the given idcode, visible or not, to learn which
+    /// device chain contains it.  Returns None if there is no such device.
+    fn find_chain_containing(&self, idcode: u32) -> Option<DeviceLocation>;
+
+    /// Create another client of the same device network.  The clients share
+    /// the device chains and their locks.
+    fn new_client(&self) -> Box<dyn IDeviceNetworkHighLevel>;
+
+    /// Retrieve a handle to the device network that can be cloned and sent
+    /// to other threads, each of which creates its own client from it.
+    fn handle(&self) -> DeviceNetworkHandle;
+
+    /// Returns a multi-line summary of the device network suitable for
+    /// printing: each device chain with its name and whether it is locked,
+    /// then each device on the chain with its position, name, type, idcode
+    /// and whether it is visible.  Every line ends with a newline.
+    fn get_network_report(&self) -> String;
+
+    /// Register a callback to call whenever enabling, disabling or resetting
+    /// devices changes which devices are visible on a device chain.  The
+    /// callback is shared by every client of the device network.
+    fn on_visibility_changed(&mut self, callback: Box<VisibilityCallback>);
 }
@@ -1,14 +1,48 @@
 //! Contains the IDeviceNetworkLowLevel trait that represents the complicated
 //! facade sub-system.
 
+use super::facade_deviceselection::DeviceSelection;
+
+//-----------------------------------------------------------------------------
+
+/// Represents a fault that can be injected into a device chain to simulate
+/// the failures a real device network can have.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeviceFault {
+    /// The device chain behaves normally.
+    None,
+    /// The device chain is held by another client, so cannot be locked.
+    Locked,
+    /// The devices on the device chain do not respond, so the chain cannot
+    /// be changed and returns no idcodes.
+    Unresponsive,
+}
+
+//-----------------------------------------------------------------------------
+
+/// Represents what the sub-system knows about a device on a device chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeviceInfo {
+    /// Name of the device, such as "DDD_CORE0".
+    pub name: String,
+    /// The device's idcode.
+    pub idcode: u32,
+    /// Name of the type of the device, such as "controller" or "core".
+    pub device_type: String,
+    /// Whether the device is currently visible on its device chain.
+    pub visible: bool,
+}
+
+//-----------------------------------------------------------------------------
+
 /// Represents a network of device chains and the low level access to that
 /// network.  In general, the caller should take a lock on a device chain
 /// before accessing it then release the lock when done.
 /// Part of the "Facade" pattern example.
 ///
 /// This interface makes it easier to contrast with the IDeviceNetworkHighLevel
-/// interface.
-pub trait IDeviceNetworkLowLevel {
+/// interface.  It is Send so the sub-system can be shared between threads.
+pub trait IDeviceNetworkLowLevel: Send {
     /// Retrieve the number of device chains available in the network.
     ///
     /// # Returns
@@ -43,7 +77,10 @@ pub trait IDeviceNetworkLowLevel {
     /// - chain_index
     ///
     ///   Index of the device chain (0..n-1).
-    fn reset_device_chain(&mut self, chain_index: usize);
+    ///
+    /// # Returns
+    /// Returns true if the devices on the device chain responded.
+    fn reset_device_chain(&mut self, chain_index: usize) -> bool;
 
     /// Make visible the specified devices on the specified device chain.
     ///
@@ -51,12 +88,15 @@ pub trait IDeviceNetworkLowLevel {
     /// - chain_index
     ///
     ///   Index of the device chain (0..n-1).
-    /// - devices_select_mask
+    /// - selection
+    ///
+    ///   The positions of the TAPs that should be made visible.  Position 0
+    ///   corresponds to the first TAP, position 1 to the second TAP, etc.
+    ///   CLTAP (TAP controller) devices are always visible.
     ///
-    ///   Bit mask where each bit set indicates the corresponding TAP should be
-    ///   made visible.  Bit 0 corresponds to the first TAP, bit 1 to the second
-    ///   TAP, etc.  CLTAP (TAP controller) devices are always visible.
-    fn enable_devices_in_device_chain(&mut self, chain_index: usize, devices_select_mask: u32);
+    /// # Returns
+    /// Returns true if the devices on the device chain responded.
+    fn enable_devices_in_device_chain(&mut self, chain_index: usize, selection: &DeviceSelection) -> bool;
 
     /// Make invisible the specified devices on the specified device chain.
     ///
@@ -64,12 +104,15 @@ pub trait IDeviceNetworkLowLevel {
     /// - chain_index
     ///
     /// Index of the device chain (0..n-1).
-    /// - devices_select_mask
+    /// - selection
     ///
-    ///   Bit mask where each bit set indicates the corresponding TAP should be
-    ///   made invisible.  Bit 0 corresponds to the first TAP, bit 1 to the
-    ///   second TAP, etc.  CLTAP (TAP controller) devices are always visible.
-    fn disable_devices_in_device_chain(&mut self, chain_index: usize, devices_select_mask: u32);
+    ///   The positions of the TAPs that should be made invisible.  Position 0
+    ///   corresponds to the first TAP, position 1 to the second TAP, etc.
+    ///   CLTAP (TAP controller) devices are always visible.
+    ///
+    /// # Returns
+    /// Returns true if the devices on the device chain responded.
+    fn disable_devices_in_device_chain(&mut self, chain_index: usize, selection: &DeviceSelection) -> bool;
 
     /// Retrieve a list of idcodes of all visible devices in the given device chain.
     ///
@@ -80,7 +123,59 @@ pub trait IDeviceNetworkLowLevel {
     ///
     /// # Returns
     /// Returns an array of idcodes for each visible TAP, with the first
-    /// TAP being at index 0.
+    /// TAP being at index 0.  The array is empty if the devices on the
+    /// device chain did not respond.
     fn get_idcodes(&self, chain_index: usize) -> Vec<u32>;
 
+    /// Retrieve the name of the given device chain.
+    ///
+    /// # Parameters
+    /// - chain_index
+    ///
+    ///   Index of the device chain (0..n-1).
+    ///
+    /// # Returns
+    /// Returns the name of the device chain, or an empty string if the chain
+    /// index is out of range.
+    fn get_chain_name(&self, chain_index: usize) -> String;
+
+    /// Determine whether the given device chain is locked.
+    ///
+    /// # Parameters
+    /// - chain_index
+    ///
+    ///   Index of the device chain (0..n-1).
+    ///
+    /// # Returns
+    /// Returns true if the device chain is locked.
+    fn is_device_chain_locked(&self, chain_index: usize) -> bool;
+
+    /// Retrieve what is known about every device in the given device chain,
+    /// visible or not.  This comes from the sub-system rather than from the
+    /// devices, so the device chain does not need to be locked.
+    ///
+    /// # Parameters
+    /// - chain_index
+    ///
+    ///   Index of the device chain (0..n-1).
+    ///
+    /// # Returns
+    /// Returns an array of DeviceInfo, with the TAP controller at index 0
+    /// and each TAP at the position used in a DeviceSelection.  The array is
+    /// empty if the chain index is out of range.
+    fn get_devices(&self, chain_index: usize) -> Vec<DeviceInfo>;
+
+    /// Inject a fault into the specified device chain to simulate a failure
+    /// in the device network.  Used for testing how clients deal with
+    /// failures.
+    ///
+    /// # Parameters
+    /// - chain_index
+    ///
+    ///   Index of the device chain (0..n-1).
+    /// - fault
+    ///
+    ///   The fault to inject.  DeviceFault::None removes any fault.
+    fn inject_fault(&mut self, chain_index: usize, fault: DeviceFault);
+
 }
@@ -0,0 +1,80 @@
+//! Contains the SharedNetwork struct that holds the state shared by all
+//! clients of the same device network.
+
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use super::facade_idevicenetworkhighlevel_trait::VisibilityCallback;
+use super::facade_idevicenetworklowlevel_trait::IDeviceNetworkLowLevel;
+
+//-----------------------------------------------------------------------------
+
+/// Represents the state shared by all clients of the same device network,
+/// which may be on different threads: the low level sub-system and the
+/// callbacks to call when the visible devices change.
+/// Part of the "Facade" pattern example.
+pub(crate) struct SharedNetwork {
+    /// The low level sub-system.
+    low_level_system: Mutex<Box<dyn IDeviceNetworkLowLevel>>,
+
+    /// The callbacks to call when the visible devices on a chain change.
+    visibility_callbacks: Mutex<Vec<Arc<VisibilityCallback>>>,
+}
+
+impl SharedNetwork {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - low_level_system
+    ///
+    ///   The low level sub-system to share.
+    ///
+    /// # Returns
+    /// Returns the shared network, ready to be given to clients.
+    pub(crate) fn new(low_level_system: Box<dyn IDeviceNetworkLowLevel>) -> Arc<SharedNetwork> {
+        Arc::new(SharedNetwork {
+            low_level_system: Mutex::new(low_level_system),
+            visibility_callbacks: Mutex::new(vec![]),
+        })
+    }
+
+    /// Take exclusive access to the low level sub-system for the duration of
+    /// a single call into it.  This is separate from the lock on a device
+    /// chain, which can be held across many calls.
+    ///
+    /// # Returns
+    /// Returns a guard giving access to the low level sub-system.
+    pub(crate) fn low_level_system(&self) -> MutexGuard<'_, Box<dyn IDeviceNetworkLowLevel>> {
+        // A panic on another thread cannot leave the sub-system half changed,
+        // so it is still usable.
+        self.low_level_system.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Add a callback to call when the visible devices on a chain change.
+    ///
+    /// # Parameters
+    /// - callback
+    ///
+    ///   The callback to add.
+    pub(crate) fn add_visibility_callback(&self, callback: Arc<VisibilityCallback>) {
+        self.visibility_callbacks.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).push(callback);
+    }
+
+    /// Call every visibility callback to report that the visible devices on
+    /// a chain have changed.
+    ///
+    /// # Parameters
+    /// - chain_index
+    ///
+    ///   Index of the device chain that changed.
+    /// - idcodes
+    ///
+    ///   The idcodes of the devices now visible on the device chain.
+    pub(crate) fn notify_visibility_changed(&self, chain_index: usize, idcodes: &[u32]) {
+        // Call the callbacks on a copy of the list so a callback can use the
+        // network, including adding another callback.
+        let callbacks = self.visibility_callbacks.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone();
+        for callback in callbacks {
+            callback(chain_index, idcodes);
+        }
+    }
+}
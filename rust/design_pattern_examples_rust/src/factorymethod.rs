@@ -0,0 +1,70 @@
+//! The Factory Method design pattern example module
+//!
+//! The Factory Method pattern defines a method for creating an object but
+//! lets the implementors of that method decide which concrete object to
+//! create.  The code that calls the factory method works only with the
+//! trait representing the created object and never needs to know the actual
+//! type.
+//!
+//! In this exercise, a document "creator" trait provides a factory method for
+//! creating a document.  Each creator (text, spreadsheet, and presentation)
+//! implements the factory method to produce its own kind of document.  The
+//! creator trait also provides a method for starting a new document that
+//! calls the factory method and then fills in the document in the same way
+//! regardless of which kind of document was created.
+//!
+//! Accessed through the factorymethod_exercise() function.
+
+//-----------------------------------------------------------------------------
+
+pub mod factorymethod_idocument_trait;
+pub mod factorymethod_documents;
+pub mod factorymethod_idocumentcreator_trait;
+pub mod factorymethod_creators;
+
+//-----------------------------------------------------------------------------
+
+use factorymethod_idocumentcreator_trait::IDocumentCreator;
+use factorymethod_creators::{TextDocumentCreator, SpreadsheetDocumentCreator, PresentationDocumentCreator};
+
+//-----------------------------------------------------------------------------
+
+/// Example of using the "Factory Method" design pattern.
+///
+/// The Factory Method pattern defines a method for creating an object but
+/// lets the implementors of that method decide which concrete object to
+/// create.  The code that calls the factory method works only with the
+/// trait representing the created object and never needs to know the actual
+/// type.
+///
+/// In this exercise, a document "creator" trait provides a factory method for
+/// creating a document.  Each creator (text, spreadsheet, and presentation)
+/// implements the factory method to produce its own kind of document.  The
+/// creator trait also provides a method for starting a new document that
+/// calls the factory method and then fills in the document in the same way
+/// regardless of which kind of document was created.
+// ! [Using FactoryMethod in Rust]
+pub fn factorymethod_exercise() -> Result<(), String> {
+    println!();
+    println!("FactoryMethod Exercise");
+
+    let creators: Vec<Box<dyn IDocumentCreator>> = vec![
+        TextDocumentCreator::new(),
+        SpreadsheetDocumentCreator::new(),
+        PresentationDocumentCreator::new(),
+    ];
+
+    for creator in creators.iter() {
+        println!("  Using the {} to start a new document...", creator.get_name());
+        let document = creator.new_document("Quarterly Report");
+        println!("    Created a {} document titled \"{}\":", document.get_kind(), document.get_title());
+        for line in document.render() {
+            println!("      {line}");
+        }
+    }
+
+    println!("  Done.");
+
+    Ok(())
+}
+// ! [Using FactoryMethod in Rust]
@@ -0,0 +1,89 @@
+//! Contains the TextDocumentCreator, SpreadsheetDocumentCreator, and
+//! PresentationDocumentCreator structs that each implement the factory method
+//! of the IDocumentCreator trait to create a specific kind of document.
+
+//-----------------------------------------------------------------------------
+
+use super::factorymethod_idocument_trait::IDocument;
+use super::factorymethod_idocumentcreator_trait::IDocumentCreator;
+use super::factorymethod_documents::{TextDocument, SpreadsheetDocument, PresentationDocument};
+
+//-----------------------------------------------------------------------------
+
+/// Represents a creator of text documents.
+pub struct TextDocumentCreator {}
+
+impl TextDocumentCreator {
+    /// Constructor.
+    ///
+    /// # Returns
+    /// Returns a new instance of the TextDocumentCreator struct as
+    /// represented by the IDocumentCreator trait.
+    pub fn new() -> Box<dyn IDocumentCreator> {
+        Box::new(TextDocumentCreator {})
+    }
+}
+
+impl IDocumentCreator for TextDocumentCreator {
+    fn get_name(&self) -> &str {
+        "TextDocumentCreator"
+    }
+
+    fn create_document(&self, title: &str) -> Box<dyn IDocument> {
+        TextDocument::new(title)
+    }
+}
+
+//#############################################################################
+//#############################################################################
+
+/// Represents a creator of spreadsheet documents.
+pub struct SpreadsheetDocumentCreator {}
+
+impl SpreadsheetDocumentCreator {
+    /// Constructor.
+    ///
+    /// # Returns
+    /// Returns a new instance of the SpreadsheetDocumentCreator struct as
+    /// represented by the IDocumentCreator trait.
+    pub fn new() -> Box<dyn IDocumentCreator> {
+        Box::new(SpreadsheetDocumentCreator {})
+    }
+}
+
+impl IDocumentCreator for SpreadsheetDocumentCreator {
+    fn get_name(&self) -> &str {
+        "SpreadsheetDocumentCreator"
+    }
+
+    fn create_document(&self, title: &str) -> Box<dyn IDocument> {
+        SpreadsheetDocument::new(title)
+    }
+}
+
+//#############################################################################
+//#############################################################################
+
+/// Represents a creator of presentation documents.
+pub struct PresentationDocumentCreator {}
+
+impl PresentationDocumentCreator {
+    /// Constructor.
+    ///
+    /// # Returns
+    /// Returns a new instance of the PresentationDocumentCreator struct as
+    /// represented by the IDocumentCreator trait.
+    pub fn new() -> Box<dyn IDocumentCreator> {
+        Box::new(PresentationDocumentCreator {})
+    }
+}
+
+impl IDocumentCreator for PresentationDocumentCreator {
+    fn get_name(&self) -> &str {
+        "PresentationDocumentCreator"
+    }
+
+    fn create_document(&self, title: &str) -> Box<dyn IDocument> {
+        PresentationDocument::new(title)
+    }
+}
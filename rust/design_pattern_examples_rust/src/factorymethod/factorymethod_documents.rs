@@ -0,0 +1,166 @@
+//! Contains the TextDocument, SpreadsheetDocument, and PresentationDocument
+//! structs that represent the concrete products created by the document
+//! creators.
+
+//-----------------------------------------------------------------------------
+
+use super::factorymethod_idocument_trait::IDocument;
+
+//-----------------------------------------------------------------------------
+
+/// Represents a plain text document, where each section is a paragraph.
+pub struct TextDocument {
+    /// The title of the document.
+    title: String,
+    /// The paragraphs in the document.
+    paragraphs: Vec<String>,
+}
+
+impl TextDocument {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - title
+    ///
+    ///   The title of the document.
+    ///
+    /// # Returns
+    /// Returns a new instance of the TextDocument struct as represented by
+    /// the IDocument trait.
+    pub fn new(title: &str) -> Box<dyn IDocument> {
+        Box::new(TextDocument {
+            title: title.to_string(),
+            paragraphs: vec![],
+        })
+    }
+}
+
+impl IDocument for TextDocument {
+    fn get_kind(&self) -> &str {
+        "Text"
+    }
+
+    fn get_title(&self) -> &str {
+        &self.title
+    }
+
+    fn add_section(&mut self, content: &str) {
+        self.paragraphs.push(content.to_string());
+    }
+
+    fn render(&self) -> Vec<String> {
+        let mut lines = vec![self.title.to_uppercase()];
+        lines.push("=".repeat(self.title.len()));
+        for paragraph in self.paragraphs.iter() {
+            lines.push(format!("  {paragraph}"));
+        }
+        lines
+    }
+}
+
+//#############################################################################
+//#############################################################################
+
+/// Represents a spreadsheet document, where each section is a row of cells.
+/// The cells in a section are separated by commas.
+pub struct SpreadsheetDocument {
+    /// The title of the document.
+    title: String,
+    /// The rows in the spreadsheet, each a list of cells.
+    rows: Vec<Vec<String>>,
+}
+
+impl SpreadsheetDocument {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - title
+    ///
+    ///   The title of the document.
+    ///
+    /// # Returns
+    /// Returns a new instance of the SpreadsheetDocument struct as
+    /// represented by the IDocument trait.
+    pub fn new(title: &str) -> Box<dyn IDocument> {
+        Box::new(SpreadsheetDocument {
+            title: title.to_string(),
+            rows: vec![],
+        })
+    }
+}
+
+impl IDocument for SpreadsheetDocument {
+    fn get_kind(&self) -> &str {
+        "Spreadsheet"
+    }
+
+    fn get_title(&self) -> &str {
+        &self.title
+    }
+
+    fn add_section(&mut self, content: &str) {
+        let cells = content.split(',').map(|cell| cell.trim().to_string()).collect();
+        self.rows.push(cells);
+    }
+
+    fn render(&self) -> Vec<String> {
+        let mut lines = vec![format!("[{}]", self.title)];
+        for (row_index, row) in self.rows.iter().enumerate() {
+            let cells: Vec<String> = row.iter().map(|cell| format!("{cell:<12}")).collect();
+            lines.push(format!("{:>2} | {}", row_index + 1, cells.join("| ")));
+        }
+        lines
+    }
+}
+
+//#############################################################################
+//#############################################################################
+
+/// Represents a presentation document, where each section is a slide.
+pub struct PresentationDocument {
+    /// The title of the document.
+    title: String,
+    /// The slides in the presentation.
+    slides: Vec<String>,
+}
+
+impl PresentationDocument {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - title
+    ///
+    ///   The title of the document.
+    ///
+    /// # Returns
+    /// Returns a new instance of the PresentationDocument struct as
+    /// represented by the IDocument trait.
+    pub fn new(title: &str) -> Box<dyn IDocument> {
+        Box::new(PresentationDocument {
+            title: title.to_string(),
+            slides: vec![],
+        })
+    }
+}
+
+impl IDocument for PresentationDocument {
+    fn get_kind(&self) -> &str {
+        "Presentation"
+    }
+
+    fn get_title(&self) -> &str {
+        &self.title
+    }
+
+    fn add_section(&mut self, content: &str) {
+        self.slides.push(content.to_string());
+    }
+
+    fn render(&self) -> Vec<String> {
+        let mut lines = vec![format!("Slide 0: {}", self.title)];
+        for (slide_index, slide) in self.slides.iter().enumerate() {
+            lines.push(format!("Slide {}: {}", slide_index + 1, slide));
+        }
+        lines
+    }
+}
@@ -0,0 +1,28 @@
+//! Contains the IDocument trait that represents the product created by the
+//! factory method in the "Factory Method" design pattern example.
+
+/// Represents a document of some kind.  This is the product created by the
+/// factory method on the IDocumentCreator trait.  The rest of the program
+/// only ever sees a document through this trait.
+pub trait IDocument {
+    /// Retrieve the kind of the document (for example, "Text").
+    fn get_kind(&self) -> &str;
+
+    /// Retrieve the title of the document.
+    fn get_title(&self) -> &str;
+
+    /// Add a section of content to the document.  How the content is stored
+    /// depends on the kind of document.
+    ///
+    /// # Parameters
+    /// - content
+    ///
+    ///   The content to add to the document.
+    fn add_section(&mut self, content: &str);
+
+    /// Render the document as a list of lines of text.
+    ///
+    /// # Returns
+    /// Returns a list of strings, one for each line of the rendered document.
+    fn render(&self) -> Vec<String>;
+}
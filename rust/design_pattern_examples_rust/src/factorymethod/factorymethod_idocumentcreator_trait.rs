@@ -0,0 +1,46 @@
+//! Contains the IDocumentCreator trait that declares the factory method for
+//! creating documents in the "Factory Method" design pattern example.
+
+//-----------------------------------------------------------------------------
+
+use super::factorymethod_idocument_trait::IDocument;
+
+//-----------------------------------------------------------------------------
+
+/// Represents a creator of documents.  Each implementation of this trait
+/// decides which kind of document is created by the create_document() factory
+/// method.
+pub trait IDocumentCreator {
+    /// Retrieve the name of the creator.
+    fn get_name(&self) -> &str;
+
+    /// The factory method.  Create a new, empty document.
+    ///
+    /// # Parameters
+    /// - title
+    ///
+    ///   The title of the new document.
+    ///
+    /// # Returns
+    /// Returns the new document as represented by the IDocument trait.
+    fn create_document(&self, title: &str) -> Box<dyn IDocument>;
+
+    /// Start a new document with some initial content.  This calls the
+    /// create_document() factory method to create the document so this
+    /// method works the same no matter which kind of document is created.
+    ///
+    /// # Parameters
+    /// - title
+    ///
+    ///   The title of the new document.
+    ///
+    /// # Returns
+    /// Returns the new document as represented by the IDocument trait.
+    fn new_document(&self, title: &str) -> Box<dyn IDocument> {
+        let mut document = self.create_document(title);
+        document.add_section("Summary, Revenue, Expenses");
+        document.add_section("Q1, 1200, 800");
+        document.add_section("Q2, 1500, 900");
+        document
+    }
+}
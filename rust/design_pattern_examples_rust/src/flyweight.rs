@@ -13,46 +13,111 @@
 //! and velocities.  The image is rendered to a display area using the
 //! FlyweightImage struct.  The FlyweightImage struct instances then have
 //! their positions updated, bouncing off the edges of the display area 60
-//! times a second.  This continues for 1000 iterations or until a key
-//! is pressed.
+//! times a second (by default).  This continues for 1000 iterations or until
+//! a key is pressed.  Space pauses and resumes the animation and, while
+//! paused, `.` advances it a single frame; any other key ends it early.
+//! Each FlyweightImage is shown in its own color, which, like its position
+//! and velocity, is extrinsic state kept in the image's context, while the
+//! image data is intrinsic state shared by all images in the big resource.
+//! The animation runs in the console's alternate screen, so the console is
+//! left as it was when the animation ends (even if the animation fails).
+//! After the first frame, only the characters that changed from one frame to
+//! the next are printed, which avoids flicker.  If the console window is too
+//! small for the display, or is resized while the animation runs, the
+//! display is made smaller to fit (but never smaller than an image) and the
+//! images are kept inside it.  Each FlyweightImage is also in a layer of its
+//! own, which decides which image is in front where images overlap, and
+//! characters in the big resource can be made transparent so the images
+//! behind show through.  A status line above the display shows the
+//! frame rate, the average frame time and the number of dropped frames as
+//! the animation runs, and a summary of the frame times, including the frame
+//! rate actually achieved, is shown afterwards (see FrameStats).
+//!
+//! If the `--no-interactive` exercise option is given, the animation is
+//! instead run without moving the cursor, sleeping or reading keys, and each
+//! frame is printed one after another (see render_frames()).  With the
+//! `--seed` exercise option, the images start in the same places every time,
+//! so the frames are the same every time.
+//!
+//! If the `--benchmark` exercise option is given, the animation of thousands
+//! of images on a large virtual display is instead run without any delay or
+//! output, once with the FlyweightImage structs sharing the big resource and
+//! once with each image owning a full copy of its image data, and the memory
+//! used (shared and per image), the time per frame and the throughput are
+//! shown for both.  The number of images and frames can be changed with the
+//! `--benchmark-images` and `--benchmark-frames` exercise options.
+//!
+//! The number of images, the size of the images and of the display, the
+//! number of iterations and the frame rate can be changed with the
+//! `--count`, `--image`, `--display`, `--iterations` and `--fps` exercise
+//! options, and the images made to bounce off each other with the
+//! `--collisions` exercise option (see FlyweightConfig).
+//! The generated images can be replaced by ASCII-art sprites read from the
+//! text files in a directory with the `--sprites` exercise option (see
+//! flyweight_spriteloader.rs).
 //!
 //! Accessed through the flyweight_exercise() function.
 
 //-----------------------------------------------------------------------------
 
+pub mod flyweight_benchmark;
 pub mod flyweight_bigresource;
 pub mod flyweight_bigresource_manager;
+pub mod flyweight_bigresourceid;
+pub mod flyweight_config;
 pub mod flyweight_display;
+pub mod flyweight_framestats;
+pub mod flyweight_headless;
 pub mod flyweight_context;
 pub mod flyweight_image;
+pub mod flyweight_spriteloader;
 
 //-----------------------------------------------------------------------------
 
 use std::cmp::{min, max};
-use std::time::Duration;
+use std::io::{stdout, Write};
+
+use crossterm::event::KeyCode;
+use crossterm::style::{Color, Stylize};
+use std::time::Instant;
 use std::thread;
 
-use super::helpers::{cursor, random, key_input};
+use super::helpers::{ansi, cursor, random, key_input};
+use super::helpers::key_input::ConsoleEvent;
+use crate::helpers::exercise_options::has_exercise_option;
+use crate::helpers::profiler::{begin_span, end_span, profile_span};
 use flyweight_bigresource_manager::BigResourceManager;
 use flyweight_bigresource::BigResource;
-use flyweight_display::Display;
+use flyweight_bigresourceid::BigResourceId;
+use flyweight_config::FlyweightConfig;
+use flyweight_display::{Display, DisplayChange};
+use flyweight_framestats::FrameStats;
 use flyweight_image::FlyweightImage;
 use flyweight_context::FlyweightContext;
+use flyweight_spriteloader::load_sprites;
 
 //-----------------------------------------------------------------------------
 
-/// Width of the "display", in characters, in which to render Flyweight "images".
-const DISPLAY_WIDTH: usize = 80;
-/// Height of the "display", in characters, in which to render Flyweight "images".
-const DISPLAY_HEIGHT: usize = 20;
-/// Width of an individual Flyweight "image", in characters.
-const IMAGE_WIDTH: usize = 30;
-/// Height of an individual Flyweight "image", in characters.
-const IMAGE_HEIGHT: usize = 5;
-/// Number of Flyweight "images" to generate and manipulate.
-const NUMFLYWEIGHTS: usize = 5;
-/// Number of iterations of moving and rendering the Flyweight "images".
-const NUM_ITERATIONS: usize = 1000;
+/// Colors given to the Flyweight "images", in turn.
+const IMAGE_COLORS: [Color; 6] = [Color::Red, Color::Green, Color::Yellow, Color::Blue, Color::Magenta, Color::Cyan];
+
+/// Two small images for showing how overlapping images are layered, a ring
+/// and a diamond, side by side in the rows of a big resource.  The spaces
+/// are transparent.
+const LAYERING_IMAGES: [&str; 5] = [
+    "  OOOOO      #    ",
+    " O     O   #####  ",
+    "O       O#########",
+    " O     O   #####  ",
+    "  OOOOO      #    ",
+];
+/// Names of the images in LAYERING_IMAGES, left to right.
+const LAYERING_IMAGE_NAMES: [&str; 2] = ["ring", "diamond"];
+/// Width of each image in LAYERING_IMAGES, in characters.
+const LAYERING_IMAGE_WIDTH: usize = 9;
+/// Most distinct images the big resource can hold; when there are more
+/// Flyweight "images" than this, they share the distinct images in turn.
+const MAX_DISTINCT_IMAGES: usize = 9;
 
 //-----------------------------------------------------------------------------
 
@@ -66,7 +131,7 @@ fn _flyweight_generate_big_resource(image_count: usize, image_width: usize, imag
     let image_background_character = vec!['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
 
     let mut data: Vec<Vec<char>> = vec![];
-    let num_images = min(image_count, 9);
+    let num_images = min(image_count, MAX_DISTINCT_IMAGES);
     let clipped_image_width = max(image_width, 3);
     let clipped_image_height = max(image_height, 3);
 
@@ -106,6 +171,39 @@ fn _flyweight_generate_big_resource(image_count: usize, image_width: usize, imag
     BigResource::new(data)
 }
 
+/// Get the big resource holding the distinct images to animate: the sprites
+/// in the sprite directory in the configuration, if any, or else images
+/// generated to the size in the configuration.
+///
+/// # Parameters
+/// - config
+///
+///   The configuration.  When sprites are loaded, the image size is changed
+///   to the size of the sprites.  Either way, the images must fit in the
+///   display.
+///
+/// # Returns
+/// Returns `Ok((BigResource, usize))` containing the big resource and the
+/// number of distinct images in it; otherwise, returns `Err(String)` if the
+/// sprites cannot be loaded or the images do not fit in the display.
+fn _flyweight_load_big_resource(config: &mut FlyweightConfig) -> Result<(BigResource, usize), String> {
+    let (big_resource, num_distinct_images) = match &config.sprite_directory {
+        Some(directory) => {
+            let sprites = load_sprites(directory)?;
+            config.image_width = sprites.sprite_width;
+            config.image_height = sprites.sprite_height;
+            (sprites.resource, sprites.num_sprites)
+        }
+        None => {
+            let num_distinct_images = min(config.num_flyweights, MAX_DISTINCT_IMAGES);
+            let big_resource = _flyweight_generate_big_resource(num_distinct_images, config.image_width, config.image_height);
+            (big_resource, num_distinct_images)
+        }
+    };
+    config.check_fits()?;
+    Ok((big_resource, num_distinct_images))
+}
+
 /// Generate a random velocity, which includes a speed and a direction.
 /// The velocity is 0.2 to 1.0 (in increments of 0.2) and the direction
 /// is either + or -.
@@ -126,19 +224,31 @@ fn _flyweight_generate_velocity() -> f32 {
 /// instances and associate those objects with individual contexts and a single
 /// big resource.
 ///
-/// The image and display sizes are provided so as to randomize the
-/// position of each flyweight within the display.
-fn _flyweight_generate_flyweight_images(big_resource_id: usize, num_flyweights: usize,
-    image_width: usize, image_height: usize, display_width: usize, display_height: usize,
-    image_list: &mut Vec<FlyweightImage>) {
-
-    for _image_index in 0..num_flyweights {
-        let mut context = FlyweightContext::new(_image_index * image_width, image_width, image_height);
+/// The number of flyweights and the image and display sizes come from the
+/// configuration, the sizes being used to randomize the position of each
+/// flyweight within the display.  The big resource is expected to hold
+/// num_distinct_images images side by side; the flyweights share the
+/// distinct images in turn.  Each flyweight is also given a color from
+/// IMAGE_COLORS in turn, unless colors are turned off, and a layer of its
+/// own, so later flyweights are drawn in front of earlier ones.
+fn _flyweight_generate_flyweight_images(big_resource_id: BigResourceId, num_distinct_images: usize,
+    config: &FlyweightConfig, image_list: &mut Vec<FlyweightImage>) {
+
+    let (image_width, image_height) = (config.image_width, config.image_height);
+    let (display_width, display_height) = (config.display_width, config.display_height);
+    let use_colors = ansi::use_colors();
+    for image_index in 0..config.num_flyweights {
+        let offset_x_to_image = (image_index % num_distinct_images) * image_width;
+        let mut context = FlyweightContext::new(offset_x_to_image, image_width, image_height);
         // Make sure the entire image can be rendered at each position
         context.position_x = random::random(0..(display_width - image_width) as u32) as f32;
         context.position_y = random::random(0..(display_height - image_height) as u32) as f32;
         context.velocity_x = _flyweight_generate_velocity();
         context.velocity_y = _flyweight_generate_velocity();
+        if use_colors {
+            context.color = Some(IMAGE_COLORS[image_index % IMAGE_COLORS.len()]);
+        }
+        context.layer = image_index as i32;
 
         let flyweight_image = FlyweightImage{ big_resource_id, context };
         image_list.push(flyweight_image);
@@ -146,36 +256,83 @@ fn _flyweight_generate_flyweight_images(big_resource_id: usize, num_flyweights:
 }
 
 
+/// Move the image described by the given context within the display,
+/// bouncing it off the edges of the display.
+///
+/// The display size is provided here; the image size comes from the context.
+fn _flyweight_move_context(context: &mut FlyweightContext, display_width: usize, display_height: usize) {
+    let image_width = context.image_width;
+    let image_height = context.image_height;
+    let mut new_x = context.position_x + context.velocity_x;
+    let mut new_y = context.position_y + context.velocity_y;
+    if new_x < 0.0 || (new_x + (image_width as f32)) > display_width as f32 {
+        context.velocity_x = -context.velocity_x;
+        if new_x < 0.0 {
+            new_x = 0.0;
+        } else {
+            new_x = (display_width - image_width) as f32;
+        }
+    }
+
+    if new_y < 0.0 || (new_y + (image_height as f32)) > display_height as f32 {
+        context.velocity_y = -context.velocity_y;
+        if new_y < 0.0 {
+            new_y = 0.0;
+        } else {
+            new_y = (display_height - image_height) as f32;
+        }
+    }
+
+    context.position_x = new_x;
+    context.position_y = new_y;
+}
+
+/// Bounce two images off each other if they overlap, by exchanging their
+/// velocities along the direction in which they overlap the least (as
+/// happens when two balls of the same weight collide head on).
+///
+/// Images that are already moving apart are left alone, so images that
+/// still overlap after bouncing are not bounced back together.
+fn _flyweight_bounce_contexts(first: &mut FlyweightContext, second: &mut FlyweightContext) {
+    if !first.overlaps(second) {
+        return;
+    }
+    let overlap_x = (first.position_x + first.image_width as f32).min(second.position_x + second.image_width as f32)
+        - first.position_x.max(second.position_x);
+    let overlap_y = (first.position_y + first.image_height as f32).min(second.position_y + second.image_height as f32)
+        - first.position_y.max(second.position_y);
+    if overlap_x < overlap_y {
+        let approaching = (second.position_x - first.position_x) * (second.velocity_x - first.velocity_x) < 0.0;
+        if approaching {
+            std::mem::swap(&mut first.velocity_x, &mut second.velocity_x);
+        }
+    } else {
+        let approaching = (second.position_y - first.position_y) * (second.velocity_y - first.velocity_y) < 0.0;
+        if approaching {
+            std::mem::swap(&mut first.velocity_y, &mut second.velocity_y);
+        }
+    }
+}
+
 /// Move the given flyweight instances within the display, bouncing them off
-/// the edges of the display.
+/// the edges of the display and, if collisions is true, off each other.
+///
+/// Only the contexts (the extrinsic state) of the images change; the image
+/// data they share in the big resource is untouched.
 ///
 /// The display size and image size are provided here
-fn _flyweight_move_images(images: &mut Vec<FlyweightImage>, display_width: usize, display_height: usize) {
+fn _flyweight_move_images(images: &mut [FlyweightImage], display_width: usize, display_height: usize, collisions: bool) {
     for image in images.iter_mut() {
-        let image_width = image.context.image_width;
-        let image_height = image.context.image_height;
-        let mut new_x = image.context.position_x + image.context.velocity_x;
-        let mut new_y = image.context.position_y + image.context.velocity_y;
-        if new_x < 0.0 || (new_x + (image_width as f32)) > display_width as f32 {
-            image.context.velocity_x = -image.context.velocity_x;
-            if new_x < 0.0 {
-                new_x = 0.0;
-            } else {
-                new_x = (display_width - image_width) as f32;
-            }
-        }
-
-        if new_y < 0.0 || (new_y + (image_height as f32)) > display_height as f32 {
-            image.context.velocity_y = -image.context.velocity_y;
-            if new_y < 0.0 {
-                new_y = 0.0;
-            } else {
-                new_y = (display_height - image_height) as f32;
+        _flyweight_move_context(&mut image.context, display_width, display_height);
+    }
+    if collisions {
+        for first_index in 0..images.len() {
+            let (head, tail) = images.split_at_mut(first_index + 1);
+            let first = &mut head[first_index].context;
+            for second in tail.iter_mut() {
+                _flyweight_bounce_contexts(first, &mut second.context);
             }
         }
-
-        image.context.position_x = new_x;
-        image.context.position_y = new_y;
     }
 }
 
@@ -210,33 +367,266 @@ fn _fylweight_generate_display(width: usize, height: usize) -> Display {
 
 /// Render the given images into the display, drawing on the big resource
 /// "image" found in the given Big Resource Manager.
+///
+/// The images are drawn back to front, lowest layer first, so where images
+/// overlap, the image in the higher layer is in front.  Images in the same
+/// layer are drawn in the order given.
 fn _flyweight_render_images(resource_manager: &BigResourceManager, images: &Vec<FlyweightImage>, display: &mut Display) {
+    let mut images_back_to_front: Vec<&FlyweightImage> = images.iter().collect();
+    images_back_to_front.sort_by_key(|image| image.context.layer);
+
     // Render the image into the "display", one image for each instance
     // of the Flyweight class.
-    for index in 0..images.len() {
-        images[index].render(resource_manager, display);
+    for image in images_back_to_front {
+        image.render(resource_manager, display);
+    }
+}
+
+/// Render the display to the screen in full, starting at the current cursor
+/// position, and record the display as shown.
+///
+/// # Parameters
+/// - display
+///
+///   The display to render to the console.
+fn _flyweight_show_display(display: &mut Display) {
+    display.forget_shown();
+    let mut output = String::new();
+    let mut row = 0;
+    for change in display.take_changes() {
+        // Without a previous frame, every row of the display is changed, so
+        // each change is part of a row and all rows are present.
+        if change.row != row {
+            output.push('\n');
+            row = change.row;
+        }
+        if change.col == 0 {
+            output.push_str("  ");
+        }
+        output.push_str(&_flyweight_styled_text(&change));
+    }
+
+    println!("{output}\n");
+}
+
+/// Helper function to get the text of a change to the display in the
+/// change's color.
+///
+/// # Parameters
+/// - change
+///
+///   The change to the display.
+///
+/// # Returns
+/// Returns the text, wrapped in the ANSI sequences for its color if it has
+/// one.
+fn _flyweight_styled_text(change: &DisplayChange) -> String {
+    match change.color {
+        Some(color) => change.text.clone().with(color).to_string(),
+        None => change.text.clone(),
     }
 }
 
-/// Render the display to the screen.
+/// Render to the screen only the characters in the display that have
+/// changed since it was last shown, as a single string of cursor moves and
+/// characters, and record the display as shown.
 ///
 /// # Parameters
 /// - display
 ///
 ///   The display to render to the console.
-fn _flyweight_show_display(display: &Display) {
+/// - display_left
+///
+///   Column in the console window of the display's left edge, starting at 1.
+/// - display_top
+///
+///   Row in the console window of the display's top edge, starting at 1.
+///
+/// # Returns
+/// Returns the number of characters written to the console.
+fn _flyweight_show_display_changes(display: &mut Display, display_left: u16, display_top: u16) -> usize {
     let mut output = String::new();
-    for row in 0..display.height {
-        let mut row_string = String::new();
-        row_string.push_str("  ");
-        for col in 0..display.width {
-            row_string.push(display.display[row][col]);
+    for change in display.take_changes() {
+        output.push_str(&cursor::cursor_position_sequence(display_left + change.col as u16, display_top + change.row as u16));
+        output.push_str(&_flyweight_styled_text(&change));
+    }
+    print!("{output}");
+    let _ = stdout().flush();
+    output.len()
+}
+
+/// Show the iteration count, the keys that control the animation and the
+/// frame statistics on the two rows above the display.
+///
+/// Both rows are positioned explicitly, as the console is in raw mode while
+/// the animation runs.
+///
+/// # Parameters
+/// - iteration
+///
+///   The iteration about to be shown, starting at 1.
+/// - num_iterations
+///
+///   Total number of iterations.
+/// - paused
+///
+///   true if the animation is paused.
+/// - frame_stats
+///
+///   The frame statistics so far.
+/// - left
+///
+///   Column in the console window of the left edge, starting at 1.
+/// - top
+///
+///   Row in the console window of the first row, starting at 1.
+/// - width
+///
+///   Width of each row, in characters.  Rows are padded to this width, to
+///   erase anything left over from before, or cut off at this width, so
+///   they do not wrap in a narrow console window.
+fn _flyweight_show_status(iteration: usize, num_iterations: usize, paused: bool,
+    frame_stats: &FrameStats, left: u16, top: u16, width: usize) {
+    let keys = if paused {
+        "[paused: Space resumes, . steps, other keys exit]"
+    } else {
+        "[Space pauses, other keys exit]"
+    };
+    let iteration_line = format!("  {iteration:5}/{num_iterations} iterations {keys}");
+    let status_line = format!("  {}", frame_stats.status_line());
+    cursor::set_cursor_position(left, top);
+    print!("{iteration_line:<width$.width$}");
+    cursor::set_cursor_position(left, top + 1);
+    print!("{status_line:<width$.width$}");
+    let _ = stdout().flush();
+}
+
+/// Fit the display to a console window of the given size, after clearing
+/// the console window.
+///
+/// The display is made smaller than the size in the configuration if the
+/// window is too small for it (or back up to that size as the window grows)
+/// and the images are moved back inside the display if they no longer fit.
+/// If the window is too small for even a display just larger than an image,
+/// the display is left as it is and a message is shown instead.  Either way,
+/// the whole display must be shown again.
+///
+/// # Parameters
+/// - config
+///
+///   The configuration, giving the largest display and the image size.
+/// - display
+///
+///   The display to fit, which is replaced if its size changes.
+/// - images
+///
+///   The images to keep inside the display.
+/// - console_size
+///
+///   Width and height of the console window, in characters.
+/// - display_left
+///
+///   Column in the console window of the display's left edge, starting at 1.
+/// - display_top
+///
+///   Row in the console window of the display's top edge, starting at 1.
+///
+/// # Returns
+/// Returns true if the display fits in the console window; otherwise,
+/// returns false, in which case the display should not be shown.
+fn _flyweight_fit_display(config: &FlyweightConfig, display: &mut Display, images: &mut [FlyweightImage],
+    console_size: (u16, u16), display_left: u16, display_top: u16) -> bool {
+    cursor::clear_screen();
+    display.forget_shown();
+
+    // Stay out of the last column so the console never wraps a row.
+    let (console_width, console_height) = console_size;
+    let available_width = (console_width as usize).saturating_sub(display_left as usize);
+    let available_height = (console_height as usize + 1).saturating_sub(display_top as usize);
+    let width = min(config.display_width, available_width);
+    let height = min(config.display_height, available_height);
+    if width <= config.image_width || height <= config.image_height {
+        cursor::set_cursor_position(1, display_top);
+        print!("  The window is too small to show the images; make it larger.");
+        let _ = stdout().flush();
+        return false;
+    }
+
+    if width != display.width || height != display.height {
+        *display = _fylweight_generate_display(width, height);
+        for image in images.iter_mut() {
+            let context = &mut image.context;
+            context.position_x = context.position_x.clamp(0.0, (width - context.image_width) as f32);
+            context.position_y = context.position_y.clamp(0.0, (height - context.image_height) as f32);
+        }
+    }
+    true
+}
+
+/// Show how two overlapping images are layered, once with each image in
+/// front.  The images have transparent spaces, so the image behind shows
+/// through the spaces in the image in front.
+fn _flyweight_show_layering() {
+    let mut big_resource_manager = BigResourceManager::new();
+    let data: Vec<Vec<char>> = LAYERING_IMAGES.iter().map(|row| row.chars().collect()).collect();
+    let mut big_resource = BigResource::new(data);
+    big_resource.transparent_character = Some(' ');
+    let resource_id = big_resource_manager.add_resource(big_resource);
+
+    let use_colors = ansi::use_colors();
+    let mut images: Vec<FlyweightImage> = Vec::new();
+    for (image_index, position_x) in [(0, 1.0), (1, 5.0)] {
+        let mut context = FlyweightContext::new(image_index * LAYERING_IMAGE_WIDTH,
+            LAYERING_IMAGE_WIDTH, LAYERING_IMAGES.len());
+        context.position_x = position_x;
+        context.position_y = 1.0;
+        context.layer = image_index as i32;
+        if use_colors {
+            context.color = Some(IMAGE_COLORS[image_index]);
         }
-        row_string.push('\n');
-        output.push_str(&row_string);
+        images.push(FlyweightImage { big_resource_id: resource_id, context });
     }
 
-    println!("{output}");
+    for front_index in [1, 0] {
+        let back_index = 1 - front_index;
+        images[front_index].context.layer = 1;
+        images[back_index].context.layer = 0;
+        println!("  The {} in front of the {}:", LAYERING_IMAGE_NAMES[front_index], LAYERING_IMAGE_NAMES[back_index]);
+        let mut display = _fylweight_generate_display(2 * LAYERING_IMAGE_WIDTH - 1, LAYERING_IMAGES.len() + 2);
+        _flyweight_render_images(&big_resource_manager, &images, &mut display);
+        _flyweight_show_display(&mut display);
+    }
+}
+
+/// Show that the images' big resource ID stops working once the big resource
+/// is removed from the manager, even after the same resource is added back
+/// in the same place, so images left holding it draw nothing instead of
+/// drawing from the wrong resource.
+///
+/// # Parameters
+/// - big_resource_manager
+///
+///   The manager holding the big resource.
+/// - resource_id
+///
+///   The ID of the big resource used by the images.
+fn _flyweight_show_stale_resource_id(big_resource_manager: &mut BigResourceManager, resource_id: BigResourceId) {
+    println!("  Removing big resource {resource_id} used by the images...");
+    let Some(big_resource) = big_resource_manager.remove_resource(resource_id) else {
+        println!("    Big resource {resource_id} could not be removed!");
+        return;
+    };
+    println!("    The manager now holds {} big resource(s).", big_resource_manager.resource_count());
+    let new_resource_id = big_resource_manager.add_resource(big_resource);
+    println!("  Adding it back gives it the ID {new_resource_id}; the manager holds {} big resource(s).",
+        big_resource_manager.resource_count());
+    for id in [resource_id, new_resource_id] {
+        let found = match big_resource_manager.get_resource(id) {
+            Some(_) => "the big resource",
+            None => "nothing (the ID is stale)",
+        };
+        println!("    Looking up ID {id} finds {found}.");
+    }
 }
 
 //-----------------------------------------------------------------------------
@@ -254,58 +644,191 @@ fn _flyweight_show_display(display: &Display) {
 /// image so they all share the same image but have different positions
 /// and velocities.  The image is rendered to a display area using the
 /// FlyweightImage struct.  The FlyweightImage struct instances then have their
-/// positions updated, bouncing off the edges of the display area 60
-/// times a second.  This continues for 1000 iterations or until a key
+/// positions updated, bouncing off the edges of the display area at the
+/// configured frame rate (60 frames a second by default).  This continues for 1000 iterations or until a key
+/// other than Space (pause and resume) or `.` (single step while paused)
 /// is pressed.
+///
+/// If the `--no-interactive` exercise option is given, each frame of the
+/// animation is instead printed one after another, with no cursor movement,
+/// delay or keyboard input, and nothing else is shown.
+///
+/// If the `--benchmark` exercise option is given, the animation of thousands
+/// of images is instead run without any delay or output, once with the
+/// FlyweightImage structs sharing the big resource and once with each image
+/// owning a full copy of its image data, and the memory used, time per frame
+/// and throughput are shown for both.
+///
+/// The sizes and counts come from a FlyweightConfig, which can be changed
+/// with exercise options.
 // ! [Using Flyweight in Rust]
 pub fn flyweight_exercise() -> Result<(), String> {
     println!("");
     println!("Flyweight Exercise");
 
+    let mut config = FlyweightConfig::from_exercise_options()?;
+
+    if has_exercise_option("--no-interactive") {
+        let frames = flyweight_headless::render_frames(&config, config.num_iterations)?;
+        for (index, frame) in frames.iter().enumerate() {
+            println!("  Frame {}/{}:", index + 1, config.num_iterations);
+            for row in frame.lines() {
+                println!("  {row}");
+            }
+            println!();
+        }
+        println!("  Done.");
+        return Ok(());
+    }
+
+    if let Some(seed) = config.seed {
+        random::set_seed(seed);
+    }
+    let (big_resource, num_distinct_images) = _flyweight_load_big_resource(&mut config)?;
+
+    if has_exercise_option("--benchmark") {
+        flyweight_benchmark::run_benchmark(&config, big_resource, num_distinct_images);
+        println!("  Done.");
+        return Ok(());
+    }
+
     let mut big_resource_manager = BigResourceManager::new();
-    let big_resource = _flyweight_generate_big_resource(NUMFLYWEIGHTS, IMAGE_WIDTH, IMAGE_HEIGHT);
     let resource_id = big_resource_manager.add_resource(big_resource);
     println!("bigResourceId = {resource_id}");
 
     let mut flyweight_images: Vec<FlyweightImage> = Vec::new();
-    _flyweight_generate_flyweight_images(resource_id, NUMFLYWEIGHTS,
-        IMAGE_WIDTH, IMAGE_HEIGHT, DISPLAY_WIDTH, DISPLAY_HEIGHT,
-        &mut flyweight_images);
+    _flyweight_generate_flyweight_images(resource_id, num_distinct_images, &config, &mut flyweight_images);
 
     // Create the "display".
     // We use a list of character arrays so we can write to each
     // character position individually.  In Rust, strings are immutable
     // and changing a character in a string is not allowed.
-    let mut display = _fylweight_generate_display(DISPLAY_WIDTH, DISPLAY_HEIGHT);
+    let mut display = _fylweight_generate_display(config.display_width, config.display_height);
 
     // Finally, display the rendered output.
-    println!("  The image rendered {} times:", NUMFLYWEIGHTS);
-    println!(); // Blank line for iteration count
+    println!("  The image rendered {} times:", config.num_flyweights);
     _flyweight_render_images(&big_resource_manager, &flyweight_images, &mut display);
-    _flyweight_show_display(&display);
+    _flyweight_show_display(&mut display);
 
-    // Initialize raw key input before getting cursor position.
-    cursor::hide_cursor();
+    _flyweight_show_layering();
 
     // Now let's have some fun and bounce those images around for a while!
-    // (Or until a keypress.)
-    let (cursor_left, mut cursor_top) = cursor::get_cursor_position();
-    cursor_top -= (DISPLAY_HEIGHT + 1) as u16;
-
-    for index in 0..NUM_ITERATIONS {
-        cursor::set_cursor_position(cursor_left, cursor_top - 1);
-        println!("  {:5}/{} iterations [press a key to exit early]", index + 1, NUM_ITERATIONS);
-        cursor::set_cursor_position(cursor_left, cursor_top);
+    // (Or until a keypress.)  This is done on the alternate screen, which
+    // is left when alternate_screen goes out of scope, however that happens.
+    // Raw mode reports each key as soon as it is pressed; without a console
+    // to put in raw mode, there are no keys to read anyway.
+    let alternate_screen = cursor::AlternateScreen::enter()?;
+    let raw_mode = key_input::RawMode::enable().ok();
+    display.forget_shown();
+
+    // The iteration count is on the top row, the frame statistics are on
+    // the next row and the display starts on the row after that, with each
+    // row of the display indented by two characters.
+    let cursor_left = 1;
+    let cursor_top = 3;
+    let display_left = cursor_left + 2;
+    // Without a console window, there is no size to fit to.
+    let mut status_width = config.display_width + 2;
+    let mut display_fits = true;
+    if let Some(console_size) = cursor::console_size() {
+        display_fits = _flyweight_fit_display(&config, &mut display, &mut flyweight_images,
+            console_size, display_left, cursor_top);
+        status_width = (console_size.0 as usize).saturating_sub(1);
+    }
+    let mut num_frames = 0;
+    let mut num_characters_written = 0;
+    let frame_time = config.frame_time();
+    let mut frame_stats = FrameStats::new(frame_time);
+    let mut frame_start = Instant::now();
+    let mut next_frame_due = frame_start + frame_time;
+    let mut paused = false;
+    let mut index = 0;
+    let render_loop_span = profile_span("render loop");
+    while index < config.num_iterations {
+        if paused {
+            // Wait for Space to resume or `.` to show just the next frame,
+            // showing the current frame again if the window changes size.
+            _flyweight_show_status(index + 1, config.num_iterations, paused, &frame_stats,
+                cursor_left, cursor_top - 2, status_width);
+            match key_input::wait_for_event() {
+                Some(ConsoleEvent::Key(KeyCode::Char(' '))) => paused = false,
+                Some(ConsoleEvent::Key(KeyCode::Char('.'))) => {}
+                Some(ConsoleEvent::Resize { width, height }) => {
+                    display_fits = _flyweight_fit_display(&config, &mut display, &mut flyweight_images,
+                        (width, height), display_left, cursor_top);
+                    status_width = (width as usize).saturating_sub(1);
+                    if display_fits {
+                        _flyweight_clear_display(&mut display);
+                        _flyweight_render_images(&big_resource_manager, &flyweight_images, &mut display);
+                        num_characters_written += _flyweight_show_display_changes(&mut display, display_left, cursor_top);
+                    }
+                    continue;
+                }
+                _ => break,
+            }
+            frame_start = Instant::now();
+            next_frame_due = frame_start + frame_time;
+        }
+        _flyweight_show_status(index + 1, config.num_iterations, paused, &frame_stats,
+            cursor_left, cursor_top - 2, status_width);
+        begin_span("move and render");
         _flyweight_clear_display(&mut display);
-        _flyweight_move_images(&mut flyweight_images, display.width, display.height);
+        _flyweight_move_images(&mut flyweight_images, display.width, display.height, config.collisions);
         _flyweight_render_images(&big_resource_manager, &flyweight_images, &mut display);
-        _flyweight_show_display(&display);
-        thread::sleep(Duration::from_millis(16)); // 60 frames a second
-        if key_input::check_for_key() {
-            break;
+        end_span();
+        begin_span("show display");
+        if display_fits {
+            num_characters_written += _flyweight_show_display_changes(&mut display, display_left, cursor_top);
         }
+        num_frames += 1;
+        index += 1;
+        end_span();
+        if paused {
+            // A single step, which is not counted in the frame statistics.
+            continue;
+        }
+        // Wait until the next frame is due, so frames are shown at the
+        // target frame rate however long moving and rendering took.  Frames
+        // are due at fixed times, so any time a sleep overshoots is taken off
+        // the next frame instead of adding up.  When behind, go straight on
+        // to the next frame, and count from there rather than trying to catch
+        // up with frames shown in a rush.
+        let now = Instant::now();
+        if now < next_frame_due {
+            thread::sleep(next_frame_due - now);
+            next_frame_due += frame_time;
+        } else {
+            next_frame_due = now + frame_time;
+        }
+        match key_input::get_event() {
+            Some(ConsoleEvent::Key(KeyCode::Char(' '))) => paused = true,
+            Some(ConsoleEvent::Key(KeyCode::Char('.'))) | Some(ConsoleEvent::Mouse { .. }) | None => {}
+            Some(ConsoleEvent::Resize { width, height }) => {
+                display_fits = _flyweight_fit_display(&config, &mut display, &mut flyweight_images,
+                    (width, height), display_left, cursor_top);
+                status_width = (width as usize).saturating_sub(1);
+            }
+            Some(ConsoleEvent::Key(_)) => break,
+        }
+        let frame_end = Instant::now();
+        frame_stats.record(frame_end - frame_start);
+        frame_start = frame_end;
     }
-    cursor::show_cursor();
+    drop(render_loop_span);
+    drop(raw_mode);
+    drop(alternate_screen);
+
+    println!("  The image after {num_frames} iterations:");
+    _flyweight_show_display(&mut display);
+
+    print!("{}", frame_stats.summary());
+
+    // Each row of a full frame is indented and ends with a newline.
+    let full_frame_size = (config.display_width + 3) * config.display_height;
+    println!("  Wrote {} characters per frame on average (a full frame is {full_frame_size})",
+        num_characters_written / max(num_frames, 1));
+
+    _flyweight_show_stale_resource_id(&mut big_resource_manager, resource_id);
 
     println!("  Done.");
 
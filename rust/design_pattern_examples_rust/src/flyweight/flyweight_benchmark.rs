@@ -0,0 +1,298 @@
+//! Contains the run_benchmark() function that compares the Flyweight images,
+//! which all share one big resource, against "naive" images, each of which
+//! owns a full copy of its own image data.
+
+//-----------------------------------------------------------------------------
+
+use std::mem::size_of;
+use std::time::{Duration, Instant};
+
+use super::flyweight_bigresource::BigResource;
+use super::flyweight_bigresource_manager::BigResourceManager;
+use super::flyweight_config::FlyweightConfig;
+use super::flyweight_context::FlyweightContext;
+use super::flyweight_display::Display;
+use super::flyweight_image::FlyweightImage;
+
+//-----------------------------------------------------------------------------
+
+/// Width of the virtual display the benchmark animates the images on, in
+/// characters.
+const BENCHMARK_DISPLAY_WIDTH: usize = 1000;
+/// Height of the virtual display the benchmark animates the images on, in
+/// characters.
+const BENCHMARK_DISPLAY_HEIGHT: usize = 250;
+
+//-----------------------------------------------------------------------------
+
+/// Represents an image that does not use the Flyweight pattern.  Each
+/// instance owns a full copy of the image data it renders, so the memory
+/// used grows with every image created.
+struct NaiveImage {
+    /// The image data owned by this image alone.
+    resource: BigResource,
+
+    /// The context associated with this image.  The offset into the image
+    /// data is always 0 since the image data holds only this one image.
+    context: FlyweightContext,
+}
+
+impl NaiveImage {
+    /// Render this image into the given display at the image's position.
+    ///
+    /// # Parameters
+    /// - display
+    ///
+    ///   The display to render to
+    fn render(&self, display: &mut Display) {
+        display.pen_color = self.context.color;
+        self.resource.render(display, self.context.offset_x_to_image,
+        self.context.image_width, self.context.image_height,
+        self.context.position_x as isize, self.context.position_y as isize);
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Holds the results of running the animation for one kind of image.
+struct BenchmarkResult {
+    /// Number of bytes of image data shared by all the images (the
+    /// intrinsic state).
+    shared_bytes: usize,
+    /// Number of bytes used by the images themselves, including any image
+    /// data each image has to itself.
+    per_image_bytes: usize,
+    /// Time taken by the fastest frame.
+    min_frame_time: Duration,
+    /// Time taken by the slowest frame.
+    max_frame_time: Duration,
+    /// Total time taken by all frames.
+    total_time: Duration,
+}
+
+impl BenchmarkResult {
+    /// Retrieve the average time taken by a frame.
+    ///
+    /// # Parameters
+    /// - num_frames
+    ///
+    ///   The number of frames that were run.
+    ///
+    /// # Returns
+    /// Returns the average time per frame.
+    fn average_frame_time(&self, num_frames: usize) -> Duration {
+        self.total_time / num_frames.max(1) as u32
+    }
+
+    /// Retrieve the total number of bytes used by the images and their image
+    /// data.
+    fn total_bytes(&self) -> usize {
+        self.shared_bytes + self.per_image_bytes
+    }
+
+    /// Retrieve the number of frames run per second.
+    ///
+    /// # Parameters
+    /// - num_frames
+    ///
+    ///   The number of frames that were run.
+    ///
+    /// # Returns
+    /// Returns the frames per second, or 0 if no time was taken.
+    fn frames_per_second(&self, num_frames: usize) -> f64 {
+        let seconds = self.total_time.as_secs_f64();
+        if seconds > 0.0 { num_frames as f64 / seconds } else { 0.0 }
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Calculate the number of bytes used by the given image data.
+///
+/// # Parameters
+/// - data
+///
+///   The image data, one list of characters for each row.
+///
+/// # Returns
+/// Returns the number of bytes used by the image data, including the rows.
+fn _image_data_size(data: &[Vec<char>]) -> usize {
+    data.iter().map(|row| size_of::<Vec<char>>() + row.len() * size_of::<char>()).sum()
+}
+
+/// Make a copy of the given context.
+///
+/// # Parameters
+/// - context
+///
+///   The context to copy.
+/// - offset_x_to_image
+///
+///   Offset into the image data to left edge of the image for the copy.
+///
+/// # Returns
+/// Returns a new FlyweightContext with the same size, position and velocity
+/// as the given context.
+fn _copy_context(context: &FlyweightContext, offset_x_to_image: usize) -> FlyweightContext {
+    let mut copy = FlyweightContext::new(offset_x_to_image, context.image_width, context.image_height);
+    copy.position_x = context.position_x;
+    copy.position_y = context.position_y;
+    copy.velocity_x = context.velocity_x;
+    copy.velocity_y = context.velocity_y;
+    copy.color = context.color;
+    copy
+}
+
+/// Run the animation for the given number of frames, timing each frame.  A
+/// frame consists of clearing the display, moving every image, and rendering
+/// every image into the display.
+///
+/// # Parameters
+/// - num_frames
+///
+///   Number of frames to run.
+/// - display
+///
+///   The display to render into.
+/// - frame
+///
+///   Function called to move and render the images for one frame.
+///
+/// # Returns
+/// Returns a BenchmarkResult with the frame times filled in.
+fn _time_frames<F>(num_frames: usize, display: &mut Display, mut frame: F) -> BenchmarkResult
+where F: FnMut(&mut Display) {
+    let mut result = BenchmarkResult {
+        shared_bytes: 0,
+        per_image_bytes: 0,
+        min_frame_time: Duration::MAX,
+        max_frame_time: Duration::ZERO,
+        total_time: Duration::ZERO,
+    };
+    for _frame_index in 0..num_frames {
+        let start = Instant::now();
+        super::_flyweight_clear_display(display);
+        frame(display);
+        let elapsed = start.elapsed();
+        result.min_frame_time = result.min_frame_time.min(elapsed);
+        result.max_frame_time = result.max_frame_time.max(elapsed);
+        result.total_time += elapsed;
+    }
+    result
+}
+
+/// Show the memory used in the benchmark by one kind of image.
+fn _show_memory(label: &str, result: &BenchmarkResult) {
+    println!("    {:<10} {:>12} {:>14} {:>12}", label,
+        result.shared_bytes, result.per_image_bytes, result.total_bytes());
+}
+
+/// Show the frame times and throughput of the benchmark for one kind of
+/// image.
+fn _show_times(label: &str, result: &BenchmarkResult, num_images: usize, num_frames: usize) {
+    let frames_per_second = result.frames_per_second(num_frames);
+    println!("    {:<10} {:>12.1} {:>12.1} {:>12.1} {:>10.1} {:>12.0}", label,
+        result.min_frame_time.as_secs_f64() * 1_000_000.0,
+        result.average_frame_time(num_frames).as_secs_f64() * 1_000_000.0,
+        result.max_frame_time.as_secs_f64() * 1_000_000.0,
+        frames_per_second,
+        frames_per_second * num_images as f64);
+}
+
+//-----------------------------------------------------------------------------
+
+/// Run the same animation twice, once with Flyweight images that share a
+/// single big resource and once with naive images that each own a full copy
+/// of their image data, then show the memory used (split into the image data
+/// shared by all images and the memory used by each image), the per-frame
+/// times and the throughput for both.
+///
+/// The animation is run without any output on a large virtual display of
+/// BENCHMARK_DISPLAY_WIDTH x BENCHMARK_DISPLAY_HEIGHT characters, so that
+/// the thousands of images have room to move.  The display is made larger
+/// if needed so it is always larger than an image.  Both runs start from the same
+/// positions and velocities so they do exactly the same work; the only
+/// difference is where the image data comes from.
+///
+/// # Parameters
+/// - config
+///
+///   The number of images and frames and the image size to use.
+/// - big_resource
+///
+///   The big resource holding the distinct images, shared by all the
+///   Flyweight images.
+/// - num_distinct_images
+///
+///   Number of distinct images in the big resource.
+pub fn run_benchmark(config: &FlyweightConfig, big_resource: BigResource, num_distinct_images: usize) {
+    let image_width = config.image_width;
+    let (num_images, num_frames) = (config.num_benchmark_images, config.num_benchmark_frames);
+    let display_width = BENCHMARK_DISPLAY_WIDTH.max(image_width + 1);
+    let display_height = BENCHMARK_DISPLAY_HEIGHT.max(config.image_height + 1);
+    // The naive images are cut out of a copy of the big resource, as if each
+    // image had loaded its own.
+    let source = BigResource::new(big_resource.data.clone());
+    let mut big_resource_manager = BigResourceManager::new();
+    let big_resource_size = _image_data_size(&big_resource.data);
+    let resource_id = big_resource_manager.add_resource(big_resource);
+
+    let images_config = FlyweightConfig {
+        num_flyweights: num_images,
+        display_width,
+        display_height,
+        ..config.clone()
+    };
+    let mut flyweight_images: Vec<FlyweightImage> = Vec::new();
+    super::_flyweight_generate_flyweight_images(resource_id, num_distinct_images, &images_config, &mut flyweight_images);
+
+    // The naive images each get their own copy of the image data.
+    let mut naive_images: Vec<NaiveImage> = Vec::new();
+    for image in flyweight_images.iter() {
+        let offset_x = image.context.offset_x_to_image;
+        let data: Vec<Vec<char>> = source.data.iter()
+            .map(|row| row[offset_x..offset_x + image_width].to_vec())
+            .collect();
+        naive_images.push(NaiveImage {
+            resource: BigResource::new(data),
+            context: _copy_context(&image.context, 0),
+        });
+    }
+
+    println!("  Benchmark: {num_images} images on a {display_width}x{display_height} display, {num_frames} frames each");
+
+    let mut display = super::_fylweight_generate_display(display_width, display_height);
+    let mut flyweight_result = _time_frames(num_frames, &mut display, |display| {
+        // The naive images pass through each other, so these do, too.
+        super::_flyweight_move_images(&mut flyweight_images, display.width, display.height, false);
+        super::_flyweight_render_images(&big_resource_manager, &flyweight_images, display);
+    });
+    flyweight_result.shared_bytes = big_resource_size;
+    flyweight_result.per_image_bytes = flyweight_images.len() * size_of::<FlyweightImage>();
+
+    let mut naive_result = _time_frames(num_frames, &mut display, |display| {
+        for image in naive_images.iter_mut() {
+            super::_flyweight_move_context(&mut image.context, display.width, display.height);
+        }
+        for image in naive_images.iter() {
+            image.render(display);
+        }
+    });
+    naive_result.per_image_bytes = naive_images.iter()
+        .map(|image| size_of::<NaiveImage>() + _image_data_size(&image.resource.data))
+        .sum();
+
+    println!("    {:<10} {:>12} {:>14} {:>12}", "Memory", "Shared (B)", "Per-image (B)", "Total (B)");
+    _show_memory("Flyweight", &flyweight_result);
+    _show_memory("Naive", &naive_result);
+    println!("    {:<10} {:>12} {:>12} {:>12} {:>10} {:>12}", "Time", "Min (us)", "Avg (us)", "Max (us)", "Frames/s", "Images/s");
+    _show_times("Flyweight", &flyweight_result, num_images, num_frames);
+    _show_times("Naive", &naive_result, num_images, num_frames);
+
+    let memory_ratio = naive_result.total_bytes() as f64 / flyweight_result.total_bytes().max(1) as f64;
+    println!("  The naive images use {memory_ratio:.1} times the memory of the flyweight images.");
+    println!("  Each naive image uses {} bytes; each flyweight image uses {} bytes and shares {} bytes with all the others.",
+        naive_result.per_image_bytes / num_images.max(1),
+        flyweight_result.per_image_bytes / num_images.max(1),
+        flyweight_result.shared_bytes);
+}
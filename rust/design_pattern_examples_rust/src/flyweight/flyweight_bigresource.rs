@@ -11,7 +11,12 @@ use super::flyweight_display::Display;
 /// This struct is used in rendering an image to a display using the
 /// render() function.
 pub struct BigResource {
-    data: Vec<Vec<char>>,
+    /// The "image" data, one list of characters for each row.
+    pub data: Vec<Vec<char>>,
+    /// The character in the image data that is transparent, letting
+    /// whatever is already in the display show through, or None if every
+    /// character is drawn.
+    pub transparent_character: Option<char>,
 }
 
 
@@ -27,13 +32,15 @@ impl BigResource {
     ///   not allow a string to be indexed for an individual character.
     ///
     /// # Returns
-    /// Returns the given data wrapped in a new BigResource instance.
+    /// Returns the given data wrapped in a new BigResource instance, with no
+    /// transparent character.
     pub fn new(data: Vec<Vec<char>>) -> BigResource {
-        BigResource { data }
+        BigResource { data, transparent_character: None }
     }
 
     /// Render the specified portion of the big resource into the given display at
-    /// the given coordinates in the display.
+    /// the given coordinates in the display, in the display's pen color.
+    /// Transparent characters are skipped, leaving the display as it was.
     ///
     /// # Parameters
     /// - display
@@ -96,11 +103,18 @@ impl BigResource {
         if image_render_width > 0 && image_render_height > 0 {
             let mut current_display_row = starting_position_y;
             let mut current_image_row = starting_row_in_image;
+            let pen_color = display.pen_color;
             for _row in 0..image_render_height {
                 let display_row = &mut display.display[current_display_row as usize];
+                let color_row = &mut display.colors[current_display_row as usize];
                 let image_row = &self.data[current_image_row as usize];
                 for col in 0..image_render_width {
-                    display_row[(starting_position_x + col) as usize] = image_row[(starting_col_in_image + col) as usize];
+                    let character = image_row[(starting_col_in_image + col) as usize];
+                    if Some(character) == self.transparent_character {
+                        continue;
+                    }
+                    display_row[(starting_position_x + col) as usize] = character;
+                    color_row[(starting_position_x + col) as usize] = pen_color;
                 }
                 current_display_row += 1;
                 current_image_row += 1;
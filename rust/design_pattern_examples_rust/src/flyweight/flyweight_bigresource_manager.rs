@@ -4,6 +4,18 @@
 //-----------------------------------------------------------------------------
 
 use super::flyweight_bigresource::BigResource;
+use super::flyweight_bigresourceid::BigResourceId;
+
+//-----------------------------------------------------------------------------
+
+/// Represents a place in the BigResourceManager for a big resource.
+struct ResourceSlot {
+    /// Incremented every time the resource in this slot is removed, so IDs
+    /// of removed resources no longer match.
+    generation: u32,
+    /// The resource in this slot, if any.
+    resource: Option<BigResource>,
+}
 
 //-----------------------------------------------------------------------------
 
@@ -11,9 +23,17 @@ use super::flyweight_bigresource::BigResource;
 /// Represents a list of instances of the BigResource struct.  Use the
 /// add_resource() method to add a BigResource instance to the list (and
 /// also take ownership of the BigResource instance).  Call get_resource()
-/// with the ID of the resource to get the BigResource instance.
+/// with the ID of the resource to get the BigResource instance, and
+/// remove_resource() with the ID when the resource is no longer needed.
+///
+/// A resource's ID stops working once the resource is removed, even if
+/// another resource is later stored in the same place (see BigResourceId).
 pub struct BigResourceManager {
-    resources: Vec<BigResource>,
+    /// The places for big resources, filled or not.
+    slots: Vec<ResourceSlot>,
+    /// Indices of the slots whose resources have been removed, to be reused
+    /// before adding new slots.
+    free_slots: Vec<usize>,
 }
 
 impl BigResourceManager {
@@ -22,7 +42,7 @@ impl BigResourceManager {
     /// # Returns
     /// Returns a new instance of the BigResourceManager struct.
     pub fn new() -> BigResourceManager {
-        BigResourceManager { resources: vec![] }
+        BigResourceManager { slots: vec![], free_slots: vec![] }
     }
 
     /// Add a BigResource object to the list of big resources.  The list takes
@@ -34,15 +54,44 @@ impl BigResourceManager {
     ///   The BigResource to store in the manager.
     ///
     /// # Returns
-    /// Returns the "id" of the resource so it can be used to access the
-    /// resource later on.  Technically, the ID is actually the index where the
-    /// BigResource instance appears in the internal list.
-    pub fn add_resource(&mut self, resource: BigResource) -> usize {
-        let resource_index = self.resources.len();
-        self.resources.push(resource);
-        resource_index
+    /// Returns the ID of the resource so it can be used to access the
+    /// resource later on.
+    pub fn add_resource(&mut self, resource: BigResource) -> BigResourceId {
+        let index = match self.free_slots.pop() {
+            Some(index) => index,
+            None => {
+                self.slots.push(ResourceSlot { generation: 0, resource: None });
+                self.slots.len() - 1
+            }
+        };
+        let slot = &mut self.slots[index];
+        slot.resource = Some(resource);
+        BigResourceId { index, generation: slot.generation }
     }
 
+    /// Remove a big resource from the manager, giving up ownership of it.
+    /// The resource's ID (and any FlyweightImage still using it) no longer
+    /// refers to any resource.
+    ///
+    /// # Parameters
+    /// - resource_id
+    ///
+    ///   The ID of the resource to remove.
+    ///
+    /// # Returns
+    /// Returns Some(BigResource) containing the resource that was removed;
+    /// otherwise, returns None if there is no resource with that ID (for
+    /// example, because it was already removed).
+    pub fn remove_resource(&mut self, resource_id: BigResourceId) -> Option<BigResource> {
+        let slot = self.slots.get_mut(resource_id.index)?;
+        if slot.generation != resource_id.generation {
+            return None;
+        }
+        let resource = slot.resource.take()?;
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free_slots.push(resource_id.index);
+        Some(resource)
+    }
 
     /// Retrieve the requested big resource.
     ///
@@ -53,12 +102,18 @@ impl BigResourceManager {
     ///
     /// # Returns
     /// Returns Some(&BigResource) if the resource exists; otherwise, returns
-    /// None.
-    pub fn get_resource(&self, resource_id: usize) -> Option<&BigResource> {
-        if resource_id < self.resources.len() {
-            Some(&self.resources[resource_id])
+    /// None, including when the ID is stale because the resource was removed.
+    pub fn get_resource(&self, resource_id: BigResourceId) -> Option<&BigResource> {
+        let slot = self.slots.get(resource_id.index)?;
+        if slot.generation == resource_id.generation {
+            slot.resource.as_ref()
         } else {
             None
         }
     }
+
+    /// Retrieve the number of big resources in the manager.
+    pub fn resource_count(&self) -> usize {
+        self.slots.len() - self.free_slots.len()
+    }
 }
@@ -0,0 +1,29 @@
+//! Contains the BigResourceId struct, the handle a FlyweightImage uses to
+//! refer to a big resource held by the BigResourceManager.
+
+//-----------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+//-----------------------------------------------------------------------------
+
+/// Represents the ID of a big resource in a BigResourceManager.
+///
+/// The ID is the index of the slot holding the resource along with the
+/// generation of that slot.  When a resource is removed, its slot can be
+/// reused for another resource but with the next generation, so an ID kept
+/// from before the removal (a "stale" ID) no longer matches anything instead
+/// of quietly referring to whatever resource took its place.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BigResourceId {
+    /// Index of the slot in the BigResourceManager holding the resource.
+    pub(crate) index: usize,
+    /// Generation of the slot when the resource was added.
+    pub(crate) generation: u32,
+}
+
+impl Display for BigResourceId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.index, self.generation)
+    }
+}
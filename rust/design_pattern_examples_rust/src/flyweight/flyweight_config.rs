@@ -0,0 +1,182 @@
+//! Contains the FlyweightConfig struct that holds the sizes and counts used
+//! by the Flyweight exercise.
+
+use std::time::Duration;
+
+use crate::helpers::exercise_options::{has_exercise_option, get_exercise_option_value};
+
+//-----------------------------------------------------------------------------
+
+/// Smallest width or height of an image, in characters, which leaves room
+/// for the image's border and at least one character of background.
+const MIN_IMAGE_SIZE: usize = 3;
+
+//-----------------------------------------------------------------------------
+
+/// Represents the sizes and counts used by the Flyweight exercise, which can
+/// be changed with exercise options to see how the pattern scales.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FlyweightConfig {
+    /// Width of the "display", in characters, in which to render Flyweight
+    /// "images".
+    pub display_width: usize,
+    /// Height of the "display", in characters, in which to render Flyweight
+    /// "images".
+    pub display_height: usize,
+    /// Width of an individual Flyweight "image", in characters.
+    pub image_width: usize,
+    /// Height of an individual Flyweight "image", in characters.
+    pub image_height: usize,
+    /// Number of Flyweight "images" to generate and manipulate.
+    pub num_flyweights: usize,
+    /// Number of iterations of moving and rendering the Flyweight "images".
+    pub num_iterations: usize,
+    /// Number of frames a second to show while animating the Flyweight
+    /// "images".
+    pub target_fps: usize,
+    /// true if the Flyweight "images" bounce off each other as well as off
+    /// the edges of the display.
+    pub collisions: bool,
+    /// Seed for placing the Flyweight "images" in the same places every
+    /// time, or None to place them differently every time.
+    pub seed: Option<u64>,
+    /// Number of Flyweight "images" to animate in the benchmark.
+    pub num_benchmark_images: usize,
+    /// Number of frames to run for each kind of image in the benchmark.
+    pub num_benchmark_frames: usize,
+    /// Directory from which to load the sprites to use as the Flyweight
+    /// "images", or None to generate the "images".
+    pub sprite_directory: Option<String>,
+}
+
+impl FlyweightConfig {
+    /// Constructor for the default configuration: five 30x5 images bouncing
+    /// around an 80x20 display for 1000 iterations at 60 frames a second,
+    /// passing through each other, and a benchmark of 5000 images for 100
+    /// frames.
+    pub fn new() -> FlyweightConfig {
+        FlyweightConfig {
+            display_width: 80,
+            display_height: 20,
+            image_width: 30,
+            image_height: 5,
+            num_flyweights: 5,
+            num_iterations: 1000,
+            target_fps: 60,
+            collisions: false,
+            seed: None,
+            num_benchmark_images: 5000,
+            num_benchmark_frames: 100,
+            sprite_directory: None,
+        }
+    }
+
+    /// Constructor for the default configuration changed by any of the
+    /// exercise options `--benchmark-frames <n>`, `--benchmark-images <n>`,
+    /// `--collisions`, `--count <n>`,
+    /// `--display <width>x<height>`, `--fps <n>`, `--image <width>x<height>`,
+    /// `--iterations <n>`, `--seed <n>` and `--sprites <dir>`.
+    ///
+    /// # Returns
+    /// Returns `Ok(FlyweightConfig)` containing the configuration; otherwise,
+    /// returns `Err(String)` naming the option that is not valid and why.
+    pub fn from_exercise_options() -> Result<FlyweightConfig, String> {
+        let mut config = FlyweightConfig::new();
+        config.collisions = has_exercise_option("--collisions");
+        if let Some(value) = get_exercise_option_value("--count") {
+            config.num_flyweights = _parse_count("--count", &value)?;
+        }
+        if let Some(value) = get_exercise_option_value("--iterations") {
+            config.num_iterations = _parse_count("--iterations", &value)?;
+        }
+        if let Some(value) = get_exercise_option_value("--benchmark-images") {
+            config.num_benchmark_images = _parse_count("--benchmark-images", &value)?;
+        }
+        if let Some(value) = get_exercise_option_value("--benchmark-frames") {
+            config.num_benchmark_frames = _parse_count("--benchmark-frames", &value)?;
+        }
+        if let Some(value) = get_exercise_option_value("--fps") {
+            config.target_fps = _parse_count("--fps", &value)?;
+        }
+        if let Some(value) = get_exercise_option_value("--seed") {
+            let seed = value.trim().parse::<u64>()
+                .map_err(|_| format!("--seed: '{value}' is not a whole number"))?;
+            config.seed = Some(seed);
+        }
+        config.sprite_directory = get_exercise_option_value("--sprites");
+        if let Some(value) = get_exercise_option_value("--image") {
+            (config.image_width, config.image_height) = _parse_size("--image", &value)?;
+            if config.image_width < MIN_IMAGE_SIZE || config.image_height < MIN_IMAGE_SIZE {
+                return Err(format!("--image: an image must be at least {MIN_IMAGE_SIZE}x{MIN_IMAGE_SIZE}"));
+            }
+        }
+        if let Some(value) = get_exercise_option_value("--display") {
+            (config.display_width, config.display_height) = _parse_size("--display", &value)?;
+        }
+        Ok(config)
+    }
+
+    /// How long each frame is meant to take to show the target number of
+    /// frames a second.
+    pub fn frame_time(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.target_fps as f64)
+    }
+
+    /// Make sure an image fits in the display with room to move.  The images
+    /// are placed at random positions where they fit entirely, so there must
+    /// be at least one such position to move between.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the display is larger than an image; otherwise,
+    /// returns `Err(String)` giving both sizes.
+    pub fn check_fits(&self) -> Result<(), String> {
+        if self.image_width >= self.display_width || self.image_height >= self.display_height {
+            return Err(format!("--display: the display ({}x{}) must be larger than an image ({}x{})",
+                self.display_width, self.display_height, self.image_width, self.image_height));
+        }
+        Ok(())
+    }
+}
+
+/// Helper function to parse the value of an exercise option that is a count,
+/// which must be at least 1.
+///
+/// # Parameters
+/// - option
+///
+///   Name of the option, for the error message.
+/// - value
+///
+///   The value to parse.
+///
+/// # Returns
+/// Returns `Ok(usize)` containing the count; otherwise, returns
+/// `Err(String)` describing what is wrong with the value.
+fn _parse_count(option: &str, value: &str) -> Result<usize, String> {
+    match value.trim().parse::<usize>() {
+        Ok(count) if count > 0 => Ok(count),
+        _ => Err(format!("{option}: '{value}' is not a count of 1 or more")),
+    }
+}
+
+/// Helper function to parse the value of an exercise option that is a size
+/// in the form `<width>x<height>`, such as 120x30.
+///
+/// # Parameters
+/// - option
+///
+///   Name of the option, for the error message.
+/// - value
+///
+///   The value to parse.
+///
+/// # Returns
+/// Returns `Ok((usize, usize))` containing the width and height; otherwise,
+/// returns `Err(String)` describing what is wrong with the value.
+fn _parse_size(option: &str, value: &str) -> Result<(usize, usize), String> {
+    let error = || format!("{option}: '{value}' is not a size such as 120x30");
+    let (width, height) = value.trim().split_once(['x', 'X']).ok_or_else(error)?;
+    let width = width.parse::<usize>().map_err(|_| error())?;
+    let height = height.parse::<usize>().map_err(|_| error())?;
+    Ok((width, height))
+}
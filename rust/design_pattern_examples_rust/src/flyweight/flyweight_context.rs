@@ -2,8 +2,13 @@
 //! "image" (in a big resource "image") along with the position of the Flyweight
 //! "image" within a "display".
 
+use crossterm::style::Color;
+
 /// Represents the context for an instance of the Flyweight_Image structure.
-/// In this case, the context includes position and velocity.
+/// In this case, the context includes position, velocity, color and layer.
+/// This is the extrinsic state of the Flyweight image, which differs for
+/// every image; the image data in the big resource is the intrinsic state,
+/// which is shared.
 /// 
 /// This context is manipulated outside the Flyweight Image by the
 /// controlling entity (in this case, the flyweight_exercise() function).  The
@@ -28,6 +33,13 @@ pub struct FlyweightContext {
     /// Velocity to apply to the vertical position, in fractions of a
     /// character
     pub velocity_y: f32,
+    /// Color in which to render the image, or None for the console's default
+    /// color.
+    pub color: Option<Color>,
+    /// Layer of the image, which decides which of two overlapping images is
+    /// in front: images in higher layers are drawn over images in lower
+    /// layers.
+    pub layer: i32,
 }
 
 
@@ -56,6 +68,26 @@ impl FlyweightContext {
             position_y: 0.0,
             velocity_x: 0.0,
             velocity_y: 0.0,
+            color: None,
+            layer: 0,
         }
     }
+
+    /// Determine if the image described by this context overlaps the image
+    /// described by another context in the display, by comparing the
+    /// rectangles (bounding boxes) the two images cover.
+    ///
+    /// # Parameters
+    /// - other
+    ///
+    ///   The context of the other image.
+    ///
+    /// # Returns
+    /// Returns true if the two images overlap; otherwise, returns false.
+    pub fn overlaps(&self, other: &FlyweightContext) -> bool {
+        self.position_x < other.position_x + other.image_width as f32
+            && other.position_x < self.position_x + self.image_width as f32
+            && self.position_y < other.position_y + other.image_height as f32
+            && other.position_y < self.position_y + self.image_height as f32
+    }
 }
\ No newline at end of file
@@ -1,9 +1,36 @@
 //! Contains the Display struct that represents the "display" in which to render
-//! The Flyweight "images".
+//! The Flyweight "images", and the DisplayChange struct that represents a
+//! change to the display since it was last shown.
+
+use crossterm::style::Color;
+
+//-----------------------------------------------------------------------------
+
+/// The color of each character in a display, by row then column.
+pub type ColorGrid = Vec<Vec<Option<Color>>>;
+
+/// Represents a run of characters on one row of a Display that have changed
+/// since the display was last shown, all in the same color.
+pub struct DisplayChange {
+    /// Row of the run, starting at 0.
+    pub row: usize,
+    /// Column of the first character in the run, starting at 0.
+    pub col: usize,
+    /// Color of the characters in the run, or None for the console's default
+    /// color.
+    pub color: Option<Color>,
+    /// The characters in the run.
+    pub text: String,
+}
+
+//-----------------------------------------------------------------------------
 
 /// Represents a "display" window, in which to render Flyweight images.  This
 /// "display" window is then printed to the console window at a specified
 /// coordinate to render a "frame" of motion in the "display".
+///
+/// The display remembers the last frame shown so only the characters that
+/// have changed since then need to be printed for the next frame.
 pub struct Display {
     /// The "display" as represented by a vector of vector of characters.  Each
     /// vector of characters represents a row.
@@ -12,6 +39,15 @@ pub struct Display {
     pub width: usize,
     /// Height of the "display", in characters
     pub height: usize,
+    /// The color of each character in the "display", or None for the
+    /// console's default color.  Laid out the same as `display`.
+    pub colors: ColorGrid,
+    /// The color in which characters are rendered into the "display", or
+    /// None for the console's default color.
+    pub pen_color: Option<Color>,
+    /// The "display" and its colors as they were when last shown, or None if
+    /// the display has not been shown yet.
+    shown_display: Option<(Vec<Vec<char>>, ColorGrid)>,
 }
 
 impl Display {
@@ -33,11 +69,12 @@ impl Display {
         row.resize(width, ' ');
         let mut display: Vec<Vec<char>> = Vec::new();
         display.resize(height, row);
-        Display { display, width, height }
+        let colors = vec![vec![None; width]; height];
+        Display { display, width, height, colors, pen_color: None, shown_display: None }
     }
 
-    /// Clear the "display" to a background character, erasing whatever was
-    /// there before.
+    /// Clear the "display" to a background character in the console's
+    /// default color, erasing whatever was there before.
     ///
     /// # Parameters
     /// - fill_character
@@ -47,8 +84,58 @@ impl Display {
         for row in 0..self.height {
             for col in 0..self.width {
                 self.display[row][col] = fill_character;
+                self.colors[row][col] = None;
             }
         }
     }
-}
 
+    /// Record that the "display" has been shown as it is now, so later
+    /// changes are measured from this frame.
+    pub fn mark_shown(&mut self) {
+        self.shown_display = Some((self.display.clone(), self.colors.clone()));
+    }
+
+    /// Forget the frame last shown, such as when the screen it was shown on
+    /// has been cleared, so the next call to take_changes() returns the
+    /// whole "display".
+    pub fn forget_shown(&mut self) {
+        self.shown_display = None;
+    }
+
+    /// Retrieve the runs of characters that have changed, in character or
+    /// color, since the display was last shown, then record the display as
+    /// shown.  If the display has never been shown, every row is returned.
+    ///
+    /// # Returns
+    /// Returns a list of DisplayChange, each a run of changed characters in
+    /// the same color.
+    pub fn take_changes(&mut self) -> Vec<DisplayChange> {
+        let mut changes: Vec<DisplayChange> = vec![];
+        for row in 0..self.height {
+            let shown_rows = self.shown_display.as_ref()
+                .map(|(shown_display, shown_colors)| (&shown_display[row], &shown_colors[row]));
+            let mut run: Option<DisplayChange> = None;
+            for col in 0..self.width {
+                let character = self.display[row][col];
+                let color = self.colors[row][col];
+                let changed = match shown_rows {
+                    Some((shown_row, shown_color_row)) => shown_row[col] != character || shown_color_row[col] != color,
+                    None => true,
+                };
+                // A run ends at an unchanged character or a change of color.
+                match &mut run {
+                    Some(current_run) if changed && current_run.color == color => current_run.text.push(character),
+                    _ => {
+                        changes.extend(run.take());
+                        if changed {
+                            run = Some(DisplayChange { row, col, color, text: character.to_string() });
+                        }
+                    }
+                }
+            }
+            changes.extend(run);
+        }
+        self.mark_shown();
+        changes
+    }
+}
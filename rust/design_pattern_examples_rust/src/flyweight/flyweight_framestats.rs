@@ -0,0 +1,132 @@
+//! Contains the FrameStats struct that keeps track of how long each frame of
+//! the Flyweight animation actually took.
+
+//-----------------------------------------------------------------------------
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+//-----------------------------------------------------------------------------
+
+/// Number of the most recent frames averaged for the current frame rate.
+const NUM_RECENT_FRAMES: usize = 30;
+
+//-----------------------------------------------------------------------------
+
+/// Represents the frame times of an animation that is meant to run at a
+/// fixed frame rate, from which the current frame rate, the average frame
+/// time and the number of dropped frames are worked out.
+///
+/// A frame that takes longer than the target frame time pushes out the
+/// frames that should have been shown in the meantime; those are counted as
+/// dropped frames.
+pub struct FrameStats {
+    /// How long each frame is meant to take.
+    target_frame_time: Duration,
+    /// The frame times of the most recent frames, oldest first.
+    recent_frame_times: VecDeque<Duration>,
+    /// Number of frames recorded.
+    num_frames: usize,
+    /// Total time of all frames recorded.
+    total_frame_time: Duration,
+    /// Shortest frame time recorded.
+    min_frame_time: Duration,
+    /// Longest frame time recorded.
+    max_frame_time: Duration,
+    /// Number of frames that could not be shown in time.
+    dropped_frames: usize,
+}
+
+impl FrameStats {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - target_frame_time
+    ///
+    ///   How long each frame is meant to take, such as 1/60th of a second.
+    ///
+    /// # Returns
+    /// Returns a new instance of the FrameStats struct with no frames
+    /// recorded.
+    pub fn new(target_frame_time: Duration) -> FrameStats {
+        FrameStats {
+            target_frame_time,
+            recent_frame_times: VecDeque::with_capacity(NUM_RECENT_FRAMES),
+            num_frames: 0,
+            total_frame_time: Duration::ZERO,
+            min_frame_time: Duration::MAX,
+            max_frame_time: Duration::ZERO,
+            dropped_frames: 0,
+        }
+    }
+
+    /// Record how long a frame took.
+    ///
+    /// # Parameters
+    /// - frame_time
+    ///
+    ///   Time from the start of the frame to the start of the next frame.
+    pub fn record(&mut self, frame_time: Duration) {
+        if self.recent_frame_times.len() == NUM_RECENT_FRAMES {
+            self.recent_frame_times.pop_front();
+        }
+        self.recent_frame_times.push_back(frame_time);
+        self.num_frames += 1;
+        self.total_frame_time += frame_time;
+        self.min_frame_time = self.min_frame_time.min(frame_time);
+        self.max_frame_time = self.max_frame_time.max(frame_time);
+        if !self.target_frame_time.is_zero() {
+            let num_target_frames = (frame_time.as_secs_f64() / self.target_frame_time.as_secs_f64()) as usize;
+            self.dropped_frames += num_target_frames.saturating_sub(1);
+        }
+    }
+
+    /// The frame rate over the most recent frames, in frames per second, or
+    /// 0 if no frames have been recorded.
+    pub fn current_fps(&self) -> f64 {
+        let recent_time: Duration = self.recent_frame_times.iter().sum();
+        if recent_time.is_zero() {
+            0.0
+        } else {
+            self.recent_frame_times.len() as f64 / recent_time.as_secs_f64()
+        }
+    }
+
+    /// The average time of all frames recorded, or zero if no frames have
+    /// been recorded.
+    pub fn average_frame_time(&self) -> Duration {
+        match u32::try_from(self.num_frames) {
+            Ok(num_frames) if num_frames > 0 => self.total_frame_time / num_frames,
+            _ => Duration::ZERO,
+        }
+    }
+
+    /// Get a single line describing the frames so far, for showing while the
+    /// animation runs.  The line is always the same width so that it
+    /// completely overwrites the previous line.
+    pub fn status_line(&self) -> String {
+        format!("FPS: {:5.1}  average frame: {:6.2} ms  dropped frames: {:5}",
+            self.current_fps(), _milliseconds(self.average_frame_time()), self.dropped_frames)
+    }
+
+    /// Get a summary of all frames recorded, for showing after the animation
+    /// has finished.  Each line of the summary ends in a newline.
+    pub fn summary(&self) -> String {
+        let average_frame_time = self.average_frame_time();
+        let average_fps = if average_frame_time.is_zero() { 0.0 } else { 1.0 / average_frame_time.as_secs_f64() };
+        let min_frame_time = if self.num_frames == 0 { Duration::ZERO } else { self.min_frame_time };
+        let mut summary = String::new();
+        summary.push_str(&format!("  Frames shown: {} at {average_fps:.1} frames a second (target {:.1})\n",
+            self.num_frames, 1.0 / self.target_frame_time.as_secs_f64()));
+        summary.push_str(&format!("  Frame time: {:.2} ms average, {:.2} ms shortest, {:.2} ms longest\n",
+            _milliseconds(average_frame_time), _milliseconds(min_frame_time), _milliseconds(self.max_frame_time)));
+        summary.push_str(&format!("  Dropped frames: {}\n", self.dropped_frames));
+        summary
+    }
+}
+
+/// Helper function to convert a duration to fractional milliseconds for
+/// display.
+fn _milliseconds(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
@@ -0,0 +1,75 @@
+//! Contains the render_frames() function that runs the Flyweight animation
+//! without a console, returning each frame as text instead of showing it.
+
+//-----------------------------------------------------------------------------
+
+use crate::helpers::profiler::profile_span;
+use crate::helpers::random;
+use super::flyweight_bigresource_manager::BigResourceManager;
+use super::flyweight_config::FlyweightConfig;
+use super::flyweight_display::Display;
+use super::flyweight_image::FlyweightImage;
+
+//-----------------------------------------------------------------------------
+
+/// Run the Flyweight animation for the given configuration without moving
+/// the cursor, sleeping or reading keys, and return each frame as text.
+///
+/// The images are made as for the exercise, from the sprites in the
+/// configuration, if any.  If the configuration has a seed, the images start
+/// in the same places every time, so the frames are the same every time and
+/// can be compared against known frames.
+///
+/// # Parameters
+/// - config
+///
+///   The configuration giving the sizes, the number of images, whether they
+///   collide, the seed and the sprites, if any.
+/// - num_frames
+///
+///   Number of frames to render.
+///
+/// # Returns
+/// Returns `Ok(Vec<String>)` containing one string for each frame, in order,
+/// each containing the rows of the display separated by newlines, without
+/// colors; otherwise, returns `Err(String)` if the sprites cannot be loaded
+/// or the images do not fit in the display.
+pub fn render_frames(config: &FlyweightConfig, num_frames: usize) -> Result<Vec<String>, String> {
+    if let Some(seed) = config.seed {
+        random::set_seed(seed);
+    }
+    let mut config = config.clone();
+    let (big_resource, num_distinct_images) = super::_flyweight_load_big_resource(&mut config)?;
+    let mut big_resource_manager = BigResourceManager::new();
+    let resource_id = big_resource_manager.add_resource(big_resource);
+    let mut images: Vec<FlyweightImage> = Vec::new();
+    super::_flyweight_generate_flyweight_images(resource_id, num_distinct_images, &config, &mut images);
+
+    let mut display = super::_fylweight_generate_display(config.display_width, config.display_height);
+    let mut frames: Vec<String> = Vec::with_capacity(num_frames);
+    {
+        let _render_loop_span = profile_span("render loop");
+        for _ in 0..num_frames {
+            let _frame_span = profile_span("move and render");
+            super::_flyweight_clear_display(&mut display);
+            super::_flyweight_move_images(&mut images, display.width, display.height, config.collisions);
+            super::_flyweight_render_images(&big_resource_manager, &images, &mut display);
+            frames.push(_display_text(&display));
+        }
+    }
+    Ok(frames)
+}
+
+/// Helper function to get the characters in a display as text.
+///
+/// # Parameters
+/// - display
+///
+///   The display to get the text of.
+///
+/// # Returns
+/// Returns the rows of the display separated by newlines.
+fn _display_text(display: &Display) -> String {
+    let rows: Vec<String> = display.display.iter().map(|row| row.iter().collect()).collect();
+    rows.join("\n")
+}
@@ -9,14 +9,17 @@ use super::flyweight_context::FlyweightContext;
 use super::flyweight_display::Display;
 use super::flyweight_bigresource::BigResource;
 use super::flyweight_bigresource_manager::BigResourceManager;
+use super::flyweight_bigresourceid::BigResourceId;
+use crate::bridge::bridge_loggerregistry::get_logger;
 
 //-----------------------------------------------------------------------------
 
 /// Represents an image that associates a context with a big resource.
 pub struct FlyweightImage {
     /// The big resource being referenced by this flyweight image.  This is
-    /// represented by a handle to the big resource.
-    pub big_resource_id: usize,
+    /// represented by a handle to the big resource, which goes stale if the
+    /// big resource is removed.
+    pub big_resource_id: BigResourceId,
 
     /// The context associated with this image.  The calling entity
     /// uses this context to manipulate the position of the image.
@@ -25,7 +28,7 @@ pub struct FlyweightImage {
 
 impl FlyweightImage {
     /// Render the image associated with this flyweight instance into the given
-    /// display at the given position.
+    /// display at the given position and in the given color.
     ///
     /// # Parameters
     /// - resource_manager
@@ -39,11 +42,12 @@ impl FlyweightImage {
         let resource: &BigResource = match resource_manager.get_resource(self.big_resource_id) {
             Some(r) => r,
             None =>  {
-                eprintln!("Unable to get big resource with id {}.  Cannot render images.", self.big_resource_id);
+                get_logger("flyweight").log_error(&format!("Unable to get big resource with id {}.  Cannot render images.", self.big_resource_id));
                 return
             }
         };
-        
+
+        display.pen_color = self.context.color;
         resource.render(display, self.context.offset_x_to_image,
         self.context.image_width, self.context.image_height,
         self.context.position_x as isize, self.context.position_y as isize);
@@ -0,0 +1,143 @@
+//! Contains the load_sprites() function that reads ASCII-art "images" from
+//! text files into a BigResource, for use in place of the generated images.
+//! Spaces in the sprites are transparent.
+
+//-----------------------------------------------------------------------------
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::flyweight_bigresource::BigResource;
+
+//-----------------------------------------------------------------------------
+
+/// Extension of the files in a sprite directory that are read as sprites.
+const SPRITE_FILE_EXTENSION: &str = "txt";
+/// Character in a sprite through which whatever is behind the sprite shows.
+const SPRITE_TRANSPARENT_CHARACTER: char = ' ';
+
+//-----------------------------------------------------------------------------
+
+/// Represents a set of sprites loaded from a directory, laid out side by side
+/// in a single big resource, the same way the generated images are.
+pub struct LoadedSprites {
+    /// The big resource holding all the sprites, left to right in the order
+    /// of their file names.
+    pub resource: BigResource,
+    /// Number of sprites in the big resource.
+    pub num_sprites: usize,
+    /// Width of every sprite, in characters.
+    pub sprite_width: usize,
+    /// Height of every sprite, in characters.
+    pub sprite_height: usize,
+}
+
+/// Load every `*.txt` file in the given directory as a sprite, in order of
+/// file name.  Each line of a file is a row of the sprite; trailing blank
+/// lines are ignored.  Spaces are transparent, so the sprites need not be
+/// rectangular to look right when they overlap.  All rows of a sprite must be the same width and all
+/// sprites must be the same size, since the Flyweight images all share one
+/// image size.
+///
+/// # Parameters
+/// - directory
+///
+///   Path to the directory containing the sprite files.
+///
+/// # Returns
+/// Returns `Ok(LoadedSprites)` containing the sprites; otherwise, returns
+/// `Err(String)` if the directory cannot be read, holds no sprites, or any
+/// sprite is not valid.
+pub fn load_sprites(directory: &str) -> Result<LoadedSprites, String> {
+    let paths = _sprite_paths(directory)?;
+    if paths.is_empty() {
+        return Err(format!("The sprite directory \"{directory}\" contains no .{SPRITE_FILE_EXTENSION} files"));
+    }
+
+    let mut data: Vec<Vec<char>> = Vec::new();
+    let mut size: Option<(usize, usize, &PathBuf)> = None;
+    for path in paths.iter() {
+        let rows = _read_sprite(path)?;
+        let sprite_size = (rows[0].len(), rows.len());
+        match size {
+            None => {
+                size = Some((sprite_size.0, sprite_size.1, path));
+                data.resize(sprite_size.1, Vec::new());
+            }
+            Some((width, height, first_path)) => {
+                if sprite_size != (width, height) {
+                    return Err(format!("The sprite \"{}\" is {}x{} but the sprite \"{}\" is {width}x{height}; all sprites must be the same size",
+                        path.display(), sprite_size.0, sprite_size.1, first_path.display()));
+                }
+            }
+        }
+        for (data_row, row) in data.iter_mut().zip(rows) {
+            data_row.extend(row);
+        }
+    }
+
+    let (sprite_width, sprite_height) = size.map(|(width, height, _)| (width, height)).unwrap_or_default();
+    let mut resource = BigResource::new(data);
+    resource.transparent_character = Some(SPRITE_TRANSPARENT_CHARACTER);
+    Ok(LoadedSprites {
+        resource,
+        num_sprites: paths.len(),
+        sprite_width,
+        sprite_height,
+    })
+}
+
+/// Helper function to get the paths of the sprite files in a directory,
+/// sorted by file name.
+///
+/// # Parameters
+/// - directory
+///
+///   Path to the directory containing the sprite files.
+///
+/// # Returns
+/// Returns `Ok(Vec<PathBuf>)` containing the sorted paths (which can be
+/// empty); otherwise, returns `Err(String)` if the directory cannot be read.
+fn _sprite_paths(directory: &str) -> Result<Vec<PathBuf>, String> {
+    let error = |error: std::io::Error| format!("Could not read the sprite directory \"{directory}\": {error}");
+    let mut paths: Vec<PathBuf> = Vec::new();
+    for entry in fs::read_dir(directory).map_err(error)? {
+        let path = entry.map_err(error)?.path();
+        if path.is_file() && path.extension().is_some_and(|extension| extension == SPRITE_FILE_EXTENSION) {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+/// Helper function to read a single sprite file into rows of characters,
+/// making sure the sprite is not empty and every row is the same width.
+///
+/// # Parameters
+/// - path
+///
+///   Path to the sprite file.
+///
+/// # Returns
+/// Returns `Ok(Vec<Vec<char>>)` containing the rows of the sprite, top to
+/// bottom; otherwise, returns `Err(String)` if the file cannot be read or is
+/// not a valid sprite.
+fn _read_sprite(path: &Path) -> Result<Vec<Vec<char>>, String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|error| format!("Could not read the sprite \"{}\": {error}", path.display()))?;
+    let mut rows: Vec<Vec<char>> = contents.lines().map(|line| line.chars().collect()).collect();
+    while rows.last().is_some_and(|row| row.is_empty()) {
+        rows.pop();
+    }
+
+    let width = rows.first().map_or(0, |row| row.len());
+    if width == 0 {
+        return Err(format!("The sprite \"{}\" is empty or starts with a blank line", path.display()));
+    }
+    if let Some(index) = rows.iter().position(|row| row.len() != width) {
+        return Err(format!("Line {} of the sprite \"{}\" is {} characters wide but the first line is {width}; all lines of a sprite must be the same width",
+            index + 1, path.display(), rows[index].len()));
+    }
+    Ok(rows)
+}
@@ -14,20 +14,71 @@
 //! passes message objects to each MessageWindow until the message is
 //! handled.
 //!
+//! The HandlerChain itself is generic over the kind of message passed along
+//! and what the handlers return, so the same chain also passes words to
+//! handlers that recognize them.
+//!
+//! After the messages are passed along, the windows are rendered into a
+//! Display (the same one the Flyweight example draws into) and printed, to
+//! show which windows are selected, where they are and which have closed.
+//!
+//! A snapshot of the windows can be saved in a memento, as in the Memento
+//! example, and restored later to reopen the windows closed since then.
+//!
+//! An AsyncHandlerChain passes the messages to the handlers on a background
+//! thread, so sending a message does not wait for the handlers.
+//!
+//! A window can also ask "Are you sure?" before closing, in a modal window
+//! that is given the capture so it alone is passed messages until answered.
+//!
+//! If the `--interactive` exercise option is given, the windows are drawn in
+//! the console instead, and clicking and dragging them with the mouse sends
+//! them messages through the HandlerChain.
+//!
 //! Accessed through the handlerchain_exercise() function.
 
 //-----------------------------------------------------------------------------
 
+pub mod handlerchain_asynchandlerchain;
+pub mod handlerchain_chainhandler;
+pub mod handlerchain_confirmwindow;
 pub mod handlerchain_handlerchain;
+pub mod handlerchain_ihandlerchain_trait;
+pub mod handlerchain_ihandlerresult_trait;
 pub mod handlerchain_imessagehandler_trait;
+pub mod handlerchain_imessagewindow_trait;
+pub mod handlerchain_interactive;
+pub mod handlerchain_memento;
 pub mod handlerchain_message;
+pub mod handlerchain_messagelogger;
 pub mod handlerchain_messagewindow;
+pub mod handlerchain_pendingactions;
+pub mod handlerchain_reports;
+pub mod handlerchain_windowhandlerchain;
+pub mod handlerchain_wordhandler;
 
 //-----------------------------------------------------------------------------
 
+use crate::helpers::exercise_options::has_exercise_option;
+use handlerchain_asynchandlerchain::AsyncHandlerChain;
 use handlerchain_handlerchain::HandlerChain;
+use handlerchain_imessagehandler_trait::IMessageHandler;
+use handlerchain_messagelogger::MessageLogger;
 use handlerchain_messagewindow::MessageWindow;
-use handlerchain_message::{Message, MessageType};
+use handlerchain_message::{Message, MessagePosition, MessageType};
+use handlerchain_windowhandlerchain::WindowHandlerChain;
+use handlerchain_wordhandler::WordHandler;
+use crate::flyweight::flyweight_display::Display;
+
+//-----------------------------------------------------------------------------
+
+/// Width of the display the windows are rendered into, wide enough for all
+/// the windows in the exercise.
+const DISPLAY_WIDTH: usize = 60;
+
+/// Height of the display the windows are rendered into, tall enough for all
+/// the windows in the exercise.
+const DISPLAY_HEIGHT: usize = 32;
 
 //-----------------------------------------------------------------------------
 
@@ -53,13 +104,41 @@ use handlerchain_message::{Message, MessageType};
 ///   A reference to a HandlerChain instance that the messages will be passed
 ///   to.  This HandlerChain instance also takes ownership of the MessageWindow
 ///   instances.
-fn _handlerchain_construct_window_chain(handler_chain: &mut HandlerChain) {
+fn _handlerchain_construct_window_chain(handler_chain: &mut WindowHandlerChain) {
     let mut window = MessageWindow::new("Window 1", 0, 0, 10, 10);
-    handler_chain.add_handler(window);
+    handler_chain.add_window(window);
     window = MessageWindow::new("Window 2", 20, 0, 5, 5);
-    handler_chain.add_handler(window);
+    handler_chain.add_window(window);
     window = MessageWindow::new("Window 3", 30, 10, 15, 15);
-    handler_chain.add_handler(window);
+    handler_chain.add_window(window);
+}
+
+/// Helper method to show what the windows in the handler chain look like, by
+/// rendering them into a Display and printing it, without the empty rows at
+/// the bottom.  A selected window has a doubled up border and the mouse is
+/// over the windows filled with dots.
+///
+/// # Parameters
+/// - handler_chain
+///
+///   The HandlerChain holding the windows to show.
+fn _handlerchain_show_windows(handler_chain: &WindowHandlerChain) {
+    let mut display = Display::new(DISPLAY_WIDTH, DISPLAY_HEIGHT);
+    handler_chain.render(&mut display);
+    let mut rows: Vec<String> = display.display.iter()
+        .map(|row| row.iter().collect::<String>().trim_end().to_string())
+        .collect();
+    while rows.last().is_some_and(|row| row.is_empty()) {
+        rows.pop();
+    }
+    for row in rows {
+        if row.is_empty() {
+            println!();
+        } else {
+            println!("    {row}");
+        }
+    }
+    println!();
 }
 
 /// Example of using the "Handle Chain" or "Chain of Responsibility" design
@@ -75,16 +154,27 @@ fn _handlerchain_construct_window_chain(handler_chain: &mut HandlerChain) {
 /// MessageWindows embody the handlers and the HandlerChain object
 /// passes message objects to each MessageWindow until the message is
 /// handled.
+///
+/// If the `--interactive` exercise option is given, the user clicks on
+/// windows drawn in the console instead of seeing the examples above.
 // ! [Using HandlerChain in Rust]
 pub fn handlerchain_exercise() -> Result<(), String> {
     println!("");
     println!("HandlerChain Exercise");
 
-    let mut handler_chain = HandlerChain::new();
+    if has_exercise_option("--interactive") {
+        handlerchain_interactive::run_interactive()?;
+        println!("  Done.");
+        return Ok(());
+    }
+
+    let mut handler_chain = WindowHandlerChain::new();
     _handlerchain_construct_window_chain(&mut handler_chain);
 
     println!("  Handler Chain at start:");
     println!("{}", handler_chain.to_string());
+    _handlerchain_show_windows(&handler_chain);
+    let start_memento = handler_chain.save_state("start");
 
     // Now pass messages to the windows.
 
@@ -93,24 +183,180 @@ pub fn handlerchain_exercise() -> Result<(), String> {
     handler_chain.send_message(&Message::new(MessageType::ButtonUp, 22, 1));
     println!("  Current handler chain:");
     println!("{}", handler_chain.to_string());
+    _handlerchain_show_windows(&handler_chain);
 
     println!("  Select Window 3");
     handler_chain.send_message(&Message::new(MessageType::ButtonDown, 35, 11));
     handler_chain.send_message(&Message::new(MessageType::ButtonUp, 35, 11));
     println!("  Current handler chain:");
     println!("{}", handler_chain.to_string());
+    _handlerchain_show_windows(&handler_chain);
 
     println!("  Select Window 1");
     handler_chain.send_message(&Message::new(MessageType::ButtonDown, 4, 4));
     handler_chain.send_message(&Message::new(MessageType::ButtonUp, 4, 4));
     println!("  Current handler chain:");
     println!("{}", handler_chain.to_string());
+    _handlerchain_show_windows(&handler_chain);
 
     println!("  Close Window 2");
     handler_chain.send_message(&Message::new(MessageType::ButtonDown, 24, 0));
     handler_chain.send_message(&Message::new(MessageType::ButtonUp, 24, 0));
     println!("  Current handler chain:");
     println!("{}", handler_chain.to_string());
+    _handlerchain_show_windows(&handler_chain);
+
+    println!("  Move mouse into Window 3, then into Window 1");
+    handler_chain.send_message(&Message::mouse_move(24, 0, 32, 12));
+    handler_chain.send_message(&Message::mouse_move(32, 12, 5, 5));
+    println!("  Current handler chain:");
+    println!("{}", handler_chain);
+    _handlerchain_show_windows(&handler_chain);
+
+    println!("  Redraw all windows");
+    handler_chain.send_message(&Message::redraw());
+    println!();
+
+    println!("  Select Window 1, then press 'a' and 'x' to close it");
+    handler_chain.send_message(&Message::new(MessageType::ButtonDown, 5, 5));
+    handler_chain.send_message(&Message::new(MessageType::ButtonUp, 5, 5));
+    handler_chain.send_message(&Message::key_press('a', 5, 5));
+    handler_chain.send_message(&Message::key_press('x', 5, 5));
+    println!("  Current handler chain:");
+    println!("{}", handler_chain);
+    _handlerchain_show_windows(&handler_chain);
+
+    println!("  Add Window 4, overlapping the lower right of Window 3");
+    let window_4 = MessageWindow::new("Window 4", 35, 15, 15, 15);
+    let window_4_id = window_4.id();
+    println!("  Hit test Window 4 before adding it:");
+    for (x, y) in [(35, 15), (40, 20), (48, 15), (10, 10)] {
+        println!("    ({x:2}, {y:2}) is {:?}", window_4.hit_test(&MessagePosition::new(x, y)));
+    }
+    handler_chain.add_window(window_4);
+    println!("  Current handler chain:");
+    println!("{}", handler_chain);
+    _handlerchain_show_windows(&handler_chain);
+
+    println!("  Select where Windows 3 and 4 overlap (Window 4 is in front)");
+    handler_chain.send_message(&Message::new(MessageType::ButtonDown, 40, 20));
+    handler_chain.send_message(&Message::new(MessageType::ButtonUp, 40, 20));
+
+    println!("  Select Window 3 where it is not overlapped, bringing it to the front");
+    handler_chain.send_message(&Message::new(MessageType::ButtonDown, 32, 12));
+    handler_chain.send_message(&Message::new(MessageType::ButtonUp, 32, 12));
+
+    println!("  Select where Windows 3 and 4 overlap (Window 3 is now in front)");
+    handler_chain.send_message(&Message::new(MessageType::ButtonDown, 40, 20));
+    handler_chain.send_message(&Message::new(MessageType::ButtonUp, 40, 20));
+    println!("  Current handler chain:");
+    println!("{}", handler_chain);
+    _handlerchain_show_windows(&handler_chain);
+
+    println!("  Find the window at a few positions:");
+    for (x, y) in [(40, 20), (45, 27), (5, 5)] {
+        match handler_chain.window_at(x, y) {
+            Some(window_id) => println!("    ({x:2}, {y:2}) is in the window with id {window_id}"),
+            None => println!("    ({x:2}, {y:2}) is not in any window"),
+        }
+    }
+    println!();
+
+    println!("  Drag Window 3 by its title bar to the right and down");
+    handler_chain.send_message(&Message::new(MessageType::ButtonDown, 32, 10));
+    handler_chain.send_message(&Message::mouse_move(32, 10, 37, 12));
+    handler_chain.send_message(&Message::mouse_move(37, 12, 42, 14));
+    handler_chain.send_message(&Message::new(MessageType::ButtonUp, 42, 14));
+    println!("  Current handler chain:");
+    println!("{}", handler_chain);
+    _handlerchain_show_windows(&handler_chain);
+
+    println!("  Add a screen handler with a high priority to log every message");
+    handler_chain.add_handler_with_priority(MessageLogger::new(), 10);
+    println!("  Add Window 5 to the chain just before Window 4 and Window 6 just after it");
+    handler_chain.insert_before(window_4_id, MessageWindow::new("Window 5", 0, 0, 10, 10))?;
+    handler_chain.insert_after(window_4_id, MessageWindow::new("Window 6", 12, 0, 6, 6))?;
+    println!("  Current handler chain:");
+    println!("{}", handler_chain);
+    _handlerchain_show_windows(&handler_chain);
+
+    println!("  Select Window 5, then press 'x' to close it");
+    handler_chain.send_message(&Message::new(MessageType::ButtonDown, 4, 4));
+    handler_chain.send_message(&Message::new(MessageType::ButtonUp, 4, 4));
+    handler_chain.send_message(&Message::key_press('x', 4, 4));
+    println!("  Current handler chain:");
+    println!("{}", handler_chain);
+    _handlerchain_show_windows(&handler_chain);
+
+    println!("  Select Window 6, then press 'n' to open a new window next to it");
+    handler_chain.send_message(&Message::new(MessageType::ButtonDown, 14, 3));
+    handler_chain.send_message(&Message::new(MessageType::ButtonUp, 14, 3));
+    handler_chain.send_message(&Message::key_press('n', 14, 3));
+    println!("  Current handler chain:");
+    println!("{}", handler_chain);
+    _handlerchain_show_windows(&handler_chain);
+
+    println!("  Add Window 7, which asks \"Are you sure?\" before closing");
+    let mut window_7 = MessageWindow::new("Window 7", 24, 0, 12, 6);
+    window_7.set_confirm_close(true);
+    handler_chain.add_window(window_7);
+    println!("  Select Window 7, then press 'x' to close it");
+    handler_chain.send_message(&Message::new(MessageType::ButtonDown, 28, 3));
+    handler_chain.send_message(&Message::new(MessageType::ButtonUp, 28, 3));
+    handler_chain.send_message(&Message::key_press('x', 28, 3));
+    println!("  Current handler chain:");
+    println!("{}", handler_chain);
+    _handlerchain_show_windows(&handler_chain);
+
+    println!("  Try to select Window 6 while \"Are you sure?\" has the capture, then answer No");
+    handler_chain.send_message(&Message::new(MessageType::ButtonDown, 14, 3));
+    handler_chain.send_message(&Message::new(MessageType::ButtonUp, 14, 3));
+    handler_chain.send_message(&Message::key_press('n', 14, 3));
+    println!("  Current handler chain:");
+    println!("{}", handler_chain);
+    _handlerchain_show_windows(&handler_chain);
+
+    println!("  Press 'x' in Window 7 again, then click Yes");
+    handler_chain.send_message(&Message::key_press('x', 28, 3));
+    handler_chain.send_message(&Message::new(MessageType::ButtonDown, 28, 4));
+    handler_chain.send_message(&Message::new(MessageType::ButtonUp, 28, 4));
+    println!("  Current handler chain:");
+    println!("{}", handler_chain);
+    _handlerchain_show_windows(&handler_chain);
+
+    println!("  Restore the handler chain to the snapshot named \"{}\", reopening the closed windows", start_memento.name());
+    handler_chain.restore_state(&start_memento);
+    println!("  Current handler chain:");
+    println!("{}", handler_chain);
+    _handlerchain_show_windows(&handler_chain);
+
+    println!("  A new handler chain on a background thread; select Window 2, then Window 1");
+    println!("  and press 'x' to close it, without waiting for the windows");
+    let async_chain = AsyncHandlerChain::new(|| {
+        let mut handler_chain = WindowHandlerChain::new();
+        _handlerchain_construct_window_chain(&mut handler_chain);
+        handler_chain
+    });
+    async_chain.send_message(Message::new(MessageType::ButtonDown, 22, 1))?;
+    async_chain.send_message(Message::new(MessageType::ButtonUp, 22, 1))?;
+    async_chain.send_message(Message::new(MessageType::ButtonDown, 4, 4))?;
+    async_chain.send_message(Message::new(MessageType::ButtonUp, 4, 4))?;
+    async_chain.send_message(Message::key_press('x', 4, 4))?;
+    async_chain.flush()?;
+    println!("  All messages passed on; handler chain left on the background thread:");
+    println!("{}", async_chain.join()?);
+
+    println!("  The same kind of handler chain, passing words instead of window messages:");
+    let mut word_chain: HandlerChain<String, Option<String>> = HandlerChain::new();
+    word_chain.add_handler(WordHandler::new("article", &["a", "an", "the"]));
+    word_chain.add_handler(WordHandler::new("adjective", &["quick", "brown", "lazy"]));
+    word_chain.add_handler(WordHandler::new("noun", &["fox", "dog"]));
+    println!("{}", word_chain);
+    for word in "The quick brown fox jumps over the lazy dog".split_whitespace() {
+        let category = word_chain.send_message(&word.to_string()).flatten();
+        println!("    {:<6} is {}", word, category.unwrap_or("not recognized".to_string()));
+    }
+    println!();
 
     println!("  Done.");
 
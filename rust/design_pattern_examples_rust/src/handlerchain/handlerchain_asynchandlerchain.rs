@@ -0,0 +1,149 @@
+//! Contains the AsyncHandlerChain struct, which passes messages to the
+//! handlers of a HandlerChain on a background thread.
+
+use std::sync::mpsc::{channel, Sender};
+use std::thread::{self, JoinHandle};
+
+use super::handlerchain_ihandlerchain_trait::IHandlerChain;
+
+//-----------------------------------------------------------------------------
+
+/// Represents one request waiting to be carried out by the background thread.
+enum QueuedRequest<M> {
+    /// Pass the message to the handlers.
+    Message(M),
+    /// Tell the sender once every message queued before this request has
+    /// been passed to the handlers.
+    Flush(Sender<()>),
+}
+
+/// Represents a handler chain, such as a HandlerChain or a
+/// WindowHandlerChain, whose send_message() queues the message and
+/// returns immediately, leaving a background thread to pass the messages to
+/// the handlers, in the order they were sent.  The caller never waits on the
+/// handlers unless it asks to with flush() or join().
+///
+/// The HandlerChain is created and used only on the background thread, as
+/// the handlers are not meant to be shared between threads.  Because of
+/// that, what the handlers return stays on the background thread; only
+/// what the HandlerChain looks like at the end is returned by join().
+pub struct AsyncHandlerChain<M> {
+    /// Where requests are queued; None once the AsyncHandlerChain has been
+    /// joined.
+    sender: Option<Sender<QueuedRequest<M>>>,
+    /// The background thread passing the messages to the handlers, which
+    /// returns the description of the HandlerChain when done.
+    dispatch_thread: Option<JoinHandle<String>>,
+}
+
+impl<M: Send + 'static> AsyncHandlerChain<M> {
+    /// Constructor.  Starts the background thread.
+    ///
+    /// # Parameters
+    /// - create_chain
+    ///
+    ///   Function called on the background thread to create the handler
+    ///   chain and its handlers.
+    ///
+    /// # Returns
+    /// Returns a new instance of the AsyncHandlerChain struct.
+    pub fn new<C, F>(create_chain: F) -> AsyncHandlerChain<M>
+        where C: IHandlerChain<M>, F: FnOnce() -> C + Send + 'static
+    {
+        let (sender, receiver) = channel::<QueuedRequest<M>>();
+        let dispatch_thread = thread::spawn(move || {
+            let mut handler_chain = create_chain();
+            // Ends when the sender is dropped and the queue is empty.
+            for request in receiver {
+                match request {
+                    QueuedRequest::Message(message) => {
+                        handler_chain.send_message(&message);
+                    }
+                    QueuedRequest::Flush(flushed_sender) => {
+                        let _ = flushed_sender.send(());
+                    }
+                }
+            }
+            handler_chain.to_string()
+        });
+        AsyncHandlerChain {
+            sender: Some(sender),
+            dispatch_thread: Some(dispatch_thread),
+        }
+    }
+
+    /// Queue a message to be passed to the handlers on the background thread.
+    ///
+    /// # Parameters
+    /// - message
+    ///
+    ///   The message to send to the handlers.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the message was queued; otherwise, returns
+    /// `Err(String)` if the background thread has stopped.
+    pub fn send_message(&self, message: M) -> Result<(), String> {
+        self._queue_request(QueuedRequest::Message(message))
+    }
+
+    /// Wait until every message queued so far has been passed to the
+    /// handlers.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` once the messages have been passed to the handlers;
+    /// otherwise, returns `Err(String)` if the background thread has
+    /// stopped.
+    pub fn flush(&self) -> Result<(), String> {
+        let (flushed_sender, flushed_receiver) = channel::<()>();
+        self._queue_request(QueuedRequest::Flush(flushed_sender))?;
+        flushed_receiver.recv()
+            .map_err(|_| String::from("The handler chain thread stopped before the messages were passed on"))
+    }
+
+    /// Stop taking messages, let the background thread pass on the messages
+    /// already queued, and wait for the background thread to stop.
+    ///
+    /// # Returns
+    /// Returns `Ok(String)` containing the description of each handler left
+    /// in the HandlerChain, as the HandlerChain would display itself;
+    /// otherwise, returns `Err(String)` if the background thread panicked.
+    pub fn join(mut self) -> Result<String, String> {
+        // Closing the queue tells the background thread to stop once the
+        // queue is empty.
+        self.sender = None;
+        match self.dispatch_thread.take() {
+            Some(dispatch_thread) => dispatch_thread.join()
+                .map_err(|_| String::from("The handler chain thread panicked")),
+            None => Err(String::from("The handler chain thread has already been joined")),
+        }
+    }
+
+    /// Helper method to put a request on the queue for the background thread.
+    ///
+    /// # Parameters
+    /// - request
+    ///
+    ///   The request to queue.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the request was queued; otherwise, returns
+    /// `Err(String)` if the background thread has stopped.
+    fn _queue_request(&self, request: QueuedRequest<M>) -> Result<(), String> {
+        match &self.sender {
+            Some(sender) => sender.send(request)
+                .map_err(|_| String::from("The handler chain thread has stopped")),
+            None => Err(String::from("The handler chain has been joined")),
+        }
+    }
+}
+
+impl<M> Drop for AsyncHandlerChain<M> {
+    /// Closes the queue and waits for the background thread to pass on all
+    /// of the messages still in it, if join() was not called.
+    fn drop(&mut self) {
+        self.sender = None;
+        if let Some(dispatch_thread) = self.dispatch_thread.take() {
+            let _ = dispatch_thread.join();
+        }
+    }
+}
@@ -0,0 +1,79 @@
+//! Contains the ChainHandler struct, a message handler as held by a
+//! HandlerChain, along with its extension, if it has one.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use super::handlerchain_imessagehandler_trait::IMessageHandler;
+
+//-----------------------------------------------------------------------------
+
+/// Represents a handler as held by a HandlerChain: the handler itself and,
+/// optionally, the extension of the handler, which is the same handler seen
+/// through the trait X.  The HandlerChain only passes messages to the handler
+/// and never looks at the extension; the extension is for the code using the
+/// HandlerChain, which knows what X is.  For example, the WindowHandlerChain
+/// uses the extension of each window to find which window is in front.
+///
+/// A handler without an extension, such as a handler that logs messages,
+/// can be in the same HandlerChain as handlers with one.
+pub struct ChainHandler<M, R, X: ?Sized> {
+    /// The message handler.
+    handler: Rc<RefCell<dyn IMessageHandler<M, R, X>>>,
+    /// The same handler seen through the extension trait, if it has one.
+    extension: Option<Rc<RefCell<X>>>,
+}
+
+impl<M, R, X: ?Sized> ChainHandler<M, R, X> {
+    /// Constructor for a handler without an extension.
+    ///
+    /// # Parameters
+    /// - handler
+    ///
+    ///   The message handler.  The ChainHandler takes ownership of the
+    ///   handler.
+    ///
+    /// # Returns
+    /// Returns a new instance of the ChainHandler struct.
+    pub fn new(handler: impl IMessageHandler<M, R, X> + 'static) -> ChainHandler<M, R, X> {
+        ChainHandler {
+            handler: Rc::new(RefCell::new(handler)),
+            extension: None,
+        }
+    }
+
+    /// Constructor for a handler with an extension.
+    ///
+    /// # Parameters
+    /// - handler
+    ///
+    ///   The message handler.
+    /// - extension
+    ///
+    ///   The same handler, seen through the extension trait.
+    ///
+    /// # Returns
+    /// Returns a new instance of the ChainHandler struct.
+    pub fn with_extension(handler: Rc<RefCell<dyn IMessageHandler<M, R, X>>>, extension: Rc<RefCell<X>>) -> ChainHandler<M, R, X> {
+        ChainHandler {
+            handler,
+            extension: Some(extension),
+        }
+    }
+
+    /// The message handler.
+    pub fn handler(&self) -> &RefCell<dyn IMessageHandler<M, R, X>> {
+        &self.handler
+    }
+
+    /// The handler seen through the extension trait, or None if the handler
+    /// does not have an extension.
+    pub fn extension(&self) -> Option<&RefCell<X>> {
+        self.extension.as_deref()
+    }
+
+    /// ID of the handler.
+    pub fn id(&self) -> i32 {
+        self.handler.borrow().id()
+    }
+}
@@ -0,0 +1,308 @@
+//! Contains the ConfirmWindow struct, a modal window that asks whether to
+//! close another window.
+
+use std::cmp::max;
+
+use super::handlerchain_message::{MessagePosition, MessageType, Message};
+use super::handlerchain_imessagehandler_trait::IMessageHandler;
+use super::handlerchain_imessagewindow_trait::IMessageWindow;
+use super::handlerchain_handlerchain::{get_next_handler_id, MessageReturnTypes};
+use super::handlerchain_messagewindow::{WindowRectangle, SELECTED_COLOR};
+use super::handlerchain_windowhandlerchain::{WindowChainHandler, WindowPendingActions};
+use super::handlerchain_pendingactions::PendingAction;
+use super::handlerchain_reports::report;
+use crate::flyweight::flyweight_display::Display;
+
+//-----------------------------------------------------------------------------
+
+/// Title of every ConfirmWindow.
+const CONFIRM_TITLE: &str = "Are you sure?";
+
+/// Text of the choice that closes the other window.
+const YES_TEXT: &str = "[Y]es";
+
+/// Text of the choice that leaves the other window open.
+const NO_TEXT: &str = "[N]o";
+
+/// Key that chooses Yes.
+const YES_KEY: char = 'y';
+
+/// Key that chooses No.
+const NO_KEY: char = 'n';
+
+/// Smallest width of a ConfirmWindow.
+const MINIMUM_CONFIRM_WIDTH: i32 = 20;
+
+/// Height of a ConfirmWindow: the title, the question, the choices and the
+/// bottom border.
+const CONFIRM_HEIGHT: i32 = 4;
+
+//-----------------------------------------------------------------------------
+
+/// Represents a modal window that asks whether to close another window.
+///
+/// The window asking to be closed adds a ConfirmWindow to the HandlerChain
+/// and gives it the capture, so the ConfirmWindow is passed every message,
+/// and no other window can be selected, until Yes or No is chosen, either by
+/// pressing 'y' or 'n' or by clicking the choice.  The ConfirmWindow then
+/// releases the capture and removes itself, sending the other window a Close
+/// message if Yes was chosen.
+pub struct ConfirmWindow {
+    /// Unique ID of this window.
+    id: i32,
+
+    /// Position of this window in the z-order, where higher is closer to the
+    /// front.  Set by the HandlerChain.
+    z_order: i32,
+
+    /// ID of the window to close if Yes is chosen.
+    owner_id: i32,
+
+    /// Title of the window to close, for the question.
+    owner_title: String,
+
+    /// Position of this window in global coordinates.
+    window_box: WindowRectangle,
+}
+
+impl ConfirmWindow {
+    /// Constructor
+    ///
+    /// # Parameters
+    /// - owner_id
+    ///
+    ///   ID of the window to close if Yes is chosen.
+    /// - owner_title
+    ///
+    ///   Title of the window to close.
+    /// - x
+    ///
+    ///   X position of the upper left corner of the window's region.
+    /// - y
+    ///
+    ///   Y position of the upper left corner of the window's region.
+    ///
+    /// # Returns
+    /// Returns a new instance of the ConfirmWindow struct, wide enough for
+    /// the question.
+    pub fn new(owner_id: i32, owner_title: &str, x: i32, y: i32) -> ConfirmWindow {
+        let mut confirm_window = ConfirmWindow {
+            id: get_next_handler_id(),
+            z_order: 0,
+            owner_id,
+            owner_title: owner_title.to_string(),
+            window_box: WindowRectangle::new(x, y, 0, 0),
+        };
+        let width = max(MINIMUM_CONFIRM_WIDTH, confirm_window._question().chars().count() as i32 + 2);
+        confirm_window.window_box = WindowRectangle::new(x, y, width, CONFIRM_HEIGHT);
+        confirm_window
+    }
+
+    /// Helper method to get the question shown inside the window.
+    fn _question(&self) -> String {
+        format!("Close \"{}\"?", self.owner_title)
+    }
+
+    /// Helper method to get the x positions at which the Yes and No choices
+    /// start, on the row below the question.
+    ///
+    /// # Returns
+    /// Returns a tuple of the x position of the Yes choice followed by the x
+    /// position of the No choice.
+    fn _choice_positions(&self) -> (i32, i32) {
+        let yes_x = self.window_box.left + 2;
+        let no_x = self.window_box.right - 2 - NO_TEXT.len() as i32;
+        (yes_x, no_x)
+    }
+
+    /// Helper method to determine which choice, if any, is at the given
+    /// point.
+    ///
+    /// # Parameters
+    /// - point
+    ///
+    ///   A MessagePosition struct to look for in the choices.
+    ///
+    /// # Returns
+    /// Returns `Some(true)` if the point is on Yes, `Some(false)` if the
+    /// point is on No; otherwise, returns None.
+    fn _choice_at(&self, point: &MessagePosition) -> Option<bool> {
+        let (yes_x, no_x) = self._choice_positions();
+        if point.y != self.window_box.top + 2 {
+            None
+        } else if point.x >= yes_x && point.x < yes_x + YES_TEXT.len() as i32 {
+            Some(true)
+        } else if point.x >= no_x && point.x < no_x + NO_TEXT.len() as i32 {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Helper method to get the character drawn at the given position in the
+    /// window: a doubled up border with the title across the top, then the
+    /// question and the choices.
+    ///
+    /// # Parameters
+    /// - x
+    ///
+    ///   X position, which must be in the window.
+    /// - y
+    ///
+    ///   Y position, which must be in the window.
+    ///
+    /// # Returns
+    /// Returns the character to draw.
+    fn _character_at(&self, x: i32, y: i32) -> char {
+        let window_box = &self.window_box;
+        let on_left_or_right = x == window_box.left || x == window_box.right - 1;
+        let on_top_or_bottom = y == window_box.top || y == window_box.bottom - 1;
+        let (yes_x, no_x) = self._choice_positions();
+        if on_left_or_right && on_top_or_bottom {
+            '#'
+        } else if y == window_box.top {
+            CONFIRM_TITLE.chars().nth((x - window_box.left - 1) as usize).unwrap_or('=')
+        } else if on_top_or_bottom {
+            '='
+        } else if on_left_or_right {
+            '#'
+        } else if y == window_box.top + 1 {
+            self._question().chars().nth((x - window_box.left - 1) as usize).unwrap_or(' ')
+        } else if x >= no_x {
+            NO_TEXT.chars().nth((x - no_x) as usize).unwrap_or(' ')
+        } else if x >= yes_x {
+            YES_TEXT.chars().nth((x - yes_x) as usize).unwrap_or(' ')
+        } else {
+            ' '
+        }
+    }
+
+    /// Helper method to act on the choice made: release the capture, remove
+    /// this window and, if Yes was chosen, send the Close message to the
+    /// other window.
+    ///
+    /// # Parameters
+    /// - close_owner
+    ///
+    ///   true if Yes was chosen.
+    /// - pending_actions
+    ///
+    ///   Queue on which to post the requests.
+    ///
+    /// # Returns
+    /// Returns a value from the MessageReturnTypes enumeration.  In this
+    /// case, always return Stop.
+    fn _choose(&mut self, close_owner: bool, pending_actions: &mut WindowPendingActions) -> MessageReturnTypes {
+        if close_owner {
+            report(&format!("  --> \"{0}\" answered Yes, posting Close message to \"{1}\"", CONFIRM_TITLE, self.owner_title));
+            pending_actions.post(PendingAction::SendMessage { handler_id: self.owner_id, message: Message::close() });
+        } else {
+            report(&format!("  --> \"{0}\" answered No, \"{1}\" stays open", CONFIRM_TITLE, self.owner_title));
+        }
+        pending_actions.post(PendingAction::ReleaseCapture(self.id));
+        pending_actions.post(PendingAction::RemoveHandler(self.id));
+        MessageReturnTypes::Stop
+    }
+
+    /// Helper method to handle the ButtonDown message.  Clicking a choice
+    /// chooses it; clicking anywhere else does nothing, as no other window
+    /// can be selected while this window is open.
+    ///
+    /// # Parameters
+    /// - message
+    ///
+    ///   A Message object describing the ButtonDown message.
+    /// - pending_actions
+    ///
+    ///   Queue on which to post the requests if a choice was clicked.
+    ///
+    /// # Returns
+    /// Returns a value from the MessageReturnTypes enumeration.  In this
+    /// case, always return Stop.
+    fn handle_button_down_message(&mut self, message: &Message, pending_actions: &mut WindowPendingActions) -> MessageReturnTypes {
+        match self._choice_at(&message.message_position) {
+            Some(close_owner) => self._choose(close_owner, pending_actions),
+            None => {
+                report(&format!("  --> Button Down at ({0}) ignored, \"{1}\" has the capture",
+                    message.message_position, CONFIRM_TITLE));
+                MessageReturnTypes::Stop
+            }
+        }
+    }
+
+    /// Helper method to handle the KeyPress message.
+    ///
+    /// # Parameters
+    /// - key
+    ///
+    ///   The key that was pressed.
+    /// - pending_actions
+    ///
+    ///   Queue on which to post the requests if a choice was made.
+    ///
+    /// # Returns
+    /// Returns a value from the MessageReturnTypes enumeration.  In this
+    /// case, always return Stop.
+    fn handle_key_press_message(&mut self, key: char, pending_actions: &mut WindowPendingActions) -> MessageReturnTypes {
+        match key.to_ascii_lowercase() {
+            YES_KEY => self._choose(true, pending_actions),
+            NO_KEY => self._choose(false, pending_actions),
+            _ => {
+                report(&format!("  --> Key '{0}' ignored, \"{1}\" only takes '{2}' or '{3}'", key, CONFIRM_TITLE, YES_KEY, NO_KEY));
+                MessageReturnTypes::Stop
+            }
+        }
+    }
+}
+
+
+impl IMessageWindow for ConfirmWindow {
+    fn z_order(&self) -> i32 {
+        self.z_order
+    }
+
+    fn set_z_order(&mut self, z_order: i32) {
+        self.z_order = z_order;
+    }
+
+    fn covers_position(&self, message: &Message) -> bool {
+        self.window_box.point_inside(&message.message_position)
+    }
+
+    fn deselect(&mut self) {
+        // The question is always the window in use, so it is never
+        // deselected.
+    }
+
+    fn save_state(&self) -> Option<WindowChainHandler> {
+        // The question only makes sense while it is being asked, so it is
+        // not part of a snapshot.
+        None
+    }
+
+    fn render(&self, display: &mut Display) {
+        // The question is always the window in use, so it is rendered as
+        // selected.
+        self.window_box.render(display, Some(SELECTED_COLOR), |x, y| self._character_at(x, y));
+    }
+}
+
+impl IMessageHandler<Message, MessageReturnTypes, dyn IMessageWindow> for ConfirmWindow {
+    fn id(&self) -> i32 {
+        self.id
+    }
+
+    fn process_message(&mut self, message: &Message, pending_actions: &mut WindowPendingActions) -> MessageReturnTypes {
+        match message.message_type {
+            MessageType::ButtonDown => self.handle_button_down_message(message, pending_actions),
+            MessageType::KeyPress(key) => self.handle_key_press_message(key, pending_actions),
+            // Closing the question is the same as answering No.
+            MessageType::Close => self._choose(false, pending_actions),
+            MessageType::ButtonUp | MessageType::MouseMove { .. } | MessageType::Redraw => MessageReturnTypes::Stop,
+        }
+    }
+
+    fn to_string(&self) -> String {
+        format!("[id={:2}] \"{}\" ({}), z={}, asking to close \"{}\"", self.id, CONFIRM_TITLE, self.window_box, self.z_order, self.owner_title)
+    }
+}
@@ -1,24 +1,53 @@
-//! Contains the HandlerChain struct that manages all windows represented by
-//! the IMessageHandler trait.
+//! Contains the generic HandlerChain struct that manages a list of message
+//! handlers represented by the IMessageHandler trait.
 
-use std::cell::RefCell;
 use std::fmt::Display;
+use std::sync::atomic::{AtomicI32, Ordering};
 
-use super::handlerchain_message::Message;
+use super::handlerchain_chainhandler::ChainHandler;
+use super::handlerchain_ihandlerchain_trait::IHandlerChain;
+use super::handlerchain_ihandlerresult_trait::IHandlerResult;
 use super::handlerchain_imessagehandler_trait::IMessageHandler;
+use super::handlerchain_memento::{HandlerChainMemento, SavedHandler};
+use super::handlerchain_pendingactions::{PendingAction, PendingActions};
 
 //-----------------------------------------------------------------------------
 
-/// Represents the type of actions the process_message() method returns for the
-/// HandlerChain::send_message() to act on.
+/// Represents the type of actions the process_message() method of a window
+/// returns for the HandlerChain::send_message() to act on.
 pub enum MessageReturnTypes {
     /// Message was processed, stop further processing of the message.
     Stop,
     /// Continue with processing of the message.
     Continue,
-    /// Window is closed, remove from handlers list and stop further processing
-    /// of the message.
-    Close,
+}
+
+impl IHandlerResult for MessageReturnTypes {
+    fn is_handled(&self) -> bool {
+        matches!(self, MessageReturnTypes::Stop)
+    }
+}
+
+/// Priority given to handlers added with HandlerChain::add_handler().
+pub const DEFAULT_PRIORITY: i32 = 0;
+
+/// Retrieve the next handler ID.  Every message handler gets its ID from
+/// here so every handler in a HandlerChain has a unique ID.
+pub fn get_next_handler_id() -> i32 {
+    // Use a static to hold the next handler ID.
+    static NEXT_HANDLER_ID: AtomicI32 = AtomicI32::new(1);
+
+    NEXT_HANDLER_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+//-----------------------------------------------------------------------------
+
+/// Represents a handler in the HandlerChain along with its priority.
+struct HandlerEntry<M, R, X: ?Sized> {
+    /// Handlers with a higher priority are passed messages first.
+    priority: i32,
+    /// The message handler.
+    handler: ChainHandler<M, R, X>,
 }
 
 //-----------------------------------------------------------------------------
@@ -26,73 +55,438 @@ pub enum MessageReturnTypes {
 /// Represents a list of handlers that all implement the IMessageHandler
 /// trait.  This list can be dynamically updated and each element in
 /// the list is passed messages for processing.
-pub struct HandlerChain {
-    /// The list of message handlers.
-    message_handlers: Vec<Box<RefCell<dyn IMessageHandler>>>,
+///
+/// The HandlerChain is generic over the type of message (M) and the type
+/// each handler returns (R), so it can pass along any kind of message.  The
+/// handlers can also be extended by X, which the HandlerChain passes along
+/// without looking at (see ChainHandler).  The windows in this example are
+/// in a WindowHandlerChain, which is built on a HandlerChain.
+///
+/// Each handler has a priority and messages are passed to the handlers in
+/// order of priority, highest first; handlers with the same priority are
+/// passed messages in the order they were added.
+///
+/// While passing a message to the handlers, the handlers can post requests to
+/// send messages to, add or remove handlers; these are carried out once the
+/// message has been passed to the handlers.
+///
+/// A handler can also be given the capture, in which case it is the only
+/// handler passed messages until it releases the capture or is removed.  This
+/// is how a modal window, such as one asking to confirm closing a window,
+/// keeps the other windows from seeing any messages while it is open.
+///
+/// A snapshot of the handlers can be saved with save_state() and restored
+/// later with restore_state(), such as to reopen windows that were closed.
+pub struct HandlerChain<M, R, X: ?Sized = ()> {
+    /// The list of message handlers, in order of priority.
+    message_handlers: Vec<HandlerEntry<M, R, X>>,
+    /// Requests posted by the handlers that have not yet been carried out.
+    pending_actions: PendingActions<M, R, X>,
+    /// ID of the handler that has the capture, if any.
+    capture_handler_id: Option<i32>,
 }
 
-impl HandlerChain {
+impl<M, R: IHandlerResult, X: ?Sized> HandlerChain<M, R, X> {
     /// Constructor.
     ///
     /// # Returns
-    /// Returns a new instance of the HandlerChain struct.
-    pub fn new() -> HandlerChain {
+    /// Returns a new instance of the HandlerChain struct, which passes every
+    /// message to the handlers in order of priority.
+    pub fn new() -> HandlerChain<M, R, X> {
         HandlerChain {
             message_handlers: vec![],
+            pending_actions: PendingActions::new(),
+            capture_handler_id: None,
         }
     }
 
     /// Add the given instance of the IMessageHandler interface to end of the
-    /// list of handlers.
+    /// list of handlers with the DEFAULT_PRIORITY.
     /// 
     /// Normally this would guard against adding the same window twice, but this
     /// HandlerChain takes ownership of the window and therefore the same
     /// window can never be added twice to this HandlerChain.
-    pub fn add_handler(&mut self, handler: impl IMessageHandler+'static) {
-        self.message_handlers.push(Box::new(RefCell::new(handler)))
+    pub fn add_handler(&mut self, handler: impl IMessageHandler<M, R, X>+'static) {
+        self.add_handler_with_priority(handler, DEFAULT_PRIORITY);
+    }
+
+    /// Add the given instance of the IMessageHandler interface to the list of
+    /// handlers with the given priority, after all handlers with the same or
+    /// higher priority.
+    ///
+    /// # Parameters
+    /// - handler
+    ///
+    ///   The handler to add.
+    /// - priority
+    ///
+    ///   Priority of the handler.  Handlers with a higher priority are passed
+    ///   messages before handlers with a lower priority.
+    pub fn add_handler_with_priority(&mut self, handler: impl IMessageHandler<M, R, X>+'static, priority: i32) {
+        self.add_chain_handler(ChainHandler::new(handler), priority);
+    }
+
+    /// Add the given handler, which may have an extension, to the list of
+    /// handlers with the given priority, after all handlers with the same or
+    /// higher priority.
+    ///
+    /// # Parameters
+    /// - handler
+    ///
+    ///   The handler to add.
+    /// - priority
+    ///
+    ///   Priority of the handler.
+    pub fn add_chain_handler(&mut self, handler: ChainHandler<M, R, X>, priority: i32) {
+        let index = self.message_handlers.iter().position(|x| x.priority < priority)
+            .unwrap_or(self.message_handlers.len());
+        self._insert_handler(index, priority, handler);
+    }
+
+    /// Add the given handler to the list of handlers just before another
+    /// handler, with the same priority as that handler.
+    ///
+    /// # Parameters
+    /// - handler_id
+    ///
+    ///   ID of the handler to add the new handler before.
+    /// - handler
+    ///
+    ///   The handler to add.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the handler was added; otherwise, returns
+    /// `Err(String)` if there is no handler with the given ID.
+    pub fn insert_before(&mut self, handler_id: i32, handler: ChainHandler<M, R, X>) -> Result<(), String> {
+        let index = self._find_handler_index(handler_id)?;
+        let priority = self.message_handlers[index].priority;
+        self._insert_handler(index, priority, handler);
+        Ok(())
+    }
+
+    /// Add the given handler to the list of handlers just after another
+    /// handler, with the same priority as that handler.
+    ///
+    /// # Parameters
+    /// - handler_id
+    ///
+    ///   ID of the handler to add the new handler after.
+    /// - handler
+    ///
+    ///   The handler to add.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the handler was added; otherwise, returns
+    /// `Err(String)` if there is no handler with the given ID.
+    pub fn insert_after(&mut self, handler_id: i32, handler: ChainHandler<M, R, X>) -> Result<(), String> {
+        let index = self._find_handler_index(handler_id)?;
+        let priority = self.message_handlers[index].priority;
+        self._insert_handler(index + 1, priority, handler);
+        Ok(())
+    }
+
+    /// Helper method to add a handler at the given place in the list of
+    /// handlers.
+    ///
+    /// # Parameters
+    /// - index
+    ///
+    ///   Index in the list of handlers to add the handler at.  This must keep
+    ///   the list in order of priority.
+    /// - priority
+    ///
+    ///   Priority of the handler.
+    /// - handler
+    ///
+    ///   The handler to add.
+    fn _insert_handler(&mut self, index: usize, priority: i32, handler: ChainHandler<M, R, X>) {
+        self.message_handlers.insert(index, HandlerEntry {
+            priority,
+            handler,
+        });
+    }
+
+    /// Helper method to find the index of a handler in the list of handlers.
+    ///
+    /// # Parameters
+    /// - handler_id
+    ///
+    ///   ID of the handler to look for.
+    ///
+    /// # Returns
+    /// Returns `Ok(usize)` containing the index of the handler; otherwise,
+    /// returns `Err(String)` if there is no handler with the given ID.
+    fn _find_handler_index(&self, handler_id: i32) -> Result<usize, String> {
+        self.message_handlers.iter().position(|x| x.handler.id() == handler_id)
+            .ok_or(format!("There is no handler with id {handler_id} in the handler chain"))
+    }
+
+    /// The handlers in this HandlerChain, in order of priority, so the code
+    /// using the HandlerChain can look at the extensions of the handlers.
+    pub fn handlers(&self) -> impl Iterator<Item = &ChainHandler<M, R, X>> {
+        self.message_handlers.iter().map(|x| &x.handler)
+    }
+
+    /// ID of the handler that has the capture, if any.
+    pub fn capture_handler_id(&self) -> Option<i32> {
+        self.capture_handler_id
     }
 
     /// Remove an instance of the IMessageHandler interface from the list.
+    /// If the message handler has the capture, the capture is released.
     /// 
     /// If the message handler is not in the list, the request to remove
     /// is ignored.
     pub fn remove_handler(&mut self, handler_id: i32) {
-        let found_index = self.message_handlers.iter_mut().position(|x| x.borrow().id() == handler_id);
+        let found_index = self.message_handlers.iter().position(|x| x.handler.id() == handler_id);
         if let Some(index) = found_index {
             self.message_handlers.remove(index);
+            self.release_capture(handler_id);
+        }
+    }
+
+    /// Returns a HandlerChainMemento object containing a snapshot of the
+    /// handlers in this HandlerChain, in order, such as where each window is
+    /// and whether it is selected.
+    ///
+    /// # Parameters
+    /// - name
+    ///
+    ///   The name of the memento to create.
+    /// - save_handler
+    ///
+    ///   Function that makes a copy of the given handler as it is now, or
+    ///   returns None if the handler does not save its state, in which case
+    ///   restoring the snapshot leaves the handler as it is.
+    ///
+    /// # Returns
+    /// Returns an instance of the HandlerChainMemento struct, representing the
+    /// snapshot of this HandlerChain.
+    pub fn save_state(&self, name: &str, save_handler: impl Fn(&ChainHandler<M, R, X>) -> Option<ChainHandler<M, R, X>>) -> HandlerChainMemento<M, R, X> {
+        let handlers = self.message_handlers.iter()
+            .map(|entry| SavedHandler {
+                priority: entry.priority,
+                handler_id: entry.handler.id(),
+                handler: save_handler(&entry.handler),
+            })
+            .collect();
+        HandlerChainMemento::new(name, handlers, self.capture_handler_id)
+    }
+
+    /// Restore the handlers in this HandlerChain to the snapshot stored in
+    /// the given HandlerChainMemento object (which is assumed to be from the
+    /// HandlerChain::save_state() method of this HandlerChain).
+    ///
+    /// Handlers added since the snapshot was taken are removed and handlers
+    /// removed since then are added back, as they were.  Handlers that do not
+    /// save their state are left as they are, if they are still in the
+    /// HandlerChain.  The memento can be restored again later.
+    ///
+    /// # Parameters
+    /// - memento
+    ///
+    ///   A HandlerChainMemento object containing the handlers to restore.
+    /// - save_handler
+    ///
+    ///   The same function given to HandlerChain::save_state(), used to copy
+    ///   the saved handlers out of the memento.
+    pub fn restore_state(&mut self, memento: &HandlerChainMemento<M, R, X>, save_handler: impl Fn(&ChainHandler<M, R, X>) -> Option<ChainHandler<M, R, X>>) {
+        let mut current_handlers = std::mem::take(&mut self.message_handlers);
+        for saved in memento.handlers() {
+            let handler = match &saved.handler {
+                // Copy the saved handler so the memento can be restored again.
+                Some(saved_handler) => save_handler(saved_handler),
+                None => current_handlers.iter().position(|x| x.handler.id() == saved.handler_id)
+                    .map(|index| current_handlers.remove(index).handler),
+            };
+            if let Some(handler) = handler {
+                self.message_handlers.push(HandlerEntry {
+                    priority: saved.priority,
+                    handler,
+                });
+            }
         }
+        self.capture_handler_id = memento.capture_handler_id();
     }
 
-    /// Send a message to each of the handlers in the list.
+    /// Give the capture to the given handler, so it is the only handler
+    /// passed messages until it releases the capture or is removed.
+    ///
+    /// # Parameters
+    /// - handler_id
+    ///
+    ///   ID of the handler to give the capture to.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the handler has the capture; otherwise, returns
+    /// `Err(String)` if there is no handler with the given ID.
+    pub fn set_capture(&mut self, handler_id: i32) -> Result<(), String> {
+        self._find_handler_index(handler_id)?;
+        self.capture_handler_id = Some(handler_id);
+        Ok(())
+    }
+
+    /// Release the capture, so messages are passed to all handlers again.
+    ///
+    /// If the given handler does not have the capture, the request is
+    /// ignored, so a handler cannot release the capture of another handler.
+    ///
+    /// # Parameters
+    /// - handler_id
+    ///
+    ///   ID of the handler releasing the capture.
+    pub fn release_capture(&mut self, handler_id: i32) {
+        if self.capture_handler_id == Some(handler_id) {
+            self.capture_handler_id = None;
+        }
+    }
+
+    /// Send a message to each of the handlers in the list, in order of
+    /// priority, until a handler handles it, then carry out any requests the
+    /// handlers posted.  If a handler has the capture, the message is sent
+    /// only to that handler.
+    ///
+    /// # Parameters
+    /// - message
+    ///
+    ///   The message to send to each handler.
+    ///
+    /// # Returns
+    /// Returns `Some(R)` containing what the handler that handled the message
+    /// returned, or `None` if no handler handled the message.
+    pub fn send_message(&mut self, message: &M) -> Option<R> {
+        let result = match self.capture_handler_id {
+            Some(handler_id) => self._send_message_to_capture(handler_id, message),
+            None => self._send_message_in_order(message),
+        };
+        self._apply_pending_actions();
+        result
+    }
+
+    /// Send a message, in order of priority, to each of the handlers chosen
+    /// by the given function, regardless of what the handlers return, then
+    /// carry out any requests the handlers posted.  The capture is ignored,
+    /// so the caller should check for it first.
+    ///
+    /// This is for messages that are not passed along until handled, such as
+    /// a ButtonDown message, which goes to the topmost window under it and to
+    /// every window not under it, so each can select or deselect itself.
     ///
     /// # Parameters
     /// - message
     ///
-    ///   The Message object to send to each handler.
-    pub fn send_message(&mut self, message: &Message) {
-        for handler in self.message_handlers.iter() {
-            let result = handler.borrow_mut().process_message(&message);
-            match result {
-                MessageReturnTypes::Stop => break,
-                MessageReturnTypes::Continue => continue,
-                MessageReturnTypes::Close => { 
-                    let handler_id = handler.borrow().id();
-                    self.remove_handler(handler_id);
-                    break;
+    ///   The message to send.
+    /// - should_send
+    ///
+    ///   Function that returns true if the given handler should be sent the
+    ///   message.
+    /// - result_handler_id
+    ///
+    ///   ID of the handler whose result is returned, if any.
+    ///
+    /// # Returns
+    /// Returns `Some(R)` containing what the handler with the given ID
+    /// returned if it handled the message; otherwise, returns `None`.
+    pub fn send_message_to_chosen(&mut self, message: &M, mut should_send: impl FnMut(&ChainHandler<M, R, X>) -> bool,
+        result_handler_id: Option<i32>) -> Option<R>
+    {
+        let mut chosen_result = None;
+        for entry in self.message_handlers.iter() {
+            if should_send(&entry.handler) {
+                let result = entry.handler.handler().borrow_mut().process_message(message, &mut self.pending_actions);
+                if Some(entry.handler.id()) == result_handler_id {
+                    chosen_result = Some(result);
+                }
+            }
+        }
+        self._apply_pending_actions();
+        chosen_result.filter(|x| x.is_handled())
+    }
+
+    /// Helper method to send a message to each of the handlers in the list,
+    /// in order of priority, until a handler handles it.
+    ///
+    /// # Parameters
+    /// - message
+    ///
+    ///   The message to send to each handler.
+    ///
+    /// # Returns
+    /// Returns `Some(R)` containing what the handler that handled the message
+    /// returned, or `None` if no handler handled the message.
+    fn _send_message_in_order(&mut self, message: &M) -> Option<R> {
+        for entry in self.message_handlers.iter() {
+            let result = entry.handler.handler().borrow_mut().process_message(message, &mut self.pending_actions);
+            if result.is_handled() {
+                return Some(result);
+            }
+        }
+        None
+    }
+
+    /// Helper method to send a message to only the handler that has the
+    /// capture.
+    ///
+    /// # Parameters
+    /// - handler_id
+    ///
+    ///   ID of the handler that has the capture.
+    /// - message
+    ///
+    ///   The message to send.
+    ///
+    /// # Returns
+    /// Returns `Some(R)` containing what the handler returned if it handled
+    /// the message; otherwise, returns `None`.
+    fn _send_message_to_capture(&mut self, handler_id: i32, message: &M) -> Option<R> {
+        let found_entry = self.message_handlers.iter().find(|x| x.handler.id() == handler_id);
+        found_entry
+            .map(|entry| entry.handler.handler().borrow_mut().process_message(message, &mut self.pending_actions))
+            .filter(|x| x.is_handled())
+    }
+
+    /// Helper method to carry out the requests posted by the handlers, in the
+    /// order they were posted.  Handlers can post more requests while this is
+    /// going on (such as a window removing itself when sent a Close message),
+    /// which are carried out as well.
+    fn _apply_pending_actions(&mut self) {
+        while let Some(action) = self.pending_actions.take_next() {
+            match action {
+                PendingAction::SendMessage { handler_id, message } => {
+                    let found_entry = self.message_handlers.iter().find(|x| x.handler.id() == handler_id);
+                    if let Some(entry) = found_entry {
+                        entry.handler.handler().borrow_mut().process_message(&message, &mut self.pending_actions);
+                    }
+                }
+                PendingAction::AddHandler(handler) => self.add_chain_handler(handler, DEFAULT_PRIORITY),
+                PendingAction::RemoveHandler(handler_id) => self.remove_handler(handler_id),
+                PendingAction::SetCapture(handler_id) => {
+                    // A handler asking for the capture for a handler that has
+                    // already been removed is harmless, so there is nothing
+                    // to report.
+                    let _ = self.set_capture(handler_id);
                 }
+                PendingAction::ReleaseCapture(handler_id) => self.release_capture(handler_id),
             }
         }
     }
 }
 
-impl Display for HandlerChain {
-    /// Convert this HandlerChain to a string that is returned.
+impl<M, R: IHandlerResult, X: ?Sized> IHandlerChain<M> for HandlerChain<M, R, X> {
+    type Result = R;
+
+    fn send_message(&mut self, message: &M) -> Option<R> {
+        HandlerChain::send_message(self, message)
+    }
+}
+
+impl<M, R, X: ?Sized> Display for HandlerChain<M, R, X> {
+    /// Convert this HandlerChain to a string that is returned, one line for
+    /// each handler in order of priority.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        // We make a copy of the handlers so our processing of handlers
-        // is not impacted by updates to the master handler list.
         let mut output = String::new();
-        for handler in self.message_handlers.iter() {
-            output.push_str(&format!("    {}\n", handler.borrow().to_string()))
+        for entry in self.message_handlers.iter() {
+            output.push_str(&format!("    {}\n", entry.handler.handler().borrow().to_string()))
         }
 
         f.write_fmt(format_args!("{}", output))
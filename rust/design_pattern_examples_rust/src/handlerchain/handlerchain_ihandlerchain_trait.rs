@@ -0,0 +1,27 @@
+//! Contains the IHandlerChain trait.
+
+use std::fmt::Display;
+
+//-----------------------------------------------------------------------------
+
+/// Represents anything that passes messages of type M to a chain of
+/// handlers, such as the HandlerChain or the WindowHandlerChain, so the
+/// AsyncHandlerChain can work with either.
+///
+/// Displaying the chain describes each handler in it.
+pub trait IHandlerChain<M>: Display {
+    /// The type of value the handlers return.
+    type Result;
+
+    /// Send a message to the handlers.
+    ///
+    /// # Parameters
+    /// - message
+    ///
+    ///   The message to send to the handlers.
+    ///
+    /// # Returns
+    /// Returns `Some(Result)` containing what the handler that handled the
+    /// message returned, or `None` if no handler handled the message.
+    fn send_message(&mut self, message: &M) -> Option<Self::Result>;
+}
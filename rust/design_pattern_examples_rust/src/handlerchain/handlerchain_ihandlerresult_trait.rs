@@ -0,0 +1,20 @@
+//! Contains the IHandlerResult trait.
+
+//-----------------------------------------------------------------------------
+
+/// Represents what a handler in a HandlerChain returns after processing a
+/// message.  The HandlerChain uses this to decide whether to pass the message
+/// on to the next handler.
+pub trait IHandlerResult {
+    /// Whether the handler handled the message, in which case the message is
+    /// not passed on to any other handler.
+    fn is_handled(&self) -> bool;
+}
+
+/// A handler that returns an Option has handled the message if it returns
+/// `Some`.
+impl<T> IHandlerResult for Option<T> {
+    fn is_handled(&self) -> bool {
+        self.is_some()
+    }
+}
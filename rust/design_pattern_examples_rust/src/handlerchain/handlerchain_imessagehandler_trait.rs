@@ -1,31 +1,39 @@
 //! Contains the IMessageHandler trait.
 
-use super::handlerchain_message::Message;
-use super::handlerchain_handlerchain::MessageReturnTypes;
+use super::handlerchain_pendingactions::PendingActions;
 
 //-----------------------------------------------------------------------------
 
 /// Represents a handler in a chain of handlers.  All objects that
-/// participate in the HandlerChain class must implement this trait.
-pub trait IMessageHandler {
-    /// ID of the window.  This is used to uniquely identify a window in the
+/// participate in the HandlerChain class must implement this trait, for the
+/// type of message (M) the HandlerChain passes along and the type of value
+/// (R) the handlers return.
+///
+/// X is the extension trait of the HandlerChain the handler is in (see
+/// ChainHandler).  A handler that does not care what kind of HandlerChain it
+/// is in can implement this trait for any X.
+pub trait IMessageHandler<M, R, X: ?Sized = ()> {
+    /// ID of the handler.  This is used to uniquely identify a handler in the
     /// collection.
     fn id(&self) -> i32;
 
-    /// Called with a message for the window.
+    /// Called with a message for the handler.
     ///
     /// # Parameters
     /// - message
     ///
-    ///   Message object representing the message to process.
+    ///   The message to process.
+    /// - pending_actions
+    ///
+    ///   Queue on which to post requests to the HandlerChain, such as closing
+    ///   or adding a window.  The HandlerChain carries out the requests after
+    ///   the message has been passed to the handlers.
     ///
     /// # Returns
-    /// Returns a value from the MessageReturnTypes enumeration indicating what
-    /// action the caller should take: (Stop) This message was handled and
-    /// processing should stop, (Continue) this message can be passed on to
-    /// other handlers, or (Close) this handler is closed so remove from the
-    /// handlers list and stop further processing.
-    fn process_message(&mut self, message: &Message) -> MessageReturnTypes;
+    /// Returns a value indicating whether the message was handled, in which
+    /// case processing should stop, or the message can be passed on to other
+    /// handlers (see the IHandlerResult trait).
+    fn process_message(&mut self, message: &M, pending_actions: &mut PendingActions<M, R, X>) -> R;
 
     /// Return a string representation of the message handler.
     fn to_string(&self) -> String;
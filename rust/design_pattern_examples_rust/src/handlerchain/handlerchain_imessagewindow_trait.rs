@@ -0,0 +1,64 @@
+//! Contains the IMessageWindow trait.
+
+use crate::flyweight::flyweight_display::Display;
+use super::handlerchain_message::Message;
+use super::handlerchain_windowhandlerchain::WindowChainHandler;
+
+//-----------------------------------------------------------------------------
+
+/// Represents the parts of a window that only the WindowHandlerChain cares
+/// about: where the window is in the z-order, the area it covers and how it
+/// is drawn.  Every
+/// window in a WindowHandlerChain implements this trait as well as the
+/// IMessageHandler trait; the HandlerChain itself only sees the latter.
+pub trait IMessageWindow {
+    /// Position of the window in the z-order.  A window with a higher
+    /// z-order is in front of (on top of) a window with a lower z-order.  A
+    /// window that has not been placed in the z-order yet has a z-order of 0.
+    fn z_order(&self) -> i32;
+
+    /// Set the position of the window in the z-order.
+    ///
+    /// # Parameters
+    /// - z_order
+    ///
+    ///   The new position in the z-order, where higher is closer to the front.
+    fn set_z_order(&mut self, z_order: i32);
+
+    /// Determine if the position of the given message is in the area covered
+    /// by the window.
+    ///
+    /// # Parameters
+    /// - message
+    ///
+    ///   The message whose position to look for in the window.
+    ///
+    /// # Returns
+    /// Returns true if the position is in the window; otherwise, returns
+    /// false.
+    fn covers_position(&self, message: &Message) -> bool;
+
+    /// Called when another window has been selected, so this window is no
+    /// longer selected.
+    fn deselect(&mut self);
+
+    /// Render the window into the given display, over whatever is already
+    /// there.
+    ///
+    /// # Parameters
+    /// - display
+    ///
+    ///   The Display to render into, where column 0 of row 0 is x position 0
+    ///   and y position 0.
+    fn render(&self, display: &mut Display);
+
+    /// Make a copy of the window as it is now, for a snapshot of the
+    /// WindowHandlerChain.
+    ///
+    /// # Returns
+    /// Returns `Some(WindowChainHandler)` containing the copy, ready to be put
+    /// in a WindowHandlerChain; otherwise, returns None if the window does not
+    /// save its state, in which case restoring the snapshot leaves the window
+    /// as it is.
+    fn save_state(&self) -> Option<WindowChainHandler>;
+}
@@ -0,0 +1,159 @@
+//! Contains run_interactive() for selecting, dragging and closing windows
+//! drawn in the console with the mouse, with each mouse click passed to the
+//! windows as messages through a HandlerChain.
+
+use std::collections::VecDeque;
+use std::io::{stdout, Write};
+
+use crossterm::event::{KeyCode, MouseButton, MouseEventKind};
+use crossterm::style::Stylize;
+
+use crate::flyweight::flyweight_display::Display;
+use crate::helpers::cursor::{self, AlternateScreen};
+use crate::helpers::key_input::{self, ConsoleEvent, MouseCapture, RawMode};
+use super::handlerchain_message::{Message, MessageType};
+use super::handlerchain_messagewindow::MessageWindow;
+use super::handlerchain_reports;
+use super::handlerchain_windowhandlerchain::WindowHandlerChain;
+
+//-----------------------------------------------------------------------------
+
+/// The instructions shown on the first row of the console.
+const INSTRUCTIONS: &str = "Click a window to select it, drag its title to move it, click its X's or press x to close it (then y or n), n to copy it.  Esc exits.";
+
+/// Row of the console, starting at 0, at which y position 0 of the windows
+/// is, leaving room for the instructions.
+const WINDOWS_TOP: u16 = 2;
+
+/// Number of the most recent descriptions from the windows shown at the
+/// bottom of the console.
+const NUM_REPORT_LINES: usize = 6;
+
+//-----------------------------------------------------------------------------
+
+/// Helper function to clear the console and draw the instructions, the
+/// windows and the most recent descriptions from the windows.
+///
+/// # Parameters
+/// - handler_chain
+///
+///   The HandlerChain holding the windows to draw.
+/// - recent_reports
+///
+///   The most recent descriptions from the windows, oldest first.
+fn _draw_screen(handler_chain: &WindowHandlerChain, recent_reports: &VecDeque<String>) {
+    cursor::clear_screen();
+    let mut output = cursor::cursor_position_sequence(1, 1);
+    output.push_str(INSTRUCTIONS);
+
+    // The windows are rendered into a display filling the console between
+    // the instructions and the descriptions.
+    let (width, height) = cursor::console_size().unwrap_or((80, 25));
+    let display_height = height.saturating_sub(WINDOWS_TOP + NUM_REPORT_LINES as u16);
+    let mut display = Display::new(width as usize, display_height as usize);
+    handler_chain.render(&mut display);
+    for change in display.take_changes() {
+        output.push_str(&cursor::cursor_position_sequence(1 + change.col as u16, WINDOWS_TOP + 1 + change.row as u16));
+        match change.color {
+            Some(color) => output.push_str(&change.text.with(color).to_string()),
+            None => output.push_str(&change.text),
+        }
+    }
+
+    let first_row = height.saturating_sub(NUM_REPORT_LINES as u16) + 1;
+    for (row, report) in (first_row..).zip(recent_reports.iter()) {
+        output.push_str(&cursor::cursor_position_sequence(1, row));
+        output.push_str(report);
+    }
+    print!("{output}");
+    let _ = stdout().flush();
+}
+
+/// Helper function to convert a mouse event to the message to send to the
+/// windows.
+///
+/// # Parameters
+/// - kind
+///
+///   What the mouse did.
+/// - x
+///
+///   X position of the mouse in the same coordinates as the windows.
+/// - y
+///
+///   Y position of the mouse in the same coordinates as the windows.
+/// - last_position
+///
+///   Where the mouse was at the last mouse event, for the distance moved.
+///
+/// # Returns
+/// Returns `Some(Message)` containing the message to send; otherwise,
+/// returns None if the windows are not interested in what the mouse did.
+fn _mouse_message(kind: MouseEventKind, x: i32, y: i32, last_position: (i32, i32)) -> Option<Message> {
+    match kind {
+        MouseEventKind::Down(MouseButton::Left) => Some(Message::new(MessageType::ButtonDown, x, y)),
+        MouseEventKind::Up(MouseButton::Left) => Some(Message::new(MessageType::ButtonUp, x, y)),
+        MouseEventKind::Drag(MouseButton::Left) | MouseEventKind::Moved => {
+            Some(Message::mouse_move(last_position.0, last_position.1, x, y))
+        }
+        _ => None,
+    }
+}
+
+/// Draw some windows in the console and pass the clicks, drags and mouse
+/// movements made with the mouse, along with the keys pressed, to the
+/// windows as messages through a HandlerChain, until Esc is pressed.
+///
+/// # Returns
+/// Returns `Ok(())` when the user is done; otherwise, returns `Err(String)`
+/// if the console could not be set up for drawing or for the mouse.
+pub fn run_interactive() -> Result<(), String> {
+    let mut handler_chain = WindowHandlerChain::new();
+    for (title, x, y, width, height) in [("Window 1", 2, 1, 24, 8), ("Window 2", 34, 3, 20, 7), ("Window 3", 16, 6, 26, 9)] {
+        let mut window = MessageWindow::new(title, x, y, width, height);
+        window.set_confirm_close(true);
+        handler_chain.add_window(window);
+    }
+
+    let alternate_screen = AlternateScreen::enter()?;
+    let raw_mode = RawMode::enable()?;
+    let mouse_capture = MouseCapture::enable()?;
+    handlerchain_reports::collect_reports(true);
+
+    let mut recent_reports: VecDeque<String> = VecDeque::with_capacity(NUM_REPORT_LINES);
+    let mut mouse_position = (0, 0);
+    loop {
+        _draw_screen(&handler_chain, &recent_reports);
+        let message = match key_input::wait_for_event() {
+            None | Some(ConsoleEvent::Key(KeyCode::Esc)) => break,
+            Some(ConsoleEvent::Key(KeyCode::Char(key))) => Message::key_press(key, mouse_position.0, mouse_position.1),
+            Some(ConsoleEvent::Mouse { kind, column, row }) => {
+                let position = (column as i32, row as i32 - WINDOWS_TOP as i32);
+                let message = _mouse_message(kind, position.0, position.1, mouse_position);
+                mouse_position = position;
+                match message {
+                    Some(message) => message,
+                    None => continue,
+                }
+            }
+            // Anything else, such as the console changing size, just needs
+            // the screen drawn again.
+            Some(_) => continue,
+        };
+        handler_chain.send_message(&message);
+        for report in handlerchain_reports::take_reports() {
+            if recent_reports.len() == NUM_REPORT_LINES {
+                recent_reports.pop_front();
+            }
+            recent_reports.push_back(report);
+        }
+    }
+
+    handlerchain_reports::collect_reports(false);
+    drop(mouse_capture);
+    drop(raw_mode);
+    drop(alternate_screen);
+    println!("  Windows left open:");
+    println!("{}", handler_chain);
+    Ok(())
+}
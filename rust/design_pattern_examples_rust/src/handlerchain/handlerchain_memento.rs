@@ -0,0 +1,75 @@
+//! Contains the HandlerChainMemento struct that represents a snapshot of the
+//! handlers in a HandlerChain for later restoration.
+
+use super::handlerchain_chainhandler::ChainHandler;
+
+//-----------------------------------------------------------------------------
+
+/// Represents one handler in a HandlerChainMemento.
+pub(crate) struct SavedHandler<M, R, X: ?Sized> {
+    /// Priority of the handler in the HandlerChain.
+    pub(crate) priority: i32,
+    /// ID of the handler.
+    pub(crate) handler_id: i32,
+    /// Copy of the handler as it was when the snapshot was taken, or None if
+    /// the handler does not save its state (see
+    /// HandlerChain::save_state()).
+    pub(crate) handler: Option<ChainHandler<M, R, X>>,
+}
+
+/// Represents a single memento (snapshot) of the handlers in a HandlerChain,
+/// such as where each window is and whether it is selected, taken before
+/// the handlers are changed.  The HandlerChain can later be restored to the
+/// snapshot, as often as needed.
+///
+/// This is the same approach as the Memento struct of the Memento example:
+/// only the HandlerChain that created the memento knows what is in it.
+pub struct HandlerChainMemento<M, R, X: ?Sized = ()> {
+    /// The name of this memento, for display purposes.
+    name: String,
+    /// The handlers, in the order they were in the HandlerChain.
+    handlers: Vec<SavedHandler<M, R, X>>,
+    /// ID of the handler that had the capture, if any.
+    capture_handler_id: Option<i32>,
+}
+
+impl<M, R, X: ?Sized> HandlerChainMemento<M, R, X> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - name
+    ///
+    ///   The name of the memento to create.
+    /// - handlers
+    ///
+    ///   The handlers to be saved in the memento.
+    /// - capture_handler_id
+    ///
+    ///   ID of the handler that has the capture, if any.
+    ///
+    /// # Returns
+    /// Returns a new instance of the HandlerChainMemento struct.
+    pub(crate) fn new(name: &str, handlers: Vec<SavedHandler<M, R, X>>, capture_handler_id: Option<i32>) -> HandlerChainMemento<M, R, X> {
+        HandlerChainMemento {
+            name: name.to_string(),
+            handlers,
+            capture_handler_id,
+        }
+    }
+
+    /// The saved handlers in this memento.  This is meant to be used only by
+    /// the HandlerChain that created the memento.
+    pub(crate) fn handlers(&self) -> &[SavedHandler<M, R, X>] {
+        &self.handlers
+    }
+
+    /// ID of the handler that had the capture when the memento was created.
+    pub(crate) fn capture_handler_id(&self) -> Option<i32> {
+        self.capture_handler_id
+    }
+
+    /// The name of the memento (snapshot).
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
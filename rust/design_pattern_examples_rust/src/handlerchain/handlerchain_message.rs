@@ -8,18 +8,40 @@ use std::fmt::Display;
 /// Represent the types of messages that can be sent to a MessageWindow (as
 /// seen by the IMessageHandler::process_message() method).
 ///
-/// Normally, the Close message type would be supported but Rust does not like
-/// nested calls to mutable objects, which is required with Close (the Button
-/// Up handler would send a Close message to the HandlerChain).  Instead, the
-/// Button Up message handler of the MessageWindow returns a Close return code
-/// so HandlerChain::send_message() can take care of removing the window from
-/// the handler list.
+/// A window cannot send a Close message to the HandlerChain while the
+/// HandlerChain is passing it a message, since Rust does not like nested calls
+/// to mutable objects.  Instead, the window posts a request for the Close
+/// message (see PendingActions), which the HandlerChain sends once it has
+/// finished passing the current message to the windows.
+///
+/// Some message types carry extra data with them, such as the key that was
+/// pressed.
 pub enum MessageType {
     /// Selects a window based on position.
-    ButtonDown = 1,
+    ButtonDown,
 
     /// Take an action on the currently selected window.
     ButtonUp,
+
+    /// The mouse moved to the message position.  The x and y values are how
+    /// far the mouse moved since the last MouseMove message, so the mouse was
+    /// previously at the message position minus these values.
+    MouseMove {
+        /// Distance moved horizontally.
+        x: i32,
+        /// Distance moved vertically.
+        y: i32,
+    },
+
+    /// A key was pressed.  Goes to the currently selected window, no matter
+    /// where the message position is.
+    KeyPress(char),
+
+    /// All windows should redraw themselves.
+    Redraw,
+
+    /// Close the window.  Sent only to the window being closed.
+    Close,
 }
 
 //=============================================================================
@@ -75,6 +97,9 @@ pub struct Message {
 impl Message {
     /// Constructor.
     ///
+    /// See also mouse_move(), key_press() and redraw() for creating messages
+    /// that carry extra data.
+    ///
     /// # Parameters
     /// - message_type
     ///
@@ -95,6 +120,64 @@ impl Message {
             message_position: MessagePosition::new(x, y),
         }
     }
+
+    /// Create a MouseMove message for the mouse moving from one position to
+    /// another.
+    ///
+    /// # Parameters
+    /// - from_x
+    ///
+    ///   X position the mouse moved from.
+    /// - from_y
+    ///
+    ///   Y position the mouse moved from.
+    /// - to_x
+    ///
+    ///   X position the mouse moved to (the position of the message).
+    /// - to_y
+    ///
+    ///   Y position the mouse moved to (the position of the message).
+    ///
+    /// # Returns
+    /// Returns a new instance of the Message struct.
+    pub fn mouse_move(from_x: i32, from_y: i32, to_x: i32, to_y: i32) -> Message {
+        Message::new(MessageType::MouseMove { x: to_x - from_x, y: to_y - from_y }, to_x, to_y)
+    }
+
+    /// Create a KeyPress message.
+    ///
+    /// # Parameters
+    /// - key
+    ///
+    ///   The key that was pressed.
+    /// - x
+    ///
+    ///   X position of the mouse when the key was pressed.
+    /// - y
+    ///
+    ///   Y position of the mouse when the key was pressed.
+    ///
+    /// # Returns
+    /// Returns a new instance of the Message struct.
+    pub fn key_press(key: char, x: i32, y: i32) -> Message {
+        Message::new(MessageType::KeyPress(key), x, y)
+    }
+
+    /// Create a Redraw message.  The position of the message is not used.
+    ///
+    /// # Returns
+    /// Returns a new instance of the Message struct.
+    pub fn redraw() -> Message {
+        Message::new(MessageType::Redraw, 0, 0)
+    }
+
+    /// Create a Close message.  The position of the message is not used.
+    ///
+    /// # Returns
+    /// Returns a new instance of the Message struct.
+    pub fn close() -> Message {
+        Message::new(MessageType::Close, 0, 0)
+    }
 }
 
 
@@ -102,8 +185,12 @@ impl Display for Message {
     /// Convert the Message struct contents to a string.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let type_as_string = match self.message_type {
-            MessageType::ButtonDown => "ButtonDown",
-            MessageType::ButtonUp => "ButtonUp",
+            MessageType::ButtonDown => "ButtonDown".to_string(),
+            MessageType::ButtonUp => "ButtonUp".to_string(),
+            MessageType::MouseMove { x, y } => format!("MouseMove by ({x},{y})"),
+            MessageType::KeyPress(key) => format!("KeyPress '{key}'"),
+            MessageType::Redraw => "Redraw".to_string(),
+            MessageType::Close => "Close".to_string(),
         };
 
         f.write_fmt(format_args!("{0} at ({1})", type_as_string, self.message_position))
@@ -0,0 +1,55 @@
+//! Contains the MessageLogger struct, a message handler that covers the whole
+//! screen and logs every message it sees.
+
+use super::handlerchain_message::Message;
+use super::handlerchain_imessagehandler_trait::IMessageHandler;
+use super::handlerchain_handlerchain::{get_next_handler_id, MessageReturnTypes};
+use super::handlerchain_pendingactions::PendingActions;
+use super::handlerchain_reports::report;
+
+//-----------------------------------------------------------------------------
+
+/// Represents a handler for the whole screen that shows every message passed
+/// to it and then passes the message on to other handlers.  Given a high
+/// priority in a HandlerChain, it sees every message before any window does.
+///
+/// The MessageLogger is not a window and does not take up any room on the
+/// screen, so it can be in any HandlerChain passing Message objects and is
+/// never the topmost window under a ButtonDown message.
+pub struct MessageLogger {
+    /// Unique ID of this handler.
+    id: i32,
+
+    /// Number of messages logged so far.
+    messages_logged: usize,
+}
+
+impl MessageLogger {
+    /// Constructor
+    ///
+    /// # Returns
+    /// Returns a new instance of the MessageLogger struct.
+    pub fn new() -> MessageLogger {
+        MessageLogger {
+            id: get_next_handler_id(),
+            messages_logged: 0,
+        }
+    }
+}
+
+
+impl<X: ?Sized> IMessageHandler<Message, MessageReturnTypes, X> for MessageLogger {
+    fn id(&self) -> i32 {
+        self.id
+    }
+
+    fn process_message(&mut self, message: &Message, _pending_actions: &mut PendingActions<Message, MessageReturnTypes, X>) -> MessageReturnTypes {
+        self.messages_logged += 1;
+        report(&format!("  [screen] {}", message));
+        MessageReturnTypes::Continue
+    }
+
+    fn to_string(&self) -> String {
+        format!("[id={:2}] Screen message logger, messages logged={}", self.id, self.messages_logged)
+    }
+}
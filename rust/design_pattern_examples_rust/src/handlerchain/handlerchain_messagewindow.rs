@@ -2,11 +2,17 @@
 
 use std::cmp::max;
 use std::fmt::Display;
-use std::sync::atomic::{AtomicI32, Ordering};
 
+use super::handlerchain_confirmwindow::ConfirmWindow;
 use super::handlerchain_message::{MessagePosition, MessageType, Message};
 use super::handlerchain_imessagehandler_trait::IMessageHandler;
-use super::handlerchain_handlerchain::MessageReturnTypes;
+use super::handlerchain_imessagewindow_trait::IMessageWindow;
+use super::handlerchain_handlerchain::{get_next_handler_id, MessageReturnTypes};
+use super::handlerchain_windowhandlerchain::{window_handler, WindowChainHandler, WindowPendingActions};
+use super::handlerchain_pendingactions::PendingAction;
+use super::handlerchain_reports::report;
+use crate::flyweight::flyweight_display;
+use crossterm::style::Color;
 
 //-----------------------------------------------------------------------------
 
@@ -21,20 +27,47 @@ const CLOSE_WIDTH: i32 = 2;
 /// Height of the QUIT region in the upper right corner of the region.
 const CLOSE_HEIGHT: i32 = 2;
 
-/// Retrieve the next window ID.
-fn get_next_window_id() -> i32 {
-    // Use a static to hold the next window ID.
-    static NEXT_WINDOW_ID: AtomicI32 = AtomicI32::new(1);
-
-    NEXT_WINDOW_ID.fetch_add(1, Ordering::Relaxed)
+/// Height of the title bar along the top of the window, by which the window
+/// can be dragged.
+const TITLE_HEIGHT: i32 = 1;
+
+/// Key that closes the currently selected window.
+const CLOSE_KEY: char = 'x';
+
+/// Key that opens a new window next to the currently selected window.
+const NEW_WINDOW_KEY: char = 'n';
+
+/// How far down and to the right of the selected window a new window opens.
+const NEW_WINDOW_OFFSET: i32 = 2;
+
+/// How far down and to the right of a window the window asking to confirm
+/// closing it opens.
+const CONFIRM_WINDOW_OFFSET: i32 = 2;
+
+/// Represents the part of a window that a point is in, as found by
+/// MessageWindow::hit_test().
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HitArea {
+    /// Inside the border of the window.
+    Client,
+    /// In the close box in the upper right corner of the window.
+    CloseBox,
+    /// On the border of the window, which includes the title bar.
+    Border,
+    /// Not in the window at all.
+    Outside,
 }
 
+/// Color in which a selected window is rendered.
+pub const SELECTED_COLOR: Color = Color::Yellow;
+
 //-----------------------------------------------------------------------------
 
 /// Represents a rectangular region, with upper left and lower right
 /// coordinates.
 ///
 /// For this example, the minimum width of a rectangle is 4 x 4.
+#[derive(Clone)]
 pub struct WindowRectangle {
         pub left: i32,
         pub top: i32,
@@ -88,6 +121,47 @@ impl WindowRectangle {
         }
         is_inside
     }
+
+    /// Move the rectangle by the given amounts, keeping its size.
+    ///
+    /// # Parameters
+    /// - x
+    ///
+    ///   Distance to move horizontally.
+    /// - y
+    ///
+    ///   Distance to move vertically.
+    pub fn offset(&mut self, x: i32, y: i32) {
+        self.left += x;
+        self.right += x;
+        self.top += y;
+        self.bottom += y;
+    }
+
+    /// Render the part of the rectangle that is in the given display into
+    /// the display, skipping any part outside it.
+    ///
+    /// # Parameters
+    /// - display
+    ///
+    ///   The Display to render into.
+    /// - color
+    ///
+    ///   Color of the characters, or None for the console's default color.
+    /// - character_at
+    ///
+    ///   Function returning the character at the given x and y positions,
+    ///   which are always in the rectangle.
+    pub fn render(&self, display: &mut flyweight_display::Display, color: Option<Color>, character_at: impl Fn(i32, i32) -> char) {
+        let first_x = self.left.max(0);
+        let last_x = self.right.min(display.width as i32);
+        for y in self.top.max(0)..self.bottom.min(display.height as i32) {
+            for x in first_x..last_x {
+                display.display[y as usize][x as usize] = character_at(x, y);
+                display.colors[y as usize][x as usize] = color;
+            }
+        }
+    }
 }
 
 impl Display for WindowRectangle {
@@ -106,10 +180,15 @@ impl Display for WindowRectangle {
 /// "Facade" design pattern, where the complexity of the MessageWindow is
 /// exposed only through a few methods to a system that only needs to deal with
 /// those few methods.
+#[derive(Clone)]
 pub struct MessageWindow {
     /// Unique ID of this window.
     id: i32,
 
+    /// Position of this window in the z-order, where higher is closer to the
+    /// front.  Set by the HandlerChain.
+    z_order: i32,
+
     /// Title/Name of this window.
     title: String,
 
@@ -124,6 +203,16 @@ pub struct MessageWindow {
     /// Whether this window has been selected (a button click occurred
     /// within the window).
     selected: bool,
+
+    /// Whether the mouse is currently over this window (highlighted).
+    hovered: bool,
+
+    /// Whether this window is being dragged (the button went down in the
+    /// title bar and has not yet come up).
+    dragging: bool,
+
+    /// Whether this window asks "Are you sure?" before closing.
+    confirm_close: bool,
 }
 
 impl MessageWindow {
@@ -149,12 +238,147 @@ impl MessageWindow {
     /// # Returns
     /// Returns a new instance of the MessageWindow struct.
     pub fn new(title: &str, x: i32, y:i32, width: i32, height: i32) -> MessageWindow {
+        let window_box = WindowRectangle::new(x, y, width, height);
+        // The close box is smaller than the minimum size of a rectangle, so
+        // its corners are given directly.
+        let close_box = WindowRectangle {
+            left: window_box.right - CLOSE_WIDTH,
+            top: window_box.top,
+            right: window_box.right,
+            bottom: window_box.top + CLOSE_HEIGHT,
+        };
         MessageWindow {
-            id : get_next_window_id(),
+            id : get_next_handler_id(),
+            z_order: 0,
             title : title.to_string(),
-            window_box: WindowRectangle::new(x, y, width, height),
-            close_box: WindowRectangle::new(x + width - CLOSE_WIDTH, y, CLOSE_WIDTH, CLOSE_HEIGHT),
+            window_box,
+            close_box,
             selected: false,
+            hovered: false,
+            dragging: false,
+            confirm_close: false,
+        }
+    }
+
+    /// Set whether this window asks "Are you sure?" in a modal ConfirmWindow
+    /// before closing, instead of closing right away.
+    ///
+    /// # Parameters
+    /// - confirm_close
+    ///
+    ///   true if this window should ask before closing.
+    pub fn set_confirm_close(&mut self, confirm_close: bool) {
+        self.confirm_close = confirm_close;
+    }
+
+    /// Helper method to describe what _request_close() posts, for reports.
+    fn _close_request_text(&self) -> &'static str {
+        if self.confirm_close {
+            "opening \"Are you sure?\" window"
+        } else {
+            "posting Close message"
+        }
+    }
+
+    /// Helper method to ask for this window to be closed.  If this window
+    /// asks before closing, post requests to add a ConfirmWindow and give it
+    /// the capture; otherwise, post the Close message to this window.
+    ///
+    /// # Parameters
+    /// - pending_actions
+    ///
+    ///   Queue on which to post the requests.
+    fn _request_close(&self, pending_actions: &mut WindowPendingActions) {
+        if self.confirm_close {
+            let confirm_window = ConfirmWindow::new(self.id, &self.title,
+                self.window_box.left + CONFIRM_WINDOW_OFFSET, self.window_box.top + CONFIRM_WINDOW_OFFSET);
+            let confirm_window_id = confirm_window.id();
+            pending_actions.post(PendingAction::AddHandler(window_handler(confirm_window)));
+            pending_actions.post(PendingAction::SetCapture(confirm_window_id));
+        } else {
+            pending_actions.post(PendingAction::SendMessage { handler_id: self.id, message: Message::close() });
+        }
+    }
+
+    /// Determine which part of this window the given point is in.
+    ///
+    /// # Parameters
+    /// - point
+    ///
+    ///   A MessagePosition struct to look for in this window.
+    ///
+    /// # Returns
+    /// Returns a value from the HitArea enumeration: CloseBox if the point is
+    /// in the close box, which takes precedence over the border it overlaps,
+    /// Border if the point is on the border, Client if it is inside the
+    /// border, or Outside if the point is not in this window.
+    pub fn hit_test(&self, point: &MessagePosition) -> HitArea {
+        let window_box = &self.window_box;
+        if !window_box.point_inside(point) {
+            HitArea::Outside
+        } else if self.close_box.point_inside(point) {
+            HitArea::CloseBox
+        } else if point.x == window_box.left || point.x == window_box.right - 1 ||
+            point.y == window_box.top || point.y == window_box.bottom - 1 {
+            HitArea::Border
+        } else {
+            HitArea::Client
+        }
+    }
+
+    /// Helper method to determine if the given point is in the title bar of
+    /// the window, not counting the close box.
+    ///
+    /// # Parameters
+    /// - point
+    ///
+    ///   A MessagePosition struct to look for in the title bar.
+    ///
+    /// # Returns
+    /// Returns true if the point is in the title bar; otherwise, returns
+    /// false.
+    fn _point_in_title_bar(&self, point: &MessagePosition) -> bool {
+        self.hit_test(point) == HitArea::Border && point.y < self.window_box.top + TITLE_HEIGHT
+    }
+
+    /// Helper method to get the character drawn at the given position in the
+    /// window: the border (doubled up when the window is selected) with the
+    /// title across the top (cut short so it stops a character before the
+    /// close box), X's in the close box, and the inside of the
+    /// window, which is dotted when the mouse is over the window.
+    ///
+    /// # Parameters
+    /// - x
+    ///
+    ///   X position, which must be in the window.
+    /// - y
+    ///
+    ///   Y position, which must be in the window.
+    ///
+    /// # Returns
+    /// Returns the character to draw.
+    fn _character_at(&self, x: i32, y: i32) -> char {
+        let window_box = &self.window_box;
+        let (horizontal, vertical, corner) = if self.selected { ('=', '#', '#') } else { ('-', '|', '+') };
+        match self.hit_test(&MessagePosition::new(x, y)) {
+            HitArea::CloseBox => 'X',
+            HitArea::Border => {
+                let on_left_or_right = x == window_box.left || x == window_box.right - 1;
+                let on_top_or_bottom = y == window_box.top || y == window_box.bottom - 1;
+                if on_left_or_right && on_top_or_bottom {
+                    corner
+                } else if y == window_box.top {
+                    let title_index = (x - window_box.left - 1) as usize;
+                    let title_width = (window_box.right - window_box.left - 2 - CLOSE_WIDTH).max(0) as usize;
+                    self.title.chars().take(title_width).nth(title_index).unwrap_or(horizontal)
+                } else if on_top_or_bottom {
+                    horizontal
+                } else {
+                    vertical
+                }
+            }
+            HitArea::Client if self.hovered => '.',
+            HitArea::Client | HitArea::Outside => ' ',
         }
     }
 
@@ -168,26 +392,25 @@ impl MessageWindow {
     ///
     /// # Returns
     /// Returns a value from the MessageReturnTypes enumeration indicating what
-    /// action the caller should take.  In this case, always return Continue so
-    /// other handlers can react to the same message (assumes no windows are
-    /// overlapping).
+    /// action the caller should take: (Stop) The button went down in this
+    /// window so no window behind it should see the message, or (Continue)
+    /// the button went down outside this window so other windows can react
+    /// to the message.
     fn handle_button_down_message(&mut self, message: &Message) -> MessageReturnTypes {
-        if self.window_box.point_inside(&message.message_position) {
+        if self.hit_test(&message.message_position) != HitArea::Outside {
             if !self.selected {
                 self.selected = true;
-                println!("  --> Button Down in \"{0}\", window selected", self.title);
+                report(&format!("  --> Button Down in \"{0}\", window selected", self.title));
             }
-        } else {
-            if self.selected {
-                self.selected = false;
-                println!("  --> Button Down not in \"{0}\", window deselected", self.title);
+            if self._point_in_title_bar(&message.message_position) {
+                self.dragging = true;
+                report(&format!("  --> Button Down in \"{0}\" title bar, drag started", self.title));
             }
+            MessageReturnTypes::Stop
+        } else {
+            self.deselect();
+            MessageReturnTypes::Continue
         }
-
-        // Note: we are saying we didn't handled the message here since
-        // we want other windows to get the button down message as
-        // well so they can select or deselect themselves.
-        MessageReturnTypes::Continue
     }
 
     /// Helper method to handle the ButtonUp message.
@@ -196,70 +419,221 @@ impl MessageWindow {
     /// - message
     ///
     ///   A Message object describing the ButtonUp message.
+    /// - pending_actions
+    ///
+    ///   Queue on which to post a request to close this window if the button
+    ///   came up in the close box.
     ///
     /// # Returns
     /// Returns a value from the MessageReturnTypes enumeration indicating what
     /// action the caller should take: (Stop) This message was handled and
-    /// processing should stop, (Continue) this message can be passed on to
-    /// other handlers, or (Close) this handler is closed so remove from the
-    /// handlers list and stop further processing.
-    fn handle_button_up_message(&mut self, message: &Message) -> MessageReturnTypes {
+    /// processing should stop, or (Continue) this message can be passed on to
+    /// other handlers.
+    fn handle_button_up_message(&mut self, message: &Message, pending_actions: &mut WindowPendingActions) -> MessageReturnTypes {
         let mut continue_code = MessageReturnTypes::Continue;
 
-        if self.selected {
-            if self.window_box.point_inside(&message.message_position) {
-                continue_code = MessageReturnTypes::Stop;
-                if self.close_box.point_inside(&message.message_position) {
-                    println!("  --> Button Up in \"{0}\" close box, forwarding message to close handler", self.title);
-                    continue_code = self.handle_close_message(message);
-                } else {
-                    println!("  --> Button Up in \"{}\", no further action taken", self.title);
+        if self.dragging {
+            self.dragging = false;
+            report(&format!("  --> Button Up in \"{0}\", drag ended at ({1})", self.title, self.window_box));
+            continue_code = MessageReturnTypes::Stop;
+        } else if self.selected {
+            match self.hit_test(&message.message_position) {
+                HitArea::CloseBox => {
+                    report(&format!("  --> Button Up in \"{0}\" close box, {1}", self.title, self._close_request_text()));
+                    self._request_close(pending_actions);
+                    continue_code = MessageReturnTypes::Stop;
                 }
+                HitArea::Client | HitArea::Border => {
+                    report(&format!("  --> Button Up in \"{}\", no further action taken", self.title));
+                    continue_code = MessageReturnTypes::Stop;
+                }
+                HitArea::Outside => {}
+            }
+        }
+        continue_code
+    }
+
+    /// Helper method to handle the MouseMove message.
+    ///
+    /// # Parameters
+    /// - message
+    ///
+    ///   A Message object describing the MouseMove message.
+    ///
+    /// - x
+    ///
+    ///   Distance the mouse moved horizontally.
+    /// - y
+    ///
+    ///   Distance the mouse moved vertically.
+    ///
+    /// # Returns
+    /// Returns a value from the MessageReturnTypes enumeration indicating what
+    /// action the caller should take.  In this case, always return Continue so
+    /// the window the mouse left can remove its highlight as well as the window
+    /// the mouse entered adding its highlight.
+    fn handle_mouse_move_message(&mut self, message: &Message, x: i32, y: i32) -> MessageReturnTypes {
+        if self.dragging {
+            // The window follows the mouse, so the mouse stays at the same
+            // place in the window.
+            self.window_box.offset(x, y);
+            self.close_box.offset(x, y);
+            report(&format!("  --> Mouse Move dragged \"{0}\" to ({1})", self.title, self.window_box));
+        }
+        if self.hit_test(&message.message_position) != HitArea::Outside {
+            if !self.hovered {
+                self.hovered = true;
+                report(&format!("  --> Mouse entered \"{0}\", window highlighted", self.title));
+            }
+        } else if self.hovered {
+            self.hovered = false;
+            report(&format!("  --> Mouse left \"{0}\", window no longer highlighted", self.title));
+        }
+        MessageReturnTypes::Continue
+    }
+
+    /// Helper method to handle the KeyPress message.
+    ///
+    /// # Parameters
+    /// - message
+    ///
+    ///   A Message object describing the KeyPress message.
+    /// - key
+    ///
+    ///   The key that was pressed.
+    /// - pending_actions
+    ///
+    ///   Queue on which to post a request to close this window or to open a
+    ///   new window, depending on the key.
+    ///
+    /// # Returns
+    /// Returns a value from the MessageReturnTypes enumeration indicating what
+    /// action the caller should take: (Stop) This message was handled by the
+    /// selected window, or (Continue) this window is not selected so pass the
+    /// message on to other handlers.
+    fn handle_key_press_message(&mut self, _message: &Message, key: char, pending_actions: &mut WindowPendingActions) -> MessageReturnTypes {
+        let mut continue_code = MessageReturnTypes::Continue;
+
+        if self.selected {
+            if key == CLOSE_KEY {
+                report(&format!("  --> Key '{0}' in \"{1}\", {2}", key, self.title, self._close_request_text()));
+                self._request_close(pending_actions);
+            } else if key == NEW_WINDOW_KEY {
+                let new_title = format!("{} copy", self.title);
+                report(&format!("  --> Key '{0}' in \"{1}\", posting request to add window \"{2}\"", key, self.title, new_title));
+                let mut new_window = MessageWindow::new(&new_title,
+                    self.window_box.left + NEW_WINDOW_OFFSET, self.window_box.top + NEW_WINDOW_OFFSET,
+                    self.window_box.right - self.window_box.left, self.window_box.bottom - self.window_box.top);
+                new_window.set_confirm_close(self.confirm_close);
+                pending_actions.post(PendingAction::AddHandler(window_handler(new_window)));
+            } else {
+                report(&format!("  --> Key '{0}' in \"{1}\", no further action taken", key, self.title));
             }
+            continue_code = MessageReturnTypes::Stop;
         }
         continue_code
     }
 
+    /// Helper method to handle the Redraw message.
+    ///
+    /// # Parameters
+    /// - _message
+    ///
+    ///   NOT USED.
+    ///
+    /// # Returns
+    /// Returns a value from the MessageReturnTypes enumeration indicating what
+    /// action the caller should take.  In this case, always return Continue so
+    /// all windows redraw themselves.
+    fn handle_redraw_message(&mut self, _message: &Message) -> MessageReturnTypes {
+        let mut state = String::new();
+        if self.selected {
+            state.push_str(", selected");
+        }
+        if self.hovered {
+            state.push_str(", highlighted");
+        }
+        report(&format!("  --> Redraw \"{0}\" at ({1}){2}", self.title, self.window_box, state));
+        MessageReturnTypes::Continue
+    }
+
     /// Helper method to handle the Close message.
     ///
     /// # Parameters
     /// - _message
     ///
     ///   NOT USED.
+    /// - pending_actions
+    ///
+    ///   Queue on which to post the request to remove this window.
     ///
     /// # Returns
     /// Returns a value from the MessageReturnTypes enumeration indicating what
-    /// action the caller should take.  In this case, always return Close to
-    /// indicate this handler should be removed from the handler list so no
-    /// further messages can be sent to this window.
-    fn handle_close_message(&mut self, _message: &Message) -> MessageReturnTypes {
-        let mut continue_code = MessageReturnTypes::Continue;
+    /// action the caller should take.  In this case, always return Stop, as
+    /// the Close message is meant only for this window.
+    fn handle_close_message(&mut self, _message: &Message, pending_actions: &mut WindowPendingActions) -> MessageReturnTypes {
+        report(&format!("  --> Close in \"{}\", removing window from handler chain", self.title));
+        // This window is being closed, so ask the HandlerChain to remove it
+        // so no further messages can be sent to this window.
+        self.selected = false;
+        pending_actions.post(PendingAction::RemoveHandler(self.id));
+        MessageReturnTypes::Stop
+    }
+}
+
+
+impl IMessageWindow for MessageWindow {
+    fn z_order(&self) -> i32 {
+        self.z_order
+    }
+
+    fn set_z_order(&mut self, z_order: i32) {
+        self.z_order = z_order;
+    }
+
+    fn covers_position(&self, message: &Message) -> bool {
+        self.hit_test(&message.message_position) != HitArea::Outside
+    }
+
+    fn save_state(&self) -> Option<WindowChainHandler> {
+        // A window being dragged when the snapshot is taken is not being
+        // dragged once restored, as the button has long since come up.
+        let mut window = self.clone();
+        window.dragging = false;
+        Some(window_handler(window))
+    }
 
+    fn deselect(&mut self) {
+        self.dragging = false;
         if self.selected {
-            println!("  --> Close in \"{}\", removing window from handler chain", self.title);
-            // This window is being closed.  We are handling the message
-            // so no other window needs to see it.
-            continue_code = MessageReturnTypes::Close;
             self.selected = false;
+            report(&format!("  --> Button Down not in \"{0}\", window deselected", self.title));
         }
-        continue_code
     }
-}
 
+    fn render(&self, display: &mut flyweight_display::Display) {
+        let color = if self.selected { Some(SELECTED_COLOR) } else { None };
+        self.window_box.render(display, color, |x, y| self._character_at(x, y));
+    }
+}
 
-impl IMessageHandler for MessageWindow {
+impl IMessageHandler<Message, MessageReturnTypes, dyn IMessageWindow> for MessageWindow {
     fn id(&self) -> i32 {
         self.id
     }
 
-    fn process_message(&mut self, message: &super::handlerchain_message::Message) -> MessageReturnTypes {
+    fn process_message(&mut self, message: &Message, pending_actions: &mut WindowPendingActions) -> MessageReturnTypes {
         match message.message_type {
             MessageType::ButtonDown => self.handle_button_down_message(message),
-            MessageType::ButtonUp => self.handle_button_up_message(message),
+            MessageType::ButtonUp => self.handle_button_up_message(message, pending_actions),
+            MessageType::MouseMove { x, y } => self.handle_mouse_move_message(message, x, y),
+            MessageType::KeyPress(key) => self.handle_key_press_message(message, key, pending_actions),
+            MessageType::Redraw => self.handle_redraw_message(message),
+            MessageType::Close => self.handle_close_message(message, pending_actions),
         }
     }
 
     fn to_string(&self) -> String {
-        format!("[id={:2}] \"{}\" ({}), selected={}", self.id, self.title, self.window_box, self.selected)
+        format!("[id={:2}] \"{}\" ({}), z={}, selected={}, highlighted={}", self.id, self.title, self.window_box, self.z_order, self.selected, self.hovered)
     }
 }
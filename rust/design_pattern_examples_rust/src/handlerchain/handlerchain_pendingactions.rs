@@ -0,0 +1,84 @@
+//! Contains the PendingAction enumeration and the PendingActions struct, the
+//! queue of requests that message handlers post to the HandlerChain while
+//! they are processing a message.
+
+use std::collections::VecDeque;
+
+use super::handlerchain_chainhandler::ChainHandler;
+
+//-----------------------------------------------------------------------------
+
+/// Represents a request a message handler makes of a HandlerChain that
+/// passes messages of type M to handlers returning type R, with handlers
+/// extended by X (see ChainHandler).
+///
+/// A handler cannot change the HandlerChain while the HandlerChain is passing
+/// it a message, since the HandlerChain is busy going through its handlers
+/// (and Rust does not allow the HandlerChain to be borrowed again).  Instead,
+/// the handler posts one of these requests, which the HandlerChain carries
+/// out after the message has been passed to the handlers.
+pub enum PendingAction<M, R, X: ?Sized = ()> {
+    /// Send a message to only the handler with the given ID, such as a Close
+    /// message to a window.
+    SendMessage {
+        /// ID of the handler to send the message to.
+        handler_id: i32,
+        /// The message to send.
+        message: M,
+    },
+
+    /// Add the given handler to the HandlerChain, such as a new window.
+    AddHandler(ChainHandler<M, R, X>),
+
+    /// Remove the handler with the given ID from the HandlerChain, such as a
+    /// window that has closed.
+    RemoveHandler(i32),
+
+    /// Give the handler with the given ID the capture, so it is passed every
+    /// message until it releases the capture, such as a modal window.
+    SetCapture(i32),
+
+    /// Release the capture if the handler with the given ID has it.
+    ReleaseCapture(i32),
+}
+
+//=============================================================================
+
+/// Represents the queue of requests posted by message handlers, in the order
+/// they were posted.
+pub struct PendingActions<M, R, X: ?Sized = ()> {
+    /// The requests not yet carried out, oldest first.
+    actions: VecDeque<PendingAction<M, R, X>>,
+}
+
+impl<M, R, X: ?Sized> PendingActions<M, R, X> {
+    /// Constructor.
+    ///
+    /// # Returns
+    /// Returns a new instance of the PendingActions struct with no requests.
+    pub fn new() -> PendingActions<M, R, X> {
+        PendingActions {
+            actions: VecDeque::new(),
+        }
+    }
+
+    /// Post a request to be carried out after the current message has been
+    /// passed to the handlers.
+    ///
+    /// # Parameters
+    /// - action
+    ///
+    ///   A value from the PendingAction enumeration describing the request.
+    pub fn post(&mut self, action: PendingAction<M, R, X>) {
+        self.actions.push_back(action);
+    }
+
+    /// Take the oldest request off the queue.
+    ///
+    /// # Returns
+    /// Returns `Some(PendingAction)` containing the oldest request, or `None`
+    /// if there are no more requests.
+    pub fn take_next(&mut self) -> Option<PendingAction<M, R, X>> {
+        self.actions.pop_front()
+    }
+}
@@ -0,0 +1,48 @@
+//! Contains the report() function through which the message handlers
+//! describe what they do with each message.  The descriptions are normally
+//! printed but can be collected instead, as the interactive mode does to
+//! show them below the windows it draws.
+
+use std::cell::RefCell;
+
+//-----------------------------------------------------------------------------
+
+thread_local! {
+    /// The descriptions collected so far, or None if descriptions are being
+    /// printed instead.
+    static COLLECTED_REPORTS: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+}
+
+/// Print a description of what a message handler did, or add it to the
+/// collected descriptions if collect_reports() has been turned on.
+///
+/// # Parameters
+/// - text
+///
+///   The description.
+pub fn report(text: &str) {
+    COLLECTED_REPORTS.with(|reports| match reports.borrow_mut().as_mut() {
+        Some(reports) => reports.push(text.to_string()),
+        None => println!("{text}"),
+    });
+}
+
+/// Turn collecting the descriptions given to report() on or off.  Turning
+/// collecting off throws away any descriptions not yet taken.
+///
+/// # Parameters
+/// - collect
+///
+///   true to collect the descriptions; false to print them.
+pub fn collect_reports(collect: bool) {
+    COLLECTED_REPORTS.with(|reports| *reports.borrow_mut() = if collect { Some(vec![]) } else { None });
+}
+
+/// Take the descriptions collected since the last call.
+///
+/// # Returns
+/// Returns the descriptions in the order they were given, which is empty if
+/// none were given or collecting is off.
+pub fn take_reports() -> Vec<String> {
+    COLLECTED_REPORTS.with(|reports| reports.borrow_mut().as_mut().map(std::mem::take).unwrap_or_default())
+}
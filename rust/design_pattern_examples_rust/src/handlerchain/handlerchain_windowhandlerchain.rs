@@ -0,0 +1,375 @@
+//! Contains the WindowHandlerChain struct, the HandlerChain for windows,
+//! which knows which window is in front of which.
+
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::rc::Rc;
+
+use crate::flyweight::flyweight_display;
+use super::handlerchain_chainhandler::ChainHandler;
+use super::handlerchain_handlerchain::{HandlerChain, MessageReturnTypes, DEFAULT_PRIORITY};
+use super::handlerchain_ihandlerchain_trait::IHandlerChain;
+use super::handlerchain_imessagehandler_trait::IMessageHandler;
+use super::handlerchain_imessagewindow_trait::IMessageWindow;
+use super::handlerchain_memento::HandlerChainMemento;
+use super::handlerchain_message::{Message, MessageType};
+use super::handlerchain_pendingactions::PendingActions;
+
+//-----------------------------------------------------------------------------
+
+/// A handler in a WindowHandlerChain, which has an IMessageWindow extension
+/// if it is a window.
+pub type WindowChainHandler = ChainHandler<Message, MessageReturnTypes, dyn IMessageWindow>;
+
+/// The queue of requests the windows post to the WindowHandlerChain.
+pub type WindowPendingActions = PendingActions<Message, MessageReturnTypes, dyn IMessageWindow>;
+
+/// A snapshot of the windows in a WindowHandlerChain.
+pub type WindowHandlerChainMemento = HandlerChainMemento<Message, MessageReturnTypes, dyn IMessageWindow>;
+
+/// Wrap the given window so it can be added to a WindowHandlerChain, with
+/// the window as both the message handler and the IMessageWindow extension.
+///
+/// # Parameters
+/// - window
+///
+///   The window to wrap.  The WindowChainHandler takes ownership of the
+///   window.
+///
+/// # Returns
+/// Returns a WindowChainHandler containing the window.
+pub fn window_handler<T>(window: T) -> WindowChainHandler
+    where T: IMessageWindow + IMessageHandler<Message, MessageReturnTypes, dyn IMessageWindow> + 'static
+{
+    let window = Rc::new(RefCell::new(window));
+    ChainHandler::with_extension(window.clone(), window)
+}
+
+//-----------------------------------------------------------------------------
+
+/// Represents a HandlerChain of windows.  The windows can overlap, so each
+/// window also has a z-order.  A ButtonDown message goes to the topmost
+/// window covering the position of the message but not to the windows
+/// hidden behind it, and the topmost window is then brought to the front.
+/// New windows are placed in front of all other windows.
+///
+/// Handlers that are not windows, such as the MessageLogger, can be in the
+/// WindowHandlerChain as well; they are passed every message but are never
+/// the topmost window.
+pub struct WindowHandlerChain {
+    /// The HandlerChain passing the messages to the windows.
+    handler_chain: HandlerChain<Message, MessageReturnTypes, dyn IMessageWindow>,
+}
+
+impl WindowHandlerChain {
+    /// Constructor.
+    ///
+    /// # Returns
+    /// Returns a new instance of the WindowHandlerChain struct with no
+    /// windows.
+    pub fn new() -> WindowHandlerChain {
+        WindowHandlerChain {
+            handler_chain: HandlerChain::new(),
+        }
+    }
+
+    /// Add the given window to the end of the list of handlers with the
+    /// DEFAULT_PRIORITY.  The window is placed in front of all other windows
+    /// in the z-order.
+    ///
+    /// # Parameters
+    /// - window
+    ///
+    ///   The window to add.
+    pub fn add_window<T>(&mut self, window: T)
+        where T: IMessageWindow + IMessageHandler<Message, MessageReturnTypes, dyn IMessageWindow> + 'static
+    {
+        self.handler_chain.add_chain_handler(window_handler(window), DEFAULT_PRIORITY);
+        self._place_new_windows();
+    }
+
+    /// Add the given handler, which is not a window, to the list of handlers
+    /// with the given priority, after all handlers with the same or higher
+    /// priority.
+    ///
+    /// # Parameters
+    /// - handler
+    ///
+    ///   The handler to add.
+    /// - priority
+    ///
+    ///   Priority of the handler.  Handlers with a higher priority are passed
+    ///   messages before handlers with a lower priority.
+    pub fn add_handler_with_priority(&mut self, handler: impl IMessageHandler<Message, MessageReturnTypes, dyn IMessageWindow> + 'static, priority: i32) {
+        self.handler_chain.add_handler_with_priority(handler, priority);
+    }
+
+    /// Add the given window to the list of handlers just before another
+    /// handler, with the same priority as that handler.  The window is placed
+    /// in front of all other windows in the z-order.
+    ///
+    /// # Parameters
+    /// - handler_id
+    ///
+    ///   ID of the handler to add the window before.
+    /// - window
+    ///
+    ///   The window to add.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the window was added; otherwise, returns
+    /// `Err(String)` if there is no handler with the given ID.
+    pub fn insert_before<T>(&mut self, handler_id: i32, window: T) -> Result<(), String>
+        where T: IMessageWindow + IMessageHandler<Message, MessageReturnTypes, dyn IMessageWindow> + 'static
+    {
+        self.handler_chain.insert_before(handler_id, window_handler(window))?;
+        self._place_new_windows();
+        Ok(())
+    }
+
+    /// Add the given window to the list of handlers just after another
+    /// handler, with the same priority as that handler.  The window is placed
+    /// in front of all other windows in the z-order.
+    ///
+    /// # Parameters
+    /// - handler_id
+    ///
+    ///   ID of the handler to add the window after.
+    /// - window
+    ///
+    ///   The window to add.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the window was added; otherwise, returns
+    /// `Err(String)` if there is no handler with the given ID.
+    pub fn insert_after<T>(&mut self, handler_id: i32, window: T) -> Result<(), String>
+        where T: IMessageWindow + IMessageHandler<Message, MessageReturnTypes, dyn IMessageWindow> + 'static
+    {
+        self.handler_chain.insert_after(handler_id, window_handler(window))?;
+        self._place_new_windows();
+        Ok(())
+    }
+
+    /// Send a message to the windows, then carry out any requests the
+    /// windows posted.  A ButtonDown message goes only to the topmost window
+    /// under it (and to the windows and other handlers not under it), unless
+    /// a window has the capture; every other message is passed along until a
+    /// window handles it.
+    ///
+    /// # Parameters
+    /// - message
+    ///
+    ///   The message to send.
+    ///
+    /// # Returns
+    /// Returns `Some(MessageReturnTypes)` containing what the window that
+    /// handled the message returned, or `None` if no window handled the
+    /// message.
+    pub fn send_message(&mut self, message: &Message) -> Option<MessageReturnTypes> {
+        let is_topmost_message = matches!(message.message_type, MessageType::ButtonDown);
+        let result = if is_topmost_message && self.handler_chain.capture_handler_id().is_none() {
+            self._send_message_to_topmost(message)
+        } else {
+            self.handler_chain.send_message(message)
+        };
+        self._place_new_windows();
+        if let Some(handler_id) = self.handler_chain.capture_handler_id() {
+            // A window with the capture is always in front.
+            self.bring_to_front(handler_id);
+        }
+        result
+    }
+
+    /// Helper method to send a message, in order of priority, to every
+    /// handler except the windows covering the message position that are
+    /// hidden behind the topmost window there; those are deselected instead.
+    /// The topmost window is then brought to the front.
+    ///
+    /// # Parameters
+    /// - message
+    ///
+    ///   The message to send.
+    ///
+    /// # Returns
+    /// Returns `Some(MessageReturnTypes)` containing what the topmost window
+    /// returned if it handled the message; otherwise, returns `None`.
+    fn _send_message_to_topmost(&mut self, message: &Message) -> Option<MessageReturnTypes> {
+        let topmost_id = self._topmost_window_at(message);
+        let result = self.handler_chain.send_message_to_chosen(message, |handler| {
+            let window = match handler.extension() {
+                Some(window) => window,
+                None => return true,
+            };
+            let is_hidden = Some(handler.id()) != topmost_id && window.borrow().covers_position(message);
+            if is_hidden {
+                window.borrow_mut().deselect();
+            }
+            !is_hidden
+        }, topmost_id);
+        if let Some(handler_id) = topmost_id {
+            self.bring_to_front(handler_id);
+        }
+        result
+    }
+
+    /// Helper method to find the topmost window covering the position of the
+    /// given message.
+    ///
+    /// # Parameters
+    /// - message
+    ///
+    ///   The message whose position to look for.
+    ///
+    /// # Returns
+    /// Returns `Some(i32)` containing the ID of the topmost window covering
+    /// the position; otherwise, returns None if no window covers it.
+    fn _topmost_window_at(&self, message: &Message) -> Option<i32> {
+        self.handler_chain.handlers()
+            .filter_map(|handler| handler.extension().map(|window| (handler.id(), window.borrow())))
+            .filter(|(_, window)| window.covers_position(message))
+            .max_by_key(|(_, window)| window.z_order())
+            .map(|(handler_id, _)| handler_id)
+    }
+
+    /// Find the window at the given position, which is the window a click
+    /// there would select.
+    ///
+    /// # Parameters
+    /// - x
+    ///
+    ///   X position to look for.
+    /// - y
+    ///
+    ///   Y position to look for.
+    ///
+    /// # Returns
+    /// Returns `Some(i32)` containing the ID of the topmost window at the
+    /// position; otherwise, returns None if there is no window there.
+    pub fn window_at(&self, x: i32, y: i32) -> Option<i32> {
+        self._topmost_window_at(&Message::new(MessageType::ButtonDown, x, y))
+    }
+
+    /// Helper method to get the z-order of the window in front of all other
+    /// windows, or 0 if there are no windows.
+    fn _top_z_order(&self) -> i32 {
+        self.handler_chain.handlers()
+            .filter_map(|handler| handler.extension().map(|window| window.borrow().z_order()))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Helper method to place the windows that have not been placed in the
+    /// z-order yet, such as windows added by other windows, in front of all
+    /// other windows, in the order they are in the list of handlers.
+    fn _place_new_windows(&mut self) {
+        for handler in self.handler_chain.handlers() {
+            if let Some(window) = handler.extension() {
+                if window.borrow().z_order() == 0 {
+                    let top_z_order = self._top_z_order();
+                    window.borrow_mut().set_z_order(top_z_order + 1);
+                }
+            }
+        }
+    }
+
+    /// Move the given window in front of all other windows in the z-order.
+    ///
+    /// If the window is not in the list or is already in front, the request
+    /// is ignored.
+    ///
+    /// # Parameters
+    /// - handler_id
+    ///
+    ///   ID of the window to bring to the front.
+    pub fn bring_to_front(&mut self, handler_id: i32) {
+        let top_z_order = self._top_z_order();
+        let found_window = self.handler_chain.handlers()
+            .find(|handler| handler.id() == handler_id)
+            .and_then(|handler| handler.extension());
+        if let Some(window) = found_window {
+            let mut window = window.borrow_mut();
+            if window.z_order() != top_z_order {
+                window.set_z_order(top_z_order + 1);
+            }
+        }
+    }
+
+    /// Render the windows into the given display, back to front in the
+    /// z-order, so windows in front are drawn over the windows behind them.
+    ///
+    /// # Parameters
+    /// - display
+    ///
+    ///   The Display to render into, which should be cleared first.
+    pub fn render(&self, display: &mut flyweight_display::Display) {
+        let mut windows: Vec<_> = self.handler_chain.handlers()
+            .filter_map(|handler| handler.extension())
+            .map(|window| window.borrow())
+            .collect();
+        windows.sort_by_key(|window| window.z_order());
+        for window in windows {
+            window.render(display);
+        }
+    }
+
+    /// Returns a WindowHandlerChainMemento object containing a snapshot of
+    /// the windows, such as where each window is and whether it is selected.
+    /// Handlers that are not windows are left as they are when the snapshot
+    /// is restored.
+    ///
+    /// # Parameters
+    /// - name
+    ///
+    ///   The name of the memento to create.
+    ///
+    /// # Returns
+    /// Returns an instance of the WindowHandlerChainMemento, representing the
+    /// snapshot of this WindowHandlerChain.
+    pub fn save_state(&self, name: &str) -> WindowHandlerChainMemento {
+        self.handler_chain.save_state(name, _save_window)
+    }
+
+    /// Restore the windows to the snapshot stored in the given
+    /// WindowHandlerChainMemento object (which is assumed to be from the
+    /// WindowHandlerChain::save_state() method of this WindowHandlerChain).
+    ///
+    /// # Parameters
+    /// - memento
+    ///
+    ///   A WindowHandlerChainMemento object containing the windows to
+    ///   restore.
+    pub fn restore_state(&mut self, memento: &WindowHandlerChainMemento) {
+        self.handler_chain.restore_state(memento, _save_window);
+    }
+}
+
+/// Helper function to make a copy of a window for a snapshot of a
+/// WindowHandlerChain.
+///
+/// # Parameters
+/// - handler
+///
+///   The handler to copy.
+///
+/// # Returns
+/// Returns `Some(WindowChainHandler)` containing the copy; otherwise, returns
+/// None if the handler is not a window or the window does not save its state.
+fn _save_window(handler: &WindowChainHandler) -> Option<WindowChainHandler> {
+    handler.extension().and_then(|window| window.borrow().save_state())
+}
+
+impl IHandlerChain<Message> for WindowHandlerChain {
+    type Result = MessageReturnTypes;
+
+    fn send_message(&mut self, message: &Message) -> Option<MessageReturnTypes> {
+        WindowHandlerChain::send_message(self, message)
+    }
+}
+
+impl Display for WindowHandlerChain {
+    /// Convert this WindowHandlerChain to a string that is returned, one line
+    /// for each handler in order of priority.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.handler_chain.fmt(f)
+    }
+}
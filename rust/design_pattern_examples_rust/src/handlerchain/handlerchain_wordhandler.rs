@@ -0,0 +1,64 @@
+//! Contains the WordHandler struct, a message handler for a HandlerChain that
+//! passes words along instead of window messages.
+
+use super::handlerchain_handlerchain::get_next_handler_id;
+use super::handlerchain_imessagehandler_trait::IMessageHandler;
+use super::handlerchain_pendingactions::PendingActions;
+
+//-----------------------------------------------------------------------------
+
+/// Represents a handler that recognizes the words in one category, such as
+/// nouns.  Passed a word, the handler returns the category if it knows the
+/// word; otherwise, the word is passed on to the next handler.
+pub struct WordHandler {
+    /// Unique ID of this handler.
+    id: i32,
+
+    /// The category of words this handler recognizes.
+    category: String,
+
+    /// The words this handler recognizes, in lowercase.
+    words: Vec<String>,
+}
+
+impl WordHandler {
+    /// Constructor
+    ///
+    /// # Parameters
+    /// - category
+    ///
+    ///   The category of words the handler recognizes.
+    /// - words
+    ///
+    ///   The words the handler recognizes.  Case does not matter.
+    ///
+    /// # Returns
+    /// Returns a new instance of the WordHandler struct.
+    pub fn new(category: &str, words: &[&str]) -> WordHandler {
+        WordHandler {
+            id: get_next_handler_id(),
+            category: category.to_string(),
+            words: words.iter().map(|word| word.to_lowercase()).collect(),
+        }
+    }
+}
+
+
+impl IMessageHandler<String, Option<String>> for WordHandler {
+    fn id(&self) -> i32 {
+        self.id
+    }
+
+    fn process_message(&mut self, message: &String, _pending_actions: &mut PendingActions<String, Option<String>>) -> Option<String> {
+        let word = message.to_lowercase();
+        if self.words.contains(&word) {
+            Some(self.category.clone())
+        } else {
+            None
+        }
+    }
+
+    fn to_string(&self) -> String {
+        format!("[id={:2}] {}: {}", self.id, self.category, self.words.join(", "))
+    }
+}
@@ -1,7 +1,11 @@
 //! Contains various kinds of helper modules for the Rust adaptations of the
 //! Design Pattern Examples.
 
+pub mod ansi;
 pub mod key_input;
 pub mod cursor;
+pub mod exercise_options;
+pub mod json;
+pub mod profiler;
 pub mod random;
 pub mod titlecase;
@@ -0,0 +1,38 @@
+//! Contains supports_ansi() for finding out whether the console window
+//! understands ANSI escape sequences (colors, cursor movement, and so on),
+//! and use_colors() for finding out whether colors should be shown.
+
+
+/// Determine whether the console window understands ANSI escape sequences.
+///
+/// On Windows, this asks the crossterm crate, which also does some internal
+/// initialization that crossterm needs before it responds to ANSI escape
+/// sequences (so this should be called once early on).  All other platforms
+/// are assumed to support ANSI escape sequences.
+///
+/// # Returns
+/// Returns true if ANSI escape sequences are supported; otherwise, returns
+/// false.
+pub fn supports_ansi() -> bool {
+    #[cfg(windows)]
+    {
+        crossterm::ansi_support::supports_ansi()
+    }
+    #[cfg(not(windows))]
+    {
+        true
+    }
+}
+
+/// Determine whether the console output should use ANSI escape sequences for
+/// colors and text styles.  That is the case if the console supports them
+/// and the user has not turned colors off by setting the NO_COLOR
+/// environment variable to anything other than an empty string (see
+/// https://no-color.org).
+///
+/// # Returns
+/// Returns true if colors should be used; otherwise, returns false.
+pub fn use_colors() -> bool {
+    let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+    supports_ansi() && !no_color
+}
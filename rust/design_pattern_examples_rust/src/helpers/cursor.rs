@@ -1,9 +1,10 @@
-//! Contains hide_cursor(), show_cursor, get_cursor_position() and
-//! set_cursor_position() for dealing with the cursor and its position in a
-//! console window.
+//! Contains hide_cursor(), show_cursor() and set_cursor_position() for
+//! dealing with the cursor and its position in a console window,
+//! clear_screen() and console_size() for dealing with the window itself, and
+//! the AlternateScreen struct for drawing on the console's alternate screen.
 
 
-use std::io::{stdin, stdout, Read, Write};
+use std::io::{stdout, Write};
 use crossterm::{
     ExecutableCommand,
     cursor,
@@ -26,48 +27,30 @@ pub fn hide_cursor() {
 /// We could use an ANSI sequence for this (ESC[?25h), but the sequence is
 /// private and not necessarily supported everywhere.  The crossterm crate
 /// makes this simple and hides any system differences.
+///
+/// Does nothing if the cursor cannot be shown, so it is safe to call while
+/// cleaning up after an error or a panic.
 pub fn show_cursor() {
     let mut stdout = stdout();
-    stdout.execute(cursor::Show).unwrap();
+    let _ = stdout.execute(cursor::Show);
 }
 
-/// Retrieve the current cursor position in the console window.
+/// Retrieve the ANSI sequence that moves the text cursor to the specified
+/// screen coordinates in the console window, for building up output that
+/// is printed all at once.
+///
+/// # Parameters
+/// - position_x
 ///
-/// This uses an ANSI string sequence to query the cursor position as the
-/// crossterm crate does not provide the ability to get the cursor position.
+///   Column index from left, starting at 1, in characters.
+/// - position_y
+///
+///   Row index from top, starting at 1, in characters
 ///
 /// # Returns
-/// Returns a tuple (x, y) containing the x and y position of the cursor
-/// position in the console window, relative to the upper left corner of the
-/// window.  Position starts at 1,1.
-pub fn get_cursor_position() -> (u16, u16) {
-    let mut position_x = 0;
-    let mut position_y = 0;
-
-    terminal::enable_raw_mode().unwrap();
-    print!("\x1b[6n"); // Make sure we don't add a newline
-    let mut stdout = stdout();
-    stdout.flush().unwrap(); // No newline so we must flush to activate the sequence.
-    let mut stdin = stdin();
-    let mut buffer = vec!(0u8;16);
-
-    if let Ok(n) = stdin.read(&mut buffer) {
-        // Expecting ESC [ <r> ; <c> R (no spaces)
-        if n > 2 && buffer[0] == 0x1b && buffer[1] == b'[' {
-            if let Ok(s) = String::from_utf8(buffer) {
-                let items : Vec<&str> = s.split(&['[', ';', 'R']).collect();
-                if items.len() >= 3 {
-                    let row_as_string = items[1];
-                    let col_as_string = items[2];
-                    position_y = row_as_string.parse().unwrap();
-                    position_x = col_as_string.parse().unwrap();
-                }
-            }
-        }
-    }
-    terminal::disable_raw_mode().unwrap();
-
-    (position_x, position_y)
+/// Returns the ANSI sequence as a string.
+pub fn cursor_position_sequence(position_x: u16, position_y: u16) -> String {
+    format!("\x1b[{position_y};{position_x}H")
 }
 
 /// Move the text cursor to the specified screen coordinates in the console
@@ -81,7 +64,67 @@ pub fn get_cursor_position() -> (u16, u16) {
 ///
 ///   Row index from top, starting at 1, in characters
 pub fn set_cursor_position(position_x: u16, position_y: u16) {
-    print!("\x1b[{position_y};{position_x}H");
+    print!("{}", cursor_position_sequence(position_x, position_y));
     let mut stdout = stdout();
     stdout.flush().unwrap();
 }
+
+/// Clear the console window (or the alternate screen, if shown).
+///
+/// Does nothing if the console cannot be cleared.
+pub fn clear_screen() {
+    let mut stdout = stdout();
+    let _ = stdout.execute(terminal::Clear(terminal::ClearType::All));
+}
+
+/// Retrieve the size of the console window.
+///
+/// # Returns
+/// Returns `Some((u16, u16))` containing the width and height of the console
+/// window, in characters; otherwise, returns None if the size is not known
+/// (for example, because the output is not going to a console).
+pub fn console_size() -> Option<(u16, u16)> {
+    match terminal::size() {
+        Ok((width, height)) if width > 0 && height > 0 => Some((width, height)),
+        _ => None,
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Represents the console's alternate screen, a separate screen for drawing
+/// full-screen output that leaves the normal screen untouched.  While an
+/// instance exists, the console shows the alternate screen with the cursor
+/// hidden.  Dropping the instance, including when unwinding from an error or
+/// a panic, shows the cursor and returns to the normal screen.
+pub struct AlternateScreen {
+    /// Keeps the struct from being created other than through enter().
+    _private: (),
+}
+
+impl AlternateScreen {
+    /// Switch the console to the alternate screen, cleared, with the cursor
+    /// hidden.
+    ///
+    /// # Returns
+    /// Returns `Ok(AlternateScreen)` that returns to the normal screen when
+    /// dropped; otherwise, returns `Err(String)` if the console could not be
+    /// switched.
+    pub fn enter() -> Result<AlternateScreen, String> {
+        let mut stdout = stdout();
+        stdout.execute(terminal::EnterAlternateScreen)
+            .and_then(|stdout| stdout.execute(terminal::Clear(terminal::ClearType::All)))
+            .map_err(|error| format!("Could not switch to the alternate screen: {error}"))?;
+        hide_cursor();
+        Ok(AlternateScreen { _private: () })
+    }
+}
+
+impl Drop for AlternateScreen {
+    /// Show the cursor and return to the normal screen.
+    fn drop(&mut self) {
+        show_cursor();
+        // Nothing more can be done if the console cannot be restored.
+        let _ = stdout().execute(terminal::LeaveAlternateScreen);
+    }
+}
@@ -0,0 +1,62 @@
+//! Contains set_exercise_options(), has_exercise_option() and
+//! get_exercise_option_value() for making exercise-specific command line
+//! options available to the exercises.
+//!
+//! The exercise functions do not take any parameters so the options parsed in
+//! main() are stored here once and each exercise looks up the options it
+//! cares about.
+
+//-----------------------------------------------------------------------------
+
+use std::sync::OnceLock;
+
+//-----------------------------------------------------------------------------
+
+/// The exercise options given on the command line.  Each option is the name
+/// of the option (including the leading "--") and an optional value.
+static EXERCISE_OPTIONS: OnceLock<Vec<(String, Option<String>)>> = OnceLock::new();
+
+
+/// Store the exercise options parsed from the command line.  Only the first
+/// call has any effect.
+///
+/// # Parameters
+/// - options
+///
+///   A list of (name, value) pairs, where the name includes the leading "--"
+///   and the value is None if the option was not followed by a value.
+pub fn set_exercise_options(options: Vec<(String, Option<String>)>) {
+    let _ = EXERCISE_OPTIONS.set(options);
+}
+
+/// Determine if the given exercise option was specified on the command line.
+///
+/// # Parameters
+/// - name
+///
+///   Name of the option, including the leading "--".
+///
+/// # Returns
+/// Returns true if the option was specified; otherwise, returns false.
+pub fn has_exercise_option(name: &str) -> bool {
+    match EXERCISE_OPTIONS.get() {
+        Some(options) => options.iter().any(|(option_name, _)| option_name == name),
+        None => false,
+    }
+}
+
+/// Retrieve the value given with an exercise option on the command line.
+///
+/// # Parameters
+/// - name
+///
+///   Name of the option, including the leading "--".
+///
+/// # Returns
+/// Returns the value of the first occurrence of the option, or None if the
+/// option was not specified or was specified without a value.
+pub fn get_exercise_option_value(name: &str) -> Option<String> {
+    EXERCISE_OPTIONS.get()?.iter()
+        .find(|(option_name, _)| option_name == name)
+        .and_then(|(_, value)| value.clone())
+}
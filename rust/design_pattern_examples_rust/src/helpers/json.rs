@@ -0,0 +1,220 @@
+//! Contains the json_string() function and the JsonReader struct, which
+//! write and read the small subset of JSON used by the examples that save
+//! and load their data: arrays, objects, strings, integers and booleans.
+
+use std::iter::Peekable;
+use std::str::Chars;
+
+//-----------------------------------------------------------------------------
+
+/// Convert text to a JSON string, including the quotes.
+///
+/// # Parameters
+/// - text
+///
+///   The text to convert.
+///
+/// # Returns
+///   A string containing the text as a JSON string.
+pub fn json_string(text: &str) -> String {
+    let mut output = String::from("\"");
+    for character in text.chars() {
+        match character {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            c if (c as u32) < 0x20 => output.push_str(&format!("\\u{:04x}", c as u32)),
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+    output
+}
+
+//-----------------------------------------------------------------------------
+
+/// Reads the small subset of JSON used by the examples: arrays, objects,
+/// strings, integers and booleans.
+pub struct JsonReader<'a> {
+    /// The characters still to be read.
+    chars: Peekable<Chars<'a>>,
+}
+
+impl<'a> JsonReader<'a> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - json
+    ///
+    ///   The JSON text to read.
+    pub fn new(json: &'a str) -> JsonReader<'a> {
+        JsonReader { chars: json.chars().peekable() }
+    }
+
+    /// Skip any white space before the next character.
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|c| c.is_whitespace()).is_some() {}
+    }
+
+    /// Read the given character, after any white space.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the next character was the expected one;
+    /// otherwise, returns `Err(String)` describing what was found instead.
+    pub fn expect(&mut self, expected: char) -> Result<(), String> {
+        self.skip_whitespace();
+        match self.chars.next() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => Err(format!("Expected '{expected}' but found '{c}'")),
+            None => Err(format!("Expected '{expected}' but found the end of the text")),
+        }
+    }
+
+    /// Read the given character if it is next, after any white space.
+    ///
+    /// # Returns
+    /// Returns true if the character was read; otherwise, returns false.
+    pub fn accept(&mut self, wanted: char) -> bool {
+        self.skip_whitespace();
+        self.chars.next_if_eq(&wanted).is_some()
+    }
+
+    /// Make sure nothing but white space is left in the text.
+    ///
+    /// # Parameters
+    /// - what
+    ///
+    ///   Description of what was read, for the error message.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the end of the text was reached; otherwise,
+    /// returns `Err(String)` describing what was found instead.
+    pub fn expect_end(&mut self, what: &str) -> Result<(), String> {
+        self.skip_whitespace();
+        match self.chars.next() {
+            Some(c) => Err(format!("Unexpected '{c}' after {what}")),
+            None => Ok(()),
+        }
+    }
+
+    /// Read the elements of an array, which starts at the next character,
+    /// using the given function to read each element.
+    ///
+    /// # Returns
+    /// Returns `Ok(Vec<T>)` containing the elements; otherwise, returns
+    /// `Err(String)` describing what is wrong with the JSON.
+    pub fn read_array<T>(&mut self, read_element: fn(&mut Self) -> Result<T, String>) -> Result<Vec<T>, String> {
+        let mut elements = vec![];
+        self.expect('[')?;
+        if self.accept(']') {
+            return Ok(elements);
+        }
+        loop {
+            elements.push(read_element(self)?);
+            if self.accept(']') {
+                return Ok(elements);
+            }
+            self.expect(',')?;
+        }
+    }
+
+    /// Read the fields of an object, which starts at the next character,
+    /// calling the given function with the key of each field so it can read
+    /// the value.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the object was read; otherwise, returns
+    /// `Err(String)` describing what is wrong with the JSON, including any
+    /// error returned by the given function.
+    pub fn read_object(&mut self, mut read_field: impl FnMut(&mut Self, &str) -> Result<(), String>) -> Result<(), String> {
+        self.expect('{')?;
+        if self.accept('}') {
+            return Ok(());
+        }
+        loop {
+            let key = self.read_string()?;
+            self.expect(':')?;
+            read_field(self, &key)?;
+            if self.accept('}') {
+                return Ok(());
+            }
+            self.expect(',')?;
+        }
+    }
+
+    /// Read a string, which starts at the next character.
+    ///
+    /// # Returns
+    /// Returns `Ok(String)` containing the text of the string without the
+    /// quotes; otherwise, returns `Err(String)` describing what is wrong with
+    /// the JSON.
+    pub fn read_string(&mut self) -> Result<String, String> {
+        self.expect('"')?;
+        let mut text = String::new();
+        loop {
+            match self.chars.next() {
+                Some('"') => return Ok(text),
+                Some('\\') => {
+                    let escaped = match self.chars.next() {
+                        Some('"') => '"',
+                        Some('\\') => '\\',
+                        Some('/') => '/',
+                        Some('b') => '\u{8}',
+                        Some('f') => '\u{c}',
+                        Some('n') => '\n',
+                        Some('r') => '\r',
+                        Some('t') => '\t',
+                        Some('u') => {
+                            let digits: String = self.chars.by_ref().take(4).collect();
+                            u32::from_str_radix(&digits, 16).ok()
+                                .and_then(char::from_u32)
+                                .ok_or(format!("Bad escape \"\\u{digits}\" in a string"))?
+                        }
+                        Some(c) => return Err(format!("Bad escape \"\\{c}\" in a string")),
+                        None => return Err("The text ends inside a string".to_string()),
+                    };
+                    text.push(escaped);
+                }
+                Some(c) => text.push(c),
+                None => return Err("The text ends inside a string".to_string()),
+            }
+        }
+    }
+
+    /// Read a boolean, which starts at the next character.
+    ///
+    /// # Returns
+    /// Returns `Ok(bool)` containing the value of the boolean; otherwise,
+    /// returns `Err(String)` describing what is wrong with the JSON.
+    pub fn read_bool(&mut self) -> Result<bool, String> {
+        self.skip_whitespace();
+        let mut word = String::new();
+        while let Some(letter) = self.chars.next_if(|c| c.is_ascii_alphabetic()) {
+            word.push(letter);
+        }
+        match word.as_str() {
+            "true" => Ok(true),
+            "false" => Ok(false),
+            _ => Err(format!("Expected true or false but found \"{word}\"")),
+        }
+    }
+
+    /// Read an integer, which starts at the next character.
+    ///
+    /// # Returns
+    /// Returns `Ok(i64)` containing the value of the integer; otherwise,
+    /// returns `Err(String)` describing what is wrong with the JSON.
+    pub fn read_integer(&mut self) -> Result<i64, String> {
+        self.skip_whitespace();
+        let mut digits = String::new();
+        if let Some(sign) = self.chars.next_if_eq(&'-') {
+            digits.push(sign);
+        }
+        while let Some(digit) = self.chars.next_if(|c| c.is_ascii_digit()) {
+            digits.push(digit);
+        }
+        digits.parse::<i64>().map_err(|_| format!("Expected an integer but found \"{digits}\""))
+    }
+}
@@ -1,27 +1,172 @@
-//! Contains the check_for_key() function that polls for keyboard input and
-//! returns true if a key was pressed.
+//! Contains the get_event() and wait_for_event() functions that poll for
+//! keyboard input, mouse input and console window changes and report which
+//! key was pressed, what the mouse did or the new size of the window, the
+//! RawMode struct that makes keys available as soon as they are pressed, and
+//! the MouseCapture struct that makes mouse input available at all.
 
 //-----------------------------------------------------------------------------
 
+use std::io::stdout;
 use std::time::Duration;
 
-use crossterm::event::{poll, read, Event};
+use crossterm::event::{poll, read, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, MouseEventKind};
+use crossterm::{terminal, ExecutableCommand};
 
 //-----------------------------------------------------------------------------
 
+/// Represents something that happened in the console: a key was pressed,
+/// the mouse was used (only while the mouse is captured) or the console
+/// window changed size.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConsoleEvent {
+    /// A key was pressed.
+    Key(KeyCode),
+    /// A mouse button was pressed, released or dragged, or the mouse moved,
+    /// at the given column and row of the console window, starting at 0.
+    Mouse {
+        kind: MouseEventKind,
+        column: u16,
+        row: u16,
+    },
+    /// The console window changed to the given size, in characters.
+    Resize {
+        width: u16,
+        height: u16,
+    },
+}
+
+//-----------------------------------------------------------------------------
+
+
+/// Determine which key, if any, has been pressed on the keyboard, or whether
+/// the console window changed size, without waiting.
+///
+/// # Returns
+/// Returns `Some(ConsoleEvent)` for the key that was pressed or the new size
+/// of the console window; otherwise, returns None if nothing happened.
+pub fn get_event() -> Option<ConsoleEvent> {
+    _read_event(Duration::from_millis(0))
+}
 
-/// Determine if a key has been pressed on the keyboard.
+/// Wait for a key to be pressed on the keyboard or for the console window to
+/// change size.
 ///
 /// # Returns
-/// Returns true if a key was pressed; otherwise, returns false.
-pub fn check_for_key() -> bool {
-    let mut key_pressed = false;
-    if let Ok(event_ready) = poll(Duration::from_millis(0)) {
+/// Returns `Some(ConsoleEvent)` for the key that was pressed or the new size
+/// of the console window; otherwise, returns None if the console cannot be
+/// read.
+pub fn wait_for_event() -> Option<ConsoleEvent> {
+    loop {
+        match poll(Duration::from_millis(100)) {
+            Ok(true) => {
+                if let Some(event) = _read_event(Duration::from_millis(0)) {
+                    return Some(event);
+                }
+            }
+            Ok(false) => {}
+            Err(_) => return None,
+        }
+    }
+}
+
+/// Helper function to read the next key pressed, mouse event or change in
+/// the size of the console window, waiting no longer than the given timeout.
+/// Key releases (reported on some systems) and other events are ignored.
+///
+/// # Parameters
+/// - timeout
+///
+///   How long to wait for an event.
+///
+/// # Returns
+/// Returns `Some(ConsoleEvent)` for the key that was pressed, the mouse event
+/// or the new size of the console window; otherwise, returns None if nothing
+/// happened in time.
+fn _read_event(timeout: Duration) -> Option<ConsoleEvent> {
+    let mut console_event = None;
+    if let Ok(event_ready) = poll(timeout) {
         if event_ready {
-            if let Ok(Event::Key(_k)) = read() {
-                key_pressed = true;
+            match read() {
+                Ok(Event::Key(key_event)) if key_event.kind != KeyEventKind::Release => {
+                    console_event = Some(ConsoleEvent::Key(key_event.code));
+                }
+                Ok(Event::Mouse(mouse_event)) => {
+                    console_event = Some(ConsoleEvent::Mouse {
+                        kind: mouse_event.kind,
+                        column: mouse_event.column,
+                        row: mouse_event.row,
+                    });
+                }
+                Ok(Event::Resize(width, height)) => {
+                    console_event = Some(ConsoleEvent::Resize { width, height });
+                }
+                _ => {}
             }
         }
     }
-    key_pressed
+    console_event
+}
+
+//-----------------------------------------------------------------------------
+
+/// Represents the console being in "raw" mode, where each key is reported
+/// as soon as it is pressed instead of after Enter is pressed.  The console
+/// returns to its normal mode when this struct is dropped.
+///
+/// In raw mode, a newline only moves the cursor down, not back to the left
+/// edge, so output should be positioned explicitly.  Ctrl+C is also reported
+/// as a key instead of stopping the program.
+pub struct RawMode {
+    /// Keeps the struct from being created other than through enable().
+    _private: (),
+}
+
+impl RawMode {
+    /// Switch the console to raw mode.
+    ///
+    /// # Returns
+    /// Returns `Ok(RawMode)` that returns the console to its normal mode when
+    /// dropped; otherwise, returns `Err(String)` if the console could not be
+    /// switched (for example, because there is no console).
+    pub fn enable() -> Result<RawMode, String> {
+        terminal::enable_raw_mode()
+            .map_err(|error| format!("Could not switch the console to raw mode: {error}"))?;
+        Ok(RawMode { _private: () })
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Represents the console capturing the mouse, so mouse clicks, drags and
+/// movements are reported as ConsoleEvent::Mouse events instead of being
+/// used by the console itself (such as for selecting text).  The console
+/// stops capturing the mouse when this struct is dropped.
+pub struct MouseCapture {
+    /// Keeps the struct from being created other than through enable().
+    _private: (),
+}
+
+impl MouseCapture {
+    /// Start capturing the mouse.
+    ///
+    /// # Returns
+    /// Returns `Ok(MouseCapture)` that stops capturing the mouse when dropped;
+    /// otherwise, returns `Err(String)` if the mouse could not be captured.
+    pub fn enable() -> Result<MouseCapture, String> {
+        stdout().execute(EnableMouseCapture)
+            .map_err(|error| format!("Could not capture the mouse: {error}"))?;
+        Ok(MouseCapture { _private: () })
+    }
+}
+
+impl Drop for MouseCapture {
+    fn drop(&mut self) {
+        let _ = stdout().execute(DisableMouseCapture);
+    }
 }
@@ -0,0 +1,238 @@
+//! Contains a lightweight span profiler.  A span is a named, timed section
+//! of code; spans started while another span is open are nested inside it.
+//! The time spent in each span is gathered across all exercises and shown as
+//! a hierarchy by print_profile_report().
+//!
+//! Profiling is off until enable_profiling() is called; until then,
+//! begin_span() and end_span() do nothing.  Each thread has its own nesting
+//! of spans and is reported separately.
+
+//-----------------------------------------------------------------------------
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+//-----------------------------------------------------------------------------
+
+/// Width of the bar drawn for a span that takes all of the time of its
+/// thread.
+const BAR_WIDTH: usize = 30;
+
+/// true if spans are being timed.
+static PROFILING_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// The times gathered for all spans that have ended, from all threads.
+static PROFILE_DATA: Mutex<ProfileData> = Mutex::new(ProfileData { records: vec![], index: None });
+
+thread_local! {
+    /// The spans open on this thread, innermost last, with the time each
+    /// span began.
+    static OPEN_SPANS: RefCell<Vec<(String, Instant)>> = const { RefCell::new(vec![]) };
+}
+
+//-----------------------------------------------------------------------------
+
+/// Represents the time gathered for one span at one place in the hierarchy.
+struct SpanRecord {
+    /// Name of the thread the span ran on.
+    thread_name: String,
+    /// Names of the span and all spans it is nested in, outermost first.
+    path: Vec<String>,
+    /// Number of times the span ended.
+    count: usize,
+    /// Total time spent in the span.
+    total_time: Duration,
+}
+
+/// Represents all the times gathered so far.
+struct ProfileData {
+    /// The records, in the order each was first seen.
+    records: Vec<SpanRecord>,
+    /// Maps (thread name, path) to the index of the record in records.
+    /// Created when the first record is added.
+    index: Option<HashMap<(String, Vec<String>), usize>>,
+}
+
+impl ProfileData {
+    /// Add the time for one span to the record for that span, creating the
+    /// record if necessary.
+    fn add_time(&mut self, thread_name: &str, path: Vec<String>, elapsed: Duration) {
+        let key = (thread_name.to_string(), path);
+        let index = self.index.get_or_insert_with(HashMap::new);
+        let record_index = match index.get(&key) {
+            Some(record_index) => *record_index,
+            None => {
+                let record_index = self.records.len();
+                index.insert(key.clone(), record_index);
+                self.records.push(SpanRecord {
+                    thread_name: key.0,
+                    path: key.1,
+                    count: 0,
+                    total_time: Duration::ZERO,
+                });
+                record_index
+            }
+        };
+        let record = &mut self.records[record_index];
+        record.count += 1;
+        record.total_time += elapsed;
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Retrieve the name of the current thread for reporting.
+fn _current_thread_name() -> String {
+    let current = thread::current();
+    match current.name() {
+        Some(name) => name.to_string(),
+        None => format!("{:?}", current.id()),
+    }
+}
+
+/// Turn on the timing of spans.
+pub fn enable_profiling() {
+    PROFILING_ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Determine if spans are being timed.
+///
+/// # Returns
+/// Returns true if enable_profiling() has been called.
+pub fn is_profiling_enabled() -> bool {
+    PROFILING_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Begin a span on the current thread, nested in whatever span is already
+/// open on that thread.  Every call must be matched by a call to end_span().
+///
+/// # Parameters
+/// - name
+///
+///   Name of the span.
+pub fn begin_span(name: &str) {
+    if !is_profiling_enabled() {
+        return;
+    }
+    OPEN_SPANS.with(|spans| spans.borrow_mut().push((name.to_string(), Instant::now())));
+}
+
+/// End the innermost span open on the current thread, adding the time spent
+/// in it to the profile.  Does nothing if no span is open.
+pub fn end_span() {
+    if !is_profiling_enabled() {
+        return;
+    }
+    let ended = OPEN_SPANS.with(|spans| {
+        let mut spans = spans.borrow_mut();
+        let (_, start_time) = spans.last()?;
+        let elapsed = start_time.elapsed();
+        let path: Vec<String> = spans.iter().map(|(name, _)| name.clone()).collect();
+        spans.pop();
+        Some((path, elapsed))
+    });
+    if let Some((path, elapsed)) = ended {
+        if let Ok(mut data) = PROFILE_DATA.lock() {
+            data.add_time(&_current_thread_name(), path, elapsed);
+        }
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Represents a span that ends when it goes out of scope.  Use this instead
+/// of calling end_span() directly when the code may return early.
+pub struct SpanGuard {}
+
+impl Drop for SpanGuard {
+    fn drop(&mut self) {
+        end_span();
+    }
+}
+
+/// Begin a span that ends when the returned SpanGuard goes out of scope.
+///
+/// # Parameters
+/// - name
+///
+///   Name of the span.
+///
+/// # Returns
+/// Returns a SpanGuard that ends the span when dropped.
+pub fn profile_span(name: &str) -> SpanGuard {
+    begin_span(name);
+    SpanGuard {}
+}
+
+//-----------------------------------------------------------------------------
+
+/// Helper function to print one record and, recursively, the records nested
+/// inside it.
+///
+/// # Parameters
+/// - records
+///
+///   All the records for one thread.
+/// - record
+///
+///   The record to print.
+/// - thread_total
+///
+///   Total time of all outermost spans on the thread, used to size the bar.
+fn _print_record(records: &[&SpanRecord], record: &SpanRecord, thread_total: Duration) {
+    let depth = record.path.len();
+    let name = record.path.last().map(|name| name.as_str()).unwrap_or("");
+    let share = match thread_total.is_zero() {
+        true => 0.0,
+        false => record.total_time.as_secs_f64() / thread_total.as_secs_f64(),
+    };
+    let bar = "#".repeat(((share * BAR_WIDTH as f64).round() as usize).max(1));
+    let label = format!("{}{}", "  ".repeat(depth), name);
+    println!("  {label:<36} {:>10.3} ms {:>7}x  {:>5.1}%  {bar}",
+        record.total_time.as_secs_f64() * 1000.0, record.count, share * 100.0);
+
+    let children = records.iter()
+        .filter(|child| child.path.len() == depth + 1 && child.path.starts_with(&record.path));
+    for child in children {
+        _print_record(records, child, thread_total);
+    }
+}
+
+/// Print the time spent in every span, as a hierarchy for each thread.  Each
+/// line shows the total time spent in a span, the number of times it ran,
+/// and its share of the time of the thread as a percentage and a bar.
+pub fn print_profile_report() {
+    let data = match PROFILE_DATA.lock() {
+        Ok(data) => data,
+        Err(_) => return,
+    };
+    println!();
+    println!("Profile");
+    if data.records.is_empty() {
+        println!("  No spans were recorded.");
+        return;
+    }
+    let mut thread_names: Vec<&String> = vec![];
+    for record in data.records.iter() {
+        if !thread_names.contains(&&record.thread_name) {
+            thread_names.push(&record.thread_name);
+        }
+    }
+    for thread_name in thread_names {
+        let records: Vec<&SpanRecord> = data.records.iter()
+            .filter(|record| record.thread_name == *thread_name)
+            .collect();
+        let thread_total: Duration = records.iter()
+            .filter(|record| record.path.len() == 1)
+            .map(|record| record.total_time)
+            .sum();
+        println!("  Thread {thread_name}:");
+        for record in records.iter().filter(|record| record.path.len() == 1) {
+            _print_record(&records, record, thread_total);
+        }
+    }
+}
@@ -1,10 +1,34 @@
 //! Contains random(), a function that hides how a random number is generated
-//! from a range of u32 values.
+//! from a range of u32 values, and set_seed(), which makes the numbers
+//! repeatable.
 
+use std::cell::RefCell;
 use std::ops::Range;
-use rand::Rng;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+
+thread_local! {
+    /// The generator used on this thread after set_seed() is called; until
+    /// then, each number comes from the system's random number generator.
+    static SEEDED_RNG: RefCell<Option<StdRng>> = RefCell::new(None);
+}
 
 /// Generate a random unsigned integer value from the given range.
 pub fn random(range: Range<u32>) -> u32 {
-    rand::thread_rng().gen_range(range)
+    SEEDED_RNG.with(|seeded_rng| match seeded_rng.borrow_mut().as_mut() {
+        Some(rng) => rng.gen_range(range),
+        None => rand::thread_rng().gen_range(range),
+    })
+}
+
+/// Make the numbers from random() on this thread repeatable: after calling
+/// this with a given seed, random() gives the same sequence of numbers every
+/// time (for the same ranges).
+///
+/// # Parameters
+/// - seed
+///
+///   The seed from which to generate the numbers.
+pub fn set_seed(seed: u64) {
+    SEEDED_RNG.with(|seeded_rng| *seeded_rng.borrow_mut() = Some(StdRng::seed_from_u64(seed)));
 }
@@ -10,6 +10,12 @@
 //! where tokens can appear in any order (it's up to the creator of the token
 //! list to make sure the outcome makes any sense).
 //!
+//! The interpreter also goes the other way, converting a sentence back into
+//! tokens, so a sentence can make a round trip.
+//!
+//! The words the interpreter knows are the 40 most common words in English,
+//! unless a file of other words is given with the `--vocabulary` option.
+//!
 //! Accessed through the interpreter_exercise() function.
 
 //-----------------------------------------------------------------------------
@@ -18,7 +24,8 @@ pub mod interpreter_interpreter;
 
 //-----------------------------------------------------------------------------
 
-use interpreter_interpreter::{InterpreterConstants, interpreter_interpret};
+use crate::helpers::exercise_options::get_exercise_option_value;
+use interpreter_interpreter::{InterpreterConstants, Interpreter};
 
 //-----------------------------------------------------------------------------
 
@@ -40,6 +47,16 @@ const SENTENCE_TOKENS4: &'static [usize] = &[ 26, 27,  7, 21, 36, 17,  27,  10,
 /// Represents the sentence: "By her will we will do it."
 const SENTENCE_TOKENS5: &'static [usize] = &[ 23, 28, 32, 26, 32, 18,  10, InterpreterConstants::PERIOD as usize, InterpreterConstants::EOL as usize ];
 
+/// A sentence with words that are not among the 40 most common words, along
+/// with a comma, to show how the tokenizer handles them.
+const UNKNOWN_WORDS_SENTENCE: &str = "Would you say hello to that, my friend?";
+
+/// A small vocabulary for an interpreter of its own.
+const SMALL_VOCABULARY: &[&str] = &["the", "cat", "sat", "on", "mat", "dog"];
+
+/// A sentence using only the words in SMALL_VOCABULARY.
+const SMALL_VOCABULARY_SENTENCE: &str = "The cat sat on the mat.";
+
 /// A list of pre-defined token lists.  Each token list represents a single
 /// sentence constructed from the 40 most common words in the English language.
 /// I don't use all 40 words, though; that would be silly.
@@ -82,6 +99,19 @@ fn _tokens_to_string(tokens: &[usize]) -> String {
     return buffer;
 }
 
+/// Helper function to find how many words a vocabulary needs for every word
+/// token in the pre-defined token lists to name a word.
+///
+/// # Returns
+/// Returns one more than the largest word token in the token lists.
+fn _required_vocabulary_size() -> usize {
+    _SENTENCE_TOKEN_LISTS.iter()
+        .flat_map(|token_list| token_list.iter())
+        .filter(|&&token| token < InterpreterConstants::PERIOD as usize)
+        .max()
+        .map_or(0, |token| token + 1)
+}
+
 //-----------------------------------------------------------------------------
 
 /// Example of using the "Interpreter" design pattern.
@@ -97,24 +127,65 @@ fn _tokens_to_string(tokens: &[usize]) -> String {
 /// list to make sure the outcome makes any sense).
 /// 
 /// The output shows the token list followed by the sentence produced
-/// from the tokens.
+/// from the tokens.  Each sentence is then converted back into tokens with
+/// the interpreter to show the tokens make a round trip.
+///
+/// If the `--vocabulary` exercise option is given, the interpreter knows the
+/// words in that file instead, so the same tokens make other sentences.  The
+/// file must have enough words for every token in the token lists.
 // ! [Using Interpreter in Rust]
 pub fn interpreter_exercise() -> Result<(), String> {
     println!("");
     println!("Interpreter Exercise");
 
+    let interpreter = match get_exercise_option_value("--vocabulary") {
+        Some(path) => {
+            let interpreter = Interpreter::from_file(&path)?;
+            let required_size = _required_vocabulary_size();
+            if interpreter.vocabulary_size() < required_size {
+                return Err(format!("The vocabulary file \"{}\" has {} words, but the example sentences use tokens up to {} so it needs at least {} words",
+                    path, interpreter.vocabulary_size(), required_size - 1, required_size));
+            }
+            println!("  Using the {} words in the vocabulary file \"{}\"", interpreter.vocabulary_size(), path);
+            interpreter
+        }
+        None => Interpreter::new(),
+    };
+
     for sentence_index in 0.._SENTENCE_TOKEN_LISTS.len() {
         let token_list = _SENTENCE_TOKEN_LISTS[sentence_index];
 
         let tokens_as_string = _tokens_to_string(token_list);
 
-        let sentence = interpreter_interpret(token_list);
+        let sentence = interpreter.interpret(token_list);
 
         // 50 is a magic number corresponding to the longest token list
         // expressed as a string.  Derived empirically.  It makes the
         // output easier to, er, interpret.
         println!("  {:-50} ==> \"{}\"", tokens_as_string, sentence);
     }
+    println!();
+
+    println!("  Round trip, tokens -> sentence -> tokens:");
+    for token_list in _SENTENCE_TOKEN_LISTS.iter() {
+        let sentence = interpreter.interpret(token_list);
+        let round_trip_tokens = interpreter.tokenize(&sentence);
+        let outcome = if round_trip_tokens.as_slice() == *token_list { "same tokens" } else { "DIFFERENT tokens" };
+        println!("  {:-36} ==> {} ({})", format!("\"{}\"", sentence), _tokens_to_string(&round_trip_tokens), outcome);
+    }
+    println!();
+
+    println!("  A sentence with unknown words:");
+    let tokens = interpreter.tokenize(UNKNOWN_WORDS_SENTENCE);
+    println!("  \"{}\" ==> {}", UNKNOWN_WORDS_SENTENCE, _tokens_to_string(&tokens));
+    println!("  {} ==> \"{}\"", _tokens_to_string(&tokens), interpreter.interpret(&tokens));
+    println!();
+
+    println!("  An interpreter with a vocabulary of its own: {}", SMALL_VOCABULARY.join(", "));
+    let small_interpreter = Interpreter::from_words(SMALL_VOCABULARY)?;
+    let tokens = small_interpreter.tokenize(SMALL_VOCABULARY_SENTENCE);
+    println!("  \"{}\" ==> {}", SMALL_VOCABULARY_SENTENCE, _tokens_to_string(&tokens));
+    println!("  {} ==> \"{}\"", _tokens_to_string(&tokens), small_interpreter.interpret(&tokens));
 
     println!("  Done.");
 
@@ -1,5 +1,7 @@
-//! Contains the interpreter function along with the InterpreterConstants
-//! enumeration, as used in the Interpreter design example.
+//! Contains the Interpreter struct, which interprets tokens as words from its
+//! vocabulary and tokenizes sentences back into tokens, along with the
+//! InterpreterConstants enumeration, as used in the Interpreter design
+//! example.
 
 //-----------------------------------------------------------------------------
 
@@ -14,6 +16,8 @@ pub enum InterpreterConstants {
     PERIOD = 100,
     ///< Question mark
     QUESTION = 101,
+    ///< A word not in the list of common words.
+    UNKNOWN = 102,
     ///< Marker for end of a token list.
     EOL = -1
 }
@@ -21,7 +25,8 @@ pub enum InterpreterConstants {
 //-----------------------------------------------------------------------------
 
 /// The 40 most common words in English (in order but that doesn't really
-/// matter here).  A token is nothing more than an index into this list.
+/// matter here).  This is the vocabulary of the Interpreter unless it is
+/// given another.
 static _COMMONWORDS: [&str; 40] = 
 [
     "the",
@@ -66,87 +71,242 @@ static _COMMONWORDS: [&str; 40] =
     "what",
 ];
 
-/// Helper function to convert the token into its corresponding word or
-/// punctuation mark.
-///
-/// # Parameters
-/// - token
-///
-///   The token to interpret.
-///
-/// # Returns
-/// Returns a string containing the corresponding word or punctuation.  If
-/// the token is not recognized, the string returns "<UNKNOWN TOKEN #>",
-/// where # is the token value.
-fn _interpret_token(token: usize) -> String {
-    // Rule 1: token is between 0 and the number of common words.
-    if token < _COMMONWORDS.len()
-    {
-        _COMMONWORDS[token].to_string()
+/// Represents an interpreter that converts tokens to sentences and sentences
+/// back to tokens.  A token is nothing more than an index into the list of
+/// words the interpreter knows, its vocabulary.
+pub struct Interpreter {
+    /// The words the interpreter knows, in the order of their tokens.
+    vocabulary: Vec<String>,
+}
+
+impl Interpreter {
+    /// Constructor for an interpreter that knows the 40 most common words in
+    /// English.
+    ///
+    /// # Returns
+    /// Returns a new instance of the Interpreter struct.
+    pub fn new() -> Interpreter {
+        Interpreter {
+            vocabulary: _COMMONWORDS.iter().map(|word| word.to_string()).collect(),
+        }
     }
-    else
-    {
-        // Rule 1: token can also be a PERIOD
-        if token == (InterpreterConstants::PERIOD as usize) {
-            String::from(".")
+
+    /// Constructor for an interpreter that knows the given words.
+    ///
+    /// # Parameters
+    /// - words
+    ///
+    ///   The words the interpreter knows, in the order of their tokens.
+    ///   There must be at least one word and fewer than 100, so the tokens
+    ///   of the words are not mistaken for punctuation.  Each word must be
+    ///   made of letters, digits and apostrophes and appear only once,
+    ///   without regard to case, so it can be tokenized.
+    ///
+    /// # Returns
+    /// Returns `Ok(Interpreter)` containing the new interpreter; otherwise,
+    /// returns `Err(String)` describing what is wrong with the words.
+    pub fn from_words(words: &[&str]) -> Result<Interpreter, String> {
+        if words.is_empty() {
+            return Err(String::from("The vocabulary has no words"));
+        }
+        if words.len() >= InterpreterConstants::PERIOD as usize {
+            return Err(format!("The vocabulary has {} words but can have at most {}",
+                words.len(), InterpreterConstants::PERIOD as usize - 1));
+        }
+        for (index, word) in words.iter().enumerate() {
+            if !word.chars().all(|character| character.is_alphanumeric() || character == '\'') {
+                return Err(format!("The word \"{word}\" can only have letters, digits and apostrophes"));
+            }
+            if words[..index].iter().any(|earlier_word| earlier_word.to_lowercase() == word.to_lowercase()) {
+                return Err(format!("The word \"{word}\" appears more than once"));
+            }
         }
-        // Rule 1: or the token can also be a QUESTION
-        else if token == (InterpreterConstants::QUESTION as usize)
+        Ok(Interpreter {
+            vocabulary: words.iter().map(|word| word.to_string()).collect(),
+        })
+    }
+
+    /// Constructor for an interpreter that knows the words in the given
+    /// file, one word on each line, in the order of their tokens.  Blank
+    /// lines are ignored.
+    ///
+    /// # Parameters
+    /// - path
+    ///
+    ///   Path to the file containing the words.
+    ///
+    /// # Returns
+    /// Returns `Ok(Interpreter)` containing the new interpreter; otherwise,
+    /// returns `Err(String)` if the file cannot be read or the words cannot
+    /// be used (see from_words()).
+    pub fn from_file(path: &str) -> Result<Interpreter, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|error| format!("Could not read the vocabulary file \"{path}\": {error}"))?;
+        let words: Vec<&str> = contents.lines().map(|line| line.trim()).filter(|line| !line.is_empty()).collect();
+        Interpreter::from_words(&words)
+            .map_err(|message| format!("The vocabulary file \"{path}\" is not valid: {message}"))
+    }
+
+    /// Number of words the interpreter knows.
+    pub fn vocabulary_size(&self) -> usize {
+        self.vocabulary.len()
+    }
+
+    /// Helper method to convert the token into its corresponding word or
+    /// punctuation mark.
+    ///
+    /// # Parameters
+    /// - token
+    ///
+    ///   The token to interpret.
+    ///
+    /// # Returns
+    /// Returns a string containing the corresponding word or punctuation.  If
+    /// the token is not recognized, the string returns "<UNKNOWN TOKEN #>",
+    /// where # is the token value.
+    fn _interpret_token(&self, token: usize) -> String {
+        // Rule 1: token is between 0 and the number of words in the vocabulary.
+        if token < self.vocabulary.len()
         {
-            String::from("?")
+            self.vocabulary[token].clone()
         }
         else
         {
-            // Rule 1: Invalid tokens returned as unknown.
-            format!("<UNKNOWN TOKEN {token}>")
+            // Rule 1: token can also be a PERIOD
+            if token == (InterpreterConstants::PERIOD as usize) {
+                String::from(".")
+            }
+            // Rule 1: or the token can also be a QUESTION
+            else if token == (InterpreterConstants::QUESTION as usize)
+            {
+                String::from("?")
+            }
+            // Rule 1: or the token can also be a word that is not known
+            else if token == (InterpreterConstants::UNKNOWN as usize)
+            {
+                String::from("<UNKNOWN>")
+            }
+            else
+            {
+                // Rule 1: Invalid tokens returned as unknown.
+                format!("<UNKNOWN TOKEN {token}>")
+            }
         }
     }
-}
 
 
-/// This function is a simple interpreter.
-/// 
-/// The interpreter takes an array of integer tokens and converts each
-/// token into a word or punctuation mark.  The interpreter then arranges
-/// the words into a space-separated list in a single string.  In other
-/// words, the tokens are converted into a sentence, with the first word
-/// capitalized and no space between the last two "words" under the
-/// assumption the last word is actually a punctuation mark.
-/// 
-/// Interpreter Rules:
-/// 1. Each token must be in the range of 0 through 39 (maximum number of
-///    words known by the interpreter) or must be 100 ('.') or 101 ('?').
-/// 2. The word corresponding to the first token is always capitalized.
-/// 3. A single space appears between each word.
-/// 4. No space appears between the last two tokens.
-///
-/// # Parameters
-/// - tokenList
-///
-///   List of integer tokens to be interpreted.  The list is assumed to be
-///   terminated by -1 (EOL).
-///
-/// # Returns
-/// Returns a new String containing the result of the interpretation.
-pub fn interpreter_interpret(token_list: &[usize]) -> String {
-    let mut output = String::new();
-    let mut token_index = 0;
-    while token_list[token_index] != (InterpreterConstants::EOL as usize) {
-        let mut token_as_string = _interpret_token(token_list[token_index]);
-        if token_index == 0 {
-            // Rule 2: First word in sentence gets capitalized according to local rules.
-            token_as_string = titlecase::titlecase(&token_as_string);
-        }
-        output.push_str(&token_as_string);
+    /// This method is a simple interpreter.
+    /// 
+    /// The interpreter takes an array of integer tokens and converts each
+    /// token into a word or punctuation mark.  The interpreter then arranges
+    /// the words into a space-separated list in a single string.  In other
+    /// words, the tokens are converted into a sentence, with the first word
+    /// capitalized and no space between the last two "words" under the
+    /// assumption the last word is actually a punctuation mark.
+    /// 
+    /// Interpreter Rules:
+    /// 1. Each token must be in the range of 0 through the number of words
+    ///    in the vocabulary less 1 (39 for the 40 most common words) or
+    ///    must be 100 ('.') or 101 ('?').
+    /// 2. The word corresponding to the first token is always capitalized.
+    /// 3. A single space appears between each word.
+    /// 4. No space appears between the last two tokens.
+    ///
+    /// # Parameters
+    /// - tokenList
+    ///
+    ///   List of integer tokens to be interpreted.  The list is assumed to be
+    ///   terminated by -1 (EOL).
+    ///
+    /// # Returns
+    /// Returns a new String containing the result of the interpretation.
+    pub fn interpret(&self, token_list: &[usize]) -> String {
+        let mut output = String::new();
+        let mut token_index = 0;
+        while token_list[token_index] != (InterpreterConstants::EOL as usize) {
+            let mut token_as_string = self._interpret_token(token_list[token_index]);
+            if token_index == 0 && token_list[token_index] < self.vocabulary.len() {
+                // Rule 2: First word in sentence gets capitalized according to local rules.
+                // Markers such as <UNKNOWN> are not words, so are left as they are.
+                token_as_string = titlecase::titlecase(&token_as_string);
+            }
+            output.push_str(&token_as_string);
 
-        // Rule 4: No space between last two tokens (if the following expression is false)
-        if token_index + 2 < token_list.len() && token_list[token_index + 2] != (InterpreterConstants::EOL as usize) {
-            // Rule 3: Separate all words by a single space.
-            output.push(' ');
+            // Rule 4: No space between last two tokens (if the following expression is false)
+            if token_index + 2 < token_list.len() && token_list[token_index + 2] != (InterpreterConstants::EOL as usize) {
+                // Rule 3: Separate all words by a single space.
+                output.push(' ');
+            }
+
+            token_index += 1;
         }
+        output
+    }
 
-        token_index += 1;
+    /// Helper method to convert a word into its corresponding token, ignoring
+    /// case, so the capitalized first word of a sentence is still recognized.
+    ///
+    /// # Parameters
+    /// - word
+    ///
+    ///   The word to convert.
+    ///
+    /// # Returns
+    /// Returns the index of the word in the vocabulary, or
+    /// InterpreterConstants::UNKNOWN if the word is not in the vocabulary.
+    fn _tokenize_word(&self, word: &str) -> usize {
+        self.vocabulary.iter()
+            .position(|known_word| known_word.to_lowercase() == word.to_lowercase())
+            .unwrap_or(InterpreterConstants::UNKNOWN as usize)
     }
-    output
-}
 
+    /// This method is the reverse of the interpreter, converting a sentence
+    /// back into a list of integer tokens.
+    ///
+    /// Tokenizer Rules:
+    /// 1. A word is a run of letters, digits and apostrophes.  Words are
+    ///    matched to the vocabulary without regard to case.
+    /// 2. A word not in the vocabulary becomes the UNKNOWN token.
+    /// 3. A '.' becomes the PERIOD token and a '?' becomes the QUESTION
+    ///    token, whether or not there is a space before it.
+    /// 4. Any other character, such as a space or a comma, only separates
+    ///    words.
+    /// 5. The list of tokens is terminated by EOL.
+    ///
+    /// # Parameters
+    /// - sentence
+    ///
+    ///   The sentence to convert to tokens.
+    ///
+    /// # Returns
+    /// Returns the list of tokens, terminated by EOL, ready to be passed to
+    /// interpret().
+    pub fn tokenize(&self, sentence: &str) -> Vec<usize> {
+        let mut tokens: Vec<usize> = Vec::new();
+        let mut word = String::new();
+        for character in sentence.chars() {
+            // Rule 1: Collect the characters of a word.
+            if character.is_alphanumeric() || character == '\'' {
+                word.push(character);
+                continue;
+            }
+            if !word.is_empty() {
+                tokens.push(self._tokenize_word(&word));
+                word.clear();
+            }
+            // Rule 3: Punctuation marks become tokens; Rule 4: anything else
+            // is dropped.
+            match character {
+                '.' => tokens.push(InterpreterConstants::PERIOD as usize),
+                '?' => tokens.push(InterpreterConstants::QUESTION as usize),
+                _ => {}
+            }
+        }
+        if !word.is_empty() {
+            tokens.push(self._tokenize_word(&word));
+        }
+        // Rule 5: Terminate the list.
+        tokens.push(InterpreterConstants::EOL as usize);
+        tokens
+    }
+}
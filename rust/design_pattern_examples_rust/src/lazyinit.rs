@@ -0,0 +1,112 @@
+//! The Lazy Initialization design pattern example module
+//!
+//! The Lazy Initialization pattern defers building an expensive object until
+//! the moment it is actually needed.  Here it is combined with a virtual
+//! proxy: a cheap stand-in object that implements the same trait as the
+//! expensive object, answers what it can on its own, and builds the real
+//! object on first use.
+//!
+//! In this exercise, a gallery of image proxies is created.  Listing the
+//! names and sizes of the images does not build any real images; only
+//! rendering an image does, and rendering it again reuses the image already
+//! built.  Instrumentation counters are shown after each step to prove when
+//! each real image gets built.
+//!
+//! Accessed through the lazyinit_exercise() function.
+
+//-----------------------------------------------------------------------------
+
+pub mod lazyinit_counters;
+pub mod lazyinit_iimage_trait;
+pub mod lazyinit_imageproxy;
+pub mod lazyinit_realimage;
+
+//-----------------------------------------------------------------------------
+
+use lazyinit_counters::{get_counters, reset_counters};
+use lazyinit_iimage_trait::IImage;
+use lazyinit_imageproxy::ImageProxy;
+
+//-----------------------------------------------------------------------------
+
+/// Name, width and height of each image in the gallery.
+const GALLERY_IMAGES: [(&str, usize, usize); 3] = [
+    ("mountains", 640, 480),
+    ("harbor", 800, 600),
+    ("forest", 1024, 768),
+];
+
+//-----------------------------------------------------------------------------
+
+/// Helper function to show the instrumentation counters and which images
+/// have been built so far.
+///
+/// # Parameters
+/// - step
+///
+///   Description of the step just completed.
+/// - gallery
+///
+///   The image proxies to report on, in the same order as GALLERY_IMAGES.
+///   The names are taken from GALLERY_IMAGES so that reporting does not
+///   call the proxies and change the counters.
+fn _lazyinit_show_state(step: &str, gallery: &[ImageProxy]) {
+    let loaded: Vec<&str> = GALLERY_IMAGES.iter()
+        .zip(gallery.iter())
+        .filter(|(_, image)| image.is_loaded())
+        .map(|((name, _, _), _)| *name)
+        .collect();
+    println!("    [{step}] {}", get_counters());
+    println!("    [{step}] loaded images: {}",
+        if loaded.is_empty() { String::from("(none)") } else { loaded.join(", ") });
+}
+
+/// Example of using the "Lazy Initialization" design pattern.
+///
+/// The Lazy Initialization pattern defers building an expensive object until
+/// it is actually needed, here through a virtual proxy that builds the real
+/// object on first use.
+///
+/// In this exercise, a gallery of image proxies is created and only the
+/// images that are rendered get built, as shown by instrumentation counters.
+// ! [Using Lazy Initialization in Rust]
+pub fn lazyinit_exercise() -> Result<(), String> {
+    println!();
+    println!("Lazy Initialization Exercise");
+
+    reset_counters();
+
+    println!("  Creating gallery of image proxies");
+    let gallery: Vec<ImageProxy> = GALLERY_IMAGES.iter()
+        .map(|(name, width, height)| ImageProxy::new(name, *width, *height))
+        .collect();
+    _lazyinit_show_state("created", &gallery);
+
+    println!("  Listing the gallery (needs only names and sizes)");
+    for image in gallery.iter() {
+        let (width, height) = image.dimensions();
+        println!("    {:<10} {width} x {height}", image.name());
+    }
+    _lazyinit_show_state("listed", &gallery);
+
+    println!("  Rendering 'harbor' for the first time");
+    println!("    {}", gallery[1].render());
+    _lazyinit_show_state("rendered", &gallery);
+
+    println!("  Rendering 'harbor' again (real image already built)");
+    println!("    {}", gallery[1].render());
+    _lazyinit_show_state("re-rendered", &gallery);
+
+    println!("  Rendering 'mountains' for the first time");
+    println!("    {}", gallery[0].render());
+    _lazyinit_show_state("rendered", &gallery);
+
+    let counters = get_counters();
+    println!("  {} of {} real images were built; 'forest' was never needed.",
+        counters.real_objects_built, counters.proxies_created);
+
+    println!("  Done.");
+
+    Ok(())
+}
+// ! [Using Lazy Initialization in Rust]
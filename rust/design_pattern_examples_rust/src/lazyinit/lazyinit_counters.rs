@@ -0,0 +1,83 @@
+//! Contains the instrumentation counters used by the Lazy Initialization
+//! example to show exactly when the expensive objects are built.
+
+//-----------------------------------------------------------------------------
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+//-----------------------------------------------------------------------------
+
+/// Number of proxies created.
+static PROXIES_CREATED: AtomicUsize = AtomicUsize::new(0);
+/// Number of expensive real objects built.
+static REAL_OBJECTS_BUILT: AtomicUsize = AtomicUsize::new(0);
+/// Number of calls answered by a proxy without needing the real object.
+static CALLS_ANSWERED_BY_PROXY: AtomicUsize = AtomicUsize::new(0);
+/// Number of calls a proxy forwarded to the real object.
+static CALLS_FORWARDED: AtomicUsize = AtomicUsize::new(0);
+
+//-----------------------------------------------------------------------------
+
+/// A snapshot of the instrumentation counters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LazyInitCounters {
+    /// Number of proxies created.
+    pub proxies_created: usize,
+    /// Number of expensive real objects built.
+    pub real_objects_built: usize,
+    /// Number of calls answered by a proxy without needing the real object.
+    pub calls_answered_by_proxy: usize,
+    /// Number of calls a proxy forwarded to the real object.
+    pub calls_forwarded: usize,
+}
+
+impl std::fmt::Display for LazyInitCounters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "proxies={}, built={}, answered by proxy={}, forwarded={}",
+            self.proxies_created, self.real_objects_built,
+            self.calls_answered_by_proxy, self.calls_forwarded)
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Record that a proxy was created.
+pub fn count_proxy_created() {
+    PROXIES_CREATED.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Record that an expensive real object was built.
+pub fn count_real_object_built() {
+    REAL_OBJECTS_BUILT.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Record that a proxy answered a call without the real object.
+pub fn count_call_answered_by_proxy() {
+    CALLS_ANSWERED_BY_PROXY.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Record that a proxy forwarded a call to the real object.
+pub fn count_call_forwarded() {
+    CALLS_FORWARDED.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Retrieve a snapshot of the counters.
+///
+/// # Returns
+/// Returns a LazyInitCounters object containing the current counts.
+pub fn get_counters() -> LazyInitCounters {
+    LazyInitCounters {
+        proxies_created: PROXIES_CREATED.load(Ordering::Relaxed),
+        real_objects_built: REAL_OBJECTS_BUILT.load(Ordering::Relaxed),
+        calls_answered_by_proxy: CALLS_ANSWERED_BY_PROXY.load(Ordering::Relaxed),
+        calls_forwarded: CALLS_FORWARDED.load(Ordering::Relaxed),
+    }
+}
+
+/// Set all counters back to zero.
+pub fn reset_counters() {
+    PROXIES_CREATED.store(0, Ordering::Relaxed);
+    REAL_OBJECTS_BUILT.store(0, Ordering::Relaxed);
+    CALLS_ANSWERED_BY_PROXY.store(0, Ordering::Relaxed);
+    CALLS_FORWARDED.store(0, Ordering::Relaxed);
+}
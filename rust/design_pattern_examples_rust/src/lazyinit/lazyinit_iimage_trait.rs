@@ -0,0 +1,18 @@
+//! Contains the IImage trait implemented by both the real image and the
+//! proxy that stands in for it.
+
+//-----------------------------------------------------------------------------
+
+/// Represents an image that can be described and rendered.  Callers cannot
+/// tell whether they are holding the real image or a proxy for it.
+pub trait IImage {
+    /// Retrieve the name of the image.
+    fn name(&self) -> String;
+
+    /// Retrieve the width and height of the image, in pixels.
+    fn dimensions(&self) -> (usize, usize);
+
+    /// Render the image to a short summary string.  This needs the image's
+    /// pixel data.
+    fn render(&self) -> String;
+}
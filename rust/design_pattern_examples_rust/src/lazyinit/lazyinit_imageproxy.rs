@@ -0,0 +1,87 @@
+//! Contains the ImageProxy struct, a virtual proxy that builds the real image
+//! only when it is first needed.
+
+//-----------------------------------------------------------------------------
+
+use std::cell::OnceCell;
+
+use super::lazyinit_counters::{count_call_answered_by_proxy, count_call_forwarded, count_proxy_created};
+use super::lazyinit_iimage_trait::IImage;
+use super::lazyinit_realimage::HighResolutionImage;
+
+//-----------------------------------------------------------------------------
+
+/// A virtual proxy for a HighResolutionImage.  The proxy is cheap to create
+/// and answers questions about the image's name and size itself.  The real
+/// image is built the first time something needs the pixel data, and the
+/// same real image is used from then on.
+pub struct ImageProxy {
+    /// Name of the image.
+    name: String,
+    /// Width of the image, in pixels.
+    width: usize,
+    /// Height of the image, in pixels.
+    height: usize,
+    /// The real image, once it has been built.
+    real_image: OnceCell<HighResolutionImage>,
+}
+
+impl ImageProxy {
+    /// Constructor.  Does not build the real image.
+    ///
+    /// # Parameters
+    /// - name
+    ///
+    ///   Name of the image.
+    /// - width
+    ///
+    ///   Width of the image, in pixels.
+    /// - height
+    ///
+    ///   Height of the image, in pixels.
+    ///
+    /// # Returns
+    /// Returns a new instance of the ImageProxy struct.
+    pub fn new(name: &str, width: usize, height: usize) -> ImageProxy {
+        count_proxy_created();
+        ImageProxy {
+            name: name.to_string(),
+            width,
+            height,
+            real_image: OnceCell::new(),
+        }
+    }
+
+    /// Determine whether the real image has been built yet.
+    ///
+    /// # Returns
+    /// Returns true if the real image exists; otherwise, returns false.
+    pub fn is_loaded(&self) -> bool {
+        self.real_image.get().is_some()
+    }
+
+    /// Helper method to retrieve the real image, building it on first use.
+    ///
+    /// # Returns
+    /// Returns a reference to the real image.
+    fn get_real_image(&self) -> &HighResolutionImage {
+        self.real_image.get_or_init(|| HighResolutionImage::new(&self.name, self.width, self.height))
+    }
+}
+
+impl IImage for ImageProxy {
+    fn name(&self) -> String {
+        count_call_answered_by_proxy();
+        self.name.clone()
+    }
+
+    fn dimensions(&self) -> (usize, usize) {
+        count_call_answered_by_proxy();
+        (self.width, self.height)
+    }
+
+    fn render(&self) -> String {
+        count_call_forwarded();
+        self.get_real_image().render()
+    }
+}
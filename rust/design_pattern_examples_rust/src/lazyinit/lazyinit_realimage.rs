@@ -0,0 +1,71 @@
+//! Contains the HighResolutionImage struct, the expensive object whose
+//! construction is deferred by the Lazy Initialization example.
+
+//-----------------------------------------------------------------------------
+
+use super::lazyinit_counters::count_real_object_built;
+use super::lazyinit_iimage_trait::IImage;
+
+//-----------------------------------------------------------------------------
+
+/// Represents a high resolution image whose pixel data is expensive to
+/// produce.  For demonstration purposes, imagine the pixels are decoded from
+/// a large file on disk.
+pub struct HighResolutionImage {
+    /// Name of the image.
+    name: String,
+    /// Width of the image, in pixels.
+    width: usize,
+    /// Height of the image, in pixels.
+    height: usize,
+    /// The pixel data, one byte per pixel.
+    pixels: Vec<u8>,
+}
+
+impl HighResolutionImage {
+    /// Constructor.  Builds all of the pixel data, which is the expensive
+    /// part.
+    ///
+    /// # Parameters
+    /// - name
+    ///
+    ///   Name of the image.
+    /// - width
+    ///
+    ///   Width of the image, in pixels.
+    /// - height
+    ///
+    ///   Height of the image, in pixels.
+    ///
+    /// # Returns
+    /// Returns a new instance of the HighResolutionImage struct.
+    pub fn new(name: &str, width: usize, height: usize) -> HighResolutionImage {
+        println!("    --> Building real image '{name}' ({width} x {height})...");
+        count_real_object_built();
+        let seed = name.bytes().fold(0u8, |acc, b| acc.wrapping_add(b));
+        let pixels = (0..width * height)
+            .map(|index| (index as u8).wrapping_mul(31).wrapping_add(seed))
+            .collect();
+        HighResolutionImage {
+            name: name.to_string(),
+            width,
+            height,
+            pixels,
+        }
+    }
+}
+
+impl IImage for HighResolutionImage {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn dimensions(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    fn render(&self) -> String {
+        let checksum = self.pixels.iter().fold(0u32, |acc, pixel| acc.wrapping_add(*pixel as u32));
+        format!("'{}' rendered {} pixels (checksum {checksum})", self.name, self.pixels.len())
+    }
+}
@@ -1,6 +1,6 @@
 //! # Design Pattern Examples in Rust
 //!
-//! Provides 18 examples of the Design patterns spelled out in the book
+//! Provides 31 examples of the Design patterns spelled out in the book
 //! _Design Patterns: Elements of Object-Oriented Software_, by Gamma, Helm,
 //! Johnson, and Vlissides.
 //!
@@ -15,23 +15,36 @@ use std::env;
 
 extern crate crossterm;
 
+mod abstractfactory;
 mod adapter;
 mod bridge;
 mod command;
 mod composite;
 mod decorator;
+mod dependencyinjection;
+mod eventsourcing;
 mod facade;
+mod factorymethod;
 mod flyweight;
 mod handlerchain;
 mod interpreter;
 mod iterator;
+mod lazyinit;
 mod mediator;
 mod memento;
+mod mvc;
 mod nullobject;
+mod objectpool;
 mod observer;
+mod prototype;
 mod proxy;
+mod pubsub;
+mod repository;
+mod specification;
 mod state;
 mod strategy;
+mod templatemethod;
+mod threadpool;
 mod visitor;
 mod helpers;
 
@@ -81,6 +94,11 @@ struct Options {
     /// List of names of exercise to run.  If this list is empty, run all
     /// exercises.
     exercise_names: Vec<String>,
+
+    /// List of exercise-specific options, each a name (including the leading
+    /// "--") and an optional value.  These are made available to the
+    /// exercises through the helpers::exercise_options module.
+    exercise_options: Vec<(String, Option<String>)>,
 }
 
 
@@ -93,7 +111,7 @@ struct Options {
 fn help(exercise_list: &Vec<Exercise>) {
     let usage = format!(
 "{0} (v{1}) by Stephen P. Lepisto
-usage: {0} [options] [exercise_name][[ exercise_name][...]]
+usage: {0} [options] [exercise_name][[ exercise_name][...]] [exercise_options]
 
 Runs through a series of exercises showing off design patterns.  If no
 exercise_name is given, then run through all exercises.
@@ -101,8 +119,88 @@ exercise_name is given, then run through all exercises.
 Options:
 --help, -?
         This help text.
+--profile
+        Time the exercises and parts of the heavier exercises, then show
+        where the time was spent after all exercises have run.
 --version
         Show just the version number of this application.
+
+Exercise options (ignored by exercises that do not use them):
+--benchmark
+        Command: also time undoing a long list of commands with inverse
+        operations and by applying the remaining commands again.
+        Flyweight: instead of the animation, time the same animation of
+        thousands of images on a large virtual display, with the images
+        sharing one resource and with the images each owning a copy of the
+        resource, and show the memory used and the throughput of each.
+--benchmark-frames <n>
+        Flyweight: with --benchmark, run n frames for each kind of image
+        instead of 100.
+--benchmark-images <n>
+        Flyweight: with --benchmark, animate n images instead of 5000.
+--blocks <list>
+        Adapter: use only the memory blocks in the comma-separated list of
+        block numbers (0, 1 or 2), such as 0,2.  The default is all blocks.
+--collisions
+        Flyweight: make the images bounce off each other as well as off
+        the edges of the display.
+--count <n>
+        Flyweight: animate n images instead of 5.  With --benchmark, use
+        up to n distinct images in the big resource.
+--decorators <spec>
+        Decorator: decorate the last example with the decorators in the
+        '+'-separated list, applied innermost first, such as
+        red+underline+bold.  Decorators are bold, blink, underline,
+        whitebackground, a color (black, red, green, yellow, blue, magenta,
+        cyan or white) or a color pair such as yellow/blue.
+--display <width>x<height>
+        Flyweight: animate the images in a display of the given size
+        instead of 80x20.  The display must be larger than an image.
+        --benchmark ignores this and uses its own display of at least
+        1000x250.
+--fps <n>
+        Flyweight: show n frames a second instead of 60.
+--image <width>x<height>
+        Flyweight: make each image the given size instead of 30x5.  An
+        image must be at least 3x3.
+--interactive
+        Command: instead of the usual exercise, edit a line of text by
+        typing commands, which can be undone and redone.
+        HandlerChain: instead of the usual exercise, click, drag and close
+        windows drawn in the console with the mouse.  Esc exits.
+--iterations <n>
+        Flyweight: animate the images for n iterations instead of 1000.
+--log-file <path>
+        Bridge: write the log file examples to the given file instead of
+        bridge.log, creating any missing directories in the path.
+--no-interactive
+        Flyweight: print each frame one after another instead of animating
+        the images in place (see --iterations).
+--seed <n>
+        Flyweight: start the images in the same places every time the same
+        seed is given, so every run shows the same frames.
+--shared
+        Adapter: instead of the usual exercise, have a writer and a reader
+        pass records through the first memory block (see --blocks).
+--sprites <dir>
+        Flyweight: animate the ASCII-art sprites read from the .txt files
+        in the given directory instead of generated images (the sprites
+        directory next to src has examples).  All sprites must be the
+        same size, which replaces the --image size.  Spaces in the sprites
+        are transparent.
+--stress
+        State: also run the comment filter over many generated code
+        snippets and report whether the filter behaved correctly.
+--topology <path>
+        Facade: use the device chains described in the given JSON file
+        instead of the built-in ones (see facade_complicatedsubsystem.rs
+        for the form of the file).
+--vocabulary <path>
+        Interpreter: interpret the tokens as the words in the given file,
+        one word on each line, instead of the 40 most common words in
+        English.  A token is the position of the word in the file,
+        starting at 0.  The file needs at least 40 words, as the
+        example sentences use tokens up to 39.
 "
 , "DesignPatternExamples_rust"
 , CARGO_PKG_VERSION.unwrap_or("NOT FOUND")
@@ -139,10 +237,16 @@ fn show_version() {
 /// `Ok<Options>` if successful (the Options structure is filled in with
 /// command line parameters.  Returns `Err<"">` if help was requested and
 /// displayed (and therefore exit from program is desired).
+///
+/// Any other argument starting with "--" is an exercise option.  If the
+/// argument after an exercise option does not start with "--" and is not the
+/// name of an exercise, that argument is the value of the exercise option.
 fn parse_options(args: &[String], exercise_list: &Vec<Exercise>) -> Result<Options, &'static str> {
     let mut exercise_names: Vec<String> = Vec::new();
+    let mut exercise_options: Vec<(String, Option<String>)> = Vec::new();
 
-    for arg in args {
+    let mut arg_iter = args.iter().peekable();
+    while let Some(arg) = arg_iter.next() {
         match arg.as_str() {
             "--help" | "-?" | "/?" => {
                 help(exercise_list);
@@ -152,34 +256,54 @@ fn parse_options(args: &[String], exercise_list: &Vec<Exercise>) -> Result<Optio
                 show_version();
                 return Err("");
             }
+            _ if arg.starts_with("--") => {
+                let value = arg_iter.next_if(|next_arg| {
+                    !next_arg.starts_with("--") &&
+                    !exercise_list.iter().any(|exercise| exercise.exercise_name == **next_arg)
+                });
+                exercise_options.push((arg.to_string(), value.cloned()));
+            }
             _ => exercise_names.push(arg.to_string()),
         }
     }
 
-    Ok(Options { exercise_names })
+    Ok(Options { exercise_names, exercise_options })
 }
 
 
 /// Main entry point into this example program.
 fn main() {
     let exercise_list = vec!(
+        Exercise::new("AbstractFactory", abstractfactory::abstractfactory_exercise),
         Exercise::new("Adapter", adapter::adapter_exercise),
         Exercise::new("Bridge", bridge::bridge_exercise),
         Exercise::new("Command", command::command_exercise),
         Exercise::new("Composite", composite::composite_exercise),
         Exercise::new("Decorator", decorator::decorator_exercise),
+        Exercise::new("DependencyInjection", dependencyinjection::dependencyinjection_exercise),
+        Exercise::new("EventSourcing", eventsourcing::eventsourcing_exercise),
         Exercise::new("Facade", facade::facade_exercise),
+        Exercise::new("FactoryMethod", factorymethod::factorymethod_exercise),
         Exercise::new("Flyweight", flyweight::flyweight_exercise),
         Exercise::new("HandlerChain", handlerchain::handlerchain_exercise),
         Exercise::new("Interpreter", interpreter::interpreter_exercise),
         Exercise::new("Iterator", iterator::iterator_exercise),
+        Exercise::new("LazyInitialization", lazyinit::lazyinit_exercise),
         Exercise::new("Mediator", mediator::mediator_exercise),
         Exercise::new("Memento", memento::memento_exercise),
+        Exercise::new("MVC", mvc::mvc_exercise),
         Exercise::new("NullObject", nullobject::nullobject_exercise),
+        Exercise::new("ObjectPool", objectpool::objectpool_exercise),
         Exercise::new("Observer", observer::observer_exercise),
+        Exercise::new("Prototype", prototype::prototype_exercise),
         Exercise::new("Proxy", proxy::proxy_exercise),
+        Exercise::new("PubSub", pubsub::pubsub_exercise),
+        Exercise::new("Repository", repository::repository_exercise),
+        Exercise::new("Specification", specification::specification_exercise),
         Exercise::new("State", state::state_exercise),
         Exercise::new("Strategy", strategy::strategy_exercise),
+        Exercise::new("TemplateMethod", templatemethod::templatemethod_exercise),
+        Exercise::new("ThreadPool", threadpool::threadpool_exercise),
         Exercise::new("Visitor", visitor::visitor_exercise),
     );
 
@@ -200,11 +324,12 @@ fn main() {
     // initialization call is made.
     //
     // Also, the ansi_support module is for Windows only.  Not documented.
+    // helpers::ansi::supports_ansi() takes care of that, so it can also be
+    // used by the exercises that need to know.
 
-    #[cfg(windows)]
-    if !crossterm::ansi_support::supports_ansi() {
+    if !helpers::ansi::supports_ansi() {
         println!("ANSI sequences are not supported by this terminal, according to the crossterm crate.");
-        println!("The Decorator and Flyweight examples will not work correctly.");
+        println!("The Flyweight example will not work correctly and the Decorator example will show markers instead of colors.");
     }
 
     // skip() first argument as it is the program name.
@@ -213,14 +338,23 @@ fn main() {
     let parsed_options = parse_options(&args, &exercise_list);
 
     if let Ok(options) = parsed_options {
+        helpers::exercise_options::set_exercise_options(options.exercise_options);
+        if helpers::exercise_options::has_exercise_option("--profile") {
+            helpers::profiler::enable_profiling();
+        }
         for exercise in exercise_list {
             if options.exercise_names.is_empty() ||
                options.exercise_names.contains(&exercise.exercise_name) {
-                   let error_code = (exercise.exercise_to_run)();
+                helpers::profiler::begin_span(&exercise.exercise_name);
+                let error_code = (exercise.exercise_to_run)();
+                helpers::profiler::end_span();
                 if let Err(message) = error_code {
                     println!("  {message}");
                 }
             }
         }
+        if helpers::profiler::is_profiling_enabled() {
+            helpers::profiler::print_profile_report();
+        }
     }
 }
@@ -5,6 +5,7 @@
 
 use super::mediator_grouplist::GroupList;
 use super::mediator_userlist::UserList;
+use crate::bridge::bridge_loggerregistry::get_logger;
 
 //-----------------------------------------------------------------------------
 
@@ -116,10 +117,10 @@ impl UserGroupMediator {
         if self.user_group_container.users.contains_user(user_name) {
             match self.user_group_container.groups.find_group(group_name) {
                 Some(group) => group.borrow_mut().add_user(user_name),
-                None => eprintln!("  Error! Cannot add user '{user_name}' to group '{group_name}' as that group does not exist!"),
+                None => get_logger("mediator").log_error(&format!("Cannot add user '{user_name}' to group '{group_name}' as that group does not exist!")),
             }
         } else {
-            eprintln!("  Error! User '{user_name}' does not exist.  Cannot add to group '{group_name}'!");
+            get_logger("mediator").log_error(&format!("User '{user_name}' does not exist.  Cannot add to group '{group_name}'!"));
         }
     }
 
@@ -139,10 +140,10 @@ impl UserGroupMediator {
         if self.user_group_container.users.contains_user(user_name) {
             match self.user_group_container.groups.find_group(group_name) {
                 Some(group) => group.borrow_mut().remove_user(user_name),
-                None => eprintln!("  Error! Cannot remove user '{user_name}' from group '{group_name}' as that group does not exist!"),
+                None => get_logger("mediator").log_error(&format!("Cannot remove user '{user_name}' from group '{group_name}' as that group does not exist!")),
             }
         } else {
-            eprintln!("  Error! User '{user_name}' does not exist.  Cannot remove from group '{group_name}'!");
+            get_logger("mediator").log_error(&format!("User '{user_name}' does not exist.  Cannot remove from group '{group_name}'!"));
         }
     }
 
@@ -164,7 +165,7 @@ impl UserGroupMediator {
                 }
             }
         } else {
-            eprintln!("  Error! User '{user_name}' does not exist.  Cannot remove from all groups!");
+            get_logger("mediator").log_error(&format!("User '{user_name}' does not exist.  Cannot remove from all groups!"));
         }
     }
 
@@ -185,11 +186,11 @@ impl UserGroupMediator {
             if let Some(group) = self.user_group_container.groups.find_group(&group_name) {
                 group.borrow().contains_user(user_name)
             } else {
-                eprintln!("  Error! Cannot determine if user '{user_name}' is in group '{group_name}' as that group does not exist!");
+                get_logger("mediator").log_error(&format!("Cannot determine if user '{user_name}' is in group '{group_name}' as that group does not exist!"));
                 false
             }
         } else {
-            eprintln!("  Error! User '{user_name}' does not exist.  Cannot determine if user is in group '{group_name}'!");
+            get_logger("mediator").log_error(&format!("User '{user_name}' does not exist.  Cannot determine if user is in group '{group_name}'!"));
             false
         }
     }
@@ -216,7 +217,7 @@ impl UserGroupMediator {
                 }
             }
         } else {
-            eprintln!("  Error! User '{user_name}' does not exist.  Cannot get groups containing user!");
+            get_logger("mediator").log_error(&format!("User '{user_name}' does not exist.  Cannot get groups containing user!"));
         }
 
         groups
@@ -236,7 +237,7 @@ impl UserGroupMediator {
         if let Some(group) = self.user_group_container.groups.find_group(&group_name) {
             return group.borrow().user_names();
         } else {
-            eprintln!("  Error! Cannot determine get users in group '{group_name}' as that group does not exist!");
+            get_logger("mediator").log_error(&format!("Cannot determine get users in group '{group_name}' as that group does not exist!"));
             vec![]
         }
     }
@@ -33,17 +33,18 @@ impl Memento {
     ///
     /// # Returns
     /// Returns a new instance of the Memento struct.
-    fn new(name: &str, text: &str) -> Memento {
+    pub(crate) fn new(name: &str, text: &str) -> Memento {
         Memento {
             name: name.to_string(),
             text: text.to_string(),
         }
     }
 
-    /// The saved text in this memento.  This is accessible only by the
-    /// MementoTextObject struct since it is the only entity that knows
-    /// what to do with the text during an undo.
-    fn text(&self) -> &str {
+    /// The saved text in this memento.  This is meant to be used only by the
+    /// originator that created the memento (such as the MementoTextObject
+    /// struct) since it is the only entity that knows what to do with the
+    /// text during an undo.
+    pub(crate) fn text(&self) -> &str {
         &self.text
     }
 
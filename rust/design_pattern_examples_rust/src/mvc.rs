@@ -0,0 +1,81 @@
+//! The Model-View-Controller design pattern example module
+//!
+//! The Model-View-Controller (MVC) pattern separates an application into the
+//! Model (the data), one or more Views (ways of showing the data), and a
+//! Controller (which turns user input into changes to the model).  MVC is
+//! not a single pattern so much as several patterns working together; here,
+//! the Observer pattern connects the model to its views.
+//!
+//! In this exercise, the model is a counter.  Two views observe it using the
+//! IObserverNumberChanged trait from the Observer example: a new view that
+//! draws the count as a bar and the existing hexadecimal observer from the
+//! Observer example, reused unchanged.  The controller processes a sequence
+//! of key commands, changing the model, and the views update themselves.
+//!
+//! Accessed through the mvc_exercise() function.
+
+//-----------------------------------------------------------------------------
+
+pub mod mvc_controller;
+pub mod mvc_model;
+pub mod mvc_view;
+
+//-----------------------------------------------------------------------------
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::observer::observer_observers::ObserverHexadecimal;
+use mvc_controller::{CounterController, CONTROLLER_COMMANDS};
+use mvc_model::CounterModel;
+use mvc_view::CounterBarView;
+
+//-----------------------------------------------------------------------------
+
+/// Example of using the "Model-View-Controller" design pattern.
+///
+/// The Model-View-Controller (MVC) pattern separates an application into the
+/// Model (the data), one or more Views (ways of showing the data), and a
+/// Controller (which turns user input into changes to the model).
+///
+/// In this exercise, a counter model is shown by two views that observe it,
+/// one of them reused from the Observer example.  A controller processes a
+/// sequence of key commands that change the model.
+// ! [Using MVC in Rust]
+pub fn mvc_exercise() -> Result<(), String> {
+    println!();
+    println!("MVC Exercise");
+
+    println!("  Controller commands:");
+    for (key, description) in CONTROLLER_COMMANDS.iter() {
+        println!("    {key} : {description}");
+    }
+
+    let model = Rc::new(RefCell::new(CounterModel::new()));
+    let bar_view = CounterBarView::new('#');
+    let hexadecimal_view = ObserverHexadecimal::new();
+    println!("  Attaching views to the model:");
+    model.borrow_mut().add_observer(&bar_view);
+    model.borrow_mut().add_observer(&hexadecimal_view);
+
+    let mut controller = CounterController::new(model.clone());
+    let key_presses = "++*-x-r+q+";
+    println!("  Processing key presses \"{key_presses}\":");
+    for key in key_presses.chars() {
+        println!("  Key '{key}':");
+        match controller.handle_key(key) {
+            Ok(true) => {}
+            Ok(false) => {
+                println!("    Quitting");
+                break;
+            }
+            Err(message) => println!("    {message}"),
+        }
+    }
+    println!("  Final count in the model: {}", model.borrow().count());
+
+    println!("  Done.");
+
+    Ok(())
+}
+// ! [Using MVC in Rust]
@@ -0,0 +1,68 @@
+//! Contains the CounterController struct, the Controller in the
+//! Model-View-Controller example, which turns key commands into changes to
+//! the model.
+
+//-----------------------------------------------------------------------------
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use super::mvc_model::CounterModel;
+
+//-----------------------------------------------------------------------------
+
+/// The key commands understood by the controller, with a description of
+/// each.
+pub const CONTROLLER_COMMANDS: [(char, &str); 5] = [
+    ('+', "increase the count by 1"),
+    ('-', "decrease the count by 1"),
+    ('*', "increase the count by 5"),
+    ('r', "reset the count to 0"),
+    ('q', "quit"),
+];
+
+/// Represents the Controller: it takes input from the user (key commands) and
+/// changes the model accordingly.  The controller does not update the views;
+/// the model does that when it changes.
+pub struct CounterController {
+    /// The model to change.
+    model: Rc<RefCell<CounterModel>>,
+}
+
+impl CounterController {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - model
+    ///
+    ///   The model to change.
+    ///
+    /// # Returns
+    /// Returns a new instance of the CounterController struct.
+    pub fn new(model: Rc<RefCell<CounterModel>>) -> CounterController {
+        CounterController { model }
+    }
+
+    /// Process a key command.
+    ///
+    /// # Parameters
+    /// - key
+    ///
+    ///   The key pressed.
+    ///
+    /// # Returns
+    /// Returns `Ok(true)` if more commands should be processed, `Ok(false)`
+    /// if the quit command was given, or `Err(String)` if the key is not a
+    /// known command.
+    pub fn handle_key(&mut self, key: char) -> Result<bool, String> {
+        match key {
+            '+' => self.model.borrow_mut().increase(1),
+            '-' => self.model.borrow_mut().decrease(1),
+            '*' => self.model.borrow_mut().increase(5),
+            'r' => self.model.borrow_mut().reset(),
+            'q' => return Ok(false),
+            _ => return Err(format!("Unknown command '{key}'")),
+        }
+        Ok(true)
+    }
+}
@@ -0,0 +1,89 @@
+//! Contains the CounterModel struct, the Model in the Model-View-Controller
+//! example, which holds a count and notifies its observers when the count
+//! changes.
+
+//-----------------------------------------------------------------------------
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::observer::observer_inumberchanged_trait::IObserverNumberChanged;
+
+//-----------------------------------------------------------------------------
+
+/// Represents the Model: a count that can be increased, decreased and reset.
+/// The model knows nothing about how the count is displayed or what causes
+/// it to change; it only tells its observers (the views) when it changes,
+/// using the IObserverNumberChanged trait from the Observer example.
+pub struct CounterModel {
+    /// The current count.
+    count: u32,
+    /// The observers to notify when the count changes.
+    observers: Vec<Rc<RefCell<dyn IObserverNumberChanged>>>,
+}
+
+impl CounterModel {
+    /// Constructor.
+    ///
+    /// # Returns
+    /// Returns a new instance of the CounterModel struct with a count of 0.
+    pub fn new() -> CounterModel {
+        CounterModel { count: 0, observers: vec![] }
+    }
+
+    /// Retrieve the current count.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Subscribe an observer to changes in the count.  The observer is told
+    /// the current count immediately so it can show it.
+    ///
+    /// # Parameters
+    /// - observer
+    ///
+    ///   The observer as represented by the IObserverNumberChanged trait.
+    pub fn add_observer(&mut self, observer: &Rc<RefCell<dyn IObserverNumberChanged>>) {
+        if !self.observers.iter().any(|x| Rc::ptr_eq(x, observer)) {
+            self.observers.push(observer.clone());
+            observer.borrow_mut().notify(self.count);
+        }
+    }
+
+    /// Increase the count by the given amount.
+    ///
+    /// # Parameters
+    /// - amount
+    ///
+    ///   The amount to add to the count.
+    pub fn increase(&mut self, amount: u32) {
+        self._set_count(self.count.saturating_add(amount));
+    }
+
+    /// Decrease the count by the given amount, stopping at 0.
+    ///
+    /// # Parameters
+    /// - amount
+    ///
+    ///   The amount to subtract from the count.
+    pub fn decrease(&mut self, amount: u32) {
+        self._set_count(self.count.saturating_sub(amount));
+    }
+
+    /// Set the count back to 0.
+    pub fn reset(&mut self) {
+        self._set_count(0);
+    }
+
+    /// Helper method to change the count and notify the observers if the
+    /// count actually changed.
+    fn _set_count(&mut self, count: u32) {
+        if count != self.count {
+            self.count = count;
+            let local_observers = self.observers.clone();
+            for observer in local_observers.iter() {
+                observer.borrow_mut().notify(self.count);
+            }
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! Contains the CounterBarView struct, a View in the Model-View-Controller
+//! example, which draws the count as a bar on the console.
+
+//-----------------------------------------------------------------------------
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::observer::observer_inumberchanged_trait::IObserverNumberChanged;
+
+//-----------------------------------------------------------------------------
+
+/// Represents a View that shows the count as a bar of characters.  The view
+/// only displays what the model tells it; it never changes the model.
+pub struct CounterBarView {
+    /// Character used to draw the bar.
+    bar_character: char,
+}
+
+impl CounterBarView {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - bar_character
+    ///
+    ///   Character used to draw the bar.
+    ///
+    /// # Returns
+    /// Returns a new instance of the CounterBarView struct as represented by
+    /// the IObserverNumberChanged trait.
+    pub fn new(bar_character: char) -> Rc<RefCell<dyn IObserverNumberChanged>> {
+        Rc::new(RefCell::new(CounterBarView { bar_character }))
+    }
+}
+
+impl IObserverNumberChanged for CounterBarView {
+    fn notify(&mut self, updated_number: u32) {
+        let bar = self.bar_character.to_string().repeat(updated_number as usize);
+        println!("    Bar view   : {updated_number:3} |{bar}");
+    }
+}
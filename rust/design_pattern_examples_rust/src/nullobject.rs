@@ -12,6 +12,10 @@
 //! This example displays the commands after parsing and then "executes" the
 //! commands, which consists of printing the commands out.
 //!
+//! The same move string is then parsed again with the other unknown command
+//! policies (reject with an error, collect and report) to contrast the Null
+//! Object approach with explicit error handling.
+//!
 //! Accessed through the nullobject_exercise() function.
 
 //-----------------------------------------------------------------------------
@@ -22,7 +26,7 @@ pub mod nullobject_moveprocessor;
 
 //-----------------------------------------------------------------------------
 
-use nullobject_moveprocessor::MoveProcessor;
+use nullobject_moveprocessor::{MoveProcessor, UnknownCommandPolicy};
 
 //-----------------------------------------------------------------------------
 
@@ -43,6 +47,10 @@ use nullobject_moveprocessor::MoveProcessor;
 /// 
 /// This example highlights the ""Null Object"" pattern while also utilizing
 /// the "Command" pattern and "Interpreter" pattern.
+///
+/// Finally, the same move string is run through the other unknown command
+/// policies to show what the Null Object saves the caller from having to
+/// deal with.
 // ! [Using NullObject in Rust]
 pub fn nullobject_exercise() -> Result<(), String> {
     println!("");
@@ -53,13 +61,33 @@ pub fn nullobject_exercise() -> Result<(), String> {
     let move_string = "ur#ld!lr";
     let move_processor = MoveProcessor::new();
 
-    let move_commands = move_processor.parse(move_string);
+    let parsed_moves = move_processor.parse(move_string)?;
     println!("  Showing the move commands:");
-    move_processor.show_commands(&move_commands);
+    move_processor.show_commands(&parsed_moves.commands);
 
     println!("  Executing the move commands:");
     print!("    {0} -> ", move_string);
-    move_processor.execute_commands(&move_commands);
+    move_processor.execute_commands(&parsed_moves.commands);
+
+    println!("  Comparing unknown command policies on the same move string:");
+    let policies = [
+        UnknownCommandPolicy::NullObject,
+        UnknownCommandPolicy::Reject,
+        UnknownCommandPolicy::CollectAndReport,
+    ];
+    for policy in policies {
+        let move_processor = MoveProcessor::with_policy(policy);
+        print!("    {0:<16} {1} -> ", move_processor.policy().name(), move_string);
+        match move_processor.parse(move_string) {
+            Ok(parsed_moves) => {
+                move_processor.execute_commands(&parsed_moves.commands);
+                for (position, c) in parsed_moves.unknown_commands.iter() {
+                    println!("      skipped unknown command '{c}' at position {position}");
+                }
+            }
+            Err(message) => println!("error: {message}"),
+        }
+    }
 
     println!("  Done.");
 
@@ -9,6 +9,51 @@ use super::nullobject_movecommands::{MoveLeft, MoveRight, MoveUp, MoveDown, Move
 
 //-----------------------------------------------------------------------------
 
+/// Identifies how the MoveProcessor handles characters in the move string
+/// that are not recognized as move commands.
+#[derive(Clone, Copy, PartialEq)]
+pub enum UnknownCommandPolicy {
+    /// Assign a "Do Nothing" (Null Object) command to each unrecognized
+    /// character.  The caller never needs to know an unrecognized character
+    /// was present.
+    NullObject,
+
+    /// Stop parsing and return an error describing the first unrecognized
+    /// character.
+    Reject,
+
+    /// Skip each unrecognized character but remember it so the caller can
+    /// report all of them after parsing is finished.
+    CollectAndReport,
+}
+
+impl UnknownCommandPolicy {
+    /// Retrieve the name of the policy for display purposes.
+    ///
+    /// # Returns
+    /// Returns a string containing the name of the policy.
+    pub fn name(&self) -> &'static str {
+        match self {
+            UnknownCommandPolicy::NullObject => "NullObject",
+            UnknownCommandPolicy::Reject => "Reject",
+            UnknownCommandPolicy::CollectAndReport => "CollectAndReport",
+        }
+    }
+}
+
+/// Represents the results of parsing a string of move commands.
+pub struct ParsedMoves {
+    /// The move commands that were parsed, in the order they appeared.
+    pub commands: Vec<Box<dyn IMoveCommand>>,
+
+    /// The unrecognized characters that were skipped, each paired with its
+    /// (zero-based) byte position in the move string.  Only filled in when the
+    /// UnknownCommandPolicy::CollectAndReport policy is in effect.
+    pub unknown_commands: Vec<(usize, char)>,
+}
+
+//-----------------------------------------------------------------------------
+
 /// Represents the processor that translates the move list into a list of
 /// IMoveCommand objects then either displays them or executes them.
 /// 
@@ -19,23 +64,52 @@ use super::nullobject_movecommands::{MoveLeft, MoveRight, MoveUp, MoveDown, Move
 /// The process of executing the list of operations is an example of the
 /// "Command" pattern.  The parsing step is also an example of the
 /// "Interpreter" pattern, where the actions are the tokens to be interpreted.
-pub struct MoveProcessor { }
+pub struct MoveProcessor {
+    /// How unrecognized characters in the move string are handled.
+    policy: UnknownCommandPolicy,
+}
 
 impl MoveProcessor {
     /// Constructor
     ///
     /// # Returns
-    /// Returns a new instance of the MoveProcessor struct.
+    /// Returns a new instance of the MoveProcessor struct that uses the
+    /// UnknownCommandPolicy::NullObject policy.
     pub fn new() -> MoveProcessor {
-        MoveProcessor {}
+        MoveProcessor::with_policy(UnknownCommandPolicy::NullObject)
+    }
+
+    /// Constructor
+    ///
+    /// # Parameters
+    /// - policy
+    ///
+    ///   A value from the UnknownCommandPolicy enumeration specifying how
+    ///   unrecognized characters in the move string are handled.
+    ///
+    /// # Returns
+    /// Returns a new instance of the MoveProcessor struct.
+    pub fn with_policy(policy: UnknownCommandPolicy) -> MoveProcessor {
+        MoveProcessor { policy }
+    }
+
+    /// Retrieve the policy used for unrecognized characters.
+    pub fn policy(&self) -> UnknownCommandPolicy {
+        self.policy
     }
 
     /// Parse the given list of move commands, where each command is
     /// represented by a single character, to produce a list of move command
     /// objects.
     /// 
-    /// Recognizes 'U', 'D', 'L', and 'R' (case-insensitive).  All other
-    /// characters are assigned a "Do Nothing" (Null Object) command.
+    /// Recognizes 'U', 'D', 'L', and 'R' (case-insensitive).  What happens
+    /// to all other characters depends on the UnknownCommandPolicy given to
+    /// the constructor:
+    /// - NullObject: the character is assigned a "Do Nothing" (Null Object)
+    ///   command.
+    /// - Reject: parsing stops and an error is returned.
+    /// - CollectAndReport: the character is skipped and added to the list of
+    ///   unknown commands in the returned ParsedMoves.
     ///
     /// # Parameters
     /// - moves
@@ -43,21 +117,39 @@ impl MoveProcessor {
     ///   A string containing the move commands to parse.
     ///
     /// # Returns
-    /// Returns a list of the move commands, with each command represented by
-    /// the IMoveCommand trait.
-    pub fn parse(&self, moves: &str) -> Vec<Box<dyn IMoveCommand>> {
-        let mut commands = vec![];
-        for c in moves.to_uppercase().chars() {
+    /// Returns `Ok(ParsedMoves)` containing the list of the move commands,
+    /// with each command represented by the IMoveCommand trait.  Returns
+    /// `Err(String)` describing the first unrecognized character if the
+    /// policy is UnknownCommandPolicy::Reject.
+    pub fn parse(&self, moves: &str) -> Result<ParsedMoves, String> {
+        let mut parsed_moves = ParsedMoves {
+            commands: vec![],
+            unknown_commands: vec![],
+        };
+        // Only ASCII letters are commands, so uppercasing each character on
+        // its own keeps the positions in step with the original string.
+        // Unrecognized characters are reported as they appear in the string.
+        for (position, original) in moves.char_indices() {
+            let c = original.to_ascii_uppercase();
             let command = match c {
                 'L' => MoveLeft::new(&c.to_string()),
                 'R' => MoveRight::new(&c.to_string()),
                 'U' => MoveUp::new(&c.to_string()),
                 'D' => MoveDown::new(&c.to_string()),
-                _ => MoveNone::new(&c.to_string()),
+                _ => match self.policy {
+                    UnknownCommandPolicy::NullObject => MoveNone::new(&c.to_string()),
+                    UnknownCommandPolicy::Reject => {
+                        return Err(format!("Unrecognized move command '{original}' at position {position}"));
+                    }
+                    UnknownCommandPolicy::CollectAndReport => {
+                        parsed_moves.unknown_commands.push((position, original));
+                        continue;
+                    }
+                },
             };
-            commands.push(command);
+            parsed_moves.commands.push(command);
         }
-        commands
+        Ok(parsed_moves)
     }
 
     /// Display the given list of move commands.
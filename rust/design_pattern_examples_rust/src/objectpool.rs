@@ -0,0 +1,114 @@
+//! The Object Pool design pattern example module
+//!
+//! The Object Pool pattern keeps a set of objects that are expensive to
+//! create and hands them out for reuse instead of creating a new object each
+//! time one is needed.  When the user is done with an object, the object is
+//! returned to the pool, ready for the next user.
+//!
+//! In this exercise, the pool holds simulated database connections.  A
+//! connection is acquired from the pool and is automatically returned to the
+//! pool when it goes out of scope (the RAII idiom, implemented with the Drop
+//! trait).  The pool opens new connections only when no idle connection is
+//! available and refuses to open more than a set number of connections, in
+//! which case the pool is said to be exhausted.  The pool keeps statistics
+//! showing how often connections were reused.
+//!
+//! Accessed through the objectpool_exercise() function.
+
+//-----------------------------------------------------------------------------
+
+pub mod objectpool_connection;
+pub mod objectpool_pool;
+
+//-----------------------------------------------------------------------------
+
+use objectpool_pool::ConnectionPool;
+
+//-----------------------------------------------------------------------------
+
+/// Helper function to show the current state of the pool.
+///
+/// # Parameters
+/// - pool
+///
+///   The pool to show.
+fn _objectpool_show_pool(pool: &ConnectionPool) {
+    println!("    Pool: {} in use, {} available, {} maximum",
+        pool.in_use(), pool.available(), pool.max_size());
+}
+
+//-----------------------------------------------------------------------------
+
+/// Example of using the "Object Pool" design pattern.
+///
+/// The Object Pool pattern keeps a set of objects that are expensive to
+/// create and hands them out for reuse instead of creating a new object each
+/// time one is needed.  When the user is done with an object, the object is
+/// returned to the pool, ready for the next user.
+///
+/// In this exercise, the pool holds simulated database connections that are
+/// returned to the pool automatically when they go out of scope.  The pool
+/// refuses to open more than a set number of connections and keeps
+/// statistics showing how often connections were reused.
+// ! [Using ObjectPool in Rust]
+pub fn objectpool_exercise() -> Result<(), String> {
+    println!();
+    println!("ObjectPool Exercise");
+
+    let pool = ConnectionPool::new(3);
+    _objectpool_show_pool(&pool);
+
+    println!("  Two users acquire connections:");
+    let mut alice = pool.acquire("alice")?;
+    let mut bob = pool.acquire("bob")?;
+    println!("    {}", alice.query("SELECT * FROM orders"));
+    println!("    {}", bob.query("SELECT * FROM customers"));
+    _objectpool_show_pool(&pool);
+
+    println!("  Alice is done; her connection goes back to the pool:");
+    pool.release(alice);
+    _objectpool_show_pool(&pool);
+
+    println!("  Carol acquires a connection and gets the one Alice used:");
+    {
+        let mut carol = pool.acquire("carol")?;
+        println!("    {}", carol.query("UPDATE orders SET shipped = 1"));
+        println!("    Connection {} has now run {} queries", carol.id(), carol.queries_run());
+        _objectpool_show_pool(&pool);
+        println!("  Carol's connection goes out of scope:");
+    }
+    _objectpool_show_pool(&pool);
+
+    println!("  Many users acquire connections until the pool is exhausted:");
+    let mut borrowed = vec![];
+    for user in ["dave", "erin", "frank"] {
+        match pool.acquire(user) {
+            Ok(mut connection) => {
+                println!("    {}", connection.query("SELECT COUNT(*) FROM orders"));
+                borrowed.push(connection);
+            }
+            Err(message) => println!("    {user} could not get a connection: {message}"),
+        }
+    }
+    _objectpool_show_pool(&pool);
+
+    println!("  Everyone is done:");
+    borrowed.clear();
+    println!("    {}", bob.query("COMMIT"));
+    drop(bob);
+    _objectpool_show_pool(&pool);
+
+    let statistics = pool.statistics();
+    println!("  Pool statistics:");
+    println!("    Connections opened : {}", statistics.created);
+    println!("    Acquired           : {}", statistics.acquired);
+    println!("    Reused             : {}", statistics.reused);
+    println!("    Released           : {}", statistics.released);
+    println!("    Refused (exhausted): {}", statistics.exhausted);
+    println!("    Peak in use        : {}", statistics.peak_in_use);
+
+    println!("  Done.");
+
+    Ok(())
+}
+// ! [Using ObjectPool in Rust]
@@ -0,0 +1,74 @@
+//! Contains the Connection struct that represents an expensive object to
+//! create, in this case, a simulated connection to a database.
+
+//-----------------------------------------------------------------------------
+
+/// Represents a connection to a database.  Opening a connection is considered
+/// expensive so connections are kept in a ConnectionPool and reused instead
+/// of being opened each time one is needed.
+pub struct Connection {
+    /// Identifier of this connection, assigned when the connection is opened.
+    id: usize,
+    /// Number of queries run on this connection since it was opened.
+    queries_run: usize,
+    /// Name of the current user of the connection.  Cleared when the
+    /// connection is returned to the pool.
+    user: String,
+}
+
+impl Connection {
+    /// Constructor.  Opens a new connection.
+    ///
+    /// # Parameters
+    /// - id
+    ///
+    ///   Identifier for the new connection.
+    ///
+    /// # Returns
+    /// Returns a new instance of the Connection struct.
+    pub fn open(id: usize) -> Connection {
+        println!("      [connection {id} opened (expensive)]");
+        Connection { id, queries_run: 0, user: String::new() }
+    }
+
+    /// Retrieve the identifier of this connection.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Retrieve the number of queries run on this connection since it was
+    /// opened.
+    pub fn queries_run(&self) -> usize {
+        self.queries_run
+    }
+
+    /// Set the name of the current user of the connection.
+    ///
+    /// # Parameters
+    /// - user
+    ///
+    ///   Name of the user.
+    pub fn set_user(&mut self, user: &str) {
+        self.user = user.to_string();
+    }
+
+    /// Run a (simulated) query on the connection.
+    ///
+    /// # Parameters
+    /// - query
+    ///
+    ///   The query to run.
+    ///
+    /// # Returns
+    /// Returns a string describing the result of the query.
+    pub fn query(&mut self, query: &str) -> String {
+        self.queries_run += 1;
+        format!("connection {} ran \"{}\" for {}", self.id, query, self.user)
+    }
+
+    /// Clear any state left over from the previous user so the connection
+    /// can be safely handed to the next user.
+    pub fn reset(&mut self) {
+        self.user.clear();
+    }
+}
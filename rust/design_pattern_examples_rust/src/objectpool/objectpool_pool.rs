@@ -0,0 +1,194 @@
+//! Contains the ConnectionPool struct that holds reusable Connection objects,
+//! the PooledConnection struct that returns a Connection to the pool when it
+//! goes out of scope, and the PoolStatistics struct that describes how the
+//! pool has been used.
+
+//-----------------------------------------------------------------------------
+
+use std::cell::RefCell;
+use std::ops::{Deref, DerefMut};
+
+use super::objectpool_connection::Connection;
+
+//-----------------------------------------------------------------------------
+
+/// Represents the statistics gathered by a ConnectionPool.
+#[derive(Clone, Copy, Default)]
+pub struct PoolStatistics {
+    /// Number of connections opened by the pool.
+    pub created: usize,
+    /// Number of successful calls to acquire().
+    pub acquired: usize,
+    /// Number of successful calls to acquire() that reused a connection
+    /// instead of opening a new one.
+    pub reused: usize,
+    /// Number of connections returned to the pool.
+    pub released: usize,
+    /// Number of calls to acquire() that failed because the pool was
+    /// exhausted.
+    pub exhausted: usize,
+    /// Largest number of connections in use at the same time.
+    pub peak_in_use: usize,
+}
+
+/// The part of the ConnectionPool that changes as connections are acquired
+/// and released.
+struct PoolState {
+    /// Connections that are open but not in use.
+    available: Vec<Connection>,
+    /// Number of connections currently in use.
+    in_use: usize,
+    /// Statistics about the use of the pool.
+    statistics: PoolStatistics,
+}
+
+//-----------------------------------------------------------------------------
+
+/// Represents a pool of Connection objects.  Connections are opened only
+/// when no idle connection is available and only up to the maximum size of
+/// the pool.  Once opened, a connection is reused for as long as the pool
+/// exists.
+///
+/// Call acquire() to get a connection.  The connection is returned to the
+/// pool when the PooledConnection goes out of scope or is passed to
+/// release().
+pub struct ConnectionPool {
+    /// Maximum number of connections the pool will open.
+    max_size: usize,
+    /// The changing state of the pool.  A RefCell is used so a
+    /// PooledConnection can return its connection through a shared reference
+    /// to the pool.
+    state: RefCell<PoolState>,
+}
+
+impl ConnectionPool {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - max_size
+    ///
+    ///   Maximum number of connections the pool will open.
+    ///
+    /// # Returns
+    /// Returns a new instance of the ConnectionPool struct with no open
+    /// connections.
+    pub fn new(max_size: usize) -> ConnectionPool {
+        ConnectionPool {
+            max_size,
+            state: RefCell::new(PoolState {
+                available: vec![],
+                in_use: 0,
+                statistics: PoolStatistics::default(),
+            }),
+        }
+    }
+
+    /// Retrieve the maximum number of connections the pool will open.
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    /// Retrieve the number of connections currently in use.
+    pub fn in_use(&self) -> usize {
+        self.state.borrow().in_use
+    }
+
+    /// Retrieve the number of open connections waiting to be used.
+    pub fn available(&self) -> usize {
+        self.state.borrow().available.len()
+    }
+
+    /// Retrieve a copy of the statistics gathered so far.
+    pub fn statistics(&self) -> PoolStatistics {
+        self.state.borrow().statistics
+    }
+
+    /// Get a connection from the pool, opening a new one if no idle
+    /// connection is available and the pool is not yet full.
+    ///
+    /// # Parameters
+    /// - user
+    ///
+    ///   Name of the user of the connection.
+    ///
+    /// # Returns
+    /// Returns `Ok(PooledConnection)` if a connection is available;
+    /// otherwise, returns `Err(String)` if all connections are in use.
+    pub fn acquire(&self, user: &str) -> Result<PooledConnection<'_>, String> {
+        let mut state = self.state.borrow_mut();
+        let mut connection = match state.available.pop() {
+            Some(connection) => {
+                state.statistics.reused += 1;
+                connection
+            }
+            None => {
+                if state.in_use >= self.max_size {
+                    state.statistics.exhausted += 1;
+                    return Err(format!("Pool exhausted: all {} connections are in use", self.max_size));
+                }
+                state.statistics.created += 1;
+                Connection::open(state.statistics.created)
+            }
+        };
+        connection.set_user(user);
+        state.in_use += 1;
+        state.statistics.acquired += 1;
+        state.statistics.peak_in_use = state.statistics.peak_in_use.max(state.in_use);
+        Ok(PooledConnection { pool: self, connection: Some(connection) })
+    }
+
+    /// Return a connection to the pool.  This is the same as letting the
+    /// PooledConnection go out of scope but makes the intent obvious.
+    ///
+    /// # Parameters
+    /// - connection
+    ///
+    ///   The connection to return to the pool.
+    pub fn release(&self, connection: PooledConnection<'_>) {
+        drop(connection);
+    }
+
+    /// Put a connection back in the pool.  Called when a PooledConnection is
+    /// dropped.
+    fn return_connection(&self, mut connection: Connection) {
+        connection.reset();
+        let mut state = self.state.borrow_mut();
+        state.in_use -= 1;
+        state.statistics.released += 1;
+        state.available.push(connection);
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Represents a Connection on loan from a ConnectionPool.  Use it like a
+/// Connection; when it goes out of scope, the Connection is returned to the
+/// pool so it can be reused.
+pub struct PooledConnection<'a> {
+    /// The pool to return the connection to.
+    pool: &'a ConnectionPool,
+    /// The connection on loan.  Only None while being dropped.
+    connection: Option<Connection>,
+}
+
+impl Deref for PooledConnection<'_> {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        self.connection.as_ref().expect("connection is present until dropped")
+    }
+}
+
+impl DerefMut for PooledConnection<'_> {
+    fn deref_mut(&mut self) -> &mut Connection {
+        self.connection.as_mut().expect("connection is present until dropped")
+    }
+}
+
+impl Drop for PooledConnection<'_> {
+    fn drop(&mut self) {
+        if let Some(connection) = self.connection.take() {
+            self.pool.return_connection(connection);
+        }
+    }
+}
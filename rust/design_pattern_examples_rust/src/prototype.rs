@@ -0,0 +1,84 @@
+//! The Prototype design pattern example module
+//!
+//! The Prototype pattern creates new objects by copying (cloning) an existing,
+//! pre-configured object, called the prototype.  The code that creates the
+//! new object does not need to know the concrete type of the prototype or
+//! how the prototype was configured.
+//!
+//! In this exercise, a registry holds a number of pre-configured shapes, each
+//! under a name.  New shapes are created by asking the registry to clone a
+//! prototype by name.  The clones are then changed without affecting the
+//! prototypes.  Since a `Box<dyn Trait>` cannot be cloned directly, the shape
+//! trait provides a clone_box() method that each shape implements using
+//! Rust's Clone trait.
+//!
+//! Accessed through the prototype_exercise() function.
+
+//-----------------------------------------------------------------------------
+
+pub mod prototype_ishape_trait;
+pub mod prototype_shapes;
+pub mod prototype_registry;
+
+//-----------------------------------------------------------------------------
+
+use prototype_registry::PrototypeRegistry;
+use prototype_shapes::{Circle, Rectangle};
+
+//-----------------------------------------------------------------------------
+
+/// Example of using the "Prototype" design pattern.
+///
+/// The Prototype pattern creates new objects by copying (cloning) an existing,
+/// pre-configured object, called the prototype.  The code that creates the
+/// new object does not need to know the concrete type of the prototype or
+/// how the prototype was configured.
+///
+/// In this exercise, a registry holds a number of pre-configured shapes, each
+/// under a name.  New shapes are created by asking the registry to clone a
+/// prototype by name.  The clones are then changed without affecting the
+/// prototypes.
+// ! [Using Prototype in Rust]
+pub fn prototype_exercise() -> Result<(), String> {
+    println!();
+    println!("Prototype Exercise");
+
+    let mut registry = PrototypeRegistry::new();
+    registry.register("button", Rectangle::new("Button", 12, 3, '#'));
+    registry.register("checkbox", Rectangle::new("Checkbox", 3, 3, 'x'));
+    registry.register("marker", Circle::new("Marker", 1, '*'));
+    registry.register("spotlight", Circle::new("Spotlight", 6, 'o'));
+
+    println!("  Prototypes in the registry:");
+    registry.show_prototypes();
+
+    println!("  Cloning prototypes and changing the clones:");
+    let mut ok_button = registry.create("button")?;
+    ok_button.set_label("OK");
+    ok_button.move_to(2, 10);
+    let mut cancel_button = registry.create("button")?;
+    cancel_button.set_label("Cancel");
+    cancel_button.move_to(16, 10);
+    let mut marker = registry.create("marker")?;
+    marker.move_to(5, 5);
+    // A clone of a clone is just as good as a clone of the prototype.
+    let mut second_marker = marker.clone();
+    second_marker.move_to(8, 5);
+
+    for shape in [&ok_button, &cancel_button, &marker, &second_marker] {
+        println!("    {}", shape.describe());
+    }
+
+    println!("  Prototypes are unchanged:");
+    registry.show_prototypes();
+
+    println!("  Asking for a prototype that does not exist:");
+    if let Err(message) = registry.create("triangle") {
+        println!("    {message}");
+    }
+
+    println!("  Done.");
+
+    Ok(())
+}
+// ! [Using Prototype in Rust]
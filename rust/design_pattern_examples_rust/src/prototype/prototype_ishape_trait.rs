@@ -0,0 +1,44 @@
+//! Contains the IShape trait that represents the shapes that can be cloned
+//! in the "Prototype" design pattern example.
+
+/// Represents a shape that can be cloned.  Rust's Clone trait cannot be used
+/// on a trait object (`Box<dyn IShape>`) so this trait provides the
+/// clone_box() method instead, which each shape implements by calling its
+/// own clone() method.
+pub trait IShape {
+    /// Create a copy of this shape.
+    ///
+    /// # Returns
+    /// Returns a new copy of the shape as represented by the IShape trait.
+    fn clone_box(&self) -> Box<dyn IShape>;
+
+    /// Set the label shown for the shape.
+    ///
+    /// # Parameters
+    /// - label
+    ///
+    ///   The new label.
+    fn set_label(&mut self, label: &str);
+
+    /// Move the shape to the given position.
+    ///
+    /// # Parameters
+    /// - x
+    ///
+    ///   Column of the upper left corner of the shape.
+    /// - y
+    ///
+    ///   Row of the upper left corner of the shape.
+    fn move_to(&mut self, x: i32, y: i32);
+
+    /// Describe the shape, including all of its settings, on a single line.
+    fn describe(&self) -> String;
+}
+
+/// Allows a `Box<dyn IShape>` to be cloned with clone() as if it were any
+/// other cloneable type.
+impl Clone for Box<dyn IShape> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
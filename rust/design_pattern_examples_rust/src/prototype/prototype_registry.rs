@@ -0,0 +1,69 @@
+//! Contains the PrototypeRegistry struct that holds the prototypes and
+//! creates new shapes by cloning them.
+
+//-----------------------------------------------------------------------------
+
+use super::prototype_ishape_trait::IShape;
+
+//-----------------------------------------------------------------------------
+
+/// Represents a registry of prototype shapes, each stored under a name.  The
+/// registry owns the prototypes and only ever hands out clones of them.
+pub struct PrototypeRegistry {
+    /// The prototypes, as (name, prototype) pairs, in the order they were
+    /// registered.
+    prototypes: Vec<(String, Box<dyn IShape>)>,
+}
+
+impl PrototypeRegistry {
+    /// Constructor.
+    ///
+    /// # Returns
+    /// Returns a new, empty instance of the PrototypeRegistry struct.
+    pub fn new() -> PrototypeRegistry {
+        PrototypeRegistry { prototypes: vec![] }
+    }
+
+    /// Add a prototype to the registry under the given name.  If a prototype
+    /// already exists with that name, it is replaced.
+    ///
+    /// # Parameters
+    /// - name
+    ///
+    ///   The name used to ask for clones of the prototype.
+    /// - prototype
+    ///
+    ///   The pre-configured shape to use as the prototype.  The registry
+    ///   takes ownership of the shape.
+    pub fn register(&mut self, name: &str, prototype: Box<dyn IShape>) {
+        match self.prototypes.iter().position(|(entry_name, _)| entry_name == name) {
+            Some(index) => self.prototypes[index].1 = prototype,
+            None => self.prototypes.push((name.to_string(), prototype)),
+        }
+    }
+
+    /// Create a new shape by cloning the prototype with the given name.
+    ///
+    /// # Parameters
+    /// - name
+    ///
+    ///   Name of the prototype to clone.
+    ///
+    /// # Returns
+    /// Returns `Ok(Box<dyn IShape>)` containing the new shape if the
+    /// prototype was found; otherwise, returns `Err(String)` describing the
+    /// problem.
+    pub fn create(&self, name: &str) -> Result<Box<dyn IShape>, String> {
+        match self.prototypes.iter().find(|(entry_name, _)| entry_name == name) {
+            Some((_, prototype)) => Ok(prototype.clone_box()),
+            None => Err(format!("No prototype named '{name}' is registered")),
+        }
+    }
+
+    /// Display the name and description of every prototype in the registry.
+    pub fn show_prototypes(&self) {
+        for (name, prototype) in self.prototypes.iter() {
+            println!("    {name:<10} -> {}", prototype.describe());
+        }
+    }
+}
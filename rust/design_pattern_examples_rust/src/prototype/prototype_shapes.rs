@@ -0,0 +1,135 @@
+//! Contains the Circle and Rectangle structs that represent the shapes used
+//! as prototypes.
+
+//-----------------------------------------------------------------------------
+
+use super::prototype_ishape_trait::IShape;
+
+//-----------------------------------------------------------------------------
+
+/// Represents a circle.  Cloning a circle copies all of its settings.
+#[derive(Clone)]
+pub struct Circle {
+    /// The label shown for the circle.
+    label: String,
+    /// Radius of the circle, in characters.
+    radius: u32,
+    /// Character used to draw the circle.
+    fill_character: char,
+    /// Position of the upper left corner as (column, row).
+    position: (i32, i32),
+}
+
+impl Circle {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - label
+    ///
+    ///   The label shown for the circle.
+    /// - radius
+    ///
+    ///   Radius of the circle, in characters.
+    /// - fill_character
+    ///
+    ///   Character used to draw the circle.
+    ///
+    /// # Returns
+    /// Returns a new instance of the Circle struct as represented by the
+    /// IShape trait.
+    pub fn new(label: &str, radius: u32, fill_character: char) -> Box<dyn IShape> {
+        Box::new(Circle {
+            label: label.to_string(),
+            radius,
+            fill_character,
+            position: (0, 0),
+        })
+    }
+}
+
+impl IShape for Circle {
+    fn clone_box(&self) -> Box<dyn IShape> {
+        Box::new(self.clone())
+    }
+
+    fn set_label(&mut self, label: &str) {
+        self.label = label.to_string();
+    }
+
+    fn move_to(&mut self, x: i32, y: i32) {
+        self.position = (x, y);
+    }
+
+    fn describe(&self) -> String {
+        format!("Circle    '{0}' radius={1} fill='{2}' at ({3},{4})",
+            self.label, self.radius, self.fill_character, self.position.0, self.position.1)
+    }
+}
+
+//#############################################################################
+//#############################################################################
+
+/// Represents a rectangle.  Cloning a rectangle copies all of its settings.
+#[derive(Clone)]
+pub struct Rectangle {
+    /// The label shown for the rectangle.
+    label: String,
+    /// Width of the rectangle, in characters.
+    width: u32,
+    /// Height of the rectangle, in characters.
+    height: u32,
+    /// Character used to draw the rectangle.
+    fill_character: char,
+    /// Position of the upper left corner as (column, row).
+    position: (i32, i32),
+}
+
+impl Rectangle {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - label
+    ///
+    ///   The label shown for the rectangle.
+    /// - width
+    ///
+    ///   Width of the rectangle, in characters.
+    /// - height
+    ///
+    ///   Height of the rectangle, in characters.
+    /// - fill_character
+    ///
+    ///   Character used to draw the rectangle.
+    ///
+    /// # Returns
+    /// Returns a new instance of the Rectangle struct as represented by the
+    /// IShape trait.
+    pub fn new(label: &str, width: u32, height: u32, fill_character: char) -> Box<dyn IShape> {
+        Box::new(Rectangle {
+            label: label.to_string(),
+            width,
+            height,
+            fill_character,
+            position: (0, 0),
+        })
+    }
+}
+
+impl IShape for Rectangle {
+    fn clone_box(&self) -> Box<dyn IShape> {
+        Box::new(self.clone())
+    }
+
+    fn set_label(&mut self, label: &str) {
+        self.label = label.to_string();
+    }
+
+    fn move_to(&mut self, x: i32, y: i32) {
+        self.position = (x, y);
+    }
+
+    fn describe(&self) -> String {
+        format!("Rectangle '{0}' size={1}x{2} fill='{3}' at ({4},{5})",
+            self.label, self.width, self.height, self.fill_character, self.position.0, self.position.1)
+    }
+}
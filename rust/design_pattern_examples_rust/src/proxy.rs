@@ -10,6 +10,11 @@
 //! is actually done (in this case, a munged string with the text
 //! "Real struct received 'xxxx'".
 //!
+//! A second proxy, the CachingProxyEntity, adds a size-bounded cache in front
+//! of the real struct.  Results for repeated arguments come from the cache
+//! instead of the real struct and the least recently used result is evicted
+//! when the cache is full.
+//!
 //! Accessed through the proxy_exercise() function.
 
 //-----------------------------------------------------------------------------
@@ -17,10 +22,14 @@
 pub mod proxy_real;
 pub mod proxy_proxy;
 pub mod proxy_iworkbyproxy_trait;
+pub mod proxy_lrucache;
+pub mod proxy_cachingproxy;
 
 //-----------------------------------------------------------------------------
 
 use proxy_proxy::ProxyEntity;
+use proxy_cachingproxy::CachingProxyEntity;
+use proxy_iworkbyproxy_trait::IWorkByProxy;
 
 //-----------------------------------------------------------------------------
 
@@ -39,6 +48,10 @@ use proxy_proxy::ProxyEntity;
 /// The only difference in output here is one additional line for the
 /// first call showing the real struct being instantiated.  The subsequent
 /// calls into the Proxy struct do not show this line.
+///
+/// The caching proxy is then given more distinct requests than its cache
+/// can hold so some results are evicted.  Afterwards, the cache contents,
+/// the evictions, and the hit ratio are shown.
 // ! [Using Proxy in Rust]
 pub fn proxy_exercise() -> Result<(), String> {
     println!("");
@@ -59,6 +72,24 @@ pub fn proxy_exercise() -> Result<(), String> {
     output = proxy.do_work("Third call");
     println!("  Output from proxy = \"{0}\"", output);
 
+    println!("  Getting caching proxy object...");
+    let mut caching_proxy = CachingProxyEntity::new(3);
+    println!("  Caching proxy holds up to {0} entries", caching_proxy.cache().max_entries());
+    let requests = ["alpha", "beta", "alpha", "gamma", "delta", "beta", "alpha", "delta", "epsilon", "delta"];
+    for request in requests {
+        println!("  Calling do_work(\"{request}\") on caching proxy...");
+        output = caching_proxy.do_work(request);
+        println!("  Output from caching proxy = \"{0}\"", output);
+    }
+
+    let cache = caching_proxy.cache();
+    println!("  Cache contents (most recently used first): {:?}", cache.keys());
+    println!("  Evictions:");
+    for message in caching_proxy.eviction_log() {
+        println!("    {message}");
+    }
+    println!("  Hits = {0}, misses = {1}, hit ratio = {2:.2}", cache.hits(), cache.misses(), cache.hit_ratio());
+
     println!("  Done.");
 
     Ok(())
@@ -0,0 +1,89 @@
+//! Contains the CachingProxyEntity struct that locally represents a (possibly
+//! remote) real entity and caches the results of calls made on the real
+//! entity so repeated calls do not need to go to the real entity.
+
+//-----------------------------------------------------------------------------
+
+use super::proxy_iworkbyproxy_trait::IWorkByProxy;
+use super::proxy_lrucache::LruCache;
+use super::proxy_real::RealEntity;
+
+//-----------------------------------------------------------------------------
+
+/// A proxy entity that implements the IWorkByProxy trait and forwards calls
+/// on that trait to a real entity only if the result for the given argument
+/// is not already in the proxy's cache.  The real entity is instantiated when
+/// it is first needed.
+///
+/// The cache holds a limited number of results.  When the cache is full, the
+/// least recently used result is evicted and a message describing the
+/// eviction is added to an eviction log.
+pub struct CachingProxyEntity {
+    /// The one and only instance of the real entity associated with this
+    /// proxy entity.  Initialized with None so it can be filled in later with
+    /// a pointer to the actual real entity instance.
+    real_entity: Option<Box<dyn IWorkByProxy>>,
+    /// The cache of results from the real entity, keyed by argument.
+    cache: LruCache,
+    /// A message for each eviction from the cache, in the order the evictions
+    /// occurred.
+    eviction_log: Vec<String>,
+}
+
+impl CachingProxyEntity {
+    /// Constructor
+    ///
+    /// # Parameters
+    /// - max_entries
+    ///
+    ///   The maximum number of results the proxy can cache.
+    ///
+    /// # Returns
+    /// Returns a new instance of the CachingProxyEntity struct.
+    pub fn new(max_entries: usize) -> CachingProxyEntity {
+        CachingProxyEntity {
+            real_entity: None,
+            cache: LruCache::new(max_entries),
+            eviction_log: vec![],
+        }
+    }
+
+    /// Helper method to retrieve the one and only instance of the
+    /// real entity.  This hides the details of instantiating the real
+    /// entity and enforces a "singleton" nature on the instance.
+    ///
+    /// # Returns
+    /// Returns a reference to the real entity as implemented by the
+    /// IWorkByProxy trait.
+    fn get_real_entity(&mut self) -> &mut Box<dyn IWorkByProxy> {
+        self.real_entity.get_or_insert_with(RealEntity::new)
+    }
+
+    /// Retrieve the cache used by this proxy, for reporting the contents and
+    /// hit statistics.
+    pub fn cache(&self) -> &LruCache {
+        &self.cache
+    }
+
+    /// Retrieve the list of eviction messages, oldest first.
+    pub fn eviction_log(&self) -> &[String] {
+        &self.eviction_log
+    }
+}
+
+impl IWorkByProxy for CachingProxyEntity {
+    fn do_work(&mut self, some_argument: &str) -> String {
+        if let Some(cached_result) = self.cache.get(some_argument) {
+            println!("  --> caching proxy: cache hit for '{some_argument}'");
+            return cached_result;
+        }
+        println!("  --> caching proxy: cache miss for '{some_argument}', forwarding to real entity...");
+        let result = self.get_real_entity().do_work(some_argument);
+        if let Some((evicted_key, _)) = self.cache.put(some_argument, &result) {
+            let message = format!("evicted '{evicted_key}' to make room for '{some_argument}'");
+            println!("  --> caching proxy: {message}");
+            self.eviction_log.push(message);
+        }
+        result
+    }
+}
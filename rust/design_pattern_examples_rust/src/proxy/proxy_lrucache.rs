@@ -0,0 +1,137 @@
+//! Contains the LruCache struct, a size-bounded cache that evicts the least
+//! recently used entry when it is full.  Used by the CachingProxyEntity.
+
+//-----------------------------------------------------------------------------
+
+use std::collections::VecDeque;
+
+//-----------------------------------------------------------------------------
+
+/// Represents a cache of string results, keyed by the string argument that
+/// produced the result.  The cache holds at most a fixed number of entries;
+/// when a new entry is added to a full cache, the least recently used entry
+/// is evicted to make room.
+///
+/// The entries are kept in order of use, with the most recently used entry at
+/// the front of the list.  A linear search is used to find an entry, which is
+/// fine for the small caches used in this example.
+pub struct LruCache {
+    /// Maximum number of entries the cache can hold.
+    max_entries: usize,
+    /// The entries in the cache as (key, value) pairs, most recently used
+    /// first.
+    entries: VecDeque<(String, String)>,
+    /// Number of lookups that found an entry in the cache.
+    hits: usize,
+    /// Number of lookups that did not find an entry in the cache.
+    misses: usize,
+}
+
+impl LruCache {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - max_entries
+    ///
+    ///   The maximum number of entries the cache can hold.  A value of 0 is
+    ///   treated as 1.
+    ///
+    /// # Returns
+    /// Returns a new instance of the LruCache struct.
+    pub fn new(max_entries: usize) -> LruCache {
+        LruCache {
+            max_entries: max_entries.max(1),
+            entries: VecDeque::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Look up the value for the given key.  If found, the entry becomes the
+    /// most recently used entry.  The lookup is counted as a hit or a miss.
+    ///
+    /// # Parameters
+    /// - key
+    ///
+    ///   The key to look for.
+    ///
+    /// # Returns
+    /// Returns Some(String) containing a copy of the cached value if the key
+    /// was found; otherwise, returns None.
+    pub fn get(&mut self, key: &str) -> Option<String> {
+        match self.entries.iter().position(|(entry_key, _)| entry_key == key) {
+            Some(index) => {
+                self.hits += 1;
+                let entry = self.entries.remove(index).unwrap();
+                let value = entry.1.clone();
+                self.entries.push_front(entry);
+                Some(value)
+            }
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Add a value to the cache as the most recently used entry.  If the key
+    /// is already in the cache, its value is replaced.  If the cache is full,
+    /// the least recently used entry is evicted first.
+    ///
+    /// # Parameters
+    /// - key
+    ///
+    ///   The key to associate with the value.
+    /// - value
+    ///
+    ///   The value to cache.
+    ///
+    /// # Returns
+    /// Returns Some((String, String)) containing the evicted key and value if
+    /// an entry had to be evicted; otherwise, returns None.
+    pub fn put(&mut self, key: &str, value: &str) -> Option<(String, String)> {
+        let mut evicted = None;
+        if let Some(index) = self.entries.iter().position(|(entry_key, _)| entry_key == key) {
+            self.entries.remove(index);
+        } else if self.entries.len() >= self.max_entries {
+            evicted = self.entries.pop_back();
+        }
+        self.entries.push_front((key.to_string(), value.to_string()));
+        evicted
+    }
+
+    /// Retrieve the maximum number of entries the cache can hold.
+    pub fn max_entries(&self) -> usize {
+        self.max_entries
+    }
+
+    /// Retrieve the keys of the entries in the cache, most recently used
+    /// first.
+    pub fn keys(&self) -> Vec<&str> {
+        self.entries.iter().map(|(key, _)| key.as_str()).collect()
+    }
+
+    /// Retrieve the number of lookups that were hits.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Retrieve the number of lookups that were misses.
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Retrieve the ratio of hits to total lookups.
+    ///
+    /// # Returns
+    /// Returns a value from 0.0 to 1.0.  Returns 0.0 if there have been no
+    /// lookups.
+    pub fn hit_ratio(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! The Publish/Subscribe design pattern example module
+//!
+//! The Publish/Subscribe pattern decouples the producers of messages from the
+//! consumers of those messages by putting an event bus between them.
+//! Producers publish messages to a named topic on the bus; consumers
+//! subscribe to the topics they are interested in.  Neither side knows about
+//! the other.  This differs from the Observer pattern, where each observer
+//! subscribes directly to a specific subject.
+//!
+//! In this exercise, the topics are typed: each topic carries one type of
+//! message, so a subscriber always receives the type of message it expects.
+//! An order desk and a warehouse publish messages about orders and stock
+//! levels; a shipping department, a purchasing department and an audit log
+//! receive them.  Partway through, the shipping department unsubscribes.
+//!
+//! Accessed through the pubsub_exercise() function.
+
+//-----------------------------------------------------------------------------
+
+pub mod pubsub_eventbus;
+pub mod pubsub_topic;
+
+//-----------------------------------------------------------------------------
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use pubsub_eventbus::EventBus;
+use pubsub_topic::{OrderPlaced, StockLow, Topic, ORDERS_TOPIC, STOCK_TOPIC};
+
+//-----------------------------------------------------------------------------
+
+/// Helper function to publish a message and show how many subscribers
+/// received it.
+///
+/// # Parameters
+/// - bus
+///
+///   The EventBus to publish on.
+/// - producer
+///
+///   Name of the producer publishing the message, for display.
+/// - topic
+///
+///   The topic to publish to.
+/// - message
+///
+///   The message to publish.
+fn _pubsub_publish<T: 'static>(bus: &EventBus, producer: &str, topic: &Topic<T>, message: &T) {
+    println!("    {producer} publishes to '{}'", topic.name());
+    let delivered = bus.publish(topic, message);
+    println!("      (delivered to {delivered} subscriber(s))");
+}
+
+//-----------------------------------------------------------------------------
+
+/// Example of using the "Publish/Subscribe" design pattern.
+///
+/// The Publish/Subscribe pattern decouples the producers of messages from the
+/// consumers of those messages by putting an event bus between them.
+/// Producers publish messages to a named topic on the bus; consumers
+/// subscribe to the topics they are interested in.  Neither side knows about
+/// the other.
+///
+/// In this exercise, an order desk and a warehouse publish messages about
+/// orders and stock levels on typed topics; a shipping department, a
+/// purchasing department and an audit log receive them.  Partway through,
+/// the shipping department unsubscribes.
+// ! [Using PubSub in Rust]
+pub fn pubsub_exercise() -> Result<(), String> {
+    println!();
+    println!("PubSub Exercise");
+
+    let mut bus = EventBus::new();
+    let audit_log: Rc<RefCell<Vec<String>>> = Rc::new(RefCell::new(vec![]));
+
+    println!("  Subscribing consumers:");
+    let shipping = bus.subscribe(&ORDERS_TOPIC, |order: &OrderPlaced| {
+        println!("      Shipping: pack {} x {} for order {}", order.quantity, order.item, order.order_id);
+    })?;
+    println!("    Shipping subscribed to '{}'", ORDERS_TOPIC.name());
+    bus.subscribe(&STOCK_TOPIC, |stock: &StockLow| {
+        println!("      Purchasing: reorder {} ({} left)", stock.item, stock.remaining);
+    })?;
+    println!("    Purchasing subscribed to '{}'", STOCK_TOPIC.name());
+    let log = audit_log.clone();
+    bus.subscribe(&ORDERS_TOPIC, move |order: &OrderPlaced| {
+        log.borrow_mut().push(format!("order {} placed", order.order_id));
+    })?;
+    let log = audit_log.clone();
+    bus.subscribe(&STOCK_TOPIC, move |stock: &StockLow| {
+        log.borrow_mut().push(format!("{} low", stock.item));
+    })?;
+    println!("    Audit log subscribed to '{}' and '{}'", ORDERS_TOPIC.name(), STOCK_TOPIC.name());
+
+    println!("  Producers publishing messages:");
+    _pubsub_publish(&bus, "Order desk", &ORDERS_TOPIC,
+        &OrderPlaced { order_id: 1001, item: String::from("widget"), quantity: 3 });
+    _pubsub_publish(&bus, "Warehouse", &STOCK_TOPIC,
+        &StockLow { item: String::from("widget"), remaining: 2 });
+
+    println!("  Shipping unsubscribes:");
+    let removed = bus.unsubscribe(shipping);
+    println!("    Unsubscribed: {removed}");
+    _pubsub_publish(&bus, "Order desk", &ORDERS_TOPIC,
+        &OrderPlaced { order_id: 1002, item: String::from("gadget"), quantity: 1 });
+
+    println!("  Publishing to a topic with no subscribers:");
+    let returns_topic: Topic<OrderPlaced> = Topic::new("returns");
+    _pubsub_publish(&bus, "Order desk", &returns_topic,
+        &OrderPlaced { order_id: 1001, item: String::from("widget"), quantity: 1 });
+
+    println!("  Subscribing to a topic name with the wrong message type:");
+    let wrong_topic: Topic<StockLow> = Topic::new("orders");
+    if let Err(message) = bus.subscribe(&wrong_topic, |_stock: &StockLow| {}) {
+        println!("    {message}");
+    }
+
+    println!("  Audit log:");
+    for entry in audit_log.borrow().iter() {
+        println!("    {entry}");
+    }
+
+    println!("  Done.");
+
+    Ok(())
+}
+// ! [Using PubSub in Rust]
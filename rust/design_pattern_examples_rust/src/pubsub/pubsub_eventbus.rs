@@ -0,0 +1,133 @@
+//! Contains the EventBus struct that delivers messages published to a topic
+//! to every subscriber of that topic.
+
+//-----------------------------------------------------------------------------
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+
+use super::pubsub_topic::Topic;
+
+//-----------------------------------------------------------------------------
+
+/// Identifies a subscription so it can be removed with
+/// EventBus::unsubscribe().
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SubscriptionId(usize);
+
+/// A subscriber's handler, wrapped so handlers for any message type can be
+/// stored together.  The wrapper recovers the message type before calling
+/// the subscriber's handler.
+type MessageHandler = Box<dyn Fn(&dyn Any)>;
+
+/// Represents the subscribers to one topic.
+struct TopicSubscribers {
+    /// The type of message published on the topic.
+    message_type: TypeId,
+    /// The subscribers, in the order they subscribed.
+    handlers: Vec<(SubscriptionId, MessageHandler)>,
+}
+
+//-----------------------------------------------------------------------------
+
+/// Represents an event bus.  Producers publish messages to a topic on the
+/// bus without knowing who, if anyone, receives them; consumers subscribe to
+/// the topics they care about without knowing who publishes to them.
+///
+/// This differs from the Observer pattern in that neither side holds a
+/// reference to the other; the bus sits between them.
+pub struct EventBus {
+    /// The subscribers to each topic, keyed by topic name.
+    topics: HashMap<&'static str, TopicSubscribers>,
+    /// Identifier to give to the next subscription.
+    next_subscription_id: usize,
+}
+
+impl EventBus {
+    /// Constructor.
+    ///
+    /// # Returns
+    /// Returns a new instance of the EventBus struct with no subscribers.
+    pub fn new() -> EventBus {
+        EventBus { topics: HashMap::new(), next_subscription_id: 1 }
+    }
+
+    /// Subscribe to the given topic.
+    ///
+    /// # Parameters
+    /// - topic
+    ///
+    ///   The topic to subscribe to.
+    /// - handler
+    ///
+    ///   Function to call with each message published to the topic.
+    ///
+    /// # Returns
+    /// Returns `Ok(SubscriptionId)` identifying the subscription; otherwise,
+    /// returns `Err(String)` if a topic with the same name but a different
+    /// message type is already in use.
+    pub fn subscribe<T: 'static>(&mut self, topic: &Topic<T>, handler: impl Fn(&T) + 'static) -> Result<SubscriptionId, String> {
+        let subscribers = self.topics.entry(topic.name()).or_insert_with(|| TopicSubscribers {
+            message_type: TypeId::of::<T>(),
+            handlers: vec![],
+        });
+        if subscribers.message_type != TypeId::of::<T>() {
+            return Err(format!("Topic '{}' is already in use with a different message type", topic.name()));
+        }
+        let id = SubscriptionId(self.next_subscription_id);
+        self.next_subscription_id += 1;
+        let wrapped_handler: MessageHandler = Box::new(move |message: &dyn Any| {
+            if let Some(message) = message.downcast_ref::<T>() {
+                handler(message);
+            }
+        });
+        subscribers.handlers.push((id, wrapped_handler));
+        Ok(id)
+    }
+
+    /// Remove the given subscription.  The subscriber receives no further
+    /// messages.
+    ///
+    /// # Parameters
+    /// - id
+    ///
+    ///   The subscription to remove, as returned from subscribe().
+    ///
+    /// # Returns
+    /// Returns true if the subscription was removed; otherwise, returns false
+    /// if there was no such subscription.
+    pub fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+        for subscribers in self.topics.values_mut() {
+            if let Some(index) = subscribers.handlers.iter().position(|(entry_id, _)| *entry_id == id) {
+                drop(subscribers.handlers.remove(index));
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Publish a message to the given topic.  Every subscriber to the topic
+    /// receives the message, in the order they subscribed.
+    ///
+    /// # Parameters
+    /// - topic
+    ///
+    ///   The topic to publish to.
+    /// - message
+    ///
+    ///   The message to publish.
+    ///
+    /// # Returns
+    /// Returns the number of subscribers that received the message.
+    pub fn publish<T: 'static>(&self, topic: &Topic<T>, message: &T) -> usize {
+        match self.topics.get(topic.name()) {
+            Some(subscribers) if subscribers.message_type == TypeId::of::<T>() => {
+                for (_, handler) in subscribers.handlers.iter() {
+                    handler(message);
+                }
+                subscribers.handlers.len()
+            }
+            _ => 0,
+        }
+    }
+}
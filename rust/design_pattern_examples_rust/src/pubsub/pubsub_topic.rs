@@ -0,0 +1,65 @@
+//! Contains the Topic struct that names a topic on the event bus and fixes
+//! the type of message published on it, along with the messages and topics
+//! used in the exercise.
+
+//-----------------------------------------------------------------------------
+
+use std::marker::PhantomData;
+
+//-----------------------------------------------------------------------------
+
+/// Represents a topic on the EventBus.  A topic has a name and a message
+/// type; only messages of that type can be published to the topic and every
+/// subscriber to the topic receives messages of that type.
+pub struct Topic<T> {
+    /// Name of the topic.
+    name: &'static str,
+    /// Ties the message type to the topic without storing a message.
+    message_type: PhantomData<fn(&T)>,
+}
+
+impl<T> Topic<T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - name
+    ///
+    ///   Name of the topic.
+    ///
+    /// # Returns
+    /// Returns a new instance of the Topic struct.
+    pub const fn new(name: &'static str) -> Topic<T> {
+        Topic { name, message_type: PhantomData }
+    }
+
+    /// Retrieve the name of the topic.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Message published when an order is placed.
+pub struct OrderPlaced {
+    /// Identifier of the order.
+    pub order_id: u32,
+    /// Name of the item ordered.
+    pub item: String,
+    /// Number of items ordered.
+    pub quantity: u32,
+}
+
+/// Message published when the stock of an item runs low.
+pub struct StockLow {
+    /// Name of the item.
+    pub item: String,
+    /// Number of items remaining in stock.
+    pub remaining: u32,
+}
+
+/// Topic on which OrderPlaced messages are published.
+pub const ORDERS_TOPIC: Topic<OrderPlaced> = Topic::new("orders");
+
+/// Topic on which StockLow messages are published.
+pub const STOCK_TOPIC: Topic<StockLow> = Topic::new("stock");
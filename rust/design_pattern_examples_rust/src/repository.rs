@@ -0,0 +1,145 @@
+//! The Repository design pattern example module
+//!
+//! The Repository pattern puts a collection-like interface in front of the
+//! storage of some kind of item.  Code using the repository adds, retrieves,
+//! updates, removes and queries items without knowing how or where they are
+//! stored, so the storage can be changed without changing that code.
+//!
+//! In this exercise, the EntryInformation records from the Strategy example
+//! are stored in two repositories that implement the same IRepository
+//! trait: one keeps the records in memory and the other keeps them in a text
+//! file.  The same code is run against both repositories.  The file-backed
+//! repository is then reopened to show the records were kept in the file.
+//!
+//! Accessed through the repository_exercise() function.
+
+//-----------------------------------------------------------------------------
+
+pub mod repository_filebacked;
+pub mod repository_inmemory;
+pub mod repository_irepository_trait;
+
+//-----------------------------------------------------------------------------
+
+use std::env;
+use std::fs;
+
+use crate::strategy::create_entries;
+use crate::strategy::strategy_entryinformation::EntryInformation;
+use repository_filebacked::{FileRepository, IRecordFormat};
+use repository_inmemory::InMemoryRepository;
+use repository_irepository_trait::{IRepository, RecordId};
+
+//-----------------------------------------------------------------------------
+
+/// Name of the file used by the file-backed repository, in the temporary
+/// directory.
+const REPOSITORY_FILENAME: &str = "repository_entries.txt";
+
+//-----------------------------------------------------------------------------
+
+impl IRecordFormat for EntryInformation {
+    fn to_record(&self) -> String {
+        format!("{}\t{}\t{}", self.name, self.age, self.height)
+    }
+
+    fn from_record(record: &str) -> Result<EntryInformation, String> {
+        let fields: Vec<&str> = record.split('\t').collect();
+        if fields.len() != 3 {
+            return Err(format!("expected 3 fields but found {}", fields.len()));
+        }
+        let age = fields[1].parse::<i32>().map_err(|_| format!("bad age '{}'", fields[1]))?;
+        let height = fields[2].parse::<i32>().map_err(|_| format!("bad height '{}'", fields[2]))?;
+        Ok(EntryInformation::new(fields[0], age, height))
+    }
+}
+
+/// Helper function to show a list of records.
+///
+/// # Parameters
+/// - records
+///
+///   The (identifier, record) pairs to show.
+fn _repository_show_records(records: &[(RecordId, EntryInformation)]) {
+    for (id, entry) in records.iter() {
+        println!("      {id:2}: {entry}");
+    }
+}
+
+/// Helper function to run the same operations against any repository of
+/// EntryInformation records.
+///
+/// # Parameters
+/// - repository
+///
+///   The repository to use.
+///
+/// # Returns
+/// Returns `Ok(())` if all operations succeeded; otherwise, returns
+/// `Err(String)` describing the problem.
+fn _repository_demonstrate(repository: &mut dyn IRepository<EntryInformation>) -> Result<(), String> {
+    println!("  Using a repository stored in {}:", repository.describe());
+    let mut ids = vec![];
+    for entry in create_entries() {
+        ids.push(repository.add(entry)?);
+    }
+    println!("    Added {} records:", ids.len());
+    _repository_show_records(&repository.get_all());
+
+    println!("    Records for individuals older than 20:");
+    _repository_show_records(&repository.find(&|entry| entry.age > 20));
+
+    let birthday_id = ids[0];
+    if let Some(mut entry) = repository.get(birthday_id) {
+        entry.age += 1;
+        println!("    Updating record {birthday_id}: {} has a birthday", entry.name);
+        repository.update(birthday_id, entry)?;
+    }
+
+    let removed = repository.remove(ids[2])?;
+    println!("    Removed record {}: {}", ids[2], removed.name);
+    if let Err(message) = repository.remove(ids[2]) {
+        println!("    Removing record {} again: {message}", ids[2]);
+    }
+
+    println!("    Remaining records:");
+    _repository_show_records(&repository.get_all());
+    Ok(())
+}
+
+//-----------------------------------------------------------------------------
+
+/// Example of using the "Repository" design pattern.
+///
+/// The Repository pattern puts a collection-like interface in front of the
+/// storage of some kind of item.  Code using the repository adds, retrieves,
+/// updates, removes and queries items without knowing how or where they are
+/// stored.
+///
+/// In this exercise, the same code stores and queries EntryInformation
+/// records in an in-memory repository and in a file-backed repository.
+// ! [Using Repository in Rust]
+pub fn repository_exercise() -> Result<(), String> {
+    println!();
+    println!("Repository Exercise");
+
+    let mut memory_repository = InMemoryRepository::new();
+    _repository_demonstrate(&mut memory_repository)?;
+
+    let repository_path = env::temp_dir().join(REPOSITORY_FILENAME);
+    let repository_path = repository_path.to_string_lossy();
+    {
+        let mut file_repository = FileRepository::create(&repository_path)?;
+        _repository_demonstrate(&mut file_repository)?;
+    }
+
+    println!("  Reopening the repository stored in the file:");
+    let file_repository: FileRepository<EntryInformation> = FileRepository::open(&repository_path)?;
+    _repository_show_records(&file_repository.get_all());
+    let _ = fs::remove_file(&*repository_path);
+
+    println!("  Done.");
+
+    Ok(())
+}
+// ! [Using Repository in Rust]
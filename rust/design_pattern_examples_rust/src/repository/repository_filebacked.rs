@@ -0,0 +1,135 @@
+//! Contains the FileRepository struct that stores items in a text file, and
+//! the IRecordFormat trait that items must implement to be stored there.
+
+//-----------------------------------------------------------------------------
+
+use std::fs;
+
+use super::repository_irepository_trait::{IRepository, RecordId};
+
+//-----------------------------------------------------------------------------
+
+/// Represents the ability to convert an item to and from a single line of
+/// text so it can be stored in a FileRepository.
+pub trait IRecordFormat: Sized {
+    /// Convert the item to a single line of text.  The text must not contain
+    /// any newlines.
+    fn to_record(&self) -> String;
+
+    /// Create an item from a line of text created by to_record().
+    ///
+    /// # Parameters
+    /// - record
+    ///
+    ///   The line of text.
+    ///
+    /// # Returns
+    /// Returns `Ok(Self)` containing the item; otherwise, returns
+    /// `Err(String)` if the text could not be understood.
+    fn from_record(record: &str) -> Result<Self, String>;
+}
+
+//-----------------------------------------------------------------------------
+
+/// Represents a repository that stores items in a text file, one item per
+/// line, with each line starting with the item's identifier.  Every change
+/// is written to the file immediately so the items outlive the repository.
+pub struct FileRepository<T> {
+    /// Path to the file holding the items.
+    path: String,
+    /// The items, as read from the file.
+    items: Vec<(RecordId, T)>,
+}
+
+impl<T: IRecordFormat + Clone> FileRepository<T> {
+    /// Create a new, empty repository, replacing the file if it exists.
+    ///
+    /// # Parameters
+    /// - path
+    ///
+    ///   Path to the file in which to store the items.
+    ///
+    /// # Returns
+    /// Returns `Ok(FileRepository)` if the file could be created; otherwise,
+    /// returns `Err(String)` describing the problem.
+    pub fn create(path: &str) -> Result<FileRepository<T>, String> {
+        let repository = FileRepository { path: path.to_string(), items: vec![] };
+        repository._save()?;
+        Ok(repository)
+    }
+
+    /// Open an existing repository, reading the items from the file.
+    ///
+    /// # Parameters
+    /// - path
+    ///
+    ///   Path to the file holding the items.
+    ///
+    /// # Returns
+    /// Returns `Ok(FileRepository)` if the file could be read; otherwise,
+    /// returns `Err(String)` describing the problem.
+    pub fn open(path: &str) -> Result<FileRepository<T>, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|error| format!("Unable to read \"{path}\": {error}"))?;
+        let mut items = vec![];
+        for (line_index, line) in contents.lines().enumerate() {
+            let (id, record) = line.split_once('\t')
+                .ok_or_else(|| format!("\"{path}\" line {}: missing record id", line_index + 1))?;
+            let id: RecordId = id.parse()
+                .map_err(|_| format!("\"{path}\" line {}: bad record id '{id}'", line_index + 1))?;
+            let item = T::from_record(record)
+                .map_err(|message| format!("\"{path}\" line {}: {message}", line_index + 1))?;
+            items.push((id, item));
+        }
+        Ok(FileRepository { path: path.to_string(), items })
+    }
+
+    /// Write all items to the file.
+    fn _save(&self) -> Result<(), String> {
+        let contents: String = self.items.iter()
+            .map(|(id, item)| format!("{id}\t{}\n", item.to_record()))
+            .collect();
+        fs::write(&self.path, contents)
+            .map_err(|error| format!("Unable to write \"{}\": {error}", self.path))
+    }
+}
+
+impl<T: IRecordFormat + Clone> IRepository<T> for FileRepository<T> {
+    fn describe(&self) -> String {
+        format!("file \"{}\"", self.path)
+    }
+
+    fn add(&mut self, item: T) -> Result<RecordId, String> {
+        let id = self.items.iter().map(|(item_id, _)| *item_id).max().unwrap_or(0) + 1;
+        self.items.push((id, item));
+        self._save()?;
+        Ok(id)
+    }
+
+    fn get(&self, id: RecordId) -> Option<T> {
+        self.items.iter().find(|(item_id, _)| *item_id == id).map(|(_, item)| item.clone())
+    }
+
+    fn update(&mut self, id: RecordId, item: T) -> Result<(), String> {
+        match self.items.iter_mut().find(|(item_id, _)| *item_id == id) {
+            Some(entry) => entry.1 = item,
+            None => return Err(format!("No record with id {id}")),
+        }
+        self._save()
+    }
+
+    fn remove(&mut self, id: RecordId) -> Result<T, String> {
+        match self.items.iter().position(|(item_id, _)| *item_id == id) {
+            Some(index) => {
+                let (_, item) = self.items.remove(index);
+                self._save()?;
+                Ok(item)
+            }
+            None => Err(format!("No record with id {id}")),
+        }
+    }
+
+    fn find(&self, predicate: &dyn Fn(&T) -> bool) -> Vec<(RecordId, T)> {
+        self.items.iter().filter(|(_, item)| predicate(item)).cloned().collect()
+    }
+}
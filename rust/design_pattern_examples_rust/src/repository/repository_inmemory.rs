@@ -0,0 +1,64 @@
+//! Contains the InMemoryRepository struct that stores items in memory.
+
+//-----------------------------------------------------------------------------
+
+use super::repository_irepository_trait::{IRepository, RecordId};
+
+//-----------------------------------------------------------------------------
+
+/// Represents a repository that stores items in memory.  The items are lost
+/// when the repository is dropped.
+pub struct InMemoryRepository<T> {
+    /// The items, as (identifier, item) pairs, in the order they were added.
+    items: Vec<(RecordId, T)>,
+    /// Identifier to assign to the next item added.
+    next_id: RecordId,
+}
+
+impl<T> InMemoryRepository<T> {
+    /// Constructor.
+    ///
+    /// # Returns
+    /// Returns a new, empty instance of the InMemoryRepository struct.
+    pub fn new() -> InMemoryRepository<T> {
+        InMemoryRepository { items: vec![], next_id: 1 }
+    }
+}
+
+impl<T: Clone> IRepository<T> for InMemoryRepository<T> {
+    fn describe(&self) -> String {
+        String::from("memory")
+    }
+
+    fn add(&mut self, item: T) -> Result<RecordId, String> {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.items.push((id, item));
+        Ok(id)
+    }
+
+    fn get(&self, id: RecordId) -> Option<T> {
+        self.items.iter().find(|(item_id, _)| *item_id == id).map(|(_, item)| item.clone())
+    }
+
+    fn update(&mut self, id: RecordId, item: T) -> Result<(), String> {
+        match self.items.iter_mut().find(|(item_id, _)| *item_id == id) {
+            Some(entry) => {
+                entry.1 = item;
+                Ok(())
+            }
+            None => Err(format!("No record with id {id}")),
+        }
+    }
+
+    fn remove(&mut self, id: RecordId) -> Result<T, String> {
+        match self.items.iter().position(|(item_id, _)| *item_id == id) {
+            Some(index) => Ok(self.items.remove(index).1),
+            None => Err(format!("No record with id {id}")),
+        }
+    }
+
+    fn find(&self, predicate: &dyn Fn(&T) -> bool) -> Vec<(RecordId, T)> {
+        self.items.iter().filter(|(_, item)| predicate(item)).cloned().collect()
+    }
+}
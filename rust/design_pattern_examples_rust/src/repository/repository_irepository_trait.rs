@@ -0,0 +1,83 @@
+//! Contains the IRepository trait that represents a collection of stored
+//! items, and the RecordId type that identifies each item.
+
+/// Identifies an item stored in a repository.  Assigned by the repository
+/// when the item is added.
+pub type RecordId = u32;
+
+/// Represents a collection of stored items of type T.  Code using a
+/// repository does not know or care how or where the items are stored.
+pub trait IRepository<T> {
+    /// Retrieve a description of where the items are stored, for display.
+    fn describe(&self) -> String;
+
+    /// Add an item to the repository.
+    ///
+    /// # Parameters
+    /// - item
+    ///
+    ///   The item to add.
+    ///
+    /// # Returns
+    /// Returns `Ok(RecordId)` containing the identifier assigned to the item;
+    /// otherwise, returns `Err(String)` describing the problem.
+    fn add(&mut self, item: T) -> Result<RecordId, String>;
+
+    /// Retrieve a copy of the item with the given identifier.
+    ///
+    /// # Parameters
+    /// - id
+    ///
+    ///   Identifier of the item.
+    ///
+    /// # Returns
+    /// Returns Some(T) if the item exists; otherwise, returns None.
+    fn get(&self, id: RecordId) -> Option<T>;
+
+    /// Replace the item with the given identifier.
+    ///
+    /// # Parameters
+    /// - id
+    ///
+    ///   Identifier of the item to replace.
+    /// - item
+    ///
+    ///   The new item.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the item was replaced; otherwise, returns
+    /// `Err(String)` describing the problem.
+    fn update(&mut self, id: RecordId, item: T) -> Result<(), String>;
+
+    /// Remove the item with the given identifier.
+    ///
+    /// # Parameters
+    /// - id
+    ///
+    ///   Identifier of the item to remove.
+    ///
+    /// # Returns
+    /// Returns `Ok(T)` containing the removed item; otherwise, returns
+    /// `Err(String)` describing the problem.
+    fn remove(&mut self, id: RecordId) -> Result<T, String>;
+
+    /// Retrieve copies of all items that match the given condition, in the
+    /// order they were added.
+    ///
+    /// # Parameters
+    /// - predicate
+    ///
+    ///   Function that returns true for each item to include.
+    ///
+    /// # Returns
+    /// Returns a list of (identifier, item) pairs.
+    fn find(&self, predicate: &dyn Fn(&T) -> bool) -> Vec<(RecordId, T)>;
+
+    /// Retrieve copies of all items, in the order they were added.
+    ///
+    /// # Returns
+    /// Returns a list of (identifier, item) pairs.
+    fn get_all(&self) -> Vec<(RecordId, T)> {
+        self.find(&|_| true)
+    }
+}
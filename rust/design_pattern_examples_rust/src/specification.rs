@@ -0,0 +1,96 @@
+//! The Specification design pattern example module
+//!
+//! The Specification pattern turns a business rule (a predicate) into an
+//! object.  Simple specifications can then be combined with "and", "or" and
+//! "not" into more complex specifications, which are themselves
+//! specifications and can be combined further.  The code that uses a
+//! specification only asks whether an item satisfies it.
+//!
+//! In this exercise, simple specifications about age, height and name are
+//! combined in several ways and used to filter the list of EntryInformation
+//! items from the Strategy example.
+//!
+//! Accessed through the specification_exercise() function.
+
+//-----------------------------------------------------------------------------
+
+pub mod specification_combinators;
+pub mod specification_entryspecifications;
+pub mod specification_ispecification_trait;
+
+//-----------------------------------------------------------------------------
+
+use crate::strategy::create_entries;
+use crate::strategy::strategy_entryinformation::EntryInformation;
+use specification_combinators::{AndSpecification, NotSpecification, OrSpecification};
+use specification_entryspecifications::{AgeAtLeastSpecification, HeightAtLeastSpecification, NameStartsWithSpecification};
+use specification_ispecification_trait::ISpecification;
+
+//-----------------------------------------------------------------------------
+
+/// Helper function to show the entries that satisfy the given specification.
+///
+/// # Parameters
+/// - entries
+///
+///   The entries to filter.
+/// - specification
+///
+///   The specification the entries must satisfy.
+fn _specification_show_matches(entries: &[EntryInformation], specification: &dyn ISpecification<EntryInformation>) {
+    println!("  Entries satisfying {}:", specification.describe());
+    let matches: Vec<&EntryInformation> = entries.iter()
+        .filter(|entry| specification.is_satisfied_by(entry))
+        .collect();
+    if matches.is_empty() {
+        println!("    (none)");
+    }
+    for entry in matches {
+        println!("    {entry}");
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Example of using the "Specification" design pattern.
+///
+/// The Specification pattern turns a business rule (a predicate) into an
+/// object.  Simple specifications can then be combined with "and", "or" and
+/// "not" into more complex specifications.
+///
+/// In this exercise, specifications about age, height and name are combined
+/// in several ways and used to filter a list of individuals.
+// ! [Using Specification in Rust]
+pub fn specification_exercise() -> Result<(), String> {
+    println!();
+    println!("Specification Exercise");
+
+    let entries = create_entries();
+    println!("  All entries:");
+    for entry in entries.iter() {
+        println!("    {entry}");
+    }
+
+    let older_than_20 = AgeAtLeastSpecification::new(21);
+    _specification_show_matches(&entries, older_than_20.as_ref());
+
+    let tall_young = AndSpecification::new(
+        HeightAtLeastSpecification::new(80),
+        NotSpecification::new(AgeAtLeastSpecification::new(20)));
+    _specification_show_matches(&entries, tall_young.as_ref());
+
+    let selected = OrSpecification::new(
+        NameStartsWithSpecification::new("J"),
+        AndSpecification::new(AgeAtLeastSpecification::new(30), HeightAtLeastSpecification::new(75)));
+    _specification_show_matches(&entries, selected.as_ref());
+
+    let nobody = AndSpecification::new(
+        NameStartsWithSpecification::new("E"),
+        NotSpecification::new(NameStartsWithSpecification::new("E")));
+    _specification_show_matches(&entries, nobody.as_ref());
+
+    println!("  Done.");
+
+    Ok(())
+}
+// ! [Using Specification in Rust]
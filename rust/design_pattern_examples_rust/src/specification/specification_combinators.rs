@@ -0,0 +1,122 @@
+//! Contains the AndSpecification, OrSpecification and NotSpecification
+//! structs that combine other specifications into new ones.
+
+//-----------------------------------------------------------------------------
+
+use super::specification_ispecification_trait::ISpecification;
+
+//-----------------------------------------------------------------------------
+
+/// Represents a specification satisfied only when both of two other
+/// specifications are satisfied.
+pub struct AndSpecification<T> {
+    /// The first specification.
+    left: Box<dyn ISpecification<T>>,
+    /// The second specification.  Not tested if the first is not satisfied.
+    right: Box<dyn ISpecification<T>>,
+}
+
+impl<T: 'static> AndSpecification<T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - left
+    ///
+    ///   The first specification.
+    /// - right
+    ///
+    ///   The second specification.
+    ///
+    /// # Returns
+    /// Returns a new instance of the AndSpecification struct as represented
+    /// by the ISpecification trait.
+    pub fn new(left: Box<dyn ISpecification<T>>, right: Box<dyn ISpecification<T>>) -> Box<dyn ISpecification<T>> {
+        Box::new(AndSpecification { left, right })
+    }
+}
+
+impl<T> ISpecification<T> for AndSpecification<T> {
+    fn is_satisfied_by(&self, item: &T) -> bool {
+        self.left.is_satisfied_by(item) && self.right.is_satisfied_by(item)
+    }
+
+    fn describe(&self) -> String {
+        format!("({} and {})", self.left.describe(), self.right.describe())
+    }
+}
+
+//#############################################################################
+//#############################################################################
+
+/// Represents a specification satisfied when either of two other
+/// specifications is satisfied.
+pub struct OrSpecification<T> {
+    /// The first specification.
+    left: Box<dyn ISpecification<T>>,
+    /// The second specification.  Not tested if the first is satisfied.
+    right: Box<dyn ISpecification<T>>,
+}
+
+impl<T: 'static> OrSpecification<T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - left
+    ///
+    ///   The first specification.
+    /// - right
+    ///
+    ///   The second specification.
+    ///
+    /// # Returns
+    /// Returns a new instance of the OrSpecification struct as represented
+    /// by the ISpecification trait.
+    pub fn new(left: Box<dyn ISpecification<T>>, right: Box<dyn ISpecification<T>>) -> Box<dyn ISpecification<T>> {
+        Box::new(OrSpecification { left, right })
+    }
+}
+
+impl<T> ISpecification<T> for OrSpecification<T> {
+    fn is_satisfied_by(&self, item: &T) -> bool {
+        self.left.is_satisfied_by(item) || self.right.is_satisfied_by(item)
+    }
+
+    fn describe(&self) -> String {
+        format!("({} or {})", self.left.describe(), self.right.describe())
+    }
+}
+
+//#############################################################################
+//#############################################################################
+
+/// Represents a specification satisfied when another specification is not.
+pub struct NotSpecification<T> {
+    /// The specification to invert.
+    inner: Box<dyn ISpecification<T>>,
+}
+
+impl<T: 'static> NotSpecification<T> {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - inner
+    ///
+    ///   The specification to invert.
+    ///
+    /// # Returns
+    /// Returns a new instance of the NotSpecification struct as represented
+    /// by the ISpecification trait.
+    pub fn new(inner: Box<dyn ISpecification<T>>) -> Box<dyn ISpecification<T>> {
+        Box::new(NotSpecification { inner })
+    }
+}
+
+impl<T> ISpecification<T> for NotSpecification<T> {
+    fn is_satisfied_by(&self, item: &T) -> bool {
+        !self.inner.is_satisfied_by(item)
+    }
+
+    fn describe(&self) -> String {
+        format!("not {}", self.inner.describe())
+    }
+}
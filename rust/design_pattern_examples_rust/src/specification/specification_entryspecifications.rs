@@ -0,0 +1,112 @@
+//! Contains the AgeAtLeastSpecification, HeightAtLeastSpecification and
+//! NameStartsWithSpecification structs, which are the simple rules about
+//! EntryInformation items that are combined in the exercise.
+
+//-----------------------------------------------------------------------------
+
+use crate::strategy::strategy_entryinformation::EntryInformation;
+use super::specification_ispecification_trait::ISpecification;
+
+//-----------------------------------------------------------------------------
+
+/// Represents the rule that an individual is at least a given age.
+pub struct AgeAtLeastSpecification {
+    /// The minimum age, in years.
+    minimum_age: i32,
+}
+
+impl AgeAtLeastSpecification {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - minimum_age
+    ///
+    ///   The minimum age, in years.
+    ///
+    /// # Returns
+    /// Returns a new instance of the AgeAtLeastSpecification struct as
+    /// represented by the ISpecification trait.
+    pub fn new(minimum_age: i32) -> Box<dyn ISpecification<EntryInformation>> {
+        Box::new(AgeAtLeastSpecification { minimum_age })
+    }
+}
+
+impl ISpecification<EntryInformation> for AgeAtLeastSpecification {
+    fn is_satisfied_by(&self, item: &EntryInformation) -> bool {
+        item.age >= self.minimum_age
+    }
+
+    fn describe(&self) -> String {
+        format!("age >= {}", self.minimum_age)
+    }
+}
+
+//#############################################################################
+//#############################################################################
+
+/// Represents the rule that an individual is at least a given height.
+pub struct HeightAtLeastSpecification {
+    /// The minimum height, in inches.
+    minimum_height: i32,
+}
+
+impl HeightAtLeastSpecification {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - minimum_height
+    ///
+    ///   The minimum height, in inches.
+    ///
+    /// # Returns
+    /// Returns a new instance of the HeightAtLeastSpecification struct as
+    /// represented by the ISpecification trait.
+    pub fn new(minimum_height: i32) -> Box<dyn ISpecification<EntryInformation>> {
+        Box::new(HeightAtLeastSpecification { minimum_height })
+    }
+}
+
+impl ISpecification<EntryInformation> for HeightAtLeastSpecification {
+    fn is_satisfied_by(&self, item: &EntryInformation) -> bool {
+        item.height >= self.minimum_height
+    }
+
+    fn describe(&self) -> String {
+        format!("height >= {}\"", self.minimum_height)
+    }
+}
+
+//#############################################################################
+//#############################################################################
+
+/// Represents the rule that an individual's name starts with a given string.
+pub struct NameStartsWithSpecification {
+    /// The string the name must start with.
+    prefix: String,
+}
+
+impl NameStartsWithSpecification {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - prefix
+    ///
+    ///   The string the name must start with.
+    ///
+    /// # Returns
+    /// Returns a new instance of the NameStartsWithSpecification struct as
+    /// represented by the ISpecification trait.
+    pub fn new(prefix: &str) -> Box<dyn ISpecification<EntryInformation>> {
+        Box::new(NameStartsWithSpecification { prefix: prefix.to_string() })
+    }
+}
+
+impl ISpecification<EntryInformation> for NameStartsWithSpecification {
+    fn is_satisfied_by(&self, item: &EntryInformation) -> bool {
+        item.name.starts_with(&self.prefix)
+    }
+
+    fn describe(&self) -> String {
+        format!("name starts with '{}'", self.prefix)
+    }
+}
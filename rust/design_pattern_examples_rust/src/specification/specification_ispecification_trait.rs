@@ -0,0 +1,22 @@
+//! Contains the ISpecification trait that represents a rule an item either
+//! satisfies or does not.
+
+/// Represents a rule (a predicate) that items of type T either satisfy or do
+/// not.  Specifications can be combined with the AndSpecification,
+/// OrSpecification and NotSpecification structs to make new rules.
+pub trait ISpecification<T> {
+    /// Determine if the given item satisfies this specification.
+    ///
+    /// # Parameters
+    /// - item
+    ///
+    ///   The item to test.
+    ///
+    /// # Returns
+    /// Returns true if the item satisfies the specification; otherwise,
+    /// returns false.
+    fn is_satisfied_by(&self, item: &T) -> bool;
+
+    /// Retrieve a description of the rule, for display.
+    fn describe(&self) -> String;
+}
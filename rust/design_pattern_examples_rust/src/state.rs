@@ -10,6 +10,10 @@
 //! The filtering process starts with creating the context that drives
 //! the state machine.  Internal structs are provided for each state.
 //!
+//! If the `--stress` exercise option is given, the filter is also run over
+//! many generated code snippets to verify the filter never panics and keeps
+//! everything that is not a comment.
+//!
 //! Accessed through the state_exercise() function.
 
 //-----------------------------------------------------------------------------
@@ -17,10 +21,12 @@
 pub mod state_context;
 pub mod state_istatebehavior_trait;
 pub mod state_istatecontext_trait;
+pub mod state_stress;
 
 //-----------------------------------------------------------------------------
 
 use state_context::StateContext;
+use crate::helpers::exercise_options::has_exercise_option;
 
 //-----------------------------------------------------------------------------
 
@@ -53,6 +59,10 @@ fn state_display_text(text: &str) {
 /// 
 /// The filtering process starts with creating the context that drives
 /// the state machine.  Internal structs are provided for each state.
+///
+/// If the `--stress` exercise option is given, the filter is also run over
+/// many generated code snippets to verify the filter never panics and keeps
+/// everything that is not a comment.
 // ! [Using State in Rust]
 pub fn state_exercise() -> Result<(), String> {
     println!("");
@@ -84,6 +94,11 @@ fn state_exercise() { // An exercise in state machines
     println!("  Filtered text:");
     state_display_text(&filtered_text);
 
+    if has_exercise_option("--stress") {
+        println!("  Running stress test on the filter...");
+        state_stress::run_stress_test(20230807, 10000)?;
+    }
+
     println!("  Done.");
 
     Ok(())
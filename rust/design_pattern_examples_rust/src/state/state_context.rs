@@ -5,6 +5,7 @@
 
 use super::state_istatecontext_trait::{IStateContext, StateChar};
 use super::state_istatebehavior_trait::{IStateBehavior, CurrentState, current_state_to_string};
+use crate::helpers::profiler::profile_span;
 
 //-----------------------------------------------------------------------------
 
@@ -405,6 +406,9 @@ impl IStateBehavior for StateBlockComment {
 /// Transitions to the following states for the seen input:
 /// - `/`  - go to CurrentState::NormalText (found end of block comment)
 /// 
+/// - `*`  - stay in CurrentState::EndBlockComment (still possibly at the end
+///   of block comment, as in `**/`)
+/// 
 /// - `{ANY}` - go to CurrentState::BlockComment (still in block comment)
 /// 
 /// - `StateChar::Eof` - go to CurrentState::Done (no more input)
@@ -431,6 +435,8 @@ impl IStateBehavior for StateEndBlockComment {
             StateChar::Char(c) => {
                 if c == '/' {
                     current_state = CurrentState::NormalText;
+                } else if c == '*' {
+                    current_state = CurrentState::EndBlockComment;
                 } else {
                     // We are in a comment to be removed, so do nothing here.
                 }
@@ -540,6 +546,8 @@ pub struct StateContext {
     /// IStateBehavior representing the behavior for that state.  This vector
     /// owns the StateXXX struct instances.
     behaviors: Vec<(CurrentState, Box<dyn IStateBehavior>)>,
+    /// Whether each state transition is displayed as it happens.
+    show_transitions: bool,
 }
 
 impl StateContext {
@@ -551,9 +559,22 @@ impl StateContext {
         StateContext {
             current_state: CurrentState::Initial,
             behaviors: vec![],
+            show_transitions: true,
         }
     }
 
+    /// Turn the display of state transitions on or off.  Transitions are
+    /// displayed by default.
+    ///
+    /// # Parameters
+    /// - show_transitions
+    ///
+    ///   true to display each state transition as it happens; false to
+    ///   filter text quietly.
+    pub fn set_show_transitions(&mut self, show_transitions: bool) {
+        self.show_transitions = show_transitions;
+    }
+
     /// Entry point for callers to filter text.  Removes Rust-style line and
     /// block comments from the text.
     ///
@@ -565,6 +586,7 @@ impl StateContext {
     /// # Returns
     /// Returns the text as a new string, without the comments.
     pub fn remove_comments(&mut self, text: &str) -> String {
+        let _span = profile_span("remove_comments");
         let mut inputoutput = InputOutput::new(text);
         self.current_state = CurrentState::Initial;
         self.set_next_state(&CurrentState::NormalText);
@@ -589,9 +611,11 @@ impl StateContext {
     ///   which to transition.
     fn set_next_state(&mut self, new_state: &CurrentState) {
         if self.current_state != *new_state {
-            println!("    --> State Transition: {0} -> {1}",
-                current_state_to_string(&self.current_state),
-                current_state_to_string(new_state));
+            if self.show_transitions {
+                println!("    --> State Transition: {0} -> {1}",
+                    current_state_to_string(&self.current_state),
+                    current_state_to_string(new_state));
+            }
             self.current_state = (*new_state).clone();
         }
     }
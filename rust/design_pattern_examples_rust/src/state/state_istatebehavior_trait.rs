@@ -28,7 +28,7 @@ pub enum CurrentState {
     LineComment,
     /// `*` transitions to EndBlockComment, EOF_CHAR transitions to Done
     BlockComment,
-    /// `/` transitions to NormalText, EOF_CHAR transitions to Done, all else transitions to BlockComment
+    /// `/` transitions to NormalText, `*` stays in EndBlockComment, EOF_CHAR transitions to Done, all else transitions to BlockComment
     EndBlockComment,
     /// Indicates processing is done
     Done
@@ -0,0 +1,286 @@
+//! Contains the run_stress_test() function that runs the comment filter over
+//! many generated code snippets and checks that the filter behaves correctly,
+//! along with the deterministic code snippet generator it uses.
+
+//-----------------------------------------------------------------------------
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use super::state_context::StateContext;
+
+//-----------------------------------------------------------------------------
+
+/// Pieces of code that contain no comments, quotes, or trailing slashes and
+/// so pass through the filter unchanged.
+const CODE_PIECES: [&str; 10] = [
+    "let x = 5;",
+    "fn main() {",
+    "}",
+    "a / b",
+    "x * y",
+    "    ",
+    "\n",
+    "value /= 2;",
+    "if a < b { return; }",
+    "*ptr = 0;",
+];
+
+/// Contents of string and character literals, chosen to include comment
+/// markers that the filter must leave alone.  Double-quoted strings escape
+/// the double quote; character literals escape the single quote.
+const STRING_PIECES: [&str; 8] = [
+    "hello",
+    "// not a comment",
+    "/* not a comment */",
+    "path/to/file",
+    "it's",
+    "say \\\"hi\\\"",
+    "back\\\\slash",
+    "",
+];
+
+/// Character literals that the filter must leave alone.
+const CHAR_PIECES: [&str; 6] = ["'a'", "'/'", "'*'", "'\"'", "'\\''", "'\\\\'"];
+
+/// Text that can appear inside a comment.  None of these contain a newline
+/// or the end of a block comment.
+const COMMENT_PIECES: [&str; 6] = [
+    "A comment.",
+    " \"quoted\" text ",
+    " it's ",
+    " nested // marker ",
+    " stars * and / slashes ",
+    "",
+];
+
+/// The characters used to generate random noise, weighted toward the
+/// characters that cause the filter to change state.
+const NOISE_CHARACTERS: [char; 12] = ['/', '*', '"', '\'', '\\', '\n', 'a', 'b', ' ', '/', '*', '"'];
+
+//-----------------------------------------------------------------------------
+
+/// Represents a simple pseudo-random number generator (xorshift) that always
+/// produces the same sequence of numbers for the same seed, so any problem
+/// found by the stress test can be reproduced.
+struct SnippetRandom {
+    /// The current state of the generator; never zero.
+    state: u64,
+}
+
+impl SnippetRandom {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - seed
+    ///
+    ///   The starting value for the generator.
+    ///
+    /// # Returns
+    /// Returns a new instance of the SnippetRandom struct.
+    fn new(seed: u64) -> SnippetRandom {
+        SnippetRandom { state: seed.max(1) }
+    }
+
+    /// Retrieve the next number in the sequence, from 0 up to but not
+    /// including the given limit.
+    fn next_below(&mut self, limit: usize) -> usize {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        (self.state % limit as u64) as usize
+    }
+
+    /// Pick one of the items in the given list.
+    fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[self.next_below(items.len())]
+    }
+}
+
+
+/// Represents one generated code snippet along with the text the comment
+/// filter is expected to produce from it.
+struct GeneratedSnippet {
+    /// The code snippet to filter.
+    input: String,
+    /// The expected output of the filter.  None if the snippet is random
+    /// noise and the expected output is not known.
+    expected: Option<String>,
+}
+
+
+/// Generate a well-formed code snippet made of code, strings, character
+/// literals, line comments, and block comments.  Since the snippet is built
+/// from known pieces, the output of the filter is known as well.
+///
+/// # Parameters
+/// - random
+///
+///   The random number generator to use.
+///
+/// # Returns
+/// Returns a GeneratedSnippet with the expected output filled in.
+fn generate_snippet(random: &mut SnippetRandom) -> GeneratedSnippet {
+    let mut input = String::new();
+    let mut expected = String::new();
+    let piece_count = 1 + random.next_below(12);
+    for _ in 0..piece_count {
+        match random.next_below(5) {
+            0 => {
+                let code = random.pick(&CODE_PIECES);
+                input.push_str(code);
+                expected.push_str(code);
+            }
+            1 => {
+                let contents = random.pick(&STRING_PIECES);
+                let literal = format!("\"{contents}\"");
+                input.push_str(&literal);
+                expected.push_str(&literal);
+            }
+            2 => {
+                let literal = random.pick(&CHAR_PIECES);
+                input.push_str(literal);
+                expected.push_str(literal);
+            }
+            3 => {
+                // A line comment is removed but the newline that ends it is
+                // kept.
+                input.push_str(&format!("//{}\n", random.pick(&COMMENT_PIECES)));
+                expected.push('\n');
+            }
+            _ => {
+                // Sometimes end the block comment with extra stars.
+                let stars = "*".repeat(1 + random.next_below(3));
+                input.push_str(&format!("/*{}{stars}/", random.pick(&COMMENT_PIECES)));
+            }
+        }
+    }
+    GeneratedSnippet { input, expected: Some(expected) }
+}
+
+
+/// Generate a snippet of random noise heavy in the characters that change the
+/// state of the filter.  The noise may contain unterminated strings and
+/// comments, so the expected output is not known.
+///
+/// # Parameters
+/// - random
+///
+///   The random number generator to use.
+///
+/// # Returns
+/// Returns a GeneratedSnippet without an expected output.
+fn generate_noise(random: &mut SnippetRandom) -> GeneratedSnippet {
+    let length = random.next_below(40);
+    let input = (0..length)
+        .map(|_| NOISE_CHARACTERS[random.next_below(NOISE_CHARACTERS.len())])
+        .collect();
+    GeneratedSnippet { input, expected: None }
+}
+
+
+/// Count the number of double quote characters in the given text.
+fn count_double_quotes(text: &str) -> usize {
+    text.chars().filter(|c| *c == '"').count()
+}
+
+
+/// Check the output of the filter against the invariants that must hold for
+/// all inputs and, if known, against the expected output.
+///
+/// # Parameters
+/// - snippet
+///
+///   The snippet that was filtered.
+/// - output
+///
+///   The output of the filter.
+///
+/// # Returns
+/// Returns Ok(()) if all checks pass; otherwise, returns `Err(String)`
+/// describing the first check that failed.
+fn check_invariants(snippet: &GeneratedSnippet, output: &str) -> Result<(), String> {
+    if output.chars().count() > snippet.input.chars().count() {
+        return Err(String::from("output is longer than input"));
+    }
+    if let Some(expected) = &snippet.expected {
+        if count_double_quotes(output) != count_double_quotes(expected) {
+            return Err(String::from("double quotes were not preserved"));
+        }
+        if output != expected {
+            return Err(String::from("non-comment content was not preserved"));
+        }
+    }
+    Ok(())
+}
+
+
+/// Run the comment filter over the given number of generated snippets and
+/// display a summary report.  Half of the snippets are well-formed code and
+/// half are random noise.
+///
+/// # Parameters
+/// - seed
+///
+///   The seed for the snippet generator.  The same seed always generates the
+///   same snippets.
+/// - snippet_count
+///
+///   Number of snippets to generate and filter.
+///
+/// # Returns
+/// Returns Ok(()) if the filter passed every check; otherwise, returns
+/// `Err(String)` giving the number of failures.
+pub fn run_stress_test(seed: u64, snippet_count: usize) -> Result<(), String> {
+    const MAX_FAILURES_TO_SHOW: usize = 5;
+
+    let mut random = SnippetRandom::new(seed);
+    let mut context = StateContext::new();
+    context.set_show_transitions(false);
+
+    let mut well_formed_count = 0;
+    let mut noise_count = 0;
+    let mut total_input_length = 0;
+    let mut total_output_length = 0;
+    let mut failures: Vec<(String, String)> = vec![];
+
+    for snippet_index in 0..snippet_count {
+        let snippet = if snippet_index % 2 == 0 {
+            well_formed_count += 1;
+            generate_snippet(&mut random)
+        } else {
+            noise_count += 1;
+            generate_noise(&mut random)
+        };
+
+        let result = catch_unwind(AssertUnwindSafe(|| context.remove_comments(&snippet.input)));
+        let check = match &result {
+            Ok(output) => {
+                total_input_length += snippet.input.chars().count();
+                total_output_length += output.chars().count();
+                check_invariants(&snippet, output)
+            }
+            Err(_) => Err(String::from("filter panicked")),
+        };
+        if let Err(reason) = check {
+            failures.push((reason, snippet.input));
+            // A panic may have left the context in a bad state.
+            context = StateContext::new();
+            context.set_show_transitions(false);
+        }
+    }
+
+    println!("  Stress test report (seed = {seed}):");
+    println!("    Snippets filtered   : {snippet_count} ({well_formed_count} well-formed, {noise_count} noise)");
+    println!("    Characters in/out   : {total_input_length} / {total_output_length}");
+    println!("    Snippets passed     : {}", snippet_count - failures.len());
+    println!("    Snippets failed     : {}", failures.len());
+    for (reason, input) in failures.iter().take(MAX_FAILURES_TO_SHOW) {
+        println!("      {reason}: {input:?}");
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("State stress test found {} failure(s)", failures.len()))
+    }
+}
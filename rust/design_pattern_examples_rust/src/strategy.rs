@@ -9,6 +9,10 @@
 //! provided (Name, Age, Height) and an option to reverse the normal order of
 //! the sort.
 //!
+//! Additional sorting strategies are then created from specifications of
+//! weighted keys, showing how a strategy can be described outside the
+//! program instead of being written into it.
+//!
 //! Accessed through the strategy_exercise() function.
 
 //-----------------------------------------------------------------------------
@@ -17,12 +21,14 @@ pub mod strategy_isortentries_trait;
 pub mod strategy_entryinformation;
 pub mod strategy_sortentries;
 pub mod strategy_showentries;
+pub mod strategy_weightedsort;
 
 //-----------------------------------------------------------------------------
 
 use strategy_showentries::StrategyShowEntries;
 use strategy_isortentries_trait::SortOptions;
 use strategy_entryinformation::EntryInformation;
+use strategy_weightedsort::StrategySortByWeightedKeys;
 
 //-----------------------------------------------------------------------------
 
@@ -31,7 +37,7 @@ use strategy_entryinformation::EntryInformation;
 ///
 /// # Returns
 /// Returns a list of EntryInformation objects that can be sorted.
-fn create_entries() -> Vec<EntryInformation> {
+pub fn create_entries() -> Vec<EntryInformation> {
     let mut entries = vec![];
     // Name, age, height (in inches)
     entries.push(EntryInformation::new("Ronnie", 19, 84));
@@ -53,6 +59,10 @@ fn create_entries() -> Vec<EntryInformation> {
 /// list of EntryInformation elements.  Three different sorting strategies are
 /// provided (Name, Age, Height) and an option to reverse the normal order of
 /// the sort.
+///
+/// Additional sorting strategies are then created from specifications of
+/// weighted keys, showing how a strategy can be described outside the
+/// program instead of being written into it.
 // ! [Using Strategy in Rust]
 pub fn strategy_exercise() -> Result<(), String> {
     println!("");
@@ -69,6 +79,22 @@ pub fn strategy_exercise() -> Result<(), String> {
     let display_name_ascending = StrategyShowEntries::new(SortOptions::ByHeight, true);
     display_name_ascending.show_entries(&entries);
 
+    // These specifications could just as easily be read from a file.
+    let sort_specs = [
+        ("age*0.7+height*0.3", false),
+        ("height*2 + age*-1", true),
+        ("age*1+weight*3", false),
+    ];
+    for (sort_spec, reversed_sort) in sort_specs {
+        match StrategySortByWeightedKeys::from_spec(sort_spec, reversed_sort) {
+            Ok(sort_strategy) => {
+                let display_weighted = StrategyShowEntries::with_strategy(sort_strategy, reversed_sort);
+                display_weighted.show_entries(&entries);
+            }
+            Err(message) => println!("    Sort specification \"{sort_spec}\" rejected: {message}"),
+        }
+    }
+
     println!("  Done.");
 
     Ok(())
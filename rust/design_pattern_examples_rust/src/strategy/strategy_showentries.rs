@@ -57,6 +57,27 @@ impl StrategyShowEntries {
         }
     }
 
+    /// Constructor that uses a sorting strategy that was already created,
+    /// such as one created from a specification.
+    ///
+    /// # Parameters
+    /// - sort_strategy
+    ///
+    ///   The sorting strategy to use.
+    /// - reversed_sort
+    ///
+    ///   true if the strategy sorts in descending order; otherwise, false.
+    ///   This is used only for display.
+    ///
+    /// # Returns
+    /// Returns a new instance of the StrategyShowEntries struct.
+    pub fn with_strategy(sort_strategy: Box<dyn ISortEntries>, reversed_sort: bool) -> StrategyShowEntries {
+        StrategyShowEntries {
+            reversed_sort,
+            sort_strategy,
+        }
+    }
+
     /// Display the specified entries in sorted order.  The sorting strategy
     /// and the order of the sort were established when the
     /// StrategyShowEntries struct was instantiated.
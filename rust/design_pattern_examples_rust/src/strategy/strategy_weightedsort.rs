@@ -0,0 +1,133 @@
+//! Contains the StrategySortByWeightedKeys struct, a sorting strategy that is
+//! described by a specification of weighted keys instead of being written
+//! into the program.
+
+//-----------------------------------------------------------------------------
+
+use std::cmp::Ordering;
+
+use super::strategy_entryinformation::EntryInformation;
+use super::strategy_isortentries_trait::ISortEntries;
+
+//-----------------------------------------------------------------------------
+
+/// Identifies the numeric fields of an EntryInformation that can be used as
+/// a key in a weighted sort.
+#[derive(Clone, Copy)]
+enum WeightedKey {
+    /// The age field.
+    Age,
+    /// The height field.
+    Height,
+}
+
+impl WeightedKey {
+    /// Retrieve the value of this key from the given entry.
+    fn value(&self, entry: &EntryInformation) -> f64 {
+        match self {
+            WeightedKey::Age => entry.age as f64,
+            WeightedKey::Height => entry.height as f64,
+        }
+    }
+
+    /// Retrieve the name of this key as used in a specification.
+    fn name(&self) -> &'static str {
+        match self {
+            WeightedKey::Age => "age",
+            WeightedKey::Height => "height",
+        }
+    }
+}
+
+//-----------------------------------------------------------------------------
+
+/// Strategy for sorting on a score computed from one or more weighted keys,
+/// in ascending (or descending) order.  Entries with the same score are
+/// sorted by name in ascending order.
+///
+/// The strategy is created from a specification, a string of `key*weight`
+/// terms separated by `+`, where the key is one of "age" or "height" and the
+/// weight is any number (negative weights are allowed).  For example, the
+/// specification "age*0.7+height*0.3" sorts on 70% of the age plus 30% of
+/// the height, and "age*1+height*-0.5" sorts on the age minus half the
+/// height.  Spaces around the terms are ignored.  The strategy's as_string()
+/// shows the specification in the same form, so it can be read back.
+/// This allows new sorting strategies to be described outside the program,
+/// such as in a configuration file, without writing any new code.
+pub struct StrategySortByWeightedKeys {
+    /// The keys and their weights, in the order given in the specification.
+    weighted_keys: Vec<(WeightedKey, f64)>,
+    /// Controls order of sort: true for descending, false for ascending.
+    reversed_sort: bool,
+}
+
+impl StrategySortByWeightedKeys {
+    /// Constructor.  Parses the given specification to create the strategy.
+    ///
+    /// # Parameters
+    /// - spec
+    ///
+    ///   The specification of weighted keys, for example,
+    ///   "age*0.7+height*0.3".
+    /// - reversed_sort
+    ///
+    ///   true if to sort in descending order; otherwise, sort in ascending
+    ///   order.
+    ///
+    /// # Returns
+    /// Returns `Ok(Box<dyn ISortEntries>)` containing the new strategy if the
+    /// specification is valid; otherwise, returns `Err(String)` describing
+    /// the problem with the specification.
+    pub fn from_spec(spec: &str, reversed_sort: bool) -> Result<Box<dyn ISortEntries>, String> {
+        let mut weighted_keys = vec![];
+        if spec.trim().is_empty() {
+            return Err(format!("Specification '{spec}' does not contain any keys"));
+        }
+        for term in spec.split('+').map(|term| term.trim()) {
+            let (key_name, weight_text) = match term.split_once('*') {
+                Some((key_name, weight_text)) => (key_name.trim(), weight_text.trim()),
+                None => return Err(format!("'{term}' is not of the form key*weight")),
+            };
+            let key = match key_name {
+                "age" => WeightedKey::Age,
+                "height" => WeightedKey::Height,
+                _ => return Err(format!("Unknown key '{key_name}' (expected age or height)")),
+            };
+            let weight = match weight_text.parse::<f64>() {
+                Ok(weight) if weight.is_finite() => weight,
+                _ => return Err(format!("Weight '{weight_text}' for key '{key_name}' is not a number")),
+            };
+            weighted_keys.push((key, weight));
+        }
+        Ok(Box::new(StrategySortByWeightedKeys { weighted_keys, reversed_sort }))
+    }
+
+    /// Compute the weighted score for the given entry.
+    fn score(&self, entry: &EntryInformation) -> f64 {
+        self.weighted_keys.iter().map(|(key, weight)| key.value(entry) * weight).sum()
+    }
+}
+
+impl ISortEntries for StrategySortByWeightedKeys {
+    fn sort(&self, entries: &mut Vec<EntryInformation>) {
+        entries.sort_by(|left, right| {
+                let ordering = if self.reversed_sort {
+                    self.score(right).total_cmp(&self.score(left))
+                } else {
+                    self.score(left).total_cmp(&self.score(right))
+                };
+                match ordering {
+                    Ordering::Equal => left.name.cmp(&right.name),
+                    _ => ordering,
+                }
+            }
+        )
+    }
+
+    fn as_string(&self) -> String {
+        let keys: Vec<String> = self.weighted_keys.iter()
+            .map(|(key, weight)| format!("{}*{}", key.name(), weight))
+            .collect();
+        format!("StrategySortByWeightedKeys({})", keys.join("+"))
+    }
+}
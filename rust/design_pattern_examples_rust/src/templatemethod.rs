@@ -0,0 +1,81 @@
+//! The Template Method design pattern example module
+//!
+//! The Template Method pattern defines the skeleton of an algorithm in one
+//! method, the template method, and leaves some of the steps of the algorithm
+//! to be filled in by the implementors.  The order of the steps never changes;
+//! only what happens in each step changes.
+//!
+//! In this exercise, a list of sales records is exported in three different
+//! formats: comma-separated values, an aligned text table, and JSON.  The
+//! template method on the exporter trait drives the export (header, each
+//! record, footer) while each exporter supplies those steps for its format.
+//! One step, deciding whether a record is included, is a "hook" with a
+//! default implementation that an exporter can choose to replace.
+//!
+//! Accessed through the templatemethod_exercise() function.
+
+//-----------------------------------------------------------------------------
+
+pub mod templatemethod_salesrecord;
+pub mod templatemethod_idataexporter_trait;
+pub mod templatemethod_exporters;
+
+//-----------------------------------------------------------------------------
+
+use templatemethod_salesrecord::SalesRecord;
+use templatemethod_idataexporter_trait::IDataExporter;
+use templatemethod_exporters::{CsvExporter, TableExporter, JsonExporter};
+
+//-----------------------------------------------------------------------------
+
+/// Helper function to create a list of sales records to export.
+///
+/// # Returns
+/// Returns a list of SalesRecord objects.
+fn create_sales_records() -> Vec<SalesRecord> {
+    vec![
+        SalesRecord::new("North", "Widgets", 120, 2.50),
+        SalesRecord::new("South", "Gadgets", 0, 11.00),
+        SalesRecord::new("East", "Widgets", 45, 2.50),
+        SalesRecord::new("West", "Doohickeys", 7, 24.95),
+    ]
+}
+
+
+/// Example of using the "Template Method" design pattern.
+///
+/// The Template Method pattern defines the skeleton of an algorithm in one
+/// method, the template method, and leaves some of the steps of the algorithm
+/// to be filled in by the implementors.  The order of the steps never changes;
+/// only what happens in each step changes.
+///
+/// In this exercise, a list of sales records is exported in three different
+/// formats: comma-separated values, an aligned text table, and JSON.  The
+/// template method on the exporter trait drives the export (header, each
+/// record, footer) while each exporter supplies those steps for its format.
+/// The table exporter also replaces the "hook" that decides whether a record
+/// is included, leaving out records with no units sold.
+// ! [Using TemplateMethod in Rust]
+pub fn templatemethod_exercise() -> Result<(), String> {
+    println!();
+    println!("TemplateMethod Exercise");
+
+    let records = create_sales_records();
+    let exporters: Vec<Box<dyn IDataExporter>> = vec![
+        CsvExporter::new(),
+        TableExporter::new(),
+        JsonExporter::new(),
+    ];
+
+    for exporter in exporters.iter() {
+        println!("  Exporting {} records as {}:", records.len(), exporter.get_format_name());
+        for line in exporter.export(&records) {
+            println!("    {line}");
+        }
+    }
+
+    println!("  Done.");
+
+    Ok(())
+}
+// ! [Using TemplateMethod in Rust]
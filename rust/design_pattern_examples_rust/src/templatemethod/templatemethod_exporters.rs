@@ -0,0 +1,128 @@
+//! Contains the CsvExporter, TableExporter, and JsonExporter structs that
+//! each fill in the steps of the IDataExporter template method for a
+//! different format.
+
+//-----------------------------------------------------------------------------
+
+use super::templatemethod_salesrecord::SalesRecord;
+use super::templatemethod_idataexporter_trait::IDataExporter;
+
+//-----------------------------------------------------------------------------
+
+/// Represents an exporter to comma-separated values.
+pub struct CsvExporter {}
+
+impl CsvExporter {
+    /// Constructor.
+    ///
+    /// # Returns
+    /// Returns a new instance of the CsvExporter struct as represented by
+    /// the IDataExporter trait.
+    pub fn new() -> Box<dyn IDataExporter> {
+        Box::new(CsvExporter {})
+    }
+}
+
+impl IDataExporter for CsvExporter {
+    fn get_format_name(&self) -> &str {
+        "CSV"
+    }
+
+    fn write_header(&self, output: &mut Vec<String>) {
+        output.push(String::from("region,product,units,unit_price,total"));
+    }
+
+    fn write_record(&self, output: &mut Vec<String>, record: &SalesRecord, _is_first: bool) {
+        output.push(format!("{},{},{},{:.2},{:.2}",
+            record.region, record.product, record.units, record.unit_price, record.total()));
+    }
+
+    fn write_footer(&self, _output: &mut Vec<String>, _record_count: usize) {
+        // CSV has no footer.
+    }
+}
+
+//#############################################################################
+//#############################################################################
+
+/// Represents an exporter to an aligned text table.  This exporter replaces
+/// the include_record() hook to leave out records with no units sold.
+pub struct TableExporter {}
+
+impl TableExporter {
+    /// Constructor.
+    ///
+    /// # Returns
+    /// Returns a new instance of the TableExporter struct as represented by
+    /// the IDataExporter trait.
+    pub fn new() -> Box<dyn IDataExporter> {
+        Box::new(TableExporter {})
+    }
+}
+
+impl IDataExporter for TableExporter {
+    fn get_format_name(&self) -> &str {
+        "text table (records with no units sold are left out)"
+    }
+
+    fn write_header(&self, output: &mut Vec<String>) {
+        output.push(format!("{:<8}{:<12}{:>6}{:>10}", "Region", "Product", "Units", "Total"));
+        output.push(format!("{:<8}{:<12}{:>6}{:>10}", "------", "-------", "-----", "-----"));
+    }
+
+    fn write_record(&self, output: &mut Vec<String>, record: &SalesRecord, _is_first: bool) {
+        output.push(format!("{:<8}{:<12}{:>6}{:>10.2}",
+            record.region, record.product, record.units, record.total()));
+    }
+
+    fn write_footer(&self, output: &mut Vec<String>, record_count: usize) {
+        output.push(format!("({record_count} rows)"));
+    }
+
+    fn include_record(&self, record: &SalesRecord) -> bool {
+        record.units > 0
+    }
+}
+
+//#############################################################################
+//#############################################################################
+
+/// Represents an exporter to JSON, as an array of objects.
+pub struct JsonExporter {}
+
+impl JsonExporter {
+    /// Constructor.
+    ///
+    /// # Returns
+    /// Returns a new instance of the JsonExporter struct as represented by
+    /// the IDataExporter trait.
+    pub fn new() -> Box<dyn IDataExporter> {
+        Box::new(JsonExporter {})
+    }
+}
+
+impl IDataExporter for JsonExporter {
+    fn get_format_name(&self) -> &str {
+        "JSON"
+    }
+
+    fn write_header(&self, output: &mut Vec<String>) {
+        output.push(String::from("["));
+    }
+
+    fn write_record(&self, output: &mut Vec<String>, record: &SalesRecord, is_first: bool) {
+        // JSON needs a comma between records, so put the comma at the end of
+        // the previous record.
+        if !is_first {
+            if let Some(previous_line) = output.last_mut() {
+                previous_line.push(',');
+            }
+        }
+        output.push(format!("  {{ \"region\": {:?}, \"product\": {:?}, \"units\": {}, \"unit_price\": {:.2} }}",
+            record.region, record.product, record.units, record.unit_price));
+    }
+
+    fn write_footer(&self, output: &mut Vec<String>, _record_count: usize) {
+        output.push(String::from("]"));
+    }
+}
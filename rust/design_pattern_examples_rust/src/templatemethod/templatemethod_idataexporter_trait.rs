@@ -0,0 +1,89 @@
+//! Contains the IDataExporter trait that provides the template method,
+//! export(), along with the steps that each exporter fills in.
+
+//-----------------------------------------------------------------------------
+
+use super::templatemethod_salesrecord::SalesRecord;
+
+//-----------------------------------------------------------------------------
+
+/// Represents an exporter of sales records to some text format.
+///
+/// The export() method is the template method.  It calls the other methods
+/// in a fixed order: write_header() once, include_record() and write_record()
+/// for each record, and write_footer() once.  Implementors provide the steps
+/// but should not replace export() itself.  (Rust has no way to prevent a
+/// provided trait method from being replaced, so this is by convention.)
+pub trait IDataExporter {
+    /// Retrieve the name of the format produced by this exporter.
+    fn get_format_name(&self) -> &str;
+
+    /// Step: write the lines that come before the first record.
+    ///
+    /// # Parameters
+    /// - output
+    ///
+    ///   The list of lines to add to.
+    fn write_header(&self, output: &mut Vec<String>);
+
+    /// Step: write the lines for one record.
+    ///
+    /// # Parameters
+    /// - output
+    ///
+    ///   The list of lines to add to.
+    /// - record
+    ///
+    ///   The record to write.
+    /// - is_first
+    ///
+    ///   true if this is the first record written; otherwise, false.
+    fn write_record(&self, output: &mut Vec<String>, record: &SalesRecord, is_first: bool);
+
+    /// Step: write the lines that come after the last record.
+    ///
+    /// # Parameters
+    /// - output
+    ///
+    ///   The list of lines to add to.
+    /// - record_count
+    ///
+    ///   Number of records that were written.
+    fn write_footer(&self, output: &mut Vec<String>, record_count: usize);
+
+    /// Hook: decide whether a record is included in the export.  By default,
+    /// all records are included.
+    ///
+    /// # Parameters
+    /// - record
+    ///
+    ///   The record to consider.
+    ///
+    /// # Returns
+    /// Returns true if the record is to be exported; otherwise, false.
+    fn include_record(&self, _record: &SalesRecord) -> bool {
+        true
+    }
+
+    /// The template method.  Export the given records by calling each of the
+    /// steps in order.
+    ///
+    /// # Parameters
+    /// - records
+    ///
+    ///   The records to export.
+    ///
+    /// # Returns
+    /// Returns a list of strings, one for each line of the exported text.
+    fn export(&self, records: &[SalesRecord]) -> Vec<String> {
+        let mut output = vec![];
+        self.write_header(&mut output);
+        let mut record_count = 0;
+        for record in records.iter().filter(|record| self.include_record(record)) {
+            self.write_record(&mut output, record, record_count == 0);
+            record_count += 1;
+        }
+        self.write_footer(&mut output, record_count);
+        output
+    }
+}
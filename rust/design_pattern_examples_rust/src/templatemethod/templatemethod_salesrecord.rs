@@ -0,0 +1,48 @@
+//! Contains the SalesRecord struct that holds the data exported in the
+//! "Template Method" design pattern example.
+
+/// Represents the sales of one product in one region.
+pub struct SalesRecord {
+    /// Name of the region.
+    pub region: String,
+    /// Name of the product.
+    pub product: String,
+    /// Number of units sold.
+    pub units: u32,
+    /// Price of one unit.
+    pub unit_price: f64,
+}
+
+impl SalesRecord {
+    /// Constructor.
+    ///
+    /// # Parameters
+    /// - region
+    ///
+    ///   Name of the region.
+    /// - product
+    ///
+    ///   Name of the product.
+    /// - units
+    ///
+    ///   Number of units sold.
+    /// - unit_price
+    ///
+    ///   Price of one unit.
+    ///
+    /// # Returns
+    /// Returns a new instance of the SalesRecord struct.
+    pub fn new(region: &str, product: &str, units: u32, unit_price: f64) -> SalesRecord {
+        SalesRecord {
+            region: region.to_string(),
+            product: product.to_string(),
+            units,
+            unit_price,
+        }
+    }
+
+    /// Retrieve the total value of the sales (units times unit price).
+    pub fn total(&self) -> f64 {
+        self.units as f64 * self.unit_price
+    }
+}
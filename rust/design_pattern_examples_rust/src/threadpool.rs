@@ -0,0 +1,86 @@
+//! The Thread Pool design pattern example module
+//!
+//! The Thread Pool pattern keeps a fixed number of worker threads that take
+//! jobs from a shared queue, instead of starting a new thread for every job.
+//! The number of threads stays under control no matter how many jobs are
+//! queued, and the cost of starting a thread is paid only once per worker.
+//!
+//! In this exercise, a pool of three workers is given eight jobs of
+//! different lengths.  The workers take jobs from a queue built on a Rust
+//! channel and report back over a second channel when each job finishes, so
+//! the order in which the jobs complete can be shown.  The pool is then shut
+//! down gracefully: it stops taking jobs, the workers finish what is queued,
+//! and the worker threads are joined.
+//!
+//! Accessed through the threadpool_exercise() function.
+
+//-----------------------------------------------------------------------------
+
+pub mod threadpool_pool;
+pub mod threadpool_worker;
+
+//-----------------------------------------------------------------------------
+
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use threadpool_pool::ThreadPool;
+
+//-----------------------------------------------------------------------------
+
+/// Number of worker threads in the pool.
+const NUM_WORKERS: usize = 3;
+
+//-----------------------------------------------------------------------------
+
+/// Example of using the "Thread Pool" design pattern.
+///
+/// The Thread Pool pattern keeps a fixed number of worker threads that take
+/// jobs from a shared queue, instead of starting a new thread for every job.
+///
+/// In this exercise, a pool of three workers runs eight jobs of different
+/// lengths and the order in which the jobs complete is shown.  The pool is
+/// then shut down gracefully.
+// ! [Using ThreadPool in Rust]
+pub fn threadpool_exercise() -> Result<(), String> {
+    println!();
+    println!("ThreadPool Exercise");
+
+    let mut pool = ThreadPool::new(NUM_WORKERS)?;
+    println!("  Started a pool of {NUM_WORKERS} workers");
+
+    // Each job sleeps for its duration to simulate work, then reports which
+    // worker ran it.
+    let job_durations_ms: [u64; 8] = [120, 40, 80, 20, 100, 10, 60, 30];
+    let (done_sender, done_receiver) = mpsc::channel::<(usize, usize, u64)>();
+    for (job_id, duration_ms) in job_durations_ms.iter().enumerate() {
+        let duration_ms = *duration_ms;
+        let done_sender = done_sender.clone();
+        pool.execute(move |worker_id| {
+            thread::sleep(Duration::from_millis(duration_ms));
+            let _ = done_sender.send((job_id + 1, worker_id, duration_ms));
+        })?;
+        println!("  Queued job {} ({duration_ms} ms)", job_id + 1);
+    }
+    // Only the jobs hold senders now, so the receiver ends when all jobs end.
+    drop(done_sender);
+
+    println!("  Jobs in the order they completed:");
+    for (job_id, worker_id, duration_ms) in done_receiver.iter() {
+        println!("    Job {job_id} ({duration_ms:3} ms) completed by worker {worker_id}");
+    }
+
+    println!("  Shutting down the pool:");
+    for (worker_id, jobs_run) in pool.shutdown() {
+        println!("    Worker {worker_id} stopped after running {jobs_run} job(s)");
+    }
+    if let Err(message) = pool.execute(|_| {}) {
+        println!("  Queuing a job after shutdown: {message}");
+    }
+
+    println!("  Done.");
+
+    Ok(())
+}
+// ! [Using ThreadPool in Rust]
@@ -0,0 +1,89 @@
+//! Contains the ThreadPool struct that runs jobs on a fixed number of worker
+//! threads.
+
+//-----------------------------------------------------------------------------
+
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex};
+
+use super::threadpool_worker::{Job, Worker};
+
+//-----------------------------------------------------------------------------
+
+/// Represents a pool of worker threads that share a queue of jobs.  Jobs
+/// given to execute() are put on the queue and run by whichever worker is
+/// free next.
+///
+/// Call shutdown() (or drop the pool) to stop taking jobs; the workers finish
+/// every job already queued and then stop.
+pub struct ThreadPool {
+    /// The workers.
+    workers: Vec<Worker>,
+    /// The sending end of the job queue.  None once the pool is shut down.
+    job_sender: Option<Sender<Job>>,
+}
+
+impl ThreadPool {
+    /// Constructor.  Starts the worker threads.
+    ///
+    /// # Parameters
+    /// - worker_count
+    ///
+    ///   Number of worker threads to start.  Must be at least 1.
+    ///
+    /// # Returns
+    /// Returns `Ok(ThreadPool)` if all workers started; otherwise, returns
+    /// `Err(String)` describing the problem.
+    pub fn new(worker_count: usize) -> Result<ThreadPool, String> {
+        if worker_count == 0 {
+            return Err(String::from("A thread pool needs at least one worker"));
+        }
+        let (job_sender, job_receiver) = mpsc::channel::<Job>();
+        let job_queue = Arc::new(Mutex::new(job_receiver));
+        let mut workers = Vec::with_capacity(worker_count);
+        for id in 1..=worker_count {
+            workers.push(Worker::new(id, job_queue.clone())?);
+        }
+        Ok(ThreadPool { workers, job_sender: Some(job_sender) })
+    }
+
+    /// Queue a job to run on the next free worker.
+    ///
+    /// # Parameters
+    /// - job
+    ///
+    ///   The job to run.  It is passed the identifier of the worker running
+    ///   it.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the job was queued; otherwise, returns
+    /// `Err(String)` if the pool has been shut down.
+    pub fn execute<F>(&self, job: F) -> Result<(), String>
+    where F: FnOnce(usize) + Send + 'static {
+        match &self.job_sender {
+            Some(sender) => sender.send(Box::new(job))
+                .map_err(|_| String::from("All workers have stopped")),
+            None => Err(String::from("The thread pool has been shut down")),
+        }
+    }
+
+    /// Stop taking jobs, let the workers finish the jobs already queued, and
+    /// wait for the workers to stop.  Does nothing if already shut down.
+    ///
+    /// # Returns
+    /// Returns a list of (worker identifier, number of jobs run) pairs, one
+    /// for each worker stopped by this call.
+    pub fn shutdown(&mut self) -> Vec<(usize, usize)> {
+        // Closing the queue tells each worker to stop once the queue is empty.
+        drop(self.job_sender.take());
+        self.workers.iter_mut()
+            .filter_map(|worker| worker.join().map(|jobs_run| (worker.id, jobs_run)))
+            .collect()
+    }
+}
+
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
@@ -0,0 +1,77 @@
+//! Contains the Worker struct that runs jobs from the ThreadPool's job queue
+//! on its own thread.
+
+//-----------------------------------------------------------------------------
+
+use std::sync::mpsc::Receiver;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+use crate::helpers::profiler::profile_span;
+
+//-----------------------------------------------------------------------------
+
+/// A unit of work to run on a worker thread.
+pub type Job = Box<dyn FnOnce(usize) + Send + 'static>;
+
+/// Represents a thread that takes jobs from a shared queue and runs them one
+/// at a time until the queue is closed.
+pub struct Worker {
+    /// Identifier of this worker, passed to each job it runs.
+    pub id: usize,
+    /// The worker's thread.  None once the thread has been joined.
+    thread: Option<JoinHandle<usize>>,
+}
+
+impl Worker {
+    /// Constructor.  Starts the worker's thread.
+    ///
+    /// # Parameters
+    /// - id
+    ///
+    ///   Identifier of the worker.
+    /// - job_queue
+    ///
+    ///   The receiving end of the job queue, shared by all workers.
+    ///
+    /// # Returns
+    /// Returns `Ok(Worker)` if the thread was started; otherwise, returns
+    /// `Err(String)` describing the problem.
+    pub fn new(id: usize, job_queue: Arc<Mutex<Receiver<Job>>>) -> Result<Worker, String> {
+        let thread = thread::Builder::new()
+            .name(format!("worker-{id}"))
+            .spawn(move || {
+                let mut jobs_run = 0;
+                loop {
+                    // Hold the lock only long enough to take the next job so
+                    // other workers can take jobs while this one runs.
+                    let next_job = match job_queue.lock() {
+                        Ok(receiver) => receiver.recv(),
+                        Err(_) => break,
+                    };
+                    match next_job {
+                        Ok(job) => {
+                            let _span = profile_span("job");
+                            job(id);
+                            jobs_run += 1;
+                        }
+                        // The queue was closed and is empty: time to stop.
+                        Err(_) => break,
+                    }
+                }
+                jobs_run
+            })
+            .map_err(|error| format!("Unable to start worker {id}: {error}"))?;
+        Ok(Worker { id, thread: Some(thread) })
+    }
+
+    /// Wait for the worker's thread to stop.  The thread stops once the job
+    /// queue has been closed and emptied.
+    ///
+    /// # Returns
+    /// Returns the number of jobs the worker ran, or None if the thread was
+    /// already joined or panicked.
+    pub fn join(&mut self) -> Option<usize> {
+        self.thread.take().and_then(|thread| thread.join().ok())
+    }
+}
@@ -12,6 +12,10 @@
 //! visitor to order ingredients from other shops.  This approach assumes no
 //! two shops sell the same thing.
 //!
+//! Afterwards, a fulfillment report is built from the order, describing every
+//! item the shops handed over to fulfill it.  The report is then displayed in
+//! Markdown and in plain text by two different report renderers.
+//!
 //! Accessed through the visitor_exercise() function.
 
 //-----------------------------------------------------------------------------
@@ -19,11 +23,15 @@
 pub mod visitor_ordervisitor;
 pub mod visitor_village;
 pub mod visitor_shop;
+pub mod visitor_fulfillmentreport;
+pub mod visitor_reportrenderers;
 
 //-----------------------------------------------------------------------------
 
 use visitor_village::Village;
 use visitor_ordervisitor::OrderVisitor;
+use visitor_fulfillmentreport::FulfillmentReport;
+use visitor_reportrenderers::{IReportRenderer, MarkdownReportRenderer, PlainTextReportRenderer};
 
 //-----------------------------------------------------------------------------
 
@@ -40,6 +48,10 @@ use visitor_ordervisitor::OrderVisitor;
 /// the way, shops that don't have the necessary ingredients use another order
 /// visitor to order ingredients from other shops.  This approach assumes no
 /// two shops sell the same thing.
+///
+/// Afterwards, a fulfillment report is built from the order, describing every
+/// item the shops handed over to fulfill it.  The report is then displayed in
+/// Markdown and in plain text by two different report renderers.
 // ! [Using Visitor in Rust]
 pub fn visitor_exercise() -> Result<(), String> {
     println!("");
@@ -60,6 +72,18 @@ pub fn visitor_exercise() -> Result<(), String> {
         println!("  Failed to receive a hamburger");
     }
 
+    let report = FulfillmentReport::new(&village.name, &order);
+    let renderers: Vec<Box<dyn IReportRenderer>> = vec![
+        MarkdownReportRenderer::new(),
+        PlainTextReportRenderer::new(),
+    ];
+    for renderer in renderers.iter() {
+        println!("  Fulfillment report rendered as {0}:", renderer.get_format_name());
+        for line in renderer.render(&report) {
+            println!("    {line}");
+        }
+    }
+
     println!("  Done.");
 
     Ok(())
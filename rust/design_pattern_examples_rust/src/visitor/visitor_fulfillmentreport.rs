@@ -0,0 +1,67 @@
+//! Contains the FulfillmentReport struct that describes how an order was
+//! fulfilled by the shops in the village, along with the FulfilledItem
+//! struct that describes each item handed over along the way.
+
+//-----------------------------------------------------------------------------
+
+use super::visitor_ordervisitor::OrderVisitor;
+
+//-----------------------------------------------------------------------------
+
+/// Represents one item handed over by a shop while an order was being
+/// fulfilled, either to the customer or to another shop that needed it as an
+/// ingredient.
+#[derive(Clone)]
+pub struct FulfilledItem {
+    /// Name of the item.
+    pub item: String,
+    /// Name of the shop that handed over the item.
+    pub shop_name: String,
+    /// Address of the shop that handed over the item.
+    pub shop_address: String,
+    /// Ingredients the shop needs to make the item.  Empty if the item is
+    /// made without any ingredients.
+    pub ingredients: Vec<String>,
+}
+
+/// Represents how an order was fulfilled: what was ordered, what was
+/// received and every item handed over by a shop along the way, in the order
+/// the items were handed over.  As a shop picks up the ingredients it needs
+/// before it hands over the item made from them, each item appears after its
+/// ingredients.
+///
+/// The report is built from the OrderVisitor once the order is complete; how
+/// the report is displayed is up to an IReportRenderer.
+pub struct FulfillmentReport {
+    /// Name of the village the order was placed in.
+    pub village_name: String,
+    /// The items that were ordered.
+    pub items_ordered: Vec<String>,
+    /// The items that were received.
+    pub items_received: Vec<String>,
+    /// Every item handed over by a shop while the order was fulfilled.
+    pub fulfilled_items: Vec<FulfilledItem>,
+}
+
+impl FulfillmentReport {
+    /// Constructor
+    ///
+    /// # Parameters
+    /// - village_name
+    ///
+    ///   Name of the village the order was placed in.
+    /// - order
+    ///
+    ///   The OrderVisitor object that carried out the order.
+    ///
+    /// # Returns
+    /// Returns a new instance of the FulfillmentReport struct.
+    pub fn new(village_name: &str, order: &OrderVisitor) -> FulfillmentReport {
+        FulfillmentReport {
+            village_name: village_name.to_string(),
+            items_ordered: order.items_to_order.clone(),
+            items_received: order.items_received.clone(),
+            fulfilled_items: order.fulfilled_items.clone(),
+        }
+    }
+}
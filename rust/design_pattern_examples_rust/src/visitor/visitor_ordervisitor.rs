@@ -3,6 +3,7 @@
 
 //-----------------------------------------------------------------------------
 
+use super::visitor_fulfillmentreport::FulfilledItem;
 use super::visitor_shop::VisitorShop;
 use super::visitor_village::Village;
 
@@ -21,6 +22,10 @@ pub struct OrderVisitor {
     pub items_received: Vec<String>,
     /// Name of the shop that provided the item(s).
     pub shop_name_received_from: String,
+    /// Every item handed over by a shop while this order was fulfilled,
+    /// including the ingredients ordered by other shops, in the order the
+    /// items were handed over.
+    pub fulfilled_items: Vec<FulfilledItem>,
 }
 
 impl OrderVisitor {
@@ -33,6 +38,7 @@ impl OrderVisitor {
             items_to_order: items_to_order.clone(),
             items_received: vec![],
             shop_name_received_from: String::new(),
+            fulfilled_items: vec![],
         }
     }
 
@@ -0,0 +1,124 @@
+//! Contains the IReportRenderer trait that represents a strategy for
+//! displaying a FulfillmentReport, along with the MarkdownReportRenderer and
+//! PlainTextReportRenderer structs that implement the trait.
+
+//-----------------------------------------------------------------------------
+
+use super::visitor_fulfillmentreport::{FulfilledItem, FulfillmentReport};
+
+//-----------------------------------------------------------------------------
+
+/// Represents a way of rendering a FulfillmentReport as text.  The report is
+/// built once from the order and any renderer can then display it.
+pub trait IReportRenderer {
+    /// Retrieve the name of the format produced by this renderer.
+    fn get_format_name(&self) -> &str;
+
+    /// Render the given report.
+    ///
+    /// # Parameters
+    /// - report
+    ///
+    ///   The report to render.
+    ///
+    /// # Returns
+    /// Returns a list of strings, one for each line of the rendered report.
+    fn render(&self, report: &FulfillmentReport) -> Vec<String>;
+}
+
+/// Helper function to describe what a fulfilled item is made from.
+///
+/// # Parameters
+/// - fulfilled_item
+///
+///   The item to describe.
+///
+/// # Returns
+/// Returns the ingredients of the item as a comma-delimited string, or
+/// "(nothing)" if the item is made without any ingredients.
+fn _made_from(fulfilled_item: &FulfilledItem) -> String {
+    match fulfilled_item.ingredients.is_empty() {
+        true => String::from("(nothing)"),
+        false => fulfilled_item.ingredients.join(", "),
+    }
+}
+
+//#############################################################################
+//#############################################################################
+
+/// Renders a report as Markdown, with a summary of the order followed by a
+/// table of the items handed over by the shops.
+pub struct MarkdownReportRenderer {}
+
+impl MarkdownReportRenderer {
+    /// Constructor
+    ///
+    /// # Returns
+    /// Returns a new instance of the MarkdownReportRenderer struct as
+    /// represented by the IReportRenderer trait.
+    pub fn new() -> Box<dyn IReportRenderer> {
+        Box::new(MarkdownReportRenderer {})
+    }
+}
+
+impl IReportRenderer for MarkdownReportRenderer {
+    fn get_format_name(&self) -> &str {
+        "Markdown"
+    }
+
+    fn render(&self, report: &FulfillmentReport) -> Vec<String> {
+        let mut lines = vec![
+            format!("# Order fulfilled in the {}", report.village_name),
+            String::new(),
+            format!("- **Ordered:** {}", report.items_ordered.join(", ")),
+            format!("- **Received:** {}", report.items_received.join(", ")),
+            String::new(),
+            String::from("| Step | Item | Shop | Address | Made from |"),
+            String::from("|-----:|------|------|---------|-----------|"),
+        ];
+        for (index, fulfilled_item) in report.fulfilled_items.iter().enumerate() {
+            lines.push(format!("| {} | {} | {} | {} | {} |", index + 1, fulfilled_item.item,
+                fulfilled_item.shop_name, fulfilled_item.shop_address, _made_from(fulfilled_item)));
+        }
+        lines
+    }
+}
+
+//#############################################################################
+//#############################################################################
+
+/// Renders a report as plain text, with a numbered line for each item handed
+/// over by the shops.
+pub struct PlainTextReportRenderer {}
+
+impl PlainTextReportRenderer {
+    /// Constructor
+    ///
+    /// # Returns
+    /// Returns a new instance of the PlainTextReportRenderer struct as
+    /// represented by the IReportRenderer trait.
+    pub fn new() -> Box<dyn IReportRenderer> {
+        Box::new(PlainTextReportRenderer {})
+    }
+}
+
+impl IReportRenderer for PlainTextReportRenderer {
+    fn get_format_name(&self) -> &str {
+        "plain text"
+    }
+
+    fn render(&self, report: &FulfillmentReport) -> Vec<String> {
+        let title = format!("ORDER FULFILLED IN THE {}", report.village_name.to_uppercase());
+        let mut lines = vec![
+            title.clone(),
+            "=".repeat(title.len()),
+            format!("Ordered:  {}", report.items_ordered.join(", ")),
+            format!("Received: {}", report.items_received.join(", ")),
+        ];
+        for (index, fulfilled_item) in report.fulfilled_items.iter().enumerate() {
+            lines.push(format!("{:>3}. {:<18} from {} ({}), made from {}", index + 1, fulfilled_item.item,
+                fulfilled_item.shop_name, fulfilled_item.shop_address, _made_from(fulfilled_item)));
+        }
+        lines
+    }
+}
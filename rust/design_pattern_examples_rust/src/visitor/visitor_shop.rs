@@ -5,6 +5,7 @@
 
 use std::collections::HashMap;
 
+use super::visitor_fulfillmentreport::FulfilledItem;
 use super::visitor_ordervisitor::OrderVisitor;
 use super::visitor_village::Village;
 
@@ -194,6 +195,7 @@ impl VisitorShop {
                             self.name, item);
                         let mut reorder_visitor = OrderVisitor::new(&items);
                         village.visit(&mut reorder_visitor);
+                        order.fulfilled_items.append(&mut reorder_visitor.fulfilled_items);
                         if are_vector_contents_the_same(&reorder_visitor.items_received, &items) {
                             self.add_item_to_inventory(item);
                         } else {
@@ -245,6 +247,12 @@ impl VisitorShop {
                 if self.does_shop_sell_item(&item) {
                     self.inventory.entry(item.clone()).and_modify(|e| { *e -= 1 });
                     items_received.push(item.clone());
+                    order.fulfilled_items.push(FulfilledItem {
+                        item: item.clone(),
+                        shop_name: self.name.clone(),
+                        shop_address: self.address.clone(),
+                        ingredients: self.ingredients_for_items[item].clone(),
+                    });
                 }
             }
             let items_as_string = stringize_list(&items_received);